@@ -9,48 +9,200 @@ use twilight_gateway::Session;
 
 use crate::{key::RedisKey, model::CachedArchive, Cache};
 
-const STORE_DURATION: usize = 240;
-
 impl Cache {
-    pub async fn freeze<S>(&self, resume_data: &HashMap<u64, Session, S>) -> Result<()> {
-        let resume_data = With::<_, SessionsRkyv>::cast(resume_data);
-        let bytes =
-            rkyv::to_bytes::<_, 128>(resume_data).wrap_err("Failed to serialize resume data")?;
-
-        self.connection()
-            .await?
-            .set_ex(RedisKey::resume_data(), bytes.as_slice(), STORE_DURATION)
-            .await
-            .wrap_err("Failed to store resume data bytes")?;
+    /// Store the resume data for `duration` seconds.
+    pub async fn freeze<S>(
+        &self,
+        resume_data: &HashMap<u64, Session, S>,
+        duration: usize,
+    ) -> Result<()> {
+        let mut conn = self.connection().await?;
 
-        info!("Successfully froze cache for {STORE_DURATION} seconds");
+        freeze(&mut *conn, resume_data, duration).await?;
+        info!("Successfully froze cache for {duration} seconds");
 
         Ok(())
     }
 
+    /// Retrieve the stored resume data.
+    ///
+    /// If there is no resume data e.g. because it expired, the database is
+    /// flushed to start with a fresh cache. Failing to read the resume data
+    /// leaves the database untouched.
     pub async fn defrost<S: BuildHasher + Default>(&self) -> Result<HashMap<u64, Session, S>> {
         let mut conn = self.connection().await?;
 
-        let resume_data_opt: Option<CachedArchive<HashMap<u64, Session, S>>> = conn
-            .get(RedisKey::resume_data())
-            .await
-            .wrap_err("Failed to get stored resume data")?;
+        defrost(&mut *conn).await
+    }
+}
+
+async fn freeze<C, S>(
+    conn: &mut C,
+    resume_data: &HashMap<u64, Session, S>,
+    duration: usize,
+) -> Result<()>
+where
+    C: ConnectionLike + Send,
+{
+    let resume_data = With::<_, SessionsRkyv>::cast(resume_data);
+    let bytes =
+        rkyv::to_bytes::<_, 128>(resume_data).wrap_err("Failed to serialize resume data")?;
+
+    conn.set_ex(RedisKey::resume_data(), bytes.as_slice(), duration)
+        .await
+        .wrap_err("Failed to store resume data bytes")
+}
+
+async fn defrost<C, S>(conn: &mut C) -> Result<HashMap<u64, Session, S>>
+where
+    C: ConnectionLike + Send,
+    S: BuildHasher + Default,
+{
+    let resume_data_opt: Option<CachedArchive<HashMap<u64, Session, S>>> = conn
+        .get(RedisKey::resume_data())
+        .await
+        .wrap_err("Failed to get stored resume data")?;
+
+    if let Some(resume_data) = resume_data_opt {
+        info!("Successfully defrosted cache");
+
+        return Ok(resume_data.deserialize_with::<SessionsRkyv>());
+    }
+
+    // Expired resume data should not prevent starting up
+    let mut cmd = Cmd::new();
+    cmd.arg("FLUSHDB");
+
+    conn.req_packed_command(&cmd)
+        .await
+        .wrap_err("Failed to flush redis entries")?;
+
+    info!("Empty resume data, starting with fresh cache");
+
+    Ok(HashMap::with_hasher(S::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::RandomState;
+
+    use bb8_redis::redis::{Arg, ErrorKind, RedisError, RedisFuture, RedisResult, Value};
+
+    use super::*;
+
+    /// In-memory stand-in for a redis connection that only knows the
+    /// commands used for cold resumes.
+    #[derive(Default)]
+    struct MockRedis {
+        entries: HashMap<Vec<u8>, Vec<u8>>,
+        fail_reads: bool,
+        flushes: usize,
+    }
+
+    impl MockRedis {
+        fn execute(&mut self, cmd: &Cmd) -> RedisResult<Value> {
+            let mut args = cmd.args_iter().filter_map(|arg| match arg {
+                Arg::Simple(arg) => Some(arg.to_vec()),
+                Arg::Cursor => None,
+            });
 
-        if let Some(resume_data) = resume_data_opt {
-            info!("Successfully defrosted cache");
+            let name = args.next().unwrap_or_default().to_ascii_uppercase();
 
-            return Ok(resume_data.deserialize_with::<SessionsRkyv>());
+            match name.as_slice() {
+                b"GET" if self.fail_reads => {
+                    Err(RedisError::from((ErrorKind::IoError, "connection dropped")))
+                }
+                b"GET" => {
+                    let key = args.next().unwrap_or_default();
+
+                    Ok(self.entries.get(&key).cloned().map_or(Value::Nil, Value::Data))
+                }
+                b"SETEX" => {
+                    let key = args.next().unwrap_or_default();
+                    let _seconds = args.next();
+                    let value = args.next().unwrap_or_default();
+                    self.entries.insert(key, value);
+
+                    Ok(Value::Okay)
+                }
+                b"FLUSHDB" => {
+                    self.entries.clear();
+                    self.flushes += 1;
+
+                    Ok(Value::Okay)
+                }
+                _ => Err(RedisError::from((ErrorKind::ClientError, "unsupported command"))),
+            }
+        }
+    }
+
+    impl ConnectionLike for MockRedis {
+        fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+            let res = self.execute(cmd);
+
+            Box::pin(async move { res })
+        }
+
+        fn req_packed_commands<'a>(
+            &'a mut self,
+            _: &'a bb8_redis::redis::Pipeline,
+            _: usize,
+            _: usize,
+        ) -> RedisFuture<'a, Vec<Value>> {
+            Box::pin(async move {
+                Err(RedisError::from((ErrorKind::ClientError, "unsupported pipeline")))
+            })
+        }
+
+        fn get_db(&self) -> i64 {
+            0
         }
+    }
+
+    fn sessions() -> HashMap<u64, Session> {
+        (0..3)
+            .map(|shard_id| {
+                let session = Session::new(shard_id * 100, format!("session_{shard_id}"));
+
+                (shard_id, session)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn freeze_defrost_round_trip() {
+        let mut redis = MockRedis::default();
+        let resume_data = sessions();
+
+        freeze(&mut redis, &resume_data, 240).await.unwrap();
+        let defrosted: HashMap<u64, Session, RandomState> = defrost(&mut redis).await.unwrap();
 
-        let mut cmd = Cmd::new();
-        cmd.arg("FLUSHDB");
+        assert_eq!(defrosted, resume_data);
+        assert_eq!(redis.flushes, 0);
+    }
+
+    #[tokio::test]
+    async fn missing_resume_data_flushes() {
+        let mut redis = MockRedis::default();
+        redis.entries.insert(b"other".to_vec(), b"data".to_vec());
+
+        let defrosted: HashMap<u64, Session, RandomState> = defrost(&mut redis).await.unwrap();
+
+        assert!(defrosted.is_empty());
+        assert_eq!(redis.flushes, 1);
+        assert!(redis.entries.is_empty());
+    }
 
-        conn.req_packed_command(&cmd)
-            .await
-            .wrap_err("Failed to flush redis entries")?;
+    #[tokio::test]
+    async fn failed_read_keeps_data() {
+        let mut redis = MockRedis::default();
+        freeze(&mut redis, &sessions(), 240).await.unwrap();
+        redis.fail_reads = true;
 
-        info!("Empty resume data, starting with fresh cache");
+        let res: Result<HashMap<u64, Session, RandomState>> = defrost(&mut redis).await;
 
-        Ok(HashMap::with_hasher(S::default()))
+        assert!(res.is_err());
+        assert_eq!(redis.flushes, 0);
+        assert_eq!(redis.entries.len(), 1);
     }
 }
@@ -7,9 +7,25 @@ use twilight_model::id::{
     Id,
 };
 
-use crate::{key::RedisKey, model::CacheChange, Cache};
+use crate::{
+    key::{RedisKey, ToCacheKey},
+    model::CacheChange,
+    Cache,
+};
 
 impl Cache {
+    /// Removes the entry for the given key if there is one.
+    pub async fn delete<K>(&self, key: &K) -> Result<()>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        self.connection()
+            .await?
+            .del(RedisKey::from(key))
+            .await
+            .wrap_err("Failed to delete entry")
+    }
+
     pub(crate) async fn delete_channel(
         &self,
         guild: Option<Id<GuildMarker>>,
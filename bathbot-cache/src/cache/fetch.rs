@@ -32,6 +32,19 @@ impl Cache {
             .wrap_err("Failed to fetch stored data")
     }
 
+    /// Checks whether an entry for the given key exists.
+    #[inline]
+    pub async fn contains<K>(&self, key: &K) -> Result<bool>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        self.connection()
+            .await?
+            .exists(RedisKey::from(key))
+            .await
+            .wrap_err("Failed to check existence of key")
+    }
+
     #[inline]
     pub async fn channel(
         &self,
@@ -72,6 +85,15 @@ impl Cache {
             .wrap_err("Failed to get member ids")
     }
 
+    #[inline]
+    pub async fn guild_roles(&self, guild: Id<GuildMarker>) -> Result<Vec<u64>> {
+        self.connection()
+            .await?
+            .smembers(RedisKey::guild_roles(guild))
+            .await
+            .wrap_err("Failed to get role ids")
+    }
+
     #[inline]
     pub async fn member(
         &self,
@@ -20,6 +20,8 @@ pub enum CardError {
     Info(#[from] InfoError),
     #[error("Failed to draw footer")]
     Footer(#[from] FooterError),
+    #[error("Failed to draw profile card")]
+    Profile(#[from] ProfileError),
     #[error("Failed to encode as PNG")]
     EncodeAsPng,
 }
@@ -90,6 +92,16 @@ pub enum FooterError {
     BrandingSvg(#[source] SvgError),
 }
 
+#[derive(Debug, ThisError)]
+pub enum ProfileError {
+    #[error("Failed to create avatar image")]
+    Avatar,
+    #[error("Failed to create flag image")]
+    Flag,
+    #[error("Font error")]
+    Font(#[from] FontError),
+}
+
 #[derive(Debug, ThisError)]
 pub enum SvgError {
     #[error("Failed to create path")]
@@ -2,7 +2,11 @@ mod builder;
 mod card;
 mod error;
 mod font;
+mod profile;
 mod skills;
 mod svg;
 
-pub use self::card::{BathbotCard, RequiredAttributes};
+pub use self::{
+    card::{BathbotCard, RequiredAttributes},
+    profile::ProfileCard,
+};
@@ -0,0 +1,346 @@
+use std::path::PathBuf;
+
+use skia_safe::{
+    font_style::Slant, surfaces, utils::text_utils::Align, Canvas, ClipOp, Color, Data,
+    EncodedImageFormat, Image, Paint, RRect, Rect,
+};
+
+use crate::{
+    builder::{font::FontBuilder, paint::PaintBuilder},
+    error::{CardError, ProfileError},
+    font::FontData,
+};
+
+const W: i32 = 900;
+const H: i32 = 320;
+const PAD: i32 = 24;
+const AVATAR_SIZE: i32 = H;
+const RIGHT_X: i32 = AVATAR_SIZE + PAD;
+const RIGHT_W: i32 = W - RIGHT_X - PAD;
+const COLUMN_W: i32 = RIGHT_W / 3;
+const FLAG_H: i32 = 40;
+const BAR_Y: i32 = 236;
+const BAR_H: i32 = 20;
+
+/// Colors for SSH, SS, SH, S, and A in that order.
+const GRADE_COLORS: [(u8, u8, u8); 5] = [
+    (224, 230, 235),
+    (255, 215, 0),
+    (176, 190, 197),
+    (240, 160, 40),
+    (136, 218, 32),
+];
+
+const GRADE_NAMES: [&str; 5] = ["SSH", "SS", "SH", "S", "A"];
+
+/// Compact image of a user's profile.
+///
+/// Contrary to [`BathbotCard`](crate::BathbotCard) it requires no top scores
+/// and optional values like the flag or the peak rank are simply left out if
+/// they're missing.
+pub struct ProfileCard<'a> {
+    username: &'a str,
+    pfp: &'a [u8],
+    flag: Option<&'a [u8]>,
+    global_rank: u32,
+    country_rank: u32,
+    peak_rank: Option<u32>,
+    pp: f32,
+    accuracy: f32,
+    grades: [u32; 5],
+    assets: PathBuf,
+}
+
+impl<'a> ProfileCard<'a> {
+    pub fn new(username: &'a str, pfp: &'a [u8], assets: PathBuf) -> Self {
+        Self {
+            username,
+            pfp,
+            flag: None,
+            global_rank: 0,
+            country_rank: 0,
+            peak_rank: None,
+            pp: 0.0,
+            accuracy: 0.0,
+            grades: [0; 5],
+            assets,
+        }
+    }
+
+    pub fn flag(mut self, flag: Option<&'a [u8]>) -> Self {
+        self.flag = flag;
+
+        self
+    }
+
+    pub fn ranks(mut self, global_rank: u32, country_rank: u32, peak_rank: Option<u32>) -> Self {
+        self.global_rank = global_rank;
+        self.country_rank = country_rank;
+        self.peak_rank = peak_rank;
+
+        self
+    }
+
+    pub fn performance(mut self, pp: f32, accuracy: f32) -> Self {
+        self.pp = pp;
+        self.accuracy = accuracy;
+
+        self
+    }
+
+    /// Amount of SSH, SS, SH, S, and A grades in that order.
+    pub fn grades(mut self, grades: [u32; 5]) -> Self {
+        self.grades = grades;
+
+        self
+    }
+
+    /// Draws the card as PNG.
+    ///
+    /// This is CPU-bound so it should be called inside of `spawn_blocking`.
+    pub fn draw(&self) -> Result<Vec<u8>, CardError> {
+        let fonts = FontData::new(self.assets.clone())?;
+        let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
+        let canvas = surface.canvas();
+
+        canvas.clear(Color::from_rgb(43, 45, 49));
+
+        self.draw_pfp(canvas)?;
+        self.draw_name(canvas, &fonts)?;
+
+        if let Some(flag) = self.flag {
+            draw_flag(canvas, flag)?;
+        }
+
+        let global_rank = format!("#{}", self.global_rank);
+        let country_rank = format!("#{}", self.country_rank);
+        let peak_rank = self.peak_rank.map(|rank| format!("#{rank}"));
+        let pp = format!("{:.0}pp", self.pp);
+        let accuracy = format!("{:.2}%", self.accuracy);
+
+        let mut upper = vec![("Global", global_rank), ("Country", country_rank)];
+
+        if let Some(peak_rank) = peak_rank {
+            upper.push(("Peak", peak_rank));
+        }
+
+        let lower = [("PP", pp), ("Accuracy", accuracy)];
+
+        draw_stats(canvas, &upper, 108, &fonts)?;
+        draw_stats(canvas, &lower, 180, &fonts)?;
+        self.draw_grades(canvas, &fonts)?;
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .map(|png_data| png_data.as_bytes().to_vec())
+            .ok_or(CardError::EncodeAsPng)
+    }
+
+    fn draw_pfp(&self, canvas: &mut Canvas) -> Result<(), ProfileError> {
+        // SAFETY: `self.pfp` has a longer lifetime than `Data`
+        let data = unsafe { Data::new_bytes(self.pfp) };
+        let img = Image::from_encoded_with_alpha_type(data, None).ok_or(ProfileError::Avatar)?;
+
+        let rect = Rect::new(0.0, 0.0, AVATAR_SIZE as f32, AVATAR_SIZE as f32);
+        let rrect = RRect::new_rect_xy(rect, 16.0, 16.0);
+
+        canvas.save();
+
+        canvas
+            .clip_rrect(rrect, Some(ClipOp::Intersect), Some(true))
+            .draw_image_rect(&img, None, rect, &Paint::default())
+            .restore();
+
+        Ok(())
+    }
+
+    fn draw_name(&self, canvas: &mut Canvas, fonts: &FontData) -> Result<(), ProfileError> {
+        let paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+        let font = FontBuilder::build(700, Slant::Upright, fonts, 48.0)?;
+
+        let pos = (RIGHT_X as f32, (PAD + 44) as f32);
+        canvas.draw_str(self.username, pos, &font, &paint);
+
+        Ok(())
+    }
+
+    fn draw_grades(&self, canvas: &mut Canvas, fonts: &FontData) -> Result<(), ProfileError> {
+        let segments = grade_segments(self.grades, RIGHT_W as u32);
+        let mut x = RIGHT_X as f32;
+
+        for (width, (r, g, b)) in segments.into_iter().zip(GRADE_COLORS) {
+            let rect = Rect::from_xywh(x, BAR_Y as f32, width as f32, BAR_H as f32);
+            canvas.draw_rect(rect, &PaintBuilder::rgb(r, g, b).build());
+            x += width as f32;
+        }
+
+        let font = FontBuilder::build(500, Slant::Upright, fonts, 24.0)?;
+        let column_w = RIGHT_W as f32 / 5.0;
+        let pos_y = (BAR_Y + BAR_H + 40) as f32;
+
+        for (i, ((name, count), (r, g, b))) in GRADE_NAMES
+            .iter()
+            .zip(self.grades)
+            .zip(GRADE_COLORS)
+            .enumerate()
+        {
+            let paint = PaintBuilder::rgb(r, g, b).anti_alias().build();
+            let pos_x = RIGHT_X as f32 + i as f32 * column_w;
+            canvas.draw_str(format!("{name} {count}"), (pos_x, pos_y), &font, &paint);
+        }
+
+        Ok(())
+    }
+}
+
+fn draw_flag(canvas: &mut Canvas, flag: &[u8]) -> Result<(), ProfileError> {
+    // SAFETY: `flag` has a longer lifetime than `Data`
+    let data = unsafe { Data::new_bytes(flag) };
+    let img = Image::from_encoded_with_alpha_type(data, None).ok_or(ProfileError::Flag)?;
+
+    let flag_w = FLAG_H as f32 * img.width() as f32 / img.height().max(1) as f32;
+    let rect = Rect::from_xywh(
+        (W - PAD) as f32 - flag_w,
+        PAD as f32 + 8.0,
+        flag_w,
+        FLAG_H as f32,
+    );
+
+    canvas.draw_image_rect(&img, None, rect, &Paint::default());
+
+    Ok(())
+}
+
+/// Draws labels with their values next to each other, starting at `pos_y`.
+fn draw_stats(
+    canvas: &mut Canvas,
+    stats: &[(&str, String)],
+    pos_y: i32,
+    fonts: &FontData,
+) -> Result<(), ProfileError> {
+    let paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let label_font = FontBuilder::build(300, Slant::Italic, fonts, 24.0)?;
+    let value_font = FontBuilder::build(900, Slant::Upright, fonts, 36.0)?;
+
+    for (i, (label, value)) in stats.iter().enumerate() {
+        let pos_x = (RIGHT_X + i as i32 * COLUMN_W) as f32;
+
+        canvas.draw_str_align(
+            label,
+            (pos_x, pos_y as f32),
+            &label_font,
+            &paint,
+            Align::Left,
+        );
+
+        canvas.draw_str_align(
+            value,
+            (pos_x, (pos_y + 38) as f32),
+            &value_font,
+            &paint,
+            Align::Left,
+        );
+    }
+
+    Ok(())
+}
+
+/// Splits `width` into segments proportional to the grade counts.
+///
+/// Rounding leftovers are added to the last non-empty segment so the bar is
+/// always filled completely. Without any grades, all segments are empty.
+fn grade_segments(grades: [u32; 5], width: u32) -> [u32; 5] {
+    let total: u64 = grades.iter().map(|&count| count as u64).sum();
+    let mut segments = [0; 5];
+
+    if total == 0 {
+        return segments;
+    }
+
+    for (segment, &count) in segments.iter_mut().zip(grades.iter()) {
+        *segment = (count as u64 * width as u64 / total) as u32;
+    }
+
+    let used: u32 = segments.iter().sum();
+
+    if let Some(last) = segments.iter_mut().rev().find(|segment| **segment > 0) {
+        *last += width - used;
+    } else if let Some(idx) = grades.iter().rposition(|&count| count > 0) {
+        segments[idx] = width;
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(w: i32, h: i32, color: Color) -> Vec<u8> {
+        let mut surface = surfaces::raster_n32_premul((w, h)).unwrap();
+        surface.canvas().clear(color);
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .unwrap()
+            .as_bytes()
+            .to_vec()
+    }
+
+    fn assets() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets")
+    }
+
+    fn decode(bytes: Vec<u8>) -> Image {
+        Image::from_encoded(Data::new_copy(&bytes)).unwrap()
+    }
+
+    #[test]
+    fn grade_segments_fill_bar() {
+        assert_eq!(grade_segments([0; 5], 240), [0; 5]);
+        assert_eq!(grade_segments([1, 1, 1, 0, 0], 100), [33, 33, 34, 0, 0]);
+        assert_eq!(grade_segments([0, 0, 0, 0, 7], 240), [0, 0, 0, 0, 240]);
+
+        let segments = grade_segments([3, 250, 1, 1000, 2000], 240);
+        assert_eq!(segments.iter().sum::<u32>(), 240);
+    }
+
+    #[test]
+    fn renders_full_card() {
+        let pfp = png(256, 256, Color::RED);
+        let flag = png(72, 48, Color::BLUE);
+
+        let bytes = ProfileCard::new("Badewanne3", &pfp, assets())
+            .flag(Some(&flag))
+            .ranks(1234, 56, Some(789))
+            .performance(9876.5, 98.76)
+            .grades([12, 34, 56, 78, 90])
+            .draw()
+            .unwrap();
+
+        let img = decode(bytes);
+        assert_eq!((img.width(), img.height()), (W, H));
+    }
+
+    #[test]
+    fn renders_without_optional_values() {
+        let pfp = png(128, 64, Color::GREEN);
+
+        let bytes = ProfileCard::new("peppy", &pfp, assets())
+            .ranks(0, 0, None)
+            .draw()
+            .unwrap();
+
+        let img = decode(bytes);
+        assert_eq!((img.width(), img.height()), (W, H));
+    }
+
+    #[test]
+    fn invalid_avatar_errors() {
+        let res = ProfileCard::new("peppy", b"not an image", assets()).draw();
+
+        assert!(matches!(res, Err(CardError::Profile(ProfileError::Avatar))));
+    }
+}
@@ -24,7 +24,7 @@ pub struct Client {
     #[cfg(feature = "twitch")]
     twitch: bathbot_model::TwitchData,
     github_auth: Box<str>,
-    ratelimiters: [LeakyBucket; 17],
+    ratelimiters: [LeakyBucket; 18],
 }
 
 impl Client {
@@ -60,6 +60,7 @@ impl Client {
 
         let ratelimiters = [
             ratelimiter(2),  // DiscordAttachment
+            ratelimiter(5),  // DiscordEmoji
             ratelimiter(10), // Flags
             ratelimiter(5),  // Github
             ratelimiter(2),  // Huismetbenen
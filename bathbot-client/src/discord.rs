@@ -10,4 +10,12 @@ impl Client {
             .await
             .map_err(Report::new)
     }
+
+    pub async fn get_discord_emoji(&self, emoji_id: u64) -> Result<Bytes> {
+        let url = format!("https://cdn.discordapp.com/emojis/{emoji_id}.png");
+
+        self.make_get_request(&url, Site::DiscordEmoji)
+            .await
+            .map_err(Report::new)
+    }
 }
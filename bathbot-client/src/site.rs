@@ -2,6 +2,7 @@
 #[repr(u8)]
 pub enum Site {
     DiscordAttachment,
+    DiscordEmoji,
     Flags,
     Github,
     Huismetbenen,
@@ -24,6 +25,7 @@ impl Site {
     pub fn as_str(self) -> &'static str {
         match self {
             Self::DiscordAttachment => "DiscordAttachment",
+            Self::DiscordEmoji => "DiscordEmoji",
             Self::Flags => "Flag",
             Self::Github => "Github",
             Self::Huismetbenen => "Huismetbenen",
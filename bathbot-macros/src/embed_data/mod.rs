@@ -53,7 +53,13 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
 
         match ident_str.as_str() {
             "author" => author = quote!(.author(self.author)),
-            "color" => color = quote!(.color(self.color)),
+            "color" => {
+                color = quote! {
+                    fn color(&self) -> Option<u32> {
+                        Some(self.color)
+                    }
+                }
+            }
             "description" => description = quote!(.description(self.description)),
             "fields" => fields = quote!(.fields(self.fields)),
             "footer" => footer = quote!(.footer(self.footer)),
@@ -75,9 +81,10 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let tokens = quote! {
         impl crate::embeds::EmbedData for #ident {
             fn build(self) -> ::bathbot_util::EmbedBuilder {
-                bathbot_util::EmbedBuilder::new()
+                let color = crate::embeds::EmbedData::color(&self);
+
+                let builder = bathbot_util::EmbedBuilder::new()
                     #author
-                    #color
                     #description
                     #fields
                     #footer
@@ -85,8 +92,15 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
                     #timestamp
                     #title
                     #thumbnail
-                    #url
+                    #url;
+
+                match color {
+                    Some(color) => builder.color(color),
+                    None => builder,
+                }
             }
+
+            #color
         }
     };
 
@@ -69,6 +69,8 @@ pub fn has_mods(input: TokenStream) -> TokenStream {
 /// - `title`
 /// - `thumbnail`
 /// - `url`
+///
+/// A `color` field of type `u32` is used for `EmbedData::color`.
 #[proc_macro_derive(EmbedData)]
 pub fn embed_data(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
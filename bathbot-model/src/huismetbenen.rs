@@ -27,7 +27,7 @@ use time::{
 use twilight_interactions::command::{CommandOption, CreateOption};
 
 use super::deser;
-use crate::KittenRoleplayCountries;
+use crate::{rkyv_util::DerefAsString, KittenRoleplayCountries};
 
 pub struct SnipeScoreParams {
     pub user_id: u32,
@@ -114,6 +114,21 @@ impl SnipeCountryListOrder {
             Self::WeightedPp => "weighted_pp",
         }
     }
+
+    /// Sorts players in descending order w.r.t. this ordering.
+    pub fn sort_players(self, players: &mut [SnipeCountryPlayer]) {
+        match self {
+            Self::Count => players.sort_by(|a, b| b.count_first.cmp(&a.count_first)),
+            Self::AvgPp => players.sort_by(|a, b| {
+                let a = a.avg_pp.unwrap_or(0.0);
+                let b = b.avg_pp.unwrap_or(0.0);
+
+                b.total_cmp(&a)
+            }),
+            Self::AvgStars => players.sort_by(|a, b| b.avg_sr.total_cmp(&a.avg_sr)),
+            Self::WeightedPp => players.sort_by(|a, b| b.pp.total_cmp(&a.pp)),
+        }
+    }
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Debug, Default, Eq, PartialEq)]
@@ -220,8 +235,9 @@ impl SnipePlayerHistory {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Archive, RkyvDeserialize, RkyvSerialize)]
 pub struct SnipeCountryPlayer {
+    #[with(DerefAsString)]
     pub username: Username,
     pub user_id: u32,
     #[serde(rename = "average_pp")]
@@ -19,6 +19,19 @@ pub struct ScoreSlim {
     pub score_id: u64,
     pub legacy_id: Option<u64>,
     pub statistics: LegacyScoreStatistics,
+    /// Only available for osu!standard scores set on lazer
+    pub slider_hits: Option<SliderHits>,
+}
+
+/// Slider judgements of lazer scores that are not part of the legacy
+/// statistics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SliderHits {
+    pub ends: u32,
+    pub max_ends: u32,
+    /// Slider ticks and repeats
+    pub large_ticks: u32,
+    pub max_large_ticks: u32,
 }
 
 impl ScoreSlim {
@@ -34,10 +47,25 @@ impl ScoreSlim {
             score: score.score,
             score_id: score.id,
             legacy_id: score.legacy_score_id,
+            slider_hits: Self::slider_hits(&score),
             statistics: score.statistics.as_legacy(score.mode),
         }
     }
 
+    fn slider_hits(score: &Score) -> Option<SliderHits> {
+        let max_ends = score.maximum_statistics.slider_tail_hit;
+
+        let slider_hits = SliderHits {
+            ends: score.statistics.slider_tail_hit,
+            max_ends,
+            large_ticks: score.statistics.large_tick_hit,
+            max_large_ticks: score.maximum_statistics.large_tick_hit,
+        };
+
+        (score.mode == GameMode::Osu && score.legacy_score_id.is_none() && max_ends > 0)
+            .then_some(slider_hits)
+    }
+
     pub fn total_hits(&self) -> u32 {
         self.statistics.total_hits(self.mode)
     }
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN mods TEXT;
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN command_channels INT8[] NOT NULL DEFAULT '{}';
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS osu_top_snapshots;
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS osu_top_snapshots (
+    discord_id  INT8 NOT NULL,
+    user_id     INT4 NOT NULL,
+    gamemode    INT2 NOT NULL,
+    score_ids   INT8[] NOT NULL,
+    map_ids     INT4[] NOT NULL,
+    pps         FLOAT4[] NOT NULL,
+    last_update TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (discord_id, user_id, gamemode)
+);
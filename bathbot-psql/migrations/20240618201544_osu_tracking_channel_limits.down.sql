@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS osu_tracking_explicit_limits;
+DROP TABLE IF EXISTS osu_tracking_channel_limits;
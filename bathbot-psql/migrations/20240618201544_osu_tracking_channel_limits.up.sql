@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS osu_tracking_channel_limits (
+    channel_id  INT8 NOT NULL,
+    track_limit INT2 NOT NULL,
+    PRIMARY KEY (channel_id)
+);
+
+CREATE TABLE IF NOT EXISTS osu_tracking_explicit_limits (
+    user_id    INT4 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    channel_id INT8 NOT NULL,
+    PRIMARY KEY (user_id, gamemode, channel_id)
+);
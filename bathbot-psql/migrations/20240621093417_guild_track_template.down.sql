@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS osu_tracking_channel_guilds;
+ALTER TABLE guild_configs DROP COLUMN track_template;
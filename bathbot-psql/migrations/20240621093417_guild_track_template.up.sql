@@ -0,0 +1,7 @@
+ALTER TABLE guild_configs ADD COLUMN track_template INT2;
+
+CREATE TABLE IF NOT EXISTS osu_tracking_channel_guilds (
+    channel_id INT8 NOT NULL,
+    guild_id   INT8 NOT NULL,
+    PRIMARY KEY (channel_id)
+);
@@ -32,7 +32,10 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  legacy_scores 
+  legacy_scores, 
+  gamemode, 
+  command_channels, 
+  track_template 
 FROM 
   guild_configs"#
         );
@@ -67,6 +70,9 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             legacy_scores,
+            default_mode,
+            command_channels,
+            track_template,
         } = config;
 
         let authorities =
@@ -75,6 +81,11 @@ FROM
         let prefixes =
             rkyv::to_bytes::<_, 32>(prefixes).wrap_err("failed to serialize prefixes")?;
 
+        let command_channels: Vec<_> = command_channels
+            .iter()
+            .map(|channel_id| channel_id.get() as i64)
+            .collect();
+
         let query = sqlx::query!(
             r#"
 INSERT INTO guild_configs (
@@ -82,12 +93,13 @@ INSERT INTO guild_configs (
   score_size, retries, osu_track_limit, 
   minimized_pp, list_size, render_button, 
   allow_custom_skins, hide_medal_solution, 
-  legacy_scores
+  legacy_scores, gamemode, command_channels, 
+  track_template
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11, $12, $13
+    $11, $12, $13, $14, $15, $16
   ) ON CONFLICT (guild_id) DO 
 UPDATE 
 SET 
@@ -102,7 +114,10 @@ SET
   render_button = $10, 
   allow_custom_skins = $11, 
   hide_medal_solution = $12, 
-  legacy_scores = $13"#,
+  legacy_scores = $13, 
+  gamemode = $14, 
+  command_channels = $15, 
+  track_template = $16"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             &prefixes as &[u8],
@@ -116,6 +131,9 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             *legacy_scores,
+            default_mode.map(|mode| mode as i16) as Option<i16>,
+            &command_channels as &[i64],
+            track_template.map(i16::from),
         );
 
         query
@@ -27,7 +27,8 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  legacy_scores 
+  legacy_scores, 
+  mods 
 FROM 
   user_configs 
 WHERE 
@@ -262,6 +263,7 @@ FROM
             timezone,
             render_button,
             legacy_scores,
+            mods,
         } = config;
 
         let query = sqlx::query!(
@@ -270,10 +272,10 @@ INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   score_size, retries, minimized_pp, 
   list_size, timezone_seconds, render_button, 
-  legacy_scores
+  legacy_scores, mods
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) ON CONFLICT (discord_id) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -285,7 +287,8 @@ SET
   list_size = $8, 
   timezone_seconds = $9, 
   render_button = $10, 
-  legacy_scores = $11"#,
+  legacy_scores = $11, 
+  mods = $12"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -297,6 +300,7 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             *legacy_scores,
+            mods.as_ref().map(ToString::to_string),
         );
 
         query
@@ -309,6 +313,94 @@ SET
         Ok(())
     }
 
+    /// Clears all preferences of the user but keeps linked accounts.
+    ///
+    /// Returns the config as it was before the reset and whether a skin was
+    /// set, or `None` if the user has no config.
+    pub async fn reset_user_config(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<(UserConfig<OsuUserId>, bool)>> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_configs AS new 
+SET 
+  gamemode = NULL, 
+  score_size = NULL, 
+  retries = NULL, 
+  minimized_pp = NULL, 
+  list_size = NULL, 
+  timezone_seconds = NULL, 
+  render_button = NULL, 
+  legacy_scores = NULL, 
+  mods = NULL, 
+  skin_url = NULL 
+FROM 
+  (
+    SELECT 
+      discord_id, 
+      score_size, 
+      list_size, 
+      minimized_pp, 
+      gamemode, 
+      osu_id, 
+      retries, 
+      twitch_id, 
+      timezone_seconds, 
+      render_button, 
+      legacy_scores, 
+      mods, 
+      skin_url 
+    FROM 
+      user_configs 
+    WHERE 
+      discord_id = $1 FOR UPDATE
+  ) AS old 
+WHERE 
+  new.discord_id = old.discord_id RETURNING old.score_size, 
+  old.list_size, 
+  old.minimized_pp, 
+  old.gamemode, 
+  old.osu_id, 
+  old.retries, 
+  old.twitch_id, 
+  old.timezone_seconds, 
+  old.render_button, 
+  old.legacy_scores, 
+  old.mods, 
+  old.skin_url"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        let Some(row) = row_opt else {
+            return Ok(None);
+        };
+
+        debug!(user_id = user_id.get(), "Reset UserConfig in DB");
+
+        let config = DbUserConfig {
+            score_size: row.score_size,
+            list_size: row.list_size,
+            minimized_pp: row.minimized_pp,
+            gamemode: row.gamemode,
+            osu_id: row.osu_id,
+            retries: row.retries,
+            twitch_id: row.twitch_id,
+            timezone_seconds: row.timezone_seconds,
+            render_button: row.render_button,
+            legacy_scores: row.legacy_scores,
+            mods: row.mods,
+        };
+
+        Ok(Some((UserConfig::from(config), row.skin_url.is_some())))
+    }
+
     pub async fn update_skin_url(
         &self,
         user_id: Id<UserMarker>,
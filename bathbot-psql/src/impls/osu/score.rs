@@ -734,6 +734,8 @@ AND osu_id IS NOT NULL"#,
 
         let map_ids: Vec<_> = scores.iter().map(|score| score.map_id as i32).collect();
 
+        // Maps that were never stored, e.g. because the bot doesn't store maps,
+        // won't be found so their scores will lack map data.
         let map_query = sqlx::query_as!(
             DbScoreBeatmapRaw,
             r#"
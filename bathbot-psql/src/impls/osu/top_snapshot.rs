@@ -0,0 +1,90 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    model::osu::{DbTopSnapshot, TopSnapshot, TopSnapshotEntry},
+    Database,
+};
+
+impl Database {
+    pub async fn select_osu_top_snapshot(
+        &self,
+        discord_id: Id<UserMarker>,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<Option<TopSnapshot>> {
+        let query = sqlx::query_as!(
+            DbTopSnapshot,
+            r#"
+SELECT 
+  score_ids, 
+  map_ids, 
+  pps, 
+  last_update 
+FROM 
+  osu_top_snapshots 
+WHERE 
+  discord_id = $1 
+  AND user_id = $2 
+  AND gamemode = $3"#,
+            discord_id.get() as i64,
+            user_id as i32,
+            mode as i16,
+        );
+
+        let snapshot = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(snapshot.map(TopSnapshot::from))
+    }
+
+    pub async fn upsert_osu_top_snapshot(
+        &self,
+        discord_id: Id<UserMarker>,
+        user_id: u32,
+        mode: GameMode,
+        entries: &[TopSnapshotEntry],
+    ) -> Result<()> {
+        let mut score_ids = Vec::with_capacity(entries.len());
+        let mut map_ids = Vec::with_capacity(entries.len());
+        let mut pps = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            score_ids.push(entry.score_id as i64);
+            map_ids.push(entry.map_id as i32);
+            pps.push(entry.pp);
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_top_snapshots (
+  discord_id, user_id, gamemode, score_ids, 
+  map_ids, pps
+) 
+VALUES 
+  ($1, $2, $3, $4, $5, $6) ON CONFLICT (discord_id, user_id, gamemode) DO 
+UPDATE 
+SET 
+  score_ids = $4, 
+  map_ids = $5, 
+  pps = $6, 
+  last_update = NOW()"#,
+            discord_id.get() as i64,
+            user_id as i32,
+            mode as i16,
+            &score_ids,
+            &map_ids,
+            &pps,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -97,12 +97,15 @@ WHERE
         Ok(())
     }
 
+    /// Also deletes all explicit limits of the user for that mode.
     pub async fn delete_tracked_osu_user_by_mode(
         &self,
         user_id: u32,
         mode: GameMode,
     ) -> Result<()> {
-        let query = sqlx::query!(
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        sqlx::query!(
             r#"
 DELETE FROM 
   tracked_osu_users 
@@ -111,12 +114,107 @@ WHERE
   AND gamemode = $2"#,
             user_id as i32,
             mode as i16,
-        );
+        )
+        .execute(&mut *tx)
+        .await
+        .wrap_err("failed to execute query")?;
 
-        query
-            .execute(self)
+        sqlx::query!(
+            r#"
+DELETE FROM 
+  osu_tracking_explicit_limits 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2"#,
+            user_id as i32,
+            mode as i16,
+        )
+        .execute(&mut *tx)
+        .await
+        .wrap_err("failed to execute query")?;
+
+        tx.commit().await.wrap_err("failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    /// Deletes all given users without channels and updates the channels of
+    /// all others within a single transaction.
+    ///
+    /// Explicit limits of channels in which a user is no longer tracked are
+    /// deleted as well.
+    pub async fn remove_osu_tracking_entries<S>(
+        &self,
+        entries: &[(TrackedOsuUserKey, Option<Channels<S>>)],
+    ) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        for (key, channels) in entries {
+            let TrackedOsuUserKey { user_id, mode } = *key;
+
+            let query_res = match channels {
+                Some(channels) => {
+                    let channels = rkyv::to_bytes::<_, 256>(channels)
+                        .wrap_err("failed to serialize channels")?;
+
+                    sqlx::query!(
+                        r#"
+UPDATE 
+  tracked_osu_users 
+SET 
+  channels = $3 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2"#,
+                        user_id as i32,
+                        mode as i16,
+                        &channels as &[u8],
+                    )
+                    .execute(&mut *tx)
+                    .await
+                }
+                None => {
+                    sqlx::query!(
+                        r#"
+DELETE FROM 
+  tracked_osu_users 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2"#,
+                        user_id as i32,
+                        mode as i16,
+                    )
+                    .execute(&mut *tx)
+                    .await
+                }
+            };
+
+            query_res.wrap_err("failed to execute query")?;
+
+            let channel_ids: Vec<_> = channels
+                .iter()
+                .flat_map(|channels| channels.keys())
+                .map(|channel| channel.get() as i64)
+                .collect();
+
+            sqlx::query!(
+                r#"
+DELETE FROM 
+  osu_tracking_explicit_limits 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2 
+  AND NOT channel_id = ANY($3)"#,
+                user_id as i32,
+                mode as i16,
+                &channel_ids as &[i64],
+            )
+            .execute(&mut *tx)
             .await
             .wrap_err("failed to execute query")?;
+        }
+
+        tx.commit().await.wrap_err("failed to commit transaction")?;
 
         Ok(())
     }
@@ -201,4 +299,233 @@ WHERE
 
         Ok(())
     }
+
+    pub async fn select_osu_tracking_channel_limits(&self) -> Result<Vec<(NonZeroU64, u8)>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  channel_id, 
+  track_limit 
+FROM 
+  osu_tracking_channel_limits"#
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        let limits = rows
+            .into_iter()
+            .filter_map(|row| {
+                let channel = NonZeroU64::new(row.channel_id as u64)?;
+
+                Some((channel, row.track_limit as u8))
+            })
+            .collect();
+
+        Ok(limits)
+    }
+
+    pub async fn upsert_osu_tracking_channel_limit(
+        &self,
+        channel_id: NonZeroU64,
+        limit: u8,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_tracking_channel_limits (channel_id, track_limit) 
+VALUES 
+  ($1, $2) ON CONFLICT (channel_id) DO 
+UPDATE 
+SET 
+  track_limit = $2"#,
+            channel_id.get() as i64,
+            limit as i16,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_osu_tracking_channel_limit(&self, channel_id: NonZeroU64) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  osu_tracking_channel_limits 
+WHERE 
+  channel_id = $1"#,
+            channel_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Users and the channels in which their limit was specified explicitly
+    pub async fn select_osu_tracking_explicit_limits(
+        &self,
+    ) -> Result<Vec<(TrackedOsuUserKey, NonZeroU64)>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  user_id, 
+  gamemode, 
+  channel_id 
+FROM 
+  osu_tracking_explicit_limits"#
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        let limits = rows
+            .into_iter()
+            .filter_map(|row| {
+                let key = TrackedOsuUserKey {
+                    user_id: row.user_id as u32,
+                    mode: (row.gamemode as u8).into(),
+                };
+
+                Some((key, NonZeroU64::new(row.channel_id as u64)?))
+            })
+            .collect();
+
+        Ok(limits)
+    }
+
+    pub async fn upsert_osu_tracking_explicit_limit(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        channel_id: NonZeroU64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_tracking_explicit_limits (user_id, gamemode, channel_id) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (user_id, gamemode, channel_id) DO NOTHING"#,
+            user_id as i32,
+            mode as i16,
+            channel_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_osu_tracking_explicit_limit(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        channel_id: NonZeroU64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  osu_tracking_explicit_limits 
+WHERE 
+  user_id = $1 
+  AND gamemode = $2 
+  AND channel_id = $3"#,
+            user_id as i32,
+            mode as i16,
+            channel_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Channels and the guild they belong to
+    pub async fn select_osu_tracking_channel_guilds(
+        &self,
+    ) -> Result<Vec<(NonZeroU64, NonZeroU64)>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  channel_id, 
+  guild_id 
+FROM 
+  osu_tracking_channel_guilds"#
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        let guilds = rows
+            .into_iter()
+            .filter_map(|row| {
+                let channel = NonZeroU64::new(row.channel_id as u64)?;
+                let guild = NonZeroU64::new(row.guild_id as u64)?;
+
+                Some((channel, guild))
+            })
+            .collect();
+
+        Ok(guilds)
+    }
+
+    pub async fn upsert_osu_tracking_channel_guild(
+        &self,
+        channel_id: NonZeroU64,
+        guild_id: NonZeroU64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_tracking_channel_guilds (channel_id, guild_id) 
+VALUES 
+  ($1, $2) ON CONFLICT (channel_id) DO 
+UPDATE 
+SET 
+  guild_id = $2"#,
+            channel_id.get() as i64,
+            guild_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_osu_tracking_channel_guild(&self, channel_id: NonZeroU64) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  osu_tracking_channel_guilds 
+WHERE 
+  channel_id = $1"#,
+            channel_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
 use super::{
-    list_size::ListSize, minimized_pp::MinimizedPp, score_size::ScoreSize, Authorities,
-    HideSolutions, Prefixes, Retries,
+    list_size::ListSize, minimized_pp::MinimizedPp, score_size::ScoreSize,
+    track_template::TrackTemplate, Authorities, HideSolutions, Prefixes, Retries,
 };
 
 pub struct DbGuildConfig {
@@ -17,6 +20,9 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub legacy_scores: Option<bool>,
+    pub gamemode: Option<i16>,
+    pub command_channels: Vec<i64>,
+    pub track_template: Option<i16>,
 }
 
 #[derive(Clone, Default)]
@@ -33,6 +39,10 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub legacy_scores: Option<bool>,
+    pub default_mode: Option<GameMode>,
+    /// Channels in which non-authority commands may be used; empty means all
+    pub command_channels: Vec<Id<ChannelMarker>>,
+    pub track_template: Option<TrackTemplate>,
 }
 
 impl From<DbGuildConfig> for GuildConfig {
@@ -52,6 +62,9 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             legacy_scores,
+            gamemode,
+            command_channels,
+            track_template,
         } = config;
 
         // SAFETY: The bytes originate from the DB which only provides valid archived
@@ -74,6 +87,14 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             legacy_scores,
+            default_mode: gamemode.map(|mode| GameMode::from(mode as u8)),
+            command_channels: command_channels
+                .into_iter()
+                .filter_map(|channel_id| Id::new_checked(channel_id as u64))
+                .collect(),
+            track_template: track_template
+                .map(TrackTemplate::try_from)
+                .and_then(Result::ok),
         }
     }
 }
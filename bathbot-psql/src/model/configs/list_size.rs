@@ -17,6 +17,17 @@ pub enum ListSize {
     Single = 2,
 }
 
+impl ListSize {
+    /// How many scores are shown on a single page
+    pub fn per_page(self) -> usize {
+        match self {
+            Self::Condensed => 10,
+            Self::Detailed => 5,
+            Self::Single => 1,
+        }
+    }
+}
+
 impl From<ListSize> for i16 {
     #[inline]
     fn from(list_size: ListSize) -> Self {
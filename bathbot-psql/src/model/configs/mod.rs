@@ -8,6 +8,7 @@ pub use self::{
     retries::Retries,
     score_size::ScoreSize,
     skin::{DbSkinEntry, SkinEntry},
+    track_template::TrackTemplate,
     user::{DbUserConfig, OsuId, OsuUserId, OsuUsername, UserConfig},
 };
 
@@ -20,4 +21,5 @@ mod prefixes;
 mod retries;
 mod score_size;
 mod skin;
+mod track_template;
 mod user;
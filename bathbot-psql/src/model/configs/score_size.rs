@@ -15,6 +15,8 @@ pub enum ScoreSize {
     InitialMaximized = 1,
     #[option(name = "Always maximized", value = "max")]
     AlwaysMaximized = 2,
+    #[option(name = "Maximize notable scores", value = "auto")]
+    Auto = 3,
 }
 
 impl From<ScoreSize> for i16 {
@@ -33,6 +35,7 @@ impl TryFrom<i16> for ScoreSize {
             0 => Ok(Self::AlwaysMinimized),
             1 => Ok(Self::InitialMaximized),
             2 => Ok(Self::AlwaysMaximized),
+            3 => Ok(Self::Auto),
             _ => Err(()),
         }
     }
@@ -0,0 +1,37 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum TrackTemplate {
+    #[option(name = "Full score embed", value = "full")]
+    Full = 0,
+    #[option(name = "Compact one-liner", value = "compact")]
+    Compact = 1,
+}
+
+impl From<TrackTemplate> for i16 {
+    #[inline]
+    fn from(template: TrackTemplate) -> Self {
+        template as Self
+    }
+}
+
+impl TryFrom<i16> for TrackTemplate {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Full),
+            1 => Ok(Self::Compact),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Default for TrackTemplate {
+    #[inline]
+    fn default() -> Self {
+        Self::Full
+    }
+}
@@ -1,4 +1,4 @@
-use rosu_v2::prelude::{GameMode, Username};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, Username};
 use time::UtcOffset;
 
 use super::{list_size::ListSize, minimized_pp::MinimizedPp, score_size::ScoreSize, Retries};
@@ -14,6 +14,7 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub legacy_scores: Option<bool>,
+    pub mods: Option<String>,
 }
 
 pub trait OsuId {
@@ -46,6 +47,10 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub legacy_scores: Option<bool>,
+    /// Mods that commands fall back to if none are specified explicitly.
+    ///
+    /// Precedence: explicit mods argument > these mods > no mods.
+    pub mods: Option<GameModsIntermode>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -62,10 +67,72 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             legacy_scores: None,
+            mods: None,
         }
     }
 }
 
+impl<O: OsuId> UserConfig<O> {
+    /// Resets all preferences to their default while keeping linked accounts.
+    ///
+    /// Returns the names of the preferences that were set before.
+    pub fn reset_preferences(&mut self) -> Vec<&'static str> {
+        let Self {
+            score_size,
+            list_size,
+            minimized_pp,
+            mode,
+            osu: _,
+            retries,
+            twitch_id: _,
+            timezone,
+            render_button,
+            legacy_scores,
+            mods,
+        } = self;
+
+        let mut cleared = Vec::new();
+
+        if mode.take().is_some() {
+            cleared.push("mode");
+        }
+
+        if score_size.take().is_some() {
+            cleared.push("score_embeds");
+        }
+
+        if list_size.take().is_some() {
+            cleared.push("list_embeds");
+        }
+
+        if retries.take().is_some() {
+            cleared.push("retries");
+        }
+
+        if minimized_pp.take().is_some() {
+            cleared.push("minimized_pp");
+        }
+
+        if timezone.take().is_some() {
+            cleared.push("timezone");
+        }
+
+        if render_button.take().is_some() {
+            cleared.push("render_button");
+        }
+
+        if legacy_scores.take().is_some() {
+            cleared.push("score_data");
+        }
+
+        if mods.take().is_some() {
+            cleared.push("mods");
+        }
+
+        cleared
+    }
+}
+
 impl From<DbUserConfig> for UserConfig<OsuUserId> {
     #[inline]
     fn from(config: DbUserConfig) -> Self {
@@ -80,6 +147,7 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             legacy_scores,
+            mods,
         } = config;
 
         Self {
@@ -95,6 +163,84 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             legacy_scores,
+            // Unparsable mods are treated as if none were set
+            mods: mods
+                .as_deref()
+                .and_then(GameModsIntermode::try_from_acronyms),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_preferences_to_default() {
+        let mut config = UserConfig::<OsuUserId> {
+            score_size: Some(ScoreSize::AlwaysMinimized),
+            list_size: Some(ListSize::Single),
+            mode: Some(GameMode::Taiko),
+            timezone: Some(UtcOffset::from_hms(2, 0, 0).unwrap()),
+            legacy_scores: Some(true),
+            ..Default::default()
+        };
+
+        let cleared = config.reset_preferences();
+
+        assert_eq!(
+            cleared,
+            [
+                "mode",
+                "score_embeds",
+                "list_embeds",
+                "timezone",
+                "score_data"
+            ]
+        );
+        assert_eq!(config, UserConfig::default());
+        assert!(config.reset_preferences().is_empty());
+    }
+
+    #[test]
+    fn db_mods_default_to_none() {
+        let db_config = |mods: Option<&str>| DbUserConfig {
+            score_size: None,
+            list_size: None,
+            minimized_pp: None,
+            gamemode: None,
+            osu_id: None,
+            retries: None,
+            twitch_id: None,
+            timezone_seconds: None,
+            render_button: None,
+            legacy_scores: None,
+            mods: mods.map(str::to_owned),
+        };
+
+        let config = UserConfig::from(db_config(None));
+        assert_eq!(config.mods, None);
+
+        let config = UserConfig::from(db_config(Some("not mods")));
+        assert_eq!(config.mods, None);
+
+        let config = UserConfig::from(db_config(Some("HDDT")));
+        let expected = GameModsIntermode::try_from_acronyms("HDDT");
+        assert_eq!(config.mods, expected);
+    }
+
+    #[test]
+    fn reset_preferences_keeps_links() {
+        let mut config = UserConfig::<OsuUserId> {
+            osu: Some(2),
+            twitch_id: Some(3),
+            render_button: Some(false),
+            ..Default::default()
+        };
+
+        assert_eq!(config.reset_preferences(), ["render_button"]);
+        assert_eq!(config.osu, Some(2));
+        assert_eq!(config.twitch_id, Some(3));
+        assert_eq!(config.render_button, None);
+    }
+}
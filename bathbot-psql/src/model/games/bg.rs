@@ -25,6 +25,8 @@ macro_rules! define_map_tags {
     ( $( $column:ident ,)* ) => {
         pub struct DbMapTagsParams {
             pub mode: GameMode,
+            pub min_stars: Option<f32>,
+            pub max_stars: Option<f32>,
             $( pub $column: Option<bool>, )*
         }
 
@@ -32,6 +34,8 @@ macro_rules! define_map_tags {
             pub fn new(mode: GameMode) -> Self {
                 Self {
                     mode,
+                    min_stars: None,
+                    max_stars: None,
                     $( $column: None, )*
                 }
             }
@@ -52,6 +56,8 @@ WHERE
                     }
                 )*
 
+                self.push_stars_condition(&mut query);
+
                 query
             }
         }
@@ -59,6 +65,45 @@ WHERE
 }
 
 impl DbMapTagsParams {
+    /// Only keep mapsets with a difficulty whose nomod star rating lies
+    /// within the given bounds.
+    fn push_stars_condition(&self, query: &mut String) {
+        if self.min_stars.is_none() && self.max_stars.is_none() {
+            return;
+        }
+
+        let table = match self.mode {
+            GameMode::Osu => "osu_map_difficulty",
+            GameMode::Taiko => "osu_map_difficulty_taiko",
+            GameMode::Catch => "osu_map_difficulty_catch",
+            GameMode::Mania => "osu_map_difficulty_mania",
+        };
+
+        let _ = write!(
+            query,
+            r#"
+  AND EXISTS (
+    SELECT 
+      1 
+    FROM 
+      osu_maps 
+      JOIN {table} USING (map_id) 
+    WHERE 
+      osu_maps.mapset_id = map_tags.mapset_id 
+      AND {table}.mods = 0"#
+        );
+
+        if let Some(min) = self.min_stars {
+            let _ = write!(query, " AND {table}.stars >= {min}");
+        }
+
+        if let Some(max) = self.max_stars {
+            let _ = write!(query, " AND {table}.stars <= {max}");
+        }
+
+        query.push(')');
+    }
+
     pub fn include(&mut self, tags: MapsetTags) {
         macro_rules! set_params {
             ( $( $field:ident: $variant:ident ,)* ) => {
@@ -1,8 +1,11 @@
-pub use self::{bookmark::*, map::*, mapset::*, score::*, tracked_user::*, user::*};
+pub use self::{
+    bookmark::*, map::*, mapset::*, score::*, top_snapshot::*, tracked_user::*, user::*,
+};
 
 mod bookmark;
 mod map;
 mod mapset;
 mod score;
+mod top_snapshot;
 mod tracked_user;
 mod user;
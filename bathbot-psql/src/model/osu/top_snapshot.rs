@@ -0,0 +1,49 @@
+use time::OffsetDateTime;
+
+pub struct DbTopSnapshot {
+    pub score_ids: Vec<i64>,
+    pub map_ids: Vec<i32>,
+    pub pps: Vec<f32>,
+    pub last_update: OffsetDateTime,
+}
+
+/// A user's top scores as they were at the time of `last_update`.
+pub struct TopSnapshot {
+    pub entries: Vec<TopSnapshotEntry>,
+    pub last_update: OffsetDateTime,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TopSnapshotEntry {
+    pub score_id: u64,
+    pub map_id: u32,
+    pub pp: f32,
+}
+
+impl From<DbTopSnapshot> for TopSnapshot {
+    #[inline]
+    fn from(snapshot: DbTopSnapshot) -> Self {
+        let DbTopSnapshot {
+            score_ids,
+            map_ids,
+            pps,
+            last_update,
+        } = snapshot;
+
+        let entries = score_ids
+            .into_iter()
+            .zip(map_ids)
+            .zip(pps)
+            .map(|((score_id, map_id), pp)| TopSnapshotEntry {
+                score_id: score_id as u64,
+                map_id: map_id as u32,
+                pp,
+            })
+            .collect();
+
+        Self {
+            entries,
+            last_update,
+        }
+    }
+}
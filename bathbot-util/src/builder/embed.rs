@@ -1,4 +1,9 @@
-use std::num::NonZeroU32;
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::NonZeroU32,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 use time::OffsetDateTime;
 use twilight_model::{
@@ -12,6 +17,9 @@ use crate::{
     AuthorBuilder, FooterBuilder,
 };
 
+/// Color of embeds that don't specify one themselves.
+static DEFAULT_COLOR: AtomicU32 = AtomicU32::new(DARK_GREEN);
+
 #[derive(Clone, Default)]
 pub struct EmbedBuilder {
     pub author: Option<AuthorBuilder>,
@@ -31,10 +39,32 @@ impl EmbedBuilder {
         Self::default()
     }
 
+    /// Set the color that is used for all embeds without an explicit color.
+    ///
+    /// Defaults to [`DARK_GREEN`].
+    pub fn set_default_color(color: u32) {
+        DEFAULT_COLOR.store(color, Ordering::Relaxed);
+    }
+
+    pub fn default_color() -> u32 {
+        DEFAULT_COLOR.load(Ordering::Relaxed)
+    }
+
+    #[cfg_attr(debug_assertions, track_caller)]
     pub fn build(self) -> Embed {
+        self.build_with_default_color(Self::default_color())
+    }
+
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn build_with_default_color(self, default_color: u32) -> Embed {
+        #[cfg(debug_assertions)]
+        if let Err(err) = self.validate() {
+            panic!("invalid embed: {err}");
+        }
+
         Embed {
             author: self.author.map(AuthorBuilder::build),
-            color: Some(self.color.map_or(DARK_GREEN, NonZeroU32::get)),
+            color: Some(self.color.map_or(default_color, NonZeroU32::get)),
             description: self.description,
             fields: self.fields,
             footer: self.footer.map(FooterBuilder::build),
@@ -59,6 +89,60 @@ impl EmbedBuilder {
         }
     }
 
+    /// Check the embed against discord's length limits.
+    pub fn validate(&self) -> Result<(), EmbedLimitError> {
+        fn check(
+            field: EmbedLimitField,
+            value: Option<&str>,
+            total: &mut usize,
+        ) -> Result<(), EmbedLimitError> {
+            let Some(value) = value else { return Ok(()) };
+
+            let len = value.chars().count();
+            *total += len;
+
+            if len > field.limit() {
+                return Err(EmbedLimitError::Field { field, len });
+            }
+
+            Ok(())
+        }
+
+        let mut total = 0;
+
+        check(EmbedLimitField::Title, self.title.as_deref(), &mut total)?;
+        check(
+            EmbedLimitField::Description,
+            self.description.as_deref(),
+            &mut total,
+        )?;
+        check(
+            EmbedLimitField::AuthorName,
+            self.author.as_ref().map(|author| author.name.as_str()),
+            &mut total,
+        )?;
+        check(
+            EmbedLimitField::FooterText,
+            self.footer.as_ref().map(|footer| footer.text.as_str()),
+            &mut total,
+        )?;
+
+        if self.fields.len() > EmbedLimitError::FIELD_COUNT {
+            return Err(EmbedLimitError::FieldCount(self.fields.len()));
+        }
+
+        for field in self.fields.iter() {
+            check(EmbedLimitField::FieldName, Some(&field.name), &mut total)?;
+            check(EmbedLimitField::FieldValue, Some(&field.value), &mut total)?;
+        }
+
+        if total > EmbedLimitError::TOTAL {
+            return Err(EmbedLimitError::Total(total));
+        }
+
+        Ok(())
+    }
+
     pub fn author(mut self, author: AuthorBuilder) -> Self {
         self.author = Some(author);
 
@@ -74,13 +158,11 @@ impl EmbedBuilder {
     }
 
     #[cfg_attr(debug_assertions, track_caller)]
-    fn color(mut self, color: u32) -> Self {
+    pub fn color(mut self, color: u32) -> Self {
         debug_assert!(color != 0, "color {color} must be non-zero");
 
-        // SAFETY: This method is private and only used for the RED and DARK_GREEN
-        // constants which are both non-zero but even if they were zero, it would have
-        // been caught with the debug_assert.
-        self.color = Some(unsafe { NonZeroU32::new_unchecked(color) });
+        // A color of zero would be treated as no color which is the default anyway
+        self.color = NonZeroU32::new(color);
 
         self
     }
@@ -98,6 +180,38 @@ impl EmbedBuilder {
         self
     }
 
+    /// Add a single field after the existing ones.
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> Self {
+        self.fields.push(EmbedField {
+            inline,
+            name: name.into(),
+            value: value.into(),
+        });
+
+        self
+    }
+
+    /// Add a single field after the existing ones unless the value is empty.
+    pub fn try_field(
+        self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> Self {
+        let value = value.into();
+
+        if value.is_empty() {
+            self
+        } else {
+            self.field(name, value, inline)
+        }
+    }
+
     pub fn push_field(&mut self, field: EmbedField) {
         self.fields.push(field);
     }
@@ -146,3 +260,171 @@ impl EmbedBuilder {
         self
     }
 }
+
+/// Parts of an embed that are restricted in their length.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmbedLimitField {
+    AuthorName,
+    Description,
+    FieldName,
+    FieldValue,
+    FooterText,
+    Title,
+}
+
+impl EmbedLimitField {
+    pub fn limit(self) -> usize {
+        match self {
+            Self::AuthorName => 256,
+            Self::Description => 4096,
+            Self::FieldName => 256,
+            Self::FieldValue => 1024,
+            Self::FooterText => 2048,
+            Self::Title => 256,
+        }
+    }
+}
+
+impl Display for EmbedLimitField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::AuthorName => "author name",
+            Self::Description => "description",
+            Self::FieldName => "field name",
+            Self::FieldValue => "field value",
+            Self::FooterText => "footer text",
+            Self::Title => "title",
+        };
+
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmbedLimitError {
+    Field { field: EmbedLimitField, len: usize },
+    FieldCount(usize),
+    Total(usize),
+}
+
+impl EmbedLimitError {
+    pub const FIELD_COUNT: usize = 25;
+    pub const TOTAL: usize = 6000;
+}
+
+impl Display for EmbedLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Field { field, len } => write!(
+                f,
+                "{field} has length {len} but may not exceed {}",
+                field.limit()
+            ),
+            Self::FieldCount(count) => write!(
+                f,
+                "embed has {count} fields but may not exceed {}",
+                Self::FIELD_COUNT
+            ),
+            Self::Total(total) => write!(
+                f,
+                "embed has total length {total} but may not exceed {}",
+                Self::TOTAL
+            ),
+        }
+    }
+}
+
+impl StdError for EmbedLimitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(value: String) -> EmbedField {
+        EmbedField {
+            inline: false,
+            name: "name".to_owned(),
+            value,
+        }
+    }
+
+    #[test]
+    fn configured_default_color() {
+        let embed = EmbedBuilder::new()
+            .description("description")
+            .build_with_default_color(0x123456);
+        assert_eq!(embed.color, Some(0x123456));
+
+        let embed = EmbedBuilder::new()
+            .color(RED)
+            .build_with_default_color(0x123456);
+        assert_eq!(embed.color, Some(RED));
+    }
+
+    #[test]
+    fn valid_embed() {
+        let embed = EmbedBuilder::new()
+            .title("title")
+            .description("a".repeat(4096))
+            .footer("footer");
+
+        assert_eq!(embed.validate(), Ok(()));
+    }
+
+    #[test]
+    fn description_too_long() {
+        let embed = EmbedBuilder::new().description("a".repeat(4097));
+
+        let err = EmbedLimitError::Field {
+            field: EmbedLimitField::Description,
+            len: 4097,
+        };
+
+        assert_eq!(embed.validate(), Err(err));
+        assert_eq!(
+            err.to_string(),
+            "description has length 4097 but may not exceed 4096"
+        );
+    }
+
+    #[test]
+    fn description_counts_chars() {
+        let embed = EmbedBuilder::new().description("★".repeat(4096));
+
+        assert_eq!(embed.validate(), Ok(()));
+    }
+
+    #[test]
+    fn field_value_too_long() {
+        let embed = EmbedBuilder::new().fields(vec![field("a".repeat(1025))]);
+
+        let err = EmbedLimitError::Field {
+            field: EmbedLimitField::FieldValue,
+            len: 1025,
+        };
+
+        assert_eq!(embed.validate(), Err(err));
+    }
+
+    #[test]
+    fn fields_accumulate_in_order() {
+        let embed = EmbedBuilder::new()
+            .fields(vec![field("first".to_owned())])
+            .field("name", "second", true)
+            .try_field("name", "", false)
+            .try_field("name", "third", false);
+
+        let values: Vec<_> = embed.fields.iter().map(|f| f.value.as_str()).collect();
+        assert_eq!(values, ["first", "second", "third"]);
+        assert!(embed.fields[1].inline);
+        assert!(!embed.fields[2].inline);
+    }
+
+    #[test]
+    fn total_too_long() {
+        let fields = (0..6).map(|_| field("a".repeat(1000))).collect();
+        let embed = EmbedBuilder::new().fields(fields);
+
+        assert_eq!(embed.validate(), Err(EmbedLimitError::Total(6024)));
+    }
+}
@@ -1,5 +1,10 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use time::OffsetDateTime;
 use twilight_model::channel::message::embed::EmbedFooter;
 
+use crate::datetime::{HowLongAgoText, NAIVE_DATETIME_FORMAT};
+
 #[derive(Clone)]
 pub struct FooterBuilder {
     pub icon_url: Option<String>,
@@ -14,6 +19,28 @@ impl FooterBuilder {
         }
     }
 
+    /// Create a footer of the form `{prefix} {datetime} ({how long ago})`.
+    pub fn with_relative_time(prefix: impl Display, datetime: &OffsetDateTime) -> Self {
+        Self::with_relative_time_since(prefix, datetime, OffsetDateTime::now_utc())
+    }
+
+    fn with_relative_time_since(
+        prefix: impl Display,
+        datetime: &OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Self {
+        let text = format!(
+            "{prefix} {datetime} ({ago})",
+            datetime = datetime.format(NAIVE_DATETIME_FORMAT).unwrap(),
+            ago = HowLongAgoSince {
+                ago: HowLongAgoText::new(datetime),
+                now,
+            },
+        );
+
+        Self::new(text)
+    }
+
     pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
         self.icon_url = Some(icon_url.into());
 
@@ -59,3 +86,49 @@ impl IntoFooterBuilder for FooterBuilder {
         self
     }
 }
+
+struct HowLongAgoSince {
+    ago: HowLongAgoText,
+    now: OffsetDateTime,
+}
+
+impl Display for HowLongAgoSince {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.ago.fmt_since(f, self.now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(unix_timestamp: i64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(unix_timestamp).unwrap()
+    }
+
+    #[test]
+    fn relative_time_days() {
+        let footer = FooterBuilder::with_relative_time_since(
+            "Joined osu!",
+            &datetime(1577880000), // 2020-01-01 12:00:00
+            datetime(1578142800),  // 2020-01-04 13:00:00
+        );
+
+        assert_eq!(footer.text, "Joined osu! 2020-01-01 12:00:00 (3 days ago)");
+    }
+
+    #[test]
+    fn relative_time_years() {
+        let footer = FooterBuilder::with_relative_time_since(
+            "Started counting",
+            &datetime(1433925000), // 2015-06-10 08:30:00
+            datetime(1688169600),  // 2023-07-01 00:00:00
+        );
+
+        assert_eq!(
+            footer.text,
+            "Started counting 2015-06-10 08:30:00 (8 years ago)"
+        );
+    }
+}
@@ -43,10 +43,16 @@ impl HowLongAgoText {
     }
 }
 
-// thx saki :)
 impl Display for HowLongAgoText {
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let now = OffsetDateTime::now_utc();
+        self.fmt_since(f, OffsetDateTime::now_utc())
+    }
+}
+
+impl HowLongAgoText {
+    // thx saki :)
+    pub(crate) fn fmt_since(&self, f: &mut Formatter<'_>, now: OffsetDateTime) -> FmtResult {
         let diff_sec = now.unix_timestamp() - self.secs;
         debug_assert!(diff_sec >= 0);
 
@@ -18,7 +18,10 @@ pub mod osu;
 pub mod string_cmp;
 
 pub use self::{
-    builder::{modal, AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder},
+    builder::{
+        modal, AuthorBuilder, EmbedBuilder, EmbedLimitError, EmbedLimitField, FooterBuilder,
+        MessageBuilder,
+    },
     cow::CowUtils,
     exp_backoff::ExponentialBackoff,
     ext::*,
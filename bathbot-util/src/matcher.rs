@@ -6,7 +6,7 @@ use twilight_model::id::{
     Id,
 };
 
-use super::osu::ModSelection;
+use super::osu::{normalize_mods, ModSelection};
 
 pub fn is_approved_skin_site(url: &str) -> bool {
     APPROVED_SKIN_SITE.get().is_match(url)
@@ -16,6 +16,14 @@ pub fn is_custom_emote(msg: &str) -> bool {
     EMOJI_MATCHER.get().is_match(msg)
 }
 
+pub fn get_emote_id(msg: &str) -> Option<u64> {
+    EMOJI_MATCHER
+        .get()
+        .captures(msg)
+        .and_then(|c| c.get(3))
+        .and_then(|c| c.as_str().parse().ok())
+}
+
 enum MentionType {
     Role,
     User,
@@ -120,9 +128,14 @@ pub fn get_osu_match_id(msg: &str) -> Option<u32> {
         .and_then(|c| c.as_str().parse::<u32>().ok())
 }
 
+/// Parses `+mods`, `+mods!`, and `-mods!` into a [`ModSelection`].
+///
+/// The mods are passed through [`normalize_mods`] so e.g. `+dtnc!` is the
+/// same as `+nc!`.
 pub fn get_mods(msg: &str) -> Option<ModSelection> {
     let selection = if let Some(captures) = MOD_PLUS_MATCHER.get().captures(msg) {
         let mods = GameModsIntermode::try_from_acronyms(captures.get(1)?.as_str())?;
+        let mods = normalize_mods(mods);
 
         if msg.ends_with('!') {
             ModSelection::Exact(mods)
@@ -131,6 +144,7 @@ pub fn get_mods(msg: &str) -> Option<ModSelection> {
         }
     } else if let Some(captures) = MOD_MINUS_MATCHER.get().captures(msg) {
         let mods = GameModsIntermode::try_from_acronyms(captures.get(1)?.as_str())?;
+        let mods = normalize_mods(mods);
 
         ModSelection::Exclude(mods)
     } else {
@@ -140,6 +154,10 @@ pub fn get_mods(msg: &str) -> Option<ModSelection> {
     Some(selection)
 }
 
+pub fn is_query_syntax(msg: &str) -> bool {
+    QUERY_SYNTAX_REGEX.get().is_match(msg)
+}
+
 #[allow(dead_code)]
 pub fn is_hit_results(msg: &str) -> bool {
     HIT_RESULTS_MATCHER.get().is_match(msg)
@@ -199,5 +217,96 @@ define_regex! {
 
     APPROVED_SKIN_SITE: r"^https://(?:(?:www\.)?(?:drive\.google\.com|dropbox\.com|mega\.nz|mediafire\.com|(?:gist\.)?github\.com)/.*$|(?:skins\.osuck\.net/skins|osu\.ppy\.sh/community/forums/topics)/\d+.*|link.issou.best/skin/\d+$)";
 
-    pub QUERY_SYNTAX_REGEX: r#"\b(?P<key>\w+)(?P<op>(:|=|(>|<)(:|=)?))(?P<value>(".*")|(\S*))"#;
+    pub QUERY_SYNTAX_REGEX: r#"\b(?P<key>\w+)(?P<op>(!(:|=)|:|=|(>|<)(:|=)?))(?P<value>(".*")|(\S*))"#;
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::GameMods;
+
+    use super::*;
+
+    fn hd() -> GameModsIntermode {
+        GameModsIntermode::try_from_acronyms("hd").unwrap()
+    }
+
+    #[test]
+    fn mods_include() {
+        assert_eq!(get_mods("+hd"), Some(ModSelection::Include(hd())));
+    }
+
+    #[test]
+    fn mods_exact() {
+        assert_eq!(get_mods("+hd!"), Some(ModSelection::Exact(hd())));
+    }
+
+    #[test]
+    fn mods_exclude() {
+        assert_eq!(get_mods("-hd"), None);
+        assert_eq!(get_mods("-hd!"), Some(ModSelection::Exclude(hd())));
+    }
+
+    fn score_mods(acronyms: &str) -> GameMods {
+        GameModsIntermode::try_from_acronyms(acronyms)
+            .unwrap()
+            .with_mode(GameMode::Osu)
+    }
+
+    #[test]
+    fn mods_normalize_nightcore() {
+        let nc = GameModsIntermode::try_from_acronyms("nc").unwrap();
+
+        assert_eq!(get_mods("+dtnc!"), Some(ModSelection::Exact(nc.clone())));
+        assert_eq!(get_mods("+nc"), Some(ModSelection::Include(nc)));
+    }
+
+    #[test]
+    fn mods_filter_dt() {
+        let selection = get_mods("+dt").unwrap();
+
+        assert!(selection.filter_mods(&score_mods("dt")));
+        assert!(selection.filter_mods(&score_mods("dtnc")));
+        assert!(selection.filter_mods(&score_mods("nc")));
+    }
+
+    #[test]
+    fn mods_filter_nc() {
+        let selection = get_mods("+nc").unwrap();
+
+        assert!(!selection.filter_mods(&score_mods("dt")));
+        assert!(selection.filter_mods(&score_mods("dtnc")));
+        assert!(selection.filter_mods(&score_mods("hdnc")));
+    }
+
+    #[test]
+    fn mods_filter_nc_exact() {
+        let selection = get_mods("+nc!").unwrap();
+
+        assert!(!selection.filter_mods(&score_mods("dt")));
+        assert!(selection.filter_mods(&score_mods("dtnc")));
+        assert!(selection.filter_mods(&score_mods("nc")));
+        assert!(!selection.filter_mods(&score_mods("hdnc")));
+    }
+
+    #[test]
+    fn mods_filter_pf_exact() {
+        let selection = get_mods("+pf!").unwrap();
+
+        assert!(!selection.filter_mods(&score_mods("sd")));
+        assert!(selection.filter_mods(&score_mods("sdpf")));
+    }
+
+    #[test]
+    fn grade_emote_ids() {
+        assert_eq!(
+            get_emote_id("<:rankingSH:519181578354255872>"),
+            Some(519181578354255872)
+        );
+        assert_eq!(
+            get_emote_id("<a:rankingX:519181578316374017>"),
+            Some(519181578316374017)
+        );
+        assert_eq!(get_emote_id("rankingS"), None);
+        assert_eq!(get_emote_id("<:rankingA:notanid>"), None);
+    }
 }
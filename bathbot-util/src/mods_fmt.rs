@@ -6,6 +6,8 @@ use rosu_v2::model::mods::{
     HalfTimeTaiko, NightcoreCatch, NightcoreMania, NightcoreOsu, NightcoreTaiko,
 };
 
+use crate::{numbers::round, osu::DifficultyAdjustSettings};
+
 pub struct ModsFormatter<'a> {
     mods: &'a GameMods,
 }
@@ -47,15 +49,73 @@ impl Display for ModsFormatter<'_> {
                     }
                 }
 
-                GameMod::DifficultyAdjustOsu(_)
-                | GameMod::DifficultyAdjustTaiko(_)
-                | GameMod::DifficultyAdjustCatch(_)
-                | GameMod::DifficultyAdjustMania(_) => {} // TODO: print something?
+                _ => {
+                    if let Some(da) = DifficultyAdjustSettings::new(gamemod) {
+                        let settings = [("CS", da.cs), ("AR", da.ar), ("HP", da.hp), ("OD", da.od)];
 
-                _ => {}
+                        fmt_settings(f, &settings)?;
+                    }
+                }
             }
         }
 
         Ok(())
     }
 }
+
+/// Writes all specified settings e.g. `(AR9.5,OD8)`
+fn fmt_settings(f: &mut Formatter<'_>, settings: &[(&str, Option<f32>)]) -> FmtResult {
+    let mut iter = settings
+        .iter()
+        .filter_map(|(name, value)| value.map(|value| (name, round(value))));
+
+    let Some((name, value)) = iter.next() else {
+        return Ok(());
+    };
+
+    write!(f, "({name}{value}")?;
+
+    for (name, value) in iter {
+        write!(f, ",{name}{value}")?;
+    }
+
+    f.write_str(")")
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::model::mods::{DifficultyAdjustMania, DifficultyAdjustOsu};
+
+    use super::*;
+
+    fn fmt(gamemod: GameMod) -> String {
+        let mods: GameMods = [gamemod].into_iter().collect();
+
+        ModsFormatter::new(&mods).to_string()
+    }
+
+    #[test]
+    fn custom_speed_change() {
+        let dt = DoubleTimeOsu {
+            speed_change: Some(1.3),
+            ..Default::default()
+        };
+
+        assert_eq!(fmt(GameMod::DoubleTimeOsu(dt)), "DT(1.3x)");
+    }
+
+    #[test]
+    fn difficulty_adjust_settings() {
+        let da = DifficultyAdjustOsu {
+            approach_rate: Some(9.5),
+            overall_difficulty: Some(8.0),
+            ..Default::default()
+        };
+
+        assert_eq!(fmt(GameMod::DifficultyAdjustOsu(da)), "DA(AR9.5,OD8)");
+
+        let da = DifficultyAdjustMania::default();
+
+        assert_eq!(fmt(GameMod::DifficultyAdjustMania(da)), "DA");
+    }
+}
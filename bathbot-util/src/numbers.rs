@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{AddAssign, Div},
 };
@@ -176,11 +177,16 @@ macro_rules! impl_number {
 
 impl_number!(u32: 1, f32: 1.0, f64: 1.0);
 
+/// Tracks the minimum, maximum, average, and median of added values.
+///
+/// An exact median requires all values so they're buffered as well. This
+/// type is only ever fed a top100 so the additional memory is negligible.
 pub struct MinMaxAvg<N> {
     min: N,
     max: N,
     sum: N,
     len: N,
+    values: Vec<N>,
 }
 
 impl<N: Number> Default for MinMaxAvg<N> {
@@ -190,6 +196,7 @@ impl<N: Number> Default for MinMaxAvg<N> {
             max: N::min(),
             sum: N::zero(),
             len: N::zero(),
+            values: Vec::new(),
         }
     }
 }
@@ -210,6 +217,13 @@ impl<N: Number> MinMaxAvg<N> {
 
         self.sum += n;
         self.len.inc();
+        self.values.push(n);
+    }
+
+    /// Whether no values were added; min, max, and average are meaningless
+    /// in that case.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
     }
 
     pub fn min(&self) -> N {
@@ -223,6 +237,42 @@ impl<N: Number> MinMaxAvg<N> {
     pub fn avg(&self) -> N {
         self.sum / self.len
     }
+
+    /// For an even amount of values, the median is the average of the two
+    /// middle values. Returns zero if no values were added.
+    pub fn median(&self) -> N {
+        let Some((mut lower, upper)) = self.middle() else {
+            return N::zero();
+        };
+
+        let mut two = N::zero();
+        two.inc();
+        two.inc();
+
+        lower += upper;
+
+        lower / two
+    }
+
+    /// Returns the two middle values of the sorted values.
+    ///
+    /// For an odd amount of values, both are the same.
+    fn middle(&self) -> Option<(N, N)> {
+        if self.values.is_empty() {
+            return None;
+        }
+
+        let mut sorted = self.values.clone();
+        sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let mid = sorted.len() / 2;
+
+        if sorted.len() % 2 == 0 {
+            Some((sorted[mid - 1], sorted[mid]))
+        } else {
+            Some((sorted[mid], sorted[mid]))
+        }
+    }
 }
 
 pub trait AsFloat {
@@ -254,6 +304,12 @@ impl<N: Number + AsFloat> MinMaxAvg<N> {
     pub fn avg_float(&self) -> f32 {
         self.sum.into_f32() / self.len.into_f32()
     }
+
+    pub fn median_float(&self) -> f32 {
+        self.middle().map_or(0.0, |(lower, upper)| {
+            (lower.into_f32() + upper.into_f32()) / 2.0
+        })
+    }
 }
 
 impl From<MinMaxAvg<f32>> for MinMaxAvg<u32> {
@@ -263,6 +319,7 @@ impl From<MinMaxAvg<f32>> for MinMaxAvg<u32> {
             max: other.max as u32,
             sum: other.sum as u32,
             len: other.len as u32,
+            values: other.values.into_iter().map(|n| n as u32).collect(),
         }
     }
 }
@@ -341,4 +398,29 @@ mod tests {
 
         assert_eq!(AbbreviatedScore::new(727_u64).to_string(), "727".to_owned());
     }
+
+    #[test]
+    fn min_max_avg_median() {
+        let mut odd = MinMaxAvg::new();
+
+        for n in [7.0_f32, 1.0, 100.0, 3.0, 5.0] {
+            odd.add(n);
+        }
+
+        assert_eq!(odd.min(), 1.0);
+        assert_eq!(odd.max(), 100.0);
+        assert_eq!(odd.avg(), 23.2);
+        assert_eq!(odd.median(), 5.0);
+
+        let mut even = MinMaxAvg::new();
+
+        for n in [4_u32, 1, 10, 3] {
+            even.add(n);
+        }
+
+        assert_eq!(even.median(), 3);
+        assert_eq!(even.median_float(), 3.5);
+
+        assert_eq!(MinMaxAvg::<f64>::new().median(), 0.0);
+    }
 }
@@ -4,7 +4,14 @@ use std::{
 };
 
 use rosu_v2::{
-    model::{mods::GameMods, score::LegacyScoreStatistics, Grade},
+    model::{
+        mods::{
+            DifficultyAdjustCatch, DifficultyAdjustMania, DifficultyAdjustOsu,
+            DifficultyAdjustTaiko, GameMods,
+        },
+        score::LegacyScoreStatistics,
+        Grade,
+    },
     mods,
     prelude::{GameMod, GameModIntermode, GameMode, GameModsIntermode, Score},
 };
@@ -33,51 +40,45 @@ impl ModSelection {
 
     /// Returns `true` if the score's mods coincide with this [`ModSelection`]
     pub fn filter_score(&self, score: &Score) -> bool {
+        self.filter_mods(&score.mods)
+    }
+
+    /// Remove all scores whos mods do not coincide with this [`ModSelection`]
+    pub fn filter_scores(&self, scores: &mut Vec<Score>) {
+        scores.retain(|score| self.filter_score(score));
+    }
+
+    /// Returns `true` if the mods coincide with this [`ModSelection`]
+    ///
+    /// Both sides are compared after [`normalize_mods`] so `+nc!` matches
+    /// scores with NC or DTNC but not plain DT. Included or excluded DT and
+    /// SD also cover NC and PF, respectively.
+    pub fn filter_mods(&self, mods: &GameMods) -> bool {
+        self.matches(normalize_mods(
+            mods.iter().map(GameMod::intermode).collect(),
+        ))
+    }
+
+    fn matches(&self, score_mods: GameModsIntermode) -> bool {
         const DT: GameModIntermode = GameModIntermode::DoubleTime;
         const NC: GameModIntermode = GameModIntermode::Nightcore;
         const SD: GameModIntermode = GameModIntermode::SuddenDeath;
         const PF: GameModIntermode = GameModIntermode::Perfect;
 
-        match self {
-            ModSelection::Include(mods) | ModSelection::Exact(mods) if mods.is_empty() => {
-                score.mods.is_empty()
-            }
-            ModSelection::Include(mods) => mods.iter().all(|gamemod| match gamemod {
-                DT => score.mods.contains_intermode(DT) || score.mods.contains_intermode(NC),
-                SD => score.mods.contains_intermode(SD) || score.mods.contains_intermode(PF),
-                _ => score.mods.contains_intermode(gamemod),
-            }),
-            ModSelection::Exclude(mods) if mods.is_empty() => !score.mods.is_empty(),
-            ModSelection::Exclude(mods) => !mods.iter().any(|gamemod| match gamemod {
-                DT => score.mods.contains_intermode(DT) || score.mods.contains_intermode(NC),
-                SD => score.mods.contains_intermode(SD) || score.mods.contains_intermode(PF),
-                _ => score.mods.contains_intermode(gamemod),
-            }),
-            ModSelection::Exact(mods) => score.mods.iter().map(GameMod::intermode).eq(mods.iter()),
-        }
-    }
+        let contains = |gamemod: GameModIntermode| match gamemod {
+            DT => score_mods.contains(DT) || score_mods.contains(NC),
+            SD => score_mods.contains(SD) || score_mods.contains(PF),
+            _ => score_mods.contains(gamemod),
+        };
 
-    /// Remove all scores whos mods do not coincide with this [`ModSelection`]
-    pub fn filter_scores(&self, scores: &mut Vec<Score>) {
         match self {
             ModSelection::Include(mods) | ModSelection::Exact(mods) if mods.is_empty() => {
-                scores.retain(|score| score.mods.is_empty())
-            }
-            ModSelection::Include(mods) => scores.retain(|score| {
-                mods.iter()
-                    .all(|gamemod| score.mods.contains_intermode(gamemod))
-            }),
-            ModSelection::Exclude(mods) if mods.is_empty() => {
-                scores.retain(|score| !score.mods.is_empty())
-            }
-            ModSelection::Exclude(mods) => scores.retain(|score| {
-                !mods
-                    .iter()
-                    .any(|gamemod| score.mods.contains_intermode(gamemod))
-            }),
-            ModSelection::Exact(mods) => {
-                scores.retain(|score| score.mods.iter().map(GameMod::intermode).eq(mods.iter()))
+                score_mods.is_empty()
             }
+            ModSelection::Include(mods) => mods.iter().all(contains),
+            ModSelection::Exclude(mods) if mods.is_empty() => !score_mods.is_empty(),
+            ModSelection::Exclude(mods) => !mods.iter().any(contains),
+            ModSelection::Exact(mods) => normalize_mods(mods.to_owned()) == score_mods,
         }
     }
 
@@ -101,6 +102,21 @@ impl ModSelection {
     }
 }
 
+/// Nightcore and Perfect imply DoubleTime and SuddenDeath. Depending on where
+/// mods come from, the implied mod may or may not be present alongside so
+/// this removes it, e.g. DTNC becomes NC and SDPF becomes PF.
+pub fn normalize_mods(mut mods: GameModsIntermode) -> GameModsIntermode {
+    if mods.contains(GameModIntermode::Nightcore) {
+        mods.remove(GameModIntermode::DoubleTime);
+    }
+
+    if mods.contains(GameModIntermode::Perfect) {
+        mods.remove(GameModIntermode::SuddenDeath);
+    }
+
+    mods
+}
+
 pub fn flag_url(country_code: &str) -> String {
     // format!("{OSU_BASE}/images/flags/{country_code}.png") // from osu itself but
     // outdated
@@ -379,6 +395,57 @@ pub trait UserStats {
     fn playcount(&self) -> u32;
 }
 
+/// Attributes that are set manually through a `DifficultyAdjust` mod.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DifficultyAdjustSettings {
+    pub cs: Option<f32>,
+    pub ar: Option<f32>,
+    pub hp: Option<f32>,
+    pub od: Option<f32>,
+}
+
+impl DifficultyAdjustSettings {
+    /// Returns `None` if the mod is not `DifficultyAdjust`.
+    pub fn new(gamemod: &GameMod) -> Option<Self> {
+        match gamemod {
+            GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+                circle_size,
+                approach_rate,
+                drain_rate,
+                overall_difficulty,
+                ..
+            })
+            | GameMod::DifficultyAdjustCatch(DifficultyAdjustCatch {
+                circle_size,
+                approach_rate,
+                drain_rate,
+                overall_difficulty,
+                ..
+            }) => Some(Self {
+                cs: *circle_size,
+                ar: *approach_rate,
+                hp: *drain_rate,
+                od: *overall_difficulty,
+            }),
+            GameMod::DifficultyAdjustTaiko(DifficultyAdjustTaiko {
+                drain_rate,
+                overall_difficulty,
+                ..
+            })
+            | GameMod::DifficultyAdjustMania(DifficultyAdjustMania {
+                drain_rate,
+                overall_difficulty,
+                ..
+            }) => Some(Self {
+                hp: *drain_rate,
+                od: *overall_difficulty,
+                ..Default::default()
+            }),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum AttributeKind {
     Ar,
@@ -4,7 +4,7 @@ use bathbot_util::MessageBuilder;
 use eyre::{Report, Result, WrapErr};
 use tokio::{
     sync::watch::{self, Receiver},
-    time::sleep,
+    time::{sleep_until, Instant},
 };
 
 use super::{
@@ -85,7 +85,8 @@ impl ActiveMessagesBuilder {
         let (activity_tx, activity_rx) = watch::channel(());
 
         if let Some(until_timeout) = active_msg.until_timeout() {
-            Self::spawn_timeout(activity_rx, response, until_timeout);
+            let max_lifetime = active_msg.max_lifetime();
+            Self::spawn_timeout(activity_rx, response, until_timeout, max_lifetime);
 
             let full = FullActiveMessage {
                 active_msg,
@@ -109,28 +110,111 @@ impl ActiveMessagesBuilder {
         }
     }
 
-    fn spawn_timeout(mut rx: Receiver<()>, response: ActiveResponse, until_timeout: Duration) {
+    fn spawn_timeout(
+        mut rx: Receiver<()>,
+        response: ActiveResponse,
+        until_timeout: Duration,
+        max_lifetime: Option<Duration>,
+    ) {
         tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    res = rx.changed() => if res.is_ok() {
-                        continue
-                    } else {
-                        return
-                    },
-                    _ = sleep(until_timeout) => {
-                        let active_msg = Context::get().active_msgs.remove_full(response.msg).await;
-
-                        if let Some(FullActiveMessage { mut active_msg, .. }) = active_msg {
-                            if let Err(err) = active_msg.on_timeout(response).await {
-                                warn!(?err, "Failed to timeout active message");
-                            }
-                        }
-
-                        return;
-                    },
+            // Deleted messages are removed from the active messages which drops
+            // the activity sender so there's nothing left to time out
+            if !wait_for_timeout(&mut rx, until_timeout, max_lifetime).await {
+                return;
+            }
+
+            let active_msg = Context::get().active_msgs.remove_full(response.msg).await;
+
+            if let Some(FullActiveMessage { mut active_msg, .. }) = active_msg {
+                if let Err(err) = active_msg.on_timeout(response).await {
+                    warn!(?err, "Failed to timeout active message");
                 }
             }
         });
     }
 }
+
+/// Returns `true` once the active message timed out and `false` as soon as the
+/// activity sender is dropped, i.e. the active message was removed.
+async fn wait_for_timeout(
+    rx: &mut Receiver<()>,
+    until_timeout: Duration,
+    max_lifetime: Option<Duration>,
+) -> bool {
+    let start = Instant::now();
+    let mut deadline = timeout_deadline(start, start, until_timeout, max_lifetime);
+
+    loop {
+        tokio::select! {
+            res = rx.changed() => if res.is_ok() {
+                deadline = timeout_deadline(start, Instant::now(), until_timeout, max_lifetime);
+            } else {
+                return false
+            },
+            _ = sleep_until(deadline) => return true,
+        }
+    }
+}
+
+/// Each activity extends the timeout but never beyond the maximum lifetime.
+fn timeout_deadline(
+    start: Instant,
+    last_activity: Instant,
+    until_timeout: Duration,
+    max_lifetime: Option<Duration>,
+) -> Instant {
+    let deadline = last_activity + until_timeout;
+
+    match max_lifetime {
+        Some(max_lifetime) => deadline.min(start + max_lifetime),
+        None => deadline,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn activity_extends_deadline_up_to_lifetime() {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(60);
+        let lifetime = Some(Duration::from_secs(600));
+
+        let deadline = timeout_deadline(start, start, timeout, lifetime);
+        assert_eq!(deadline, start + timeout);
+
+        let activity = start + Duration::from_secs(100);
+        let deadline = timeout_deadline(start, activity, timeout, lifetime);
+        assert_eq!(deadline, activity + timeout);
+
+        let activity = start + Duration::from_secs(590);
+        let deadline = timeout_deadline(start, activity, timeout, lifetime);
+        assert_eq!(deadline, start + Duration::from_secs(600));
+
+        let deadline = timeout_deadline(start, activity, timeout, None);
+        assert_eq!(deadline, activity + timeout);
+    }
+
+    #[tokio::test]
+    async fn removed_message_stops_waiting() {
+        let (tx, mut rx) = watch::channel(());
+        let wait_fut = wait_for_timeout(&mut rx, Duration::from_secs(60), None);
+
+        drop(tx);
+
+        let timed_out = tokio::time::timeout(Duration::from_secs(1), wait_fut)
+            .await
+            .expect("waited for the full timeout");
+
+        assert!(!timed_out);
+    }
+
+    #[tokio::test]
+    async fn inactive_message_times_out() {
+        let (_tx, mut rx) = watch::channel(());
+        let timeout = Duration::from_millis(10);
+
+        assert!(wait_for_timeout(&mut rx, timeout, None).await);
+    }
+}
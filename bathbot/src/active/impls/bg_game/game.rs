@@ -2,15 +2,27 @@ use std::{collections::VecDeque, sync::RwLock};
 
 use bathbot_model::Effects;
 use bathbot_psql::model::games::MapsetTagsEntries;
-use bathbot_util::{constants::OSU_BASE, CowUtils};
+use bathbot_util::{
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+    CowUtils,
+};
 use eyre::{Result, WrapErr};
+use futures::future;
 use image::{
     imageops::{self, colorops},
     GenericImageView,
 };
 use rosu_v2::model::GameMode;
-use tokio::{fs, sync::RwLock as TokioRwLock};
+use tokio::{
+    fs,
+    sync::RwLock as TokioRwLock,
+    time::{self, Duration},
+};
 use tokio_stream::StreamExt;
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
 use twilight_model::id::{
     marker::{ChannelMarker, UserMarker},
     Id,
@@ -238,6 +250,46 @@ pub async fn game_loop(
     LoopResult::Stop
 }
 
+/// Reveal a new hint every `interval` until the round is over.
+///
+/// Only resolves if the channel is gone, otherwise it's up to the caller to
+/// drop the future once the round ends.
+pub async fn hint_loop(
+    game_locked: &TokioRwLock<Game>,
+    channel: Id<ChannelMarker>,
+    interval: Option<Duration>,
+) -> LoopResult {
+    let Some(interval) = interval else {
+        return future::pending().await;
+    };
+
+    let mut interval = time::interval_at(time::Instant::now() + interval, interval);
+
+    loop {
+        interval.tick().await;
+
+        let hint = game_locked.read().await.hint();
+
+        if let Err(err) = channel.plain_message(&hint).await {
+            if let ErrorType::Response {
+                error:
+                    ApiError::General(GeneralApiError {
+                        code: UNKNOWN_CHANNEL,
+                        ..
+                    }),
+                ..
+            } = err.kind()
+            {
+                info!(%channel, "Channel of bg game is gone, stopping game");
+
+                return LoopResult::Stop;
+            }
+
+            warn!(?err, "Failed to send timed bg game hint");
+        }
+    }
+}
+
 // bool to tell whether its an exact match
 enum ContentResult {
     Title(bool),
@@ -20,7 +20,7 @@ use twilight_model::{
     id::{marker::ChannelMarker, Id},
 };
 
-use super::game::{game_loop, Game, LoopResult};
+use super::game::{game_loop, hint_loop, Game, LoopResult};
 use crate::{commands::fun::GameDifficulty, util::ChannelExt, Context};
 
 const GAME_LEN: Duration = Duration::from_secs(180);
@@ -37,6 +37,7 @@ impl BackgroundGame {
         entries: MapsetTagsEntries,
         effects: Effects,
         difficulty: GameDifficulty,
+        hint_interval: Option<Duration>,
     ) -> Self {
         let (tx, mut rx) = mpsc::unbounded_channel();
 
@@ -66,6 +67,8 @@ impl BackgroundGame {
                     option = rx.recv() => option.unwrap_or(LoopResult::Stop),
                     // Let the game run
                     result = game_loop(&mut msg_stream, &game_clone, channel) => result,
+                    // Reveal hints periodically if enabled
+                    result = hint_loop(&game_clone, channel, hint_interval) => result,
                     // Timeout after 3 minutes
                     _ = sleep(GAME_LEN) => LoopResult::Stop,
                 };
@@ -126,7 +129,7 @@ impl BackgroundGame {
                 *game_clone.write().await = game;
             }
 
-            Context::bg_games().write(&channel).await.remove();
+            Context::remove_bg_game(channel).await;
         });
 
         Self { game, tx }
@@ -1,3 +1,5 @@
+use std::{fmt::Write, time::Duration};
+
 use bathbot_model::{Effects, MapsetTags};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{constants::GENERAL_ISSUE, fields, EmbedBuilder, FooterBuilder, MessageBuilder};
@@ -32,6 +34,9 @@ mod util;
 
 pub struct BackgroundGameSetup {
     difficulty: GameDifficulty,
+    min_stars: Option<f32>,
+    max_stars: Option<f32>,
+    hint_interval: Option<Duration>,
     effects: Effects,
     excluded: MapsetTags,
     included: MapsetTags,
@@ -322,9 +327,18 @@ impl IActiveMessage for BackgroundGameSetup {
 }
 
 impl BackgroundGameSetup {
-    pub fn new(difficulty: GameDifficulty, msg_owner: Id<UserMarker>) -> Self {
+    pub fn new(
+        difficulty: GameDifficulty,
+        min_stars: Option<f32>,
+        max_stars: Option<f32>,
+        hint_interval: Option<Duration>,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
         Self {
             difficulty,
+            min_stars,
+            max_stars,
+            hint_interval,
             msg_owner,
             effects: Effects::empty(),
             excluded: MapsetTags::empty(),
@@ -334,7 +348,7 @@ impl BackgroundGameSetup {
     }
 
     async fn start(&mut self, channel: Id<ChannelMarker>) -> Result<BuildPage> {
-        if let Some(game) = Context::bg_games().write(&channel).await.remove() {
+        if let Some(game) = Context::remove_bg_game(channel).await {
             if let Err(err) = game.stop() {
                 warn!(?err, "Failed to stop previous game");
             }
@@ -344,6 +358,8 @@ impl BackgroundGameSetup {
 
         params.include(self.included);
         params.exclude(self.excluded);
+        params.min_stars = self.min_stars;
+        params.max_stars = self.max_stars;
 
         let entries = match Context::games().bggame_tags(params).await {
             Ok(entries) => entries,
@@ -381,7 +397,26 @@ impl BackgroundGameSetup {
             "Effects", effects_value, true;
         ];
 
-        let footer = FooterBuilder::new(format!("Difficulty: {:?}", self.difficulty));
+        let mut footer_text = format!("Difficulty: {:?}", self.difficulty);
+
+        match (self.min_stars, self.max_stars) {
+            (Some(min), Some(max)) => {
+                let _ = write!(footer_text, " • Stars: {min:.2}★ - {max:.2}★");
+            }
+            (Some(min), None) => {
+                let _ = write!(footer_text, " • Stars: at least {min:.2}★");
+            }
+            (None, Some(max)) => {
+                let _ = write!(footer_text, " • Stars: at most {max:.2}★");
+            }
+            (None, None) => {}
+        }
+
+        if let Some(interval) = self.hint_interval {
+            let _ = write!(footer_text, " • Hints every {}s", interval.as_secs());
+        }
+
+        let footer = FooterBuilder::new(footer_text);
         let title = format!("Selected tags ({} backgrounds)", entries.tags.len());
 
         let embed = EmbedBuilder::new()
@@ -390,7 +425,8 @@ impl BackgroundGameSetup {
             .title(title);
 
         if entries.tags.is_empty() {
-            let description = "No stored backgrounds match these tags, try different ones";
+            let description =
+                "No stored backgrounds match these tags and star ratings, try different ones";
 
             Ok(BuildPage::new(embed.description(description), true))
         } else {
@@ -400,10 +436,16 @@ impl BackgroundGameSetup {
                 "Starting game"
             );
 
-            let game_fut = BackgroundGame::new(channel, entries, self.effects, self.difficulty);
+            let game_fut = BackgroundGame::new(
+                channel,
+                entries,
+                self.effects,
+                self.difficulty,
+                self.hint_interval,
+            );
 
             let game = game_fut.await;
-            Context::bg_games().own(channel).await.insert(game);
+            Context::insert_bg_game(channel, game).await;
 
             Ok(BuildPage::new(embed, true))
         }
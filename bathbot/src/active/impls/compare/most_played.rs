@@ -15,6 +15,7 @@ use crate::{
         pagination::{handle_pagination_component, handle_pagination_modal, Pages},
         BuildPage, ComponentResult, IActiveMessage,
     },
+    embeds::attachment,
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
@@ -61,7 +62,9 @@ impl IActiveMessage for CompareMostPlayedPagination {
 
         description.pop();
 
-        let embed = EmbedBuilder::new().description(description);
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .thumbnail(attachment("avatar_fuse.png"));
 
         BuildPage::new(embed, false)
             .content(self.content.clone())
@@ -1,10 +1,10 @@
 use std::{cmp::Ordering, collections::HashMap, fmt::Write};
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_util::{constants::OSU_BASE, EmbedBuilder, FooterBuilder, IntHasher};
+use bathbot_util::{constants::OSU_BASE, EmbedBuilder, FooterBuilder, IntHasher, ModsFormatter};
 use eyre::Result;
 use futures::future::BoxFuture;
-use rosu_v2::prelude::Username;
+use rosu_v2::prelude::{GameMods, Username};
 use twilight_model::{
     channel::message::Component,
     id::{marker::UserMarker, Id},
@@ -34,6 +34,15 @@ pub struct CompareTopPagination {
     pages: Pages,
 }
 
+/// Writes a user's placement on a map alongside the pp and mods of their score
+fn write_score(description: &mut String, medal: &str, name: &str, pp: f32, mods: &GameMods) {
+    let _ = write!(
+        description,
+        ":{medal}_place: `{name}`: {pp:.2}pp +{mods}",
+        mods = ModsFormatter::new(mods),
+    );
+}
+
 impl IActiveMessage for CompareTopPagination {
     fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
         let pages = &self.pages;
@@ -51,17 +60,29 @@ impl IActiveMessage for CompareTopPagination {
                 Ordering::Greater => ("first", "second"),
             };
 
-            let _ = writeln!(
+            let _ = write!(
                 description,
-                "**{i}.** [{title} [{version}]]({OSU_BASE}b/{map_id})\n\
-                - :{medal1}_place: `{name1}`: {pp1:.2}pp :{medal2}_place: `{name2}`: {pp2:.2}pp",
+                "**{i}.** [{title} [{version}]]({OSU_BASE}b/{map_id})\n- ",
                 title = map.title,
                 version = map.version,
-                name1 = self.name1,
-                pp1 = score1.pp,
-                name2 = self.name2,
-                pp2 = score2.pp,
             );
+
+            write_score(
+                &mut description,
+                medal1,
+                &self.name1,
+                score1.pp,
+                &score1.mods,
+            );
+            description.push(' ');
+            write_score(
+                &mut description,
+                medal2,
+                &self.name2,
+                score2.pp,
+                &score2.mods,
+            );
+            description.push('\n');
         }
 
         description.pop();
@@ -100,3 +121,29 @@ impl IActiveMessage for CompareTopPagination {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::{GameMode, GameModsIntermode};
+
+    use super::*;
+
+    #[test]
+    fn scores_show_their_mods() {
+        let mods1 = GameModsIntermode::try_from_acronyms("HDDT")
+            .unwrap()
+            .with_mode(GameMode::Osu);
+
+        let mods2 = GameMods::new();
+
+        let mut description = String::new();
+        write_score(&mut description, "first", "a", 400.0, &mods1);
+        description.push(' ');
+        write_score(&mut description, "second", "b", 350.5, &mods2);
+
+        assert_eq!(
+            description,
+            ":first_place: `a`: 400.00pp +HDDT :second_place: `b`: 350.50pp +NM"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::{mem, time::Duration};
 
+use bathbot_psql::model::configs::ScoreSize;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, ORDR_ISSUE},
     EmbedBuilder, MessageBuilder,
@@ -33,6 +34,29 @@ use crate::{
 mod recent_score;
 mod top_score;
 
+/// Resolves [`ScoreSize::Auto`] based on the score's 0-based indices in the
+/// user's top scores and the map's global leaderboard.
+///
+/// Scores within the top50 of either are considered notable and will be
+/// maximized while all other scores are minimized.
+fn resolve_score_size(
+    size: ScoreSize,
+    personal_idx: Option<usize>,
+    global_idx: Option<usize>,
+) -> ScoreSize {
+    if size != ScoreSize::Auto {
+        return size;
+    }
+
+    let is_notable = |idx: Option<usize>| idx.is_some_and(|idx| idx < 50);
+
+    if is_notable(personal_idx) || is_notable(global_idx) {
+        ScoreSize::AlwaysMaximized
+    } else {
+        ScoreSize::AlwaysMinimized
+    }
+}
+
 pub struct EditOnTimeout {
     inner: EditOnTimeoutInner,
     kind: EditOnTimeoutKind,
@@ -483,3 +507,42 @@ impl ButtonData {
         (self.score_id, &mut self.replay_score)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::osu::PersonalBestIndex;
+
+    #[test]
+    fn auto_score_size() {
+        let resolve = |pb_idx: PersonalBestIndex, global_idx| {
+            resolve_score_size(ScoreSize::Auto, pb_idx.idx(), global_idx)
+        };
+
+        let pb = PersonalBestIndex::FoundScore { idx: 3 };
+        assert_eq!(resolve(pb, None), ScoreSize::AlwaysMaximized);
+
+        let v1d = PersonalBestIndex::ScoreV1d {
+            would_be_idx: 10,
+            old_idx: 20,
+        };
+        assert_eq!(resolve(v1d, None), ScoreSize::AlwaysMaximized);
+
+        let low_pb = PersonalBestIndex::IfRanked { idx: 75 };
+        assert_eq!(resolve(low_pb, None), ScoreSize::AlwaysMinimized);
+
+        let better = PersonalBestIndex::FoundBetter { idx: 1 };
+        assert_eq!(resolve(better, None), ScoreSize::AlwaysMinimized);
+        assert_eq!(
+            resolve(PersonalBestIndex::NotTop100, Some(12)),
+            ScoreSize::AlwaysMaximized
+        );
+        assert_eq!(
+            resolve(PersonalBestIndex::NotTop100, None),
+            ScoreSize::AlwaysMinimized
+        );
+
+        let size = resolve_score_size(ScoreSize::AlwaysMinimized, Some(0), Some(0));
+        assert_eq!(size, ScoreSize::AlwaysMinimized);
+    }
+}
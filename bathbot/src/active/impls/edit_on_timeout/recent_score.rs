@@ -12,7 +12,7 @@ use bathbot_util::{
 };
 use rosu_v2::prelude::{BeatmapUserScore, GameMode, Score};
 
-use super::{ButtonData, EditOnTimeout, EditOnTimeoutKind};
+use super::{resolve_score_size, ButtonData, EditOnTimeout, EditOnTimeoutKind};
 #[cfg(feature = "twitch")]
 use crate::commands::osu::RecentTwitchStream;
 use crate::{
@@ -21,7 +21,7 @@ use crate::{
     embeds::{ComboFormatter, HitResultFormatter, KeyFormatter, PpFormatter},
     manager::{redis::RedisData, OsuMap, OwnedReplayScore},
     util::{
-        osu::{grade_completion_mods, IfFc, MapInfo, PersonalBestIndex},
+        osu::{fail_position, grade_completion_mods, IfFc, MapInfo, PersonalBestIndex},
         Emote,
     },
 };
@@ -87,15 +87,22 @@ impl RecentScoreEdit {
             (combo, title)
         };
 
-        let personal_best = personal
-            .map(|top100| PersonalBestIndex::new(score, map.map_id(), map.status(), top100))
-            .and_then(|pb_idx| pb_idx.into_embed_description(origin));
+        let pb_idx = personal
+            .map(|top100| PersonalBestIndex::new(score, map.map_id(), map.status(), top100));
 
         let global_idx = map_score
             .and_then(|s| score.is_eq(s).then_some(s.pos))
             .filter(|&p| p <= 50);
 
-        let description = if personal_best.is_some() || global_idx.is_some() {
+        let size = resolve_score_size(
+            size,
+            pb_idx.as_ref().and_then(PersonalBestIndex::idx),
+            global_idx.map(|pos| pos - 1),
+        );
+
+        let personal_best = pb_idx.and_then(|pb_idx| pb_idx.into_embed_description(origin));
+
+        let mut description = if personal_best.is_some() || global_idx.is_some() {
             let mut description = String::with_capacity(25);
             description.push_str("__**");
 
@@ -119,6 +126,16 @@ impl RecentScoreEdit {
             String::new()
         };
 
+        let position = fail_position(score, map.mode(), map.n_objects(), map.seconds_drain());
+
+        if let Some(position) = position {
+            if !description.is_empty() {
+                description.push('\n');
+            }
+
+            description.push_str(&position);
+        }
+
         let url = format!("{OSU_BASE}b/{}", map.map_id());
         let author = user.author_builder();
         let pp = Some(score.pp);
@@ -133,7 +150,8 @@ impl RecentScoreEdit {
         };
 
         match size {
-            ScoreSize::AlwaysMinimized => {
+            // `ScoreSize::Auto` was resolved to one of the others already
+            ScoreSize::AlwaysMinimized | ScoreSize::Auto => {
                 let minimized = Self::minimized(
                     score,
                     map,
@@ -367,7 +385,9 @@ impl RecentScoreEdit {
 
         let combo = highlight_funny_numeral(&combo).into_owned();
 
-        let mut hits = HitResultFormatter::new(score.mode, score.statistics.clone()).to_string();
+        let mut hits = HitResultFormatter::new(score.mode, score.statistics.clone())
+            .slider_hits(score.slider_hits)
+            .to_string();
         hits = highlight_funny_numeral(&hits).into_owned();
 
         let mania = score.mode == GameMode::Mania;
@@ -11,7 +11,7 @@ use bathbot_util::{
 };
 use rosu_v2::prelude::GameMode;
 
-use super::{ButtonData, EditOnTimeout, EditOnTimeoutKind};
+use super::{resolve_score_size, ButtonData, EditOnTimeout, EditOnTimeoutKind};
 use crate::{
     active::BuildPage,
     commands::osu::TopEntry,
@@ -120,8 +120,11 @@ impl TopScoreEdit {
             },
         };
 
+        let size = resolve_score_size(size, personal_idx, global_idx);
+
         match size {
-            ScoreSize::AlwaysMinimized => {
+            // `ScoreSize::Auto` was resolved to one of the others already
+            ScoreSize::AlwaysMinimized | ScoreSize::Auto => {
                 let minimized = Self::minimized(
                     score,
                     map,
@@ -310,7 +313,9 @@ impl TopScoreEdit {
 
         fields.reserve(3 + (if_fc.is_some() as usize) * 3);
 
-        let hits = HitResultFormatter::new(score.mode, score.statistics.clone()).to_string();
+        let hits = HitResultFormatter::new(score.mode, score.statistics.clone())
+            .slider_hits(score.slider_hits)
+            .to_string();
 
         let mania = score.mode == GameMode::Mania;
         let combo_name = if mania { "Combo / Ratio" } else { "Combo" };
@@ -96,6 +96,10 @@ impl IActiveMessage for HigherLowerGame {
             ButtonState::TryAgain { .. } => Some(Duration::from_secs(30)),
         }
     }
+
+    fn max_lifetime(&self) -> Option<Duration> {
+        None
+    }
 }
 
 impl HigherLowerGame {
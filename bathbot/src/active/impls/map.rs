@@ -102,14 +102,11 @@ impl MapPagination {
             mapset_id = map.mapset_id,
         );
 
-        let mut seconds_total = map.seconds_total;
-        let mut seconds_drain = map.seconds_drain;
-        let mut bpm = map.bpm;
+        let clock_rate = resolve_clock_rate(&self.attrs, &self.mods);
 
-        let clock_rate = self.mods.legacy_clock_rate();
-        seconds_total = (seconds_total as f32 / clock_rate) as u32;
-        seconds_drain = (seconds_drain as f32 / clock_rate) as u32;
-        bpm *= clock_rate;
+        let seconds_total = adjust_seconds(map.seconds_total, clock_rate);
+        let seconds_drain = adjust_seconds(map.seconds_drain, clock_rate);
+        let bpm = map.bpm * clock_rate;
 
         let mut info_value = String::with_capacity(128);
         let mut fields = Vec::with_capacity(3);
@@ -151,7 +148,6 @@ impl MapPagination {
             .calculate(&rosu_map);
 
         let stars = attrs.stars();
-        const ACCS: [f32; 4] = [95.0, 97.0, 99.0, 100.0];
         let mut pps = Vec::with_capacity(ACCS.len());
 
         for &acc in ACCS.iter() {
@@ -162,46 +158,11 @@ impl MapPagination {
                 .clock_rate(clock_rate as f64)
                 .calculate();
 
-            let pp = pp_result.pp();
-
-            let pp_str = if pp > 100_000.0 {
-                format!("{pp:.3e}")
-            } else {
-                round(pp as f32).to_string()
-            };
-
-            pps.push(pp_str);
+            pps.push(format_pp(pp_result.pp()));
             attrs = pp_result.into();
         }
 
-        let mut pp_values = String::with_capacity(128);
-        let mut lens = Vec::with_capacity(ACCS.len());
-
-        pp_values.push_str("```ansi\nAcc ");
-
-        for (pp, &acc) in pps.iter().zip(&ACCS) {
-            let acc = acc.to_string() + "%";
-            let len = pp.len().max(acc.len()) + 2;
-            let _ = write!(pp_values, "|{acc:^len$}");
-            lens.push(len);
-        }
-
-        pp_values.push_str("\n----");
-
-        for len in lens.iter() {
-            let _ = write!(pp_values, "+{:->len$}", "-");
-        }
-
-        pp_values.push_str("\n PP ");
-
-        let bold = "\u{001b}[1m";
-        let reset = "\u{001b}[0m";
-
-        for (pp, len) in pps.iter().zip(&lens) {
-            let _ = write!(pp_values, "|{bold}{pp:^len$}{reset}");
-        }
-
-        pp_values.push_str("\n```");
+        let pp_values = pp_table(&ACCS, &pps);
 
         if let Some(combo) = map.max_combo {
             let _ = write!(info_value, "Combo: `{combo}x`");
@@ -323,6 +284,50 @@ impl MapPagination {
     }
 }
 
+const ACCS: [f32; 5] = [95.0, 97.0, 98.0, 99.0, 100.0];
+
+fn format_pp(pp: f64) -> String {
+    if pp > 100_000.0 {
+        format!("{pp:.3e}")
+    } else {
+        round(pp as f32).to_string()
+    }
+}
+
+/// Table with a column for each accuracy and its pp value below.
+fn pp_table(accs: &[f32], pps: &[String]) -> String {
+    let mut table = String::with_capacity(128);
+    let mut lens = Vec::with_capacity(accs.len());
+
+    table.push_str("```ansi\nAcc ");
+
+    for (pp, &acc) in pps.iter().zip(accs) {
+        let acc = acc.to_string() + "%";
+        let len = pp.len().max(acc.len()) + 2;
+        let _ = write!(table, "|{acc:^len$}");
+        lens.push(len);
+    }
+
+    table.push_str("\n----");
+
+    for len in lens.iter() {
+        let _ = write!(table, "+{:->len$}", "-");
+    }
+
+    table.push_str("\n PP ");
+
+    let bold = "\u{001b}[1m";
+    let reset = "\u{001b}[0m";
+
+    for (pp, len) in pps.iter().zip(&lens) {
+        let _ = write!(table, "|{bold}{pp:^len$}{reset}");
+    }
+
+    table.push_str("\n```");
+
+    table
+}
+
 async fn creator_name(map: &BeatmapExtended, mapset: &BeatmapsetExtended) -> Option<Username> {
     if map.creator_id == mapset.creator_id {
         return None;
@@ -346,3 +351,65 @@ async fn creator_name(map: &BeatmapExtended, mapset: &BeatmapsetExtended) -> Opt
         }
     }
 }
+
+/// A custom clock rate takes precedence over the mods' clock rate.
+fn resolve_clock_rate(attrs: &CustomAttrs, mods: &GameModsIntermode) -> f32 {
+    attrs
+        .clock_rate
+        .map_or_else(|| mods.legacy_clock_rate(), |rate| rate as f32)
+}
+
+fn adjust_seconds(seconds: u32, clock_rate: f32) -> u32 {
+    (seconds as f32 / clock_rate) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pp_table_columns() {
+        let pps = ["250.12", "280", "301.5"].map(String::from);
+        let table = pp_table(&[98.0, 99.0, 100.0], &pps);
+
+        let bold = "\u{001b}[1m";
+        let reset = "\u{001b}[0m";
+
+        let expected = format!(
+            "```ansi\n\
+            Acc |  98%   | 99% | 100%  \n\
+            ----+--------+-----+-------\n \
+            PP |{bold} 250.12 {reset}|{bold} 280 {reset}|{bold} 301.5 {reset}\n\
+            ```"
+        );
+
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn huge_pp_uses_scientific_notation() {
+        assert_eq!(format_pp(123.456), "123.46");
+        assert_eq!(format_pp(1_234_567.0), "1.235e6");
+    }
+
+    #[test]
+    fn custom_clock_rate_adjusts_bpm_and_length() {
+        let dt = GameModsIntermode::try_from_acronyms("DT").unwrap();
+
+        let attrs = CustomAttrs::default();
+        assert_eq!(resolve_clock_rate(&attrs, &GameModsIntermode::new()), 1.0);
+        assert_eq!(resolve_clock_rate(&attrs, &dt), 1.5);
+
+        // The custom clock rate overrides the mods' clock rate
+        let attrs = CustomAttrs {
+            clock_rate: Some(1.25),
+            ..Default::default()
+        };
+
+        let clock_rate = resolve_clock_rate(&attrs, &dt);
+        assert_eq!(clock_rate, 1.25);
+        assert_eq!(180.0 * clock_rate, 225.0);
+        assert_eq!(adjust_seconds(180, clock_rate), 144);
+        assert_eq!(adjust_seconds(100, clock_rate), 80);
+    }
+}
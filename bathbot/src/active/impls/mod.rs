@@ -1,3 +1,5 @@
+#[cfg(feature = "osutracking")]
+pub use self::track_list::TrackListPagination;
 pub use self::{
     badges::BadgesPagination,
     bg_game::{BackgroundGame, BackgroundGameSetup},
@@ -30,7 +32,10 @@ pub use self::{
     simulate::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
     skins::SkinsPagination,
     slash_commands::SlashCommandsPagination,
-    snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
+    snipe::{
+        SnipeCountryGainsPagination, SnipeCountryListColumns, SnipeCountryListPagination,
+        SnipeDifferencePagination, SnipeNetGain, SnipePlayerListPagination,
+    },
     top::TopPagination,
     top_if::TopIfPagination,
 };
@@ -66,3 +71,5 @@ mod slash_commands;
 mod snipe;
 mod top;
 mod top_if;
+#[cfg(feature = "osutracking")]
+mod track_list;
@@ -5,6 +5,7 @@ use bathbot_model::{OsekaiMedal, OsekaiRarityEntry};
 use bathbot_util::{numbers::round, EmbedBuilder, FooterBuilder};
 use eyre::Result;
 use futures::future::BoxFuture;
+use rosu_v2::prelude::Username;
 use twilight_model::{
     channel::message::Component,
     id::{marker::UserMarker, Id},
@@ -22,6 +23,7 @@ use crate::{
 pub struct MedalRarityPagination {
     #[pagination(per_page = 15)]
     ranking: Box<[OsekaiRarityEntry]>,
+    username: Option<Username>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -56,7 +58,11 @@ impl IActiveMessage for MedalRarityPagination {
             );
         }
 
-        let title = "Medal Ranking based on rarity";
+        let title = match self.username {
+            Some(ref name) => format!("Rarest medals that {name} is missing"),
+            None => "Medal Ranking based on rarity".to_owned(),
+        };
+
         let url = "https://osekai.net/rankings/?ranking=Medals&type=Rarity";
 
         let page = pages.curr_page();
@@ -102,17 +102,7 @@ impl OsuStatsPlayersPagination {
             author = author.icon_url(flag_url(country.as_str()));
         }
 
-        let mut description = String::with_capacity(1024);
-
-        for (player, i) in players.iter().zip(pages.index() + 1..) {
-            let _ = writeln!(
-                description,
-                "**#{i} [{username}]({OSU_BASE}users/{user_id})**: {count}",
-                username = player.username.cow_escape_markdown(),
-                user_id = player.user_id,
-                count = WithComma::new(player.count)
-            );
-        }
+        let description = page_description(players, pages.index());
 
         let page = pages.curr_page();
         let pages = pages.last_page();
@@ -129,3 +119,53 @@ impl OsuStatsPlayersPagination {
         Ok(BuildPage::new(embed, true).content(self.content.clone()))
     }
 }
+
+/// Lists the players of a page, `index` being the amount of players on
+/// previous pages.
+fn page_description(players: &[OsuStatsPlayer], index: usize) -> String {
+    let mut description = String::with_capacity(1024);
+
+    for (player, i) in players.iter().zip(index + 1..) {
+        let _ = writeln!(
+            description,
+            "**#{i} [{username}]({OSU_BASE}users/{user_id})**: {count}",
+            username = player.username.cow_escape_markdown(),
+            user_id = player.user_id,
+            count = WithComma::new(player.count)
+        );
+    }
+
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_page_description() {
+        let players: Vec<_> = (0..15)
+            .map(|i| OsuStatsPlayer {
+                user_id: 100 + i,
+                count: 2000 - i * 10,
+                username: format!("player_{i}").as_str().into(),
+            })
+            .collect();
+
+        let mut pages = Pages::new(15, 40);
+        pages.set_page(2);
+
+        let description = page_description(&players, pages.index());
+        let mut lines = description.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("**#16 [player\\_0](https://osu.ppy.sh/users/100)**: 2,000")
+        );
+        assert_eq!(
+            lines.last(),
+            Some("**#30 [player\\_14](https://osu.ppy.sh/users/114)**: 1,860")
+        );
+        assert_eq!(pages.last_page(), 3);
+    }
+}
@@ -129,6 +129,7 @@ impl OsuStatsScoresPagination {
                     score: score.score,
                     score_id: 0,
                     legacy_id: None,
+                    slider_hits: None,
                     statistics: LegacyScoreStatistics {
                         count_geki: score.count_geki,
                         count_300: score.count300,
@@ -195,7 +196,7 @@ impl OsuStatsScoresPagination {
                 acc = round(score.accuracy),
                 score = WithComma::new(score.score),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
-                hits = HitResultFormatter::new(score.mode, score.statistics.clone()),
+                hits = HitResultFormatter::new(score.mode, score.statistics.clone()).compact(),
                 ago = HowLongAgoDynamic::new(&score.ended_at),
             );
         }
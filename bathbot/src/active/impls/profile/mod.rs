@@ -6,13 +6,13 @@ use bathbot_model::{
     RankAccPeaks,
 };
 use bathbot_util::{
-    datetime::{HowLongAgoText, SecToMinSec, NAIVE_DATETIME_FORMAT},
+    datetime::SecToMinSec,
     fields,
     numbers::{round, MinMaxAvg, Number, WithComma},
-    osu::BonusPP,
+    osu::UserStats,
     EmbedBuilder, FooterBuilder, MessageOrigin,
 };
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
 use rkyv::{
     with::{DeserializeWith, Map},
@@ -25,7 +25,7 @@ use rosu_v2::prelude::{
 use time::UtcOffset;
 use twilight_model::{
     channel::message::{
-        component::{ActionRow, SelectMenu, SelectMenuOption},
+        component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption},
         Component,
     },
     id::{marker::UserMarker, Id},
@@ -40,8 +40,13 @@ use self::{
 use crate::{
     active::{BuildPage, ComponentResult, IActiveMessage},
     commands::osu::ProfileKind,
-    manager::redis::RedisData,
-    util::{interaction::InteractionComponent, osu::grade_emote, Authored, ComponentExt, Emote},
+    core::Context,
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{
+        interaction::InteractionComponent,
+        osu::{bonus_pp, grade_emote},
+        Authored, ComponentExt, Emote,
+    },
 };
 
 mod availability;
@@ -57,6 +62,7 @@ pub struct ProfileMenu {
     skin_url: Availability<SkinUrl>,
     scores: Availability<Box<[Score]>>,
     score_rank: Availability<ScoreData>,
+    bonus_pp: Option<f32>,
     osutrack_peaks: Option<RankAccPeaks>,
     top100stats: Option<Top100Stats>,
     mapper_names: Availability<MapperNames>,
@@ -132,52 +138,46 @@ impl IActiveMessage for ProfileMenu {
             placeholder: None,
         };
 
-        let components = vec![Component::SelectMenu(menu)];
+        let menu_row = ActionRow {
+            components: vec![Component::SelectMenu(menu)],
+        };
+
+        let mode = self.user.mode();
 
-        vec![Component::ActionRow(ActionRow { components })]
+        let mode_buttons = [
+            (GameMode::Osu, "profile_mode_osu"),
+            (GameMode::Taiko, "profile_mode_taiko"),
+            (GameMode::Catch, "profile_mode_catch"),
+            (GameMode::Mania, "profile_mode_mania"),
+        ]
+        .into_iter()
+        .map(|(button_mode, custom_id)| {
+            Component::Button(Button {
+                custom_id: Some(custom_id.to_owned()),
+                disabled: button_mode == mode,
+                emoji: Some(Emote::from(button_mode).reaction_type()),
+                label: None,
+                style: ButtonStyle::Secondary,
+                url: None,
+            })
+        })
+        .collect();
+
+        let mode_row = ActionRow {
+            components: mode_buttons,
+        };
+
+        vec![
+            Component::ActionRow(menu_row),
+            Component::ActionRow(mode_row),
+        ]
     }
 
     fn handle_component<'a>(
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
-        async fn inner(
-            component: &mut InteractionComponent,
-            kind: &mut ProfileKind,
-            msg_owner: Id<UserMarker>,
-        ) -> ComponentResult {
-            let user_id = match component.user_id() {
-                Ok(user_id) => user_id,
-                Err(err) => return ComponentResult::Err(err),
-            };
-
-            if user_id != msg_owner {
-                return ComponentResult::Ignore;
-            }
-
-            let value = component.data.values.pop();
-
-            *kind = match value.as_deref() {
-                Some("compact") => ProfileKind::Compact,
-                Some("user_stats") => ProfileKind::UserStats,
-                Some("top100_stats") => ProfileKind::Top100Stats,
-                Some("top100_mods") => ProfileKind::Top100Mods,
-                Some("top100_mappers") => ProfileKind::Top100Mappers,
-                Some("mapper_stats") => ProfileKind::MapperStats,
-                Some(other) => {
-                    return ComponentResult::Err(eyre!("Unknown profile menu option `{other}`"));
-                }
-                None => return ComponentResult::Err(eyre!("Missing value for profile menu")),
-            };
-
-            if let Err(err) = component.defer().await {
-                warn!(?err, "Failed to defer component");
-            }
-
-            ComponentResult::BuildPage
-        }
-
-        Box::pin(inner(component, &mut self.kind, self.msg_owner))
+        Box::pin(self.async_handle_component(component))
     }
 }
 
@@ -204,12 +204,103 @@ impl ProfileMenu {
             skin_url: Availability::NotRequested,
             scores: Availability::NotRequested,
             score_rank: Availability::NotRequested,
+            bonus_pp: None,
             mapper_names: Availability::NotRequested,
             origin,
             top100stats: None,
         }
     }
 
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let mode = match component.data.custom_id.as_str() {
+            "profile_menu" => {
+                let value = component.data.values.pop();
+
+                self.kind = match value.as_deref() {
+                    Some("compact") => ProfileKind::Compact,
+                    Some("user_stats") => ProfileKind::UserStats,
+                    Some("top100_stats") => ProfileKind::Top100Stats,
+                    Some("top100_mods") => ProfileKind::Top100Mods,
+                    Some("top100_mappers") => ProfileKind::Top100Mappers,
+                    Some("mapper_stats") => ProfileKind::MapperStats,
+                    Some(other) => {
+                        return ComponentResult::Err(eyre!(
+                            "Unknown profile menu option `{other}`"
+                        ));
+                    }
+                    None => return ComponentResult::Err(eyre!("Missing value for profile menu")),
+                };
+
+                if let Err(err) = component.defer().await {
+                    warn!(?err, "Failed to defer component");
+                }
+
+                return ComponentResult::BuildPage;
+            }
+            "profile_mode_osu" => GameMode::Osu,
+            "profile_mode_taiko" => GameMode::Taiko,
+            "profile_mode_catch" => GameMode::Catch,
+            "profile_mode_mania" => GameMode::Mania,
+            other => {
+                warn!(name = %other, ?component, "Unknown profile component");
+
+                return ComponentResult::Ignore;
+            }
+        };
+
+        if let Err(err) = component.defer().await {
+            warn!(?err, "Failed to defer component");
+        }
+
+        if let Err(err) = self.switch_mode(mode).await {
+            return ComponentResult::Err(err);
+        }
+
+        ComponentResult::BuildPage
+    }
+
+    /// Re-fetch the user in the given mode and clear all mode-specific data.
+    async fn switch_mode(&mut self, mode: GameMode) -> Result<()> {
+        let user_id = self.user.user_id();
+        let user_args = UserArgs::user_id(user_id).mode(mode);
+
+        let user_fut = Context::redis().osu_user(user_args);
+        let peaks_fut = Context::client().osu_user_rank_acc_peak(user_id, mode);
+
+        let (user_res, peaks_res) = tokio::join!(user_fut, peaks_fut);
+
+        self.user = user_res.wrap_err("Failed to get user for mode switch")?;
+
+        self.osutrack_peaks = match peaks_res {
+            Ok(peaks) => peaks,
+            Err(err) => {
+                warn!(?err, "Failed to get osutrack peaks");
+
+                None
+            }
+        };
+
+        self.scores = Availability::NotRequested;
+        self.score_rank = Availability::NotRequested;
+        self.bonus_pp = None;
+        self.mapper_names = Availability::NotRequested;
+        self.top100stats = None;
+
+        Ok(())
+    }
+
     async fn compact(&mut self) -> Result<BuildPage> {
         let user_id = self.user.user_id();
 
@@ -292,15 +383,7 @@ impl ProfileMenu {
 
         let bonus_pp = match scores_opt {
             Some(scores) => {
-                let mut bonus_pp = BonusPP::new();
-
-                for (i, score) in scores.iter().enumerate() {
-                    if let Some(weight) = score.weight {
-                        bonus_pp.update(weight.pp, i);
-                    }
-                }
-
-                let pp = bonus_pp.calculate(self.user.stats());
+                let pp = memoized_bonus_pp(&mut self.bonus_pp, scores, self.user.stats());
 
                 format!("{pp:.2}pp")
             }
@@ -515,6 +598,7 @@ impl ProfileMenu {
                 misses,
                 pp,
                 stars,
+                pp_per_star,
                 ar,
                 cs,
                 hp,
@@ -523,31 +607,29 @@ impl ProfileMenu {
                 len,
             } = stats;
 
-            fn min_avg_max<T: Number>(
-                v: &MinMaxAvg<T>,
-                f: fn(T) -> String,
-            ) -> (String, String, String) {
-                (f(v.min()), f(v.avg()), f(v.max()))
-            }
-
             let combo_min = combo.min().to_string();
             let combo_avg = format!("{:.2}", combo.avg_float());
+            let combo_med = format!("{:.1}", combo.median_float());
             let combo_max = combo.max().to_string();
 
             let misses_min = misses.min().to_string();
             let misses_avg = format!("{:.2}", misses.avg_float());
+            let misses_med = format!("{:.1}", misses.median_float());
             let misses_max = misses.max().to_string();
 
-            let (acc_min, acc_avg, acc_max) = min_avg_max(acc, |v| format!("{v:.2}"));
-            let (pp_min, pp_avg, pp_max) = min_avg_max(pp, |v| format!("{v:.2}"));
-            let (stars_min, stars_avg, stars_max) = min_avg_max(stars, |v| format!("{v:.2}"));
-            let (ar_min, ar_avg, ar_max) = min_avg_max(ar, |v| format!("{v:.2}"));
-            let (cs_min, cs_avg, cs_max) = min_avg_max(cs, |v| format!("{v:.2}"));
-            let (hp_min, hp_avg, hp_max) = min_avg_max(hp, |v| format!("{v:.2}"));
-            let (od_min, od_avg, od_max) = min_avg_max(od, |v| format!("{v:.2}"));
-            let (bpm_min, bpm_avg, bpm_max) = min_avg_max(bpm, |v| format!("{v:.2}"));
-            let (len_min, len_avg, len_max) =
-                min_avg_max(len, |v| SecToMinSec::new(v as u32).to_string());
+            let (acc_min, acc_avg, acc_med, acc_max) = min_avg_med_max(acc, |v| format!("{v:.2}"));
+            let (pp_min, pp_avg, pp_med, pp_max) = min_avg_med_max(pp, |v| format!("{v:.2}"));
+            let (stars_min, stars_avg, stars_med, stars_max) =
+                min_avg_med_max(stars, |v| format!("{v:.2}"));
+            let (pps_min, pps_avg, pps_med, pps_max) =
+                min_avg_med_max(pp_per_star, |v| format!("{v:.2}"));
+            let (ar_min, ar_avg, ar_med, ar_max) = min_avg_med_max(ar, |v| format!("{v:.2}"));
+            let (cs_min, cs_avg, cs_med, cs_max) = min_avg_med_max(cs, |v| format!("{v:.2}"));
+            let (hp_min, hp_avg, hp_med, hp_max) = min_avg_med_max(hp, |v| format!("{v:.2}"));
+            let (od_min, od_avg, od_med, od_max) = min_avg_med_max(od, |v| format!("{v:.2}"));
+            let (bpm_min, bpm_avg, bpm_med, bpm_max) = min_avg_med_max(bpm, |v| format!("{v:.2}"));
+            let (len_min, len_avg, len_med, len_max) =
+                min_avg_med_max(len, |v| SecToMinSec::new(v as u32).to_string());
 
             let min_w = "Minimum"
                 .len()
@@ -556,6 +638,7 @@ impl ProfileMenu {
                 .max(misses_min.len())
                 .max(pp_min.len())
                 .max(stars_min.len())
+                .max(pps_min.len())
                 .max(ar_min.len())
                 .max(cs_min.len())
                 .max(hp_min.len())
@@ -570,6 +653,7 @@ impl ProfileMenu {
                 .max(misses_avg.len())
                 .max(pp_avg.len())
                 .max(stars_avg.len())
+                .max(pps_avg.len())
                 .max(ar_avg.len())
                 .max(cs_avg.len())
                 .max(hp_avg.len())
@@ -577,6 +661,21 @@ impl ProfileMenu {
                 .max(bpm_avg.len())
                 .max(len_avg.len());
 
+            let med_w = "Median"
+                .len()
+                .max(acc_med.len())
+                .max(combo_med.len())
+                .max(misses_med.len())
+                .max(pp_med.len())
+                .max(stars_med.len())
+                .max(pps_med.len())
+                .max(ar_med.len())
+                .max(cs_med.len())
+                .max(hp_med.len())
+                .max(od_med.len())
+                .max(bpm_med.len())
+                .max(len_med.len());
+
             let max_w = "Maximum"
                 .len()
                 .max(acc_max.len())
@@ -584,6 +683,7 @@ impl ProfileMenu {
                 .max(misses_max.len())
                 .max(pp_max.len())
                 .max(stars_max.len())
+                .max(pps_max.len())
                 .max(ar_max.len())
                 .max(cs_max.len())
                 .max(hp_max.len())
@@ -593,71 +693,77 @@ impl ProfileMenu {
 
             let _ = writeln!(
                 description,
-                "         | {min:^min_w$} | {avg:^avg_w$} | {max:^max_w$}",
+                "         | {min:^min_w$} | {avg:^avg_w$} | {med:^med_w$} | {max:^max_w$}",
                 min = "Minimum",
                 avg = "Average",
+                med = "Median",
                 max = "Maximum"
             );
 
             let _ = writeln!(
                 description,
-                "{dash:-^9}+-{dash:-^min_w$}-+-{dash:-^avg_w$}-+-{dash:-^max_w$}",
+                "{dash:-^9}+-{dash:-^min_w$}-+-{dash:-^avg_w$}-+-{dash:-^med_w$}-+-{dash:-^max_w$}",
                 dash = "-"
             );
 
             let _ = writeln!(
                 description,
-                "Accuracy | {acc_min:^min_w$} | {acc_avg:^avg_w$} | {acc_max:^max_w$}",
+                "Accuracy | {acc_min:^min_w$} | {acc_avg:^avg_w$} | {acc_med:^med_w$} | {acc_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "Combo    | {combo_min:^min_w$} | {combo_avg:^avg_w$} | {combo_max:^max_w$}",
+                "Combo    | {combo_min:^min_w$} | {combo_avg:^avg_w$} | {combo_med:^med_w$} | {combo_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "Misses   | {misses_min:^min_w$} | {misses_avg:^avg_w$} | {misses_max:^max_w$}",
+                "Misses   | {misses_min:^min_w$} | {misses_avg:^avg_w$} | {misses_med:^med_w$} | {misses_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "PP       | {pp_min:^min_w$} | {pp_avg:^avg_w$} | {pp_max:^max_w$}",
+                "PP       | {pp_min:^min_w$} | {pp_avg:^avg_w$} | {pp_med:^med_w$} | {pp_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "Stars    | {stars_min:^min_w$} | {stars_avg:^avg_w$} | {stars_max:^max_w$}",
+                "Stars    | {stars_min:^min_w$} | {stars_avg:^avg_w$} | {stars_med:^med_w$} | {stars_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "AR       | {ar_min:^min_w$} | {ar_avg:^avg_w$} | {ar_max:^max_w$}",
+                "PP/Star  | {pps_min:^min_w$} | {pps_avg:^avg_w$} | {pps_med:^med_w$} | {pps_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "CS       | {cs_min:^min_w$} | {cs_avg:^avg_w$} | {cs_max:^max_w$}",
+                "AR       | {ar_min:^min_w$} | {ar_avg:^avg_w$} | {ar_med:^med_w$} | {ar_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "HP       | {hp_min:^min_w$} | {hp_avg:^avg_w$} | {hp_max:^max_w$}",
+                "CS       | {cs_min:^min_w$} | {cs_avg:^avg_w$} | {cs_med:^med_w$} | {cs_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "OD       | {od_min:^min_w$} | {od_avg:^avg_w$} | {od_max:^max_w$}",
+                "HP       | {hp_min:^min_w$} | {hp_avg:^avg_w$} | {hp_med:^med_w$} | {hp_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "BPM      | {bpm_min:^min_w$} | {bpm_avg:^avg_w$} | {bpm_max:^max_w$}",
+                "OD       | {od_min:^min_w$} | {od_avg:^avg_w$} | {od_med:^med_w$} | {od_max:^max_w$}",
             );
 
             let _ = writeln!(
                 description,
-                "Length   | {len_min:^min_w$} | {len_avg:^avg_w$} | {len_max:^max_w$}",
+                "BPM      | {bpm_min:^min_w$} | {bpm_avg:^avg_w$} | {bpm_med:^med_w$} | {bpm_max:^max_w$}",
+            );
+
+            let _ = writeln!(
+                description,
+                "Length   | {len_min:^min_w$} | {len_avg:^avg_w$} | {len_med:^med_w$} | {len_max:^max_w$}",
             );
 
             description.push_str("```");
@@ -790,47 +896,7 @@ impl ProfileMenu {
 
         if let Some(mappers) = Top100Mappers::prepare(self).await {
             description.push_str("```\n");
-
-            let mut names_len = 0;
-            let mut pp_len = 2;
-            let mut count_len = 1;
-
-            let values: Vec<_> = mappers
-                .iter()
-                .map(|entry| {
-                    let pp = format!("{:.2}", entry.pp);
-                    let count = entry.count.to_string();
-
-                    names_len = names_len.max(entry.name.len());
-                    pp_len = pp_len.max(pp.len());
-                    count_len = count_len.max(count.len());
-
-                    (pp, count)
-                })
-                .collect();
-
-            let _ = writeln!(
-                description,
-                "{blank:<names_len$} | {pp:^pp_len$} | {count:^count_len$}",
-                blank = " ",
-                pp = "PP",
-                count = "#",
-            );
-
-            let _ = writeln!(
-                description,
-                "{dash:-<names_len$}-+-{dash:->pp_len$}-+-{dash:->count_len$}-",
-                dash = "-",
-            );
-
-            for (entry, (pp, count)) in mappers.iter().zip(values) {
-                let _ = writeln!(
-                    description,
-                    "{name:<names_len$} | {pp:>pp_len$} | {count:>count_len$}",
-                    name = entry.name,
-                );
-            }
-
+            Top100Mappers::write_table(&mut description, &mappers);
             description.push_str("```");
         } else {
             description.push_str("No top scores :(");
@@ -1008,14 +1074,28 @@ impl ProfileMenu {
             join_date = join_date.to_offset(tz);
         }
 
-        let text = format!(
-            "Joined osu! {} ({})",
-            join_date.format(NAIVE_DATETIME_FORMAT).unwrap(),
-            HowLongAgoText::new(&join_date),
-        );
+        FooterBuilder::with_relative_time("Joined osu!", &join_date)
+    }
+}
+
+fn min_avg_med_max<T: Number>(
+    v: &MinMaxAvg<T>,
+    f: fn(T) -> String,
+) -> (String, String, String, String) {
+    // e.g. no pp per star if all scores are on 0-star maps
+    if v.is_empty() {
+        let none = || "-".to_owned();
 
-        FooterBuilder::new(text)
+        return (none(), none(), none(), none());
     }
+
+    (f(v.min()), f(v.avg()), f(v.median()), f(v.max()))
+}
+
+/// The bonus pp only depend on the user and their top scores so they're
+/// calculated once and reused when revisiting the page.
+fn memoized_bonus_pp(memo: &mut Option<f32>, scores: &[Score], stats: impl UserStats) -> f32 {
+    *memo.get_or_insert_with(|| bonus_pp(scores, stats))
 }
 
 trait HasLen {
@@ -1033,3 +1113,72 @@ impl HasLen for GameModIntermode {
         1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    /// Counts how often the bonus pp are calculated
+    struct Stats<'c> {
+        calculations: &'c Cell<usize>,
+    }
+
+    impl UserStats for Stats<'_> {
+        fn pp(&self) -> f32 {
+            self.calculations.set(self.calculations.get() + 1);
+
+            100.0
+        }
+
+        fn grade_counts_sum(&self) -> i32 {
+            1
+        }
+
+        fn playcount(&self) -> u32 {
+            1
+        }
+    }
+
+    #[test]
+    fn bonus_pp_is_memoized() {
+        let calculations = Cell::new(0);
+        let stats = || Stats {
+            calculations: &calculations,
+        };
+
+        let mut memo = None;
+
+        // Without any scores, all of the user's pp count as bonus pp
+        assert_eq!(memoized_bonus_pp(&mut memo, &[], stats()), 100.0);
+        assert_eq!(calculations.get(), 1);
+
+        assert_eq!(memoized_bonus_pp(&mut memo, &[], stats()), 100.0);
+        assert_eq!(calculations.get(), 1);
+
+        // Resetting the page recalculates them
+        memo = None;
+        assert_eq!(memoized_bonus_pp(&mut memo, &[], stats()), 100.0);
+        assert_eq!(calculations.get(), 2);
+    }
+
+    #[test]
+    fn empty_stats_are_dashes() {
+        let (min, avg, median, max) =
+            min_avg_med_max(&MinMaxAvg::<f32>::new(), |v| format!("{v:.2}"));
+
+        assert_eq!([min, avg, median, max], ["-", "-", "-", "-"]);
+
+        let mut stats = MinMaxAvg::new();
+        stats.add(50.0_f32);
+        stats.add(60.0);
+
+        let (min, avg, median, max) = min_avg_med_max(&stats, |v| format!("{v:.2}"));
+
+        assert_eq!(
+            [min, avg, median, max],
+            ["50.00", "55.00", "55.00", "60.00"]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
 
 use bathbot_util::IntHasher;
 use rosu_v2::prelude::Username;
@@ -51,6 +51,66 @@ impl Top100Mappers {
 
         Some(mappers)
     }
+
+    /// Write the mappers as a table with aligned columns, truncating overlong
+    /// names.
+    pub(super) fn write_table(description: &mut String, mappers: &[MapperEntry<'_>]) {
+        let mut names_len = 0;
+        let mut pp_len = 2;
+        let mut count_len = 1;
+
+        let values: Vec<_> = mappers
+            .iter()
+            .map(|entry| {
+                let name = truncate_name(entry.name);
+                let pp = format!("{:.2}", entry.pp);
+                let count = entry.count.to_string();
+
+                names_len = names_len.max(name.chars().count());
+                pp_len = pp_len.max(pp.len());
+                count_len = count_len.max(count.len());
+
+                (name, pp, count)
+            })
+            .collect();
+
+        let _ = writeln!(
+            description,
+            "{blank:<names_len$} | {pp:^pp_len$} | {count:^count_len$}",
+            blank = " ",
+            pp = "PP",
+            count = "#",
+        );
+
+        let _ = writeln!(
+            description,
+            "{dash:-<names_len$}-+-{dash:->pp_len$}-+-{dash:->count_len$}-",
+            dash = "-",
+        );
+
+        for (name, pp, count) in values {
+            let _ = writeln!(
+                description,
+                "{name:<names_len$} | {pp:>pp_len$} | {count:>count_len$}",
+            );
+        }
+    }
+}
+
+const MAX_NAME_LEN: usize = 20;
+
+fn truncate_name(name: &str) -> Cow<'_, str> {
+    match name.char_indices().nth(MAX_NAME_LEN) {
+        Some(_) => {
+            let end = name
+                .char_indices()
+                .nth(MAX_NAME_LEN - 1)
+                .map_or(name.len(), |(idx, _)| idx);
+
+            Cow::Owned(format!("{}…", &name[..end]))
+        }
+        None => Cow::Borrowed(name),
+    }
 }
 
 pub(super) struct MapperEntry<'n> {
@@ -58,3 +118,42 @@ pub(super) struct MapperEntry<'n> {
     pub pp: f32,
     pub count: u8,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_mapper_name_is_truncated() {
+        let long_name = "a".repeat(100);
+
+        let mappers = [
+            MapperEntry {
+                name: "Sotarks",
+                pp: 1234.56,
+                count: 12,
+            },
+            MapperEntry {
+                name: &long_name,
+                pp: 98.7,
+                count: 3,
+            },
+        ];
+
+        let mut table = String::new();
+        Top100Mappers::write_table(&mut table, &mappers);
+
+        let widths: Vec<_> = table.lines().map(|line| line.chars().count()).collect();
+
+        // name (20) + " | " + pp (7) + " | " + count (2) + trailing dash
+        assert!(widths.iter().all(|&width| width <= 36), "{widths:?}");
+        assert!(table.contains(&format!("{}…", "a".repeat(19))));
+        assert!(!table.contains(&"a".repeat(20)));
+    }
+
+    #[test]
+    fn short_mapper_name_is_kept() {
+        assert_eq!(truncate_name("Sotarks"), "Sotarks");
+        assert_eq!(truncate_name(&"b".repeat(20)), "b".repeat(20));
+    }
+}
@@ -11,6 +11,7 @@ pub(super) struct Top100Stats {
     pub misses: MinMaxAvg<u32>,
     pub pp: MinMaxAvg<f32>,
     pub stars: MinMaxAvg<f64>,
+    pub pp_per_star: MinMaxAvg<f32>,
     pub ar: MinMaxAvg<f64>,
     pub cs: MinMaxAvg<f64>,
     pub hp: MinMaxAvg<f64>,
@@ -57,6 +58,7 @@ impl Top100Stats {
             misses: MinMaxAvg::new(),
             pp: MinMaxAvg::new(),
             stars: MinMaxAvg::new(),
+            pp_per_star: MinMaxAvg::new(),
             ar: MinMaxAvg::new(),
             cs: MinMaxAvg::new(),
             hp: MinMaxAvg::new(),
@@ -88,6 +90,10 @@ impl Top100Stats {
 
             this.pp.add(pp);
 
+            if let Some(pp_per_star) = pp_per_star(pp, stars) {
+                this.pp_per_star.add(pp_per_star);
+            }
+
             let map_attrs = map
                 .attributes()
                 .mods(score.mods.bits())
@@ -100,9 +106,74 @@ impl Top100Stats {
             this.od.add(map_attrs.od);
             this.bpm.add(map.bpm() * map_attrs.clock_rate as f32);
             this.len
-                .add(map.seconds_drain() as f32 / map_attrs.clock_rate as f32);
+                .add(adjusted_len(map.seconds_drain(), map_attrs.clock_rate));
         }
 
         Ok(this)
     }
 }
+
+/// Drain length in seconds as it's actually played with the given clock rate.
+fn adjusted_len(seconds_drain: u32, clock_rate: f64) -> f32 {
+    seconds_drain as f32 / clock_rate as f32
+}
+
+/// How much pp a score gives per star; `None` for maps without star rating.
+fn pp_per_star(pp: f32, stars: f64) -> Option<f32> {
+    (stars > 0.0).then(|| pp / stars as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::{GameMode, GameModsIntermode};
+
+    use super::*;
+
+    #[test]
+    fn dt_len_is_shorter() {
+        let mods = GameModsIntermode::try_from_acronyms("DT")
+            .unwrap()
+            .with_mode(GameMode::Osu);
+        let clock_rate = mods.clock_rate().unwrap() as f64;
+
+        let len = adjusted_len(180, clock_rate);
+        assert!(len < 180.0);
+        assert_eq!(len, 120.0);
+
+        assert_eq!(adjusted_len(180, 1.0), 180.0);
+        assert_eq!(adjusted_len(180, 0.75), 240.0);
+    }
+
+    #[test]
+    fn pp_per_star_aggregation() {
+        assert_eq!(pp_per_star(300.0, 6.0), Some(50.0));
+        assert_eq!(pp_per_star(12.5, 0.0), None);
+
+        let mut stats = MinMaxAvg::new();
+
+        for (pp, stars) in [(400.0, 8.0), (300.0, 5.0), (275.0, 5.0), (12.5, 0.0)] {
+            if let Some(pp_per_star) = pp_per_star(pp, stars) {
+                stats.add(pp_per_star);
+            }
+        }
+
+        // The 0-star score is skipped
+        assert_eq!(stats.min(), 50.0);
+        assert_eq!(stats.max(), 60.0);
+        assert_eq!(stats.avg(), 55.0);
+        assert_eq!(stats.median(), 55.0);
+    }
+
+    #[test]
+    fn only_zero_star_scores() {
+        let mut stats = MinMaxAvg::new();
+
+        for (pp, stars) in [(12.5, 0.0), (3.0, 0.0)] {
+            if let Some(pp_per_star) = pp_per_star(pp, stars) {
+                stats.add(pp_per_star);
+            }
+        }
+
+        assert!(stats.is_empty());
+    }
+}
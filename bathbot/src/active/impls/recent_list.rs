@@ -9,6 +9,7 @@ use bathbot_util::{
 use eyre::Result;
 use futures::future::BoxFuture;
 use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
 use twilight_model::{
     channel::message::Component,
     id::{marker::UserMarker, Id},
@@ -83,13 +84,14 @@ impl IActiveMessage for RecentListPagination {
 
             description.push('\n');
 
-            let _ = writeln!(
-                description,
-                "{pp}\t[ {combo} ]\t({acc}%)\t{ago}",
-                pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
-                combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
-                acc = round(score.accuracy),
-                ago = HowLongAgoDynamic::new(&score.ended_at)
+            write_stats_row(
+                &mut description,
+                score.pp,
+                *max_pp,
+                score.max_combo,
+                *max_combo,
+                score.accuracy,
+                &score.ended_at,
             );
         }
 
@@ -127,3 +129,56 @@ impl IActiveMessage for RecentListPagination {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
     }
 }
+
+/// Writes the condensed second line of an entry i.e. pp, combo, accuracy, and
+/// when the score was set.
+fn write_stats_row(
+    description: &mut String,
+    pp: f32,
+    max_pp: f32,
+    combo: u32,
+    max_combo: u32,
+    acc: f32,
+    ended_at: &OffsetDateTime,
+) {
+    let _ = writeln!(
+        description,
+        "{pp}\t[ {combo} ]\t({acc}%)\t{ago}",
+        pp = PpFormatter::new(Some(pp), Some(max_pp)),
+        combo = ComboFormatter::new(combo, Some(max_combo)),
+        acc = round(acc),
+        ago = HowLongAgoDynamic::new(ended_at)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn condensed_stats_row() {
+        let ended_at = OffsetDateTime::from_unix_timestamp(1_704_067_200).unwrap();
+        let mut description = String::new();
+
+        write_stats_row(&mut description, 123.456, 150.0, 500, 600, 98.5, &ended_at);
+
+        assert_eq!(
+            description,
+            "**123.46**/150.00PP\t[ **500x**/600x ]\t(98.5%)\t<t:1704067200:R>\n"
+        );
+    }
+
+    #[test]
+    fn condensed_stats_row_fail_exceeding_max_pp() {
+        let ended_at = OffsetDateTime::from_unix_timestamp(0).unwrap();
+        let mut description = String::new();
+
+        // The max pp is never shown lower than the actual pp
+        write_stats_row(&mut description, 42.0, 40.0, 12, 345, 87.0, &ended_at);
+
+        assert_eq!(
+            description,
+            "**42.00**/42.00PP\t[ **12x**/345x ]\t(87%)\t<t:0:R>\n"
+        );
+    }
+}
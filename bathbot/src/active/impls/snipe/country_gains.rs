@@ -0,0 +1,137 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::CountryName;
+use bathbot_util::{constants::OSU_BASE, osu::flag_url, CowUtils, EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{CountryCode, Username};
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+/// National #1s that a player gained and lost within some time window.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SnipeNetGain {
+    pub user_id: u32,
+    pub username: Option<Username>,
+    pub gains: u32,
+    pub losses: u32,
+}
+
+impl SnipeNetGain {
+    pub fn net(&self) -> i64 {
+        self.gains as i64 - self.losses as i64
+    }
+}
+
+#[derive(PaginationBuilder)]
+pub struct SnipeCountryGainsPagination {
+    #[pagination(per_page = 10)]
+    players: Box<[SnipeNetGain]>,
+    country_code: CountryCode,
+    country_name: Option<CountryName>,
+    weeks: u32,
+    /// How many of the country's top players were considered
+    sampled: usize,
+    author_idx: Option<usize>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for SnipeCountryGainsPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let idx = self.pages.index();
+        let players = self.players.iter().skip(idx).take(self.pages.per_page());
+
+        let country = match self.country_name {
+            Some(ref name) => name.to_string(),
+            None => self.country_code.to_string(),
+        };
+
+        let title = format!(
+            "Net #1 gains of {country}{} top {sampled} players of the last {weeks} week{plural}",
+            if country.ends_with('s') { "'" } else { "'s" },
+            sampled = self.sampled,
+            weeks = self.weeks,
+            plural = if self.weeks == 1 { "" } else { "s" },
+        );
+
+        let mut description = String::with_capacity(512);
+
+        for (player, i) in players.zip(idx + 1..) {
+            let _ = write!(description, "**#{i} ");
+
+            match player.username {
+                Some(ref name) => {
+                    let _ = write!(
+                        description,
+                        "[{name}]({OSU_BASE}users/{id})",
+                        name = name.cow_escape_markdown(),
+                        id = player.user_id,
+                    );
+                }
+                None => {
+                    let _ = write!(
+                        description,
+                        "[User {id}]({OSU_BASE}users/{id})",
+                        id = player.user_id
+                    );
+                }
+            }
+
+            let _ = writeln!(
+                description,
+                "**: `{net:+}` ({gains} gained • {losses} lost)",
+                net = player.net(),
+                gains = player.gains,
+                losses = player.losses,
+            );
+        }
+
+        description.pop();
+
+        let page = self.pages.curr_page();
+        let pages = self.pages.last_page();
+        let mut footer_text = format!("Page {page}/{pages}");
+
+        if let Some(idx) = self.author_idx {
+            let _ = write!(footer_text, " • Your position: {}", idx + 1);
+        }
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .thumbnail(flag_url(self.country_code.as_str()))
+            .title(title);
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    str::FromStr,
+};
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{CountryName, SnipeCountryListOrder, SnipeCountryPlayer};
@@ -30,6 +33,7 @@ pub struct SnipeCountryListPagination {
     players: Box<[(usize, SnipeCountryPlayer)]>,
     country: Option<(CountryName, CountryCode)>,
     order: SnipeCountryListOrder,
+    columns: SnipeCountryListColumns,
     author_idx: Option<usize>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -70,35 +74,7 @@ impl IActiveMessage for SnipeCountryListPagination {
         let mut description = String::with_capacity(512);
 
         for (idx, player) in players {
-            let _ = writeln!(
-                description,
-                "**#{idx} [{name}]({OSU_BASE}users/{id})**: {w}Weighted pp: {weighted}{w}\n\
-                {c}Count: {count}{c} {avg_pp}• {s}Avg stars: {stars:.2}★{s}",
-                name = player.username.cow_escape_markdown(),
-                id = player.user_id,
-                c = if self.order == SnipeCountryListOrder::Count {
-                    "__"
-                } else {
-                    ""
-                },
-                s = if self.order == SnipeCountryListOrder::AvgStars {
-                    "__"
-                } else {
-                    ""
-                },
-                w = if self.order == SnipeCountryListOrder::WeightedPp {
-                    "__"
-                } else {
-                    ""
-                },
-                count = WithComma::new(player.count_first),
-                avg_pp = AveragePpFormatter {
-                    pp: player.avg_pp,
-                    underline: self.order == SnipeCountryListOrder::AvgPp,
-                },
-                stars = player.avg_sr,
-                weighted = WithComma::new(player.pp),
-            );
+            write_player(&mut description, *idx, player, self.order, self.columns);
         }
 
         description.pop();
@@ -140,6 +116,133 @@ impl IActiveMessage for SnipeCountryListPagination {
     }
 }
 
+/// Which metrics are shown for each player of the list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SnipeCountryListColumns {
+    pub count: bool,
+    pub pp: bool,
+    pub stars: bool,
+    pub weighted: bool,
+}
+
+impl Default for SnipeCountryListColumns {
+    fn default() -> Self {
+        Self {
+            count: true,
+            pp: true,
+            stars: true,
+            weighted: true,
+        }
+    }
+}
+
+impl FromStr for SnipeCountryListColumns {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut columns = Self {
+            count: false,
+            pp: false,
+            stars: false,
+            weighted: false,
+        };
+
+        for column in s.split([',', ' ']).filter(|column| !column.is_empty()) {
+            match column.to_ascii_lowercase().as_str() {
+                "count" => columns.count = true,
+                "pp" => columns.pp = true,
+                "stars" => columns.stars = true,
+                "weighted" | "weightedpp" => columns.weighted = true,
+                _ => {
+                    return Err(
+                        "Failed to parse `columns`. Must be a comma-separated list of \
+                        `count`, `pp`, `stars`, or `weighted`.",
+                    )
+                }
+            }
+        }
+
+        if columns.count || columns.pp || columns.stars || columns.weighted {
+            Ok(columns)
+        } else {
+            Err("`columns` must contain at least one of `count`, `pp`, `stars`, or `weighted`.")
+        }
+    }
+}
+
+fn write_player(
+    description: &mut String,
+    idx: usize,
+    player: &SnipeCountryPlayer,
+    order: SnipeCountryListOrder,
+    columns: SnipeCountryListColumns,
+) {
+    let underline = |column| if order == column { "__" } else { "" };
+
+    let _ = write!(
+        description,
+        "**#{idx} [{name}]({OSU_BASE}users/{id})**",
+        name = player.username.cow_escape_markdown(),
+        id = player.user_id,
+    );
+
+    if columns.weighted {
+        let _ = write!(
+            description,
+            ": {w}Weighted pp: {weighted}{w}",
+            w = underline(SnipeCountryListOrder::WeightedPp),
+            weighted = WithComma::new(player.pp),
+        );
+    }
+
+    description.push('\n');
+    let len = description.len();
+
+    if columns.count {
+        let _ = write!(
+            description,
+            "{c}Count: {count}{c} ",
+            c = underline(SnipeCountryListOrder::Count),
+            count = WithComma::new(player.count_first),
+        );
+    }
+
+    if columns.pp {
+        let _ = write!(
+            description,
+            "{}",
+            AveragePpFormatter {
+                pp: player.avg_pp,
+                underline: order == SnipeCountryListOrder::AvgPp,
+            }
+        );
+    }
+
+    if columns.stars {
+        let _ = write!(
+            description,
+            "• {s}Avg stars: {stars:.2}★{s}",
+            s = underline(SnipeCountryListOrder::AvgStars),
+            stars = player.avg_sr,
+        );
+    }
+
+    // Drop a leading separator or trailing space of the metrics line
+    if description[len..].starts_with("• ") {
+        description.replace_range(len..len + "• ".len(), "");
+    }
+
+    let trimmed = description.trim_end_matches(' ').len();
+    description.truncate(trimmed);
+
+    if description.len() == len {
+        // Only the weighted column was selected; no second line
+        description.pop();
+    }
+
+    description.push('\n');
+}
+
 struct AveragePpFormatter {
     pp: Option<f32>,
     underline: bool,
@@ -159,3 +262,77 @@ impl Display for AveragePpFormatter {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::Username;
+
+    use super::*;
+
+    fn player(name: &str, avg_pp: Option<f32>) -> SnipeCountryPlayer {
+        SnipeCountryPlayer {
+            username: Username::from(name),
+            user_id: 2,
+            avg_pp,
+            avg_sr: 5.4321,
+            pp: 1234.5,
+            count_first: 1500,
+        }
+    }
+
+    fn write(player: &SnipeCountryPlayer, columns: &str) -> String {
+        let columns = columns.parse().unwrap();
+        let mut description = String::new();
+        write_player(
+            &mut description,
+            1,
+            player,
+            SnipeCountryListOrder::Count,
+            columns,
+        );
+
+        description
+    }
+
+    #[test]
+    fn default_columns_keep_layout() {
+        let mut description = String::new();
+        write_player(
+            &mut description,
+            1,
+            &player("a", Some(250.0)),
+            SnipeCountryListOrder::Count,
+            SnipeCountryListColumns::default(),
+        );
+
+        assert_eq!(
+            description,
+            format!(
+                "**#1 [a]({OSU_BASE}users/2)**: Weighted pp: 1,234.50\n\
+                __Count: 1,500__ • Avg pp: 250 • Avg stars: 5.43★\n"
+            )
+        );
+    }
+
+    #[test]
+    fn selected_columns() {
+        let expected =
+            format!("**#1 [a]({OSU_BASE}users/2)**: Weighted pp: 1,234.50\n__Count: 1,500__\n");
+        assert_eq!(write(&player("a", Some(250.0)), "count,weighted"), expected);
+
+        let expected = format!("**#1 [b]({OSU_BASE}users/2)**\nAvg pp: 250 • Avg stars: 5.43★\n");
+        assert_eq!(write(&player("b", Some(250.0)), "pp,stars"), expected);
+
+        let expected = format!("**#1 [c]({OSU_BASE}users/2)**\nAvg stars: 5.43★\n");
+        assert_eq!(write(&player("c", None), "pp,stars"), expected);
+
+        let expected = format!("**#1 [d]({OSU_BASE}users/2)**: Weighted pp: 1,234.50\n");
+        assert_eq!(write(&player("d", None), "weighted"), expected);
+    }
+
+    #[test]
+    fn invalid_columns() {
+        assert!("".parse::<SnipeCountryListColumns>().is_err());
+        assert!("count,acc".parse::<SnipeCountryListColumns>().is_err());
+    }
+}
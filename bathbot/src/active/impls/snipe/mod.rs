@@ -1,8 +1,11 @@
 pub use self::{
-    country_list::SnipeCountryListPagination, difference::SnipeDifferencePagination,
+    country_gains::{SnipeCountryGainsPagination, SnipeNetGain},
+    country_list::{SnipeCountryListColumns, SnipeCountryListPagination},
+    difference::SnipeDifferencePagination,
     player_list::SnipePlayerListPagination,
 };
 
+mod country_gains;
 mod country_list;
 mod difference;
 mod player_list;
@@ -371,11 +371,7 @@ impl TopPaginationBuilder {
         let content = self.content.take().expect("missing content");
         let msg_owner = self.msg_owner.expect("missing msg_owner");
 
-        let pages = match list_size {
-            ListSize::Condensed => Pages::new(10, entries.len()),
-            ListSize::Detailed => Pages::new(5, entries.len()),
-            ListSize::Single => Pages::new(1, entries.len()),
-        };
+        let pages = Pages::new(list_size.per_page(), entries.len());
 
         TopPagination {
             user,
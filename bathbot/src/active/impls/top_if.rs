@@ -65,13 +65,14 @@ impl IActiveMessage for TopIfPagination {
 
             let _ = writeln!(
                 description,
-                "**#{original_idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
+                "**#{original_idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]{convert}\n\
                 {grade} {old_pp:.2} → {pp} • {acc}% • {score}\n\
                 [ {combo} ] • {hits} • {ago}",
                 title = map.title().cow_escape_markdown(),
                 version = map.version().cow_escape_markdown(),
                 id = map.map_id(),
                 mods = ModsFormatter::new(&score.mods),
+                convert = if map.pp_map.is_convert { " (convert)" } else { "" },
                 grade = grade_emote(score.grade),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
                 acc = round(score.accuracy),
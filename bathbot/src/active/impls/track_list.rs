@@ -0,0 +1,150 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use futures::future::BoxFuture;
+use itertools::Itertools;
+use rosu_v2::prelude::GameMode;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    commands::tracking::TracklistUserEntry,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct TrackListPagination {
+    #[pagination(per_page = 15)]
+    entries: Box<[TracklistUserEntry]>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TrackListPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let description = page_description(page_entries(&self.entries, pages));
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+        let footer_text = format!("Page {page}/{pages}");
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("Tracked osu! users in this channel (limit)");
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
+
+fn page_entries<'e>(entries: &'e [TracklistUserEntry], pages: &Pages) -> &'e [TracklistUserEntry] {
+    let start = pages.index().min(entries.len());
+    let end = entries.len().min(start + pages.per_page());
+
+    &entries[start..end]
+}
+
+/// Lists the entries grouped by their mode, assuming they're already sorted
+/// by mode.
+fn page_description(entries: &[TracklistUserEntry]) -> String {
+    let mut description = String::with_capacity(entries.len() * 24);
+
+    for (mode, group) in entries.iter().group_by(|entry| entry.mode).into_iter() {
+        let mode = match mode {
+            GameMode::Osu => "osu!standard",
+            GameMode::Taiko => "osu!taiko",
+            GameMode::Catch => "osu!ctb",
+            GameMode::Mania => "osu!mania",
+        };
+
+        let _ = writeln!(description, "__**{mode}**__");
+
+        for entry in group {
+            let _ = writeln!(description, "`{}` ({})", entry.name, entry.limit);
+        }
+    }
+
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<TracklistUserEntry> {
+        let modes = [GameMode::Osu, GameMode::Taiko, GameMode::Mania];
+
+        modes
+            .into_iter()
+            .flat_map(|mode| {
+                (0..12).map(move |i| TracklistUserEntry {
+                    name: format!("{mode:?}_{i}").into(),
+                    mode,
+                    limit: 50 + i,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pages_split_entries() {
+        let entries = entries();
+        let mut pages = Pages::new(15, entries.len());
+
+        assert_eq!(pages.last_page(), 3);
+
+        let first = page_entries(&entries, &pages);
+        assert_eq!(first.len(), 15);
+        assert_eq!(first[0].name.as_str(), "Osu_0");
+        assert_eq!(first[14].name.as_str(), "Taiko_2");
+
+        pages.set_page(2);
+        let second = page_entries(&entries, &pages);
+        assert_eq!(second.len(), 15);
+        assert_eq!(second[0].name.as_str(), "Taiko_3");
+
+        pages.set_page(3);
+        let last = page_entries(&entries, &pages);
+        assert_eq!(last.len(), 6);
+        assert_eq!(last[5].name.as_str(), "Mania_11");
+    }
+
+    #[test]
+    fn page_description_groups_modes() {
+        let entries = entries();
+        let description = page_description(&entries[10..15]);
+
+        assert_eq!(
+            description,
+            "__**osu!standard**__\n`Osu_10` (60)\n`Osu_11` (61)\n\
+            __**osu!taiko**__\n`Taiko_0` (50)\n`Taiko_1` (51)\n`Taiko_2` (52)\n"
+        );
+    }
+}
@@ -14,6 +14,8 @@ use twilight_model::{
     id::{marker::MessageMarker, Id},
 };
 
+#[cfg(feature = "osutracking")]
+use self::impls::TrackListPagination;
 pub use self::origin::ActiveMessageOriginError;
 use self::{
     builder::ActiveMessagesBuilder,
@@ -97,6 +99,8 @@ pub enum ActiveMessage {
     SnipePlayerListPagination,
     TopPagination,
     TopIfPagination,
+    #[cfg(feature = "osutracking")]
+    TrackListPagination,
 }
 
 struct FullActiveMessage {
@@ -315,7 +319,8 @@ impl ActiveMessages {
     }
 
     pub async fn clear(&self) {
-        self.inner.clear().await
+        self.inner.clear().await;
+        BotMetrics::set_active_messages(self.inner.len().await);
     }
 
     pub async fn remove(&self, msg: Id<MessageMarker>) {
@@ -323,11 +328,15 @@ impl ActiveMessages {
     }
 
     async fn remove_full(&self, msg: Id<MessageMarker>) -> Option<FullActiveMessage> {
-        self.inner.lock(&msg).await.remove()
+        let active_msg = self.inner.lock(&msg).await.remove();
+        BotMetrics::set_active_messages(self.inner.len().await);
+
+        active_msg
     }
 
     async fn insert(&self, msg: Id<MessageMarker>, active_msg: FullActiveMessage) {
         self.inner.own(msg).await.insert(active_msg);
+        BotMetrics::set_active_messages(self.inner.len().await);
     }
 }
 
@@ -397,6 +406,14 @@ pub trait IActiveMessage {
     fn until_timeout(&self) -> Option<Duration> {
         Some(Duration::from_secs(60))
     }
+
+    /// Duration after which the message is no longer active, regardless of
+    /// how recently it was interacted with. On `None` there is no limit.
+    ///
+    /// Defaults to 10 minutes.
+    fn max_lifetime(&self) -> Option<Duration> {
+        Some(Duration::from_secs(600))
+    }
 }
 
 #[derive(Clone, Default)]
@@ -62,6 +62,12 @@ impl Pages {
         self.index = self.last_index.min(new_index);
     }
 
+    /// Jump to the given page, clamped between the first and last page
+    pub fn set_page(&mut self, page: usize) {
+        let page = page.clamp(1, self.last_page());
+        self.set_index((page - 1) * self.per_page);
+    }
+
     /// Returns pagination components based on the current [`Pages`]
     pub fn components(&self) -> Vec<Component> {
         if self.last_index == 0 {
@@ -248,19 +254,43 @@ async fn async_handle_pagination_modal(
         return Ok(());
     };
 
-    let max_page = pages.last_page();
-
-    if !(1..=max_page).contains(&page) {
-        debug!("Page {page} is not between 1 and {max_page}");
-
-        return Ok(());
-    }
-
     if defer {
         modal.defer().await.wrap_err("Failed to defer modal")?;
     }
 
-    pages.set_index((page - 1) * pages.per_page());
+    pages.set_page(page);
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use bathbot_psql::model::configs::ListSize;
+
+    use super::*;
+
+    #[test]
+    fn list_size_page_counts() {
+        let last_page = |size: ListSize| Pages::new(size.per_page(), 23).last_page();
+
+        assert_eq!(last_page(ListSize::Condensed), 3);
+        assert_eq!(last_page(ListSize::Detailed), 5);
+        assert_eq!(last_page(ListSize::Single), 23);
+    }
+
+    #[test]
+    fn jump_to_page_clamps() {
+        let mut pages = Pages::new(10, 95);
+
+        pages.set_page(4);
+        assert_eq!(pages.curr_page(), 4);
+        assert_eq!(pages.index(), 30);
+
+        pages.set_page(0);
+        assert_eq!(pages.curr_page(), 1);
+
+        pages.set_page(42);
+        assert_eq!(pages.curr_page(), 10);
+        assert_eq!(pages.index(), pages.last_index());
+    }
+}
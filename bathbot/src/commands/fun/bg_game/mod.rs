@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bathbot_macros::{command, SlashCommand};
 use bathbot_model::Effects;
 use bathbot_psql::model::games::DbMapTagsParams;
@@ -120,6 +122,25 @@ pub struct Bg {
         The higher the difficulty, the more accurate guesses have to be in order to be accepted."
     )]
     difficulty: Option<GameDifficulty>,
+    #[command(
+        min_value = 0.0,
+        desc = "Only use backgrounds of mapsets with a difficulty of at least this many stars"
+    )]
+    min_stars: Option<f32>,
+    #[command(
+        min_value = 0.0,
+        desc = "Only use backgrounds of mapsets with a difficulty of at most this many stars"
+    )]
+    max_stars: Option<f32>,
+    #[command(
+        min_value = 10,
+        max_value = 120,
+        desc = "Automatically reveal a hint every this many seconds if nobody guessed it",
+        help = "If specified, I will reveal a new hint each time this many seconds \
+        passed without anyone guessing the title.\n\
+        The interval must be between 10 and 120 seconds."
+    )]
+    hint_interval: Option<u32>,
     #[command(
         desc = "Choose if a new thread should be started, defaults to staying in the channel"
     )]
@@ -164,9 +185,21 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
     let Bg {
         difficulty,
         mode,
+        min_stars,
+        max_stars,
+        hint_interval,
         thread,
     } = Bg::from_interaction(command.input_data())?;
 
+    if let (Some(min), Some(max)) = (min_stars, max_stars) {
+        if min > max {
+            let content = "`min_stars` must not be greater than `max_stars`";
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    }
+
     let can_view_channel = command.permissions.map_or(true, |permissions| {
         permissions.contains(Permissions::VIEW_CHANNEL)
     });
@@ -265,17 +298,19 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
         }
     }
 
-    if let Some(game) = Context::bg_games().write(&channel).await.remove() {
+    if let Some(game) = Context::remove_bg_game(channel).await {
         if let Err(err) = game.stop() {
             warn!(?err, "Failed to stop game");
         }
     }
 
     let difficulty = difficulty.unwrap_or_default();
+    let hint_interval = hint_interval.map(|secs| Duration::from_secs(u64::from(secs)));
 
     match mode {
         Some(BgGameMode::Osu) | None => {
-            let setup = BackgroundGameSetup::new(difficulty, author);
+            let setup =
+                BackgroundGameSetup::new(difficulty, min_stars, max_stars, hint_interval, author);
 
             if matches!(thread, Some(ThreadChannel::Thread)) {
                 let res_builder = MessageBuilder::new().embed("Starting new thread...");
@@ -287,7 +322,9 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
             }
         }
         Some(BgGameMode::Mania) => {
-            let params = DbMapTagsParams::new(GameMode::Mania);
+            let mut params = DbMapTagsParams::new(GameMode::Mania);
+            params.min_stars = min_stars;
+            params.max_stars = max_stars;
 
             let entries = match Context::games().bggame_tags(params).await {
                 Ok(entries) => entries,
@@ -298,6 +335,13 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 }
             };
 
+            if entries.tags.is_empty() {
+                let content = "No stored mania backgrounds match these star ratings";
+                command.error_callback(content).await?;
+
+                return Ok(());
+            }
+
             let content = format!(
                 "Starting mania background guessing game with {} different backgrounds",
                 entries.tags.len()
@@ -314,12 +358,15 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 command.callback(builder, false).await?;
             }
 
-            let game_fut = BackgroundGame::new(channel, entries, Effects::empty(), difficulty);
+            let game_fut = BackgroundGame::new(
+                channel,
+                entries,
+                Effects::empty(),
+                difficulty,
+                hint_interval,
+            );
 
-            Context::bg_games()
-                .own(channel)
-                .await
-                .insert(game_fut.await);
+            Context::insert_bg_game(channel, game_fut.await).await;
 
             Ok(())
         }
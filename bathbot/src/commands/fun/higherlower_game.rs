@@ -13,7 +13,7 @@ use crate::{
         impls::{HigherLowerGame, RankingPagination},
         ActiveMessages,
     },
-    commands::GameModeOption,
+    commands::{osu::default_mode, GameModeOption},
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -56,10 +56,11 @@ async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
         HigherLower::ScorePp(args) => {
             let mode = match args.mode.map(GameMode::from) {
                 Some(mode) => mode,
-                None => Context::user_config()
-                    .mode(user)
-                    .await?
-                    .unwrap_or(GameMode::Osu),
+                None => {
+                    let user_mode = Context::user_config().mode(user).await?;
+
+                    default_mode(&(&mut command).into(), user_mode).await
+                }
             };
 
             HigherLowerGame::new_score_pp(mode, user).await
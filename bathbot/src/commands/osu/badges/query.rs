@@ -17,8 +17,9 @@ use crate::{
     core::Context,
     manager::redis::RedisData,
     util::{
-        interaction::InteractionCommand, osu::get_combined_thumbnail, Authored,
-        InteractionCommandExt,
+        interaction::InteractionCommand,
+        osu::{get_combined_thumbnail, ThumbnailLayout},
+        Authored, InteractionCommandExt,
     },
 };
 
@@ -122,7 +123,9 @@ pub(super) async fn query(mut command: InteractionCommand, args: BadgesQuery_) -
     let urls = urls.iter().map(Box::as_ref);
 
     let bytes = if badges.len() == 1 {
-        match get_combined_thumbnail(urls, owners.len() as u32, Some(1024)).await {
+        match get_combined_thumbnail(urls, owners.len() as u32, Some(1024), ThumbnailLayout::Grid)
+            .await
+        {
             Ok(bytes) => Some(bytes),
             Err(err) => {
                 warn!(?err, "Failed to combine avatars");
@@ -1,8 +1,9 @@
 use std::collections::BTreeMap;
 
+use bathbot_macros::command;
 use bathbot_util::{
     constants::{AVATAR_URL, GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    MessageBuilder,
+    matcher, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
@@ -14,9 +15,39 @@ use crate::{
     commands::osu::{require_link, user_not_found},
     core::{commands::CommandOrigin, Context},
     manager::redis::{osu::UserArgs, RedisData},
-    util::osu::get_combined_thumbnail,
+    util::osu::{get_combined_thumbnail, ThumbnailLayout},
 };
 
+#[command]
+#[desc("Display all badges of a user")]
+#[help(
+    "Display all profile badges of a user alongside their award date and owner count.\n\
+    To search for badges by name, use the `/badges query` command."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("userbadges", "ub")]
+#[group(AllModes)]
+async fn prefix_badges(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    let mut name = None;
+    let mut discord = None;
+
+    if let Some(arg) = args.next() {
+        match matcher::get_mention_user(arg) {
+            Some(id) => discord = Some(id),
+            None => name = Some(arg.to_owned()),
+        }
+    }
+
+    let args = BadgesUser {
+        name,
+        sort: None,
+        discord,
+    };
+
+    user(msg.into(), args).await
+}
+
 pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser) -> Result<()> {
     let owner = orig.user_id()?;
 
@@ -122,7 +153,9 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser) -> Result<()
     let urls = urls.iter().map(Box::as_ref);
 
     let bytes = if badges.len() == 1 {
-        match get_combined_thumbnail(urls, owners.len() as u32, Some(1024)).await {
+        match get_combined_thumbnail(urls, owners.len() as u32, Some(1024), ThumbnailLayout::Grid)
+            .await
+        {
             Ok(bytes) => Some(bytes),
             Err(err) => {
                 warn!(?err, "Failed to combine avatars");
@@ -0,0 +1,263 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_util::{
+    constants::OSU_API_ISSUE, matcher, numbers::WithComma, EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
+
+use super::{default_mode, require_link, user_not_found};
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, osu::bonus_pp, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "bonuspp",
+    desc = "Show how much bonus pp a user has",
+    help = "Show how much bonus pp a user has.\n\
+    Bonus pp are awarded for the amount of ranked scores a user has set. \
+    They're derived by subtracting the weighted pp of all scores from the user's total pp.\n\
+    Since only the top 100 scores are available, the weighted pp of all other scores is estimated."
+)]
+pub struct BonusPp<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+pub async fn slash_bonuspp(mut command: InteractionCommand) -> Result<()> {
+    let args = BonusPp::from_interaction(command.input_data())?;
+
+    bonus((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Show how much bonus pp a user has")]
+#[help(
+    "Show how much bonus pp a user has.\n\
+    Bonus pp are awarded for the amount of ranked scores a user has set. \
+    They're derived by subtracting the weighted pp of all scores from the user's total pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("bpp", "bonus")]
+#[group(Osu)]
+async fn prefix_bonuspp(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = BonusPp::args(None, args);
+
+    bonus(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+#[command]
+#[desc("Show how much mania bonus pp a user has")]
+#[help(
+    "Show how much mania bonus pp a user has.\n\
+    Bonus pp are awarded for the amount of ranked scores a user has set. \
+    They're derived by subtracting the weighted pp of all scores from the user's total pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("bppm", "bonusmania")]
+#[group(Mania)]
+async fn prefix_bonusppmania(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = BonusPp::args(Some(GameModeOption::Mania), args);
+
+    bonus(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+#[command]
+#[desc("Show how much taiko bonus pp a user has")]
+#[help(
+    "Show how much taiko bonus pp a user has.\n\
+    Bonus pp are awarded for the amount of ranked scores a user has set. \
+    They're derived by subtracting the weighted pp of all scores from the user's total pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("bppt", "bonustaiko")]
+#[group(Taiko)]
+async fn prefix_bonuspptaiko(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = BonusPp::args(Some(GameModeOption::Taiko), args);
+
+    bonus(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+#[command]
+#[desc("Show how much ctb bonus pp a user has")]
+#[help(
+    "Show how much ctb bonus pp a user has.\n\
+    Bonus pp are awarded for the amount of ranked scores a user has set. \
+    They're derived by subtracting the weighted pp of all scores from the user's total pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("bppc", "bonusctb", "bonusppcatch")]
+#[group(Catch)]
+async fn prefix_bonusppctb(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = BonusPp::args(Some(GameModeOption::Catch), args);
+
+    bonus(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+impl<'m> BonusPp<'m> {
+    fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            discord,
+        }
+    }
+}
+
+async fn bonus(orig: CommandOrigin<'_>, args: BonusPp<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
+
+    let legacy_scores = match config.legacy_scores {
+        Some(legacy_scores) => legacy_scores,
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.legacy_scores)
+                .await
+                .unwrap_or(false),
+            None => false,
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
+    let scores_fut = Context::osu_scores()
+        .top(legacy_scores)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok((user, scores)) => (user, scores),
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let stats = user.stats();
+    let bonus = bonus_pp(&scores, stats);
+    let description = bonus_description(stats.pp(), weighted_top_pp(&scores), bonus);
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .thumbnail(user.avatar_url());
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+fn weighted_top_pp(scores: &[Score]) -> f32 {
+    scores
+        .iter()
+        .filter_map(|score| score.weight)
+        .map(|weight| weight.pp)
+        .sum()
+}
+
+fn bonus_description(total_pp: f32, weighted_top_pp: f32, bonus_pp: f32) -> String {
+    // Whatever is neither bonus pp nor in the top100 must come from other scores
+    let other_pp = (total_pp - weighted_top_pp - bonus_pp).max(0.0);
+
+    format!(
+        "Bonus pp: **{bonus}pp**\n\n\
+        Total pp: `{total}pp`\n\
+        Weighted pp of the top 100: `{top}pp`\n\
+        Estimated weighted pp of all other scores: `{other}pp`\n\n\
+        Bonus pp are awarded for the amount of ranked scores. \
+        They're whatever remains of the total pp after subtracting the weighted pp of all scores.",
+        bonus = WithComma::new(bonus_pp),
+        total = WithComma::new(total_pp),
+        top = WithComma::new(weighted_top_pp),
+        other = WithComma::new(other_pp),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description_accounts_for_all_pp() {
+        let description = bonus_description(10_000.0, 9_500.0, 413.89);
+
+        assert!(description.starts_with("Bonus pp: **413.89pp**"));
+        assert!(description.contains("Total pp: `10,000pp`"));
+        assert!(description.contains("Weighted pp of the top 100: `9,500pp`"));
+        assert!(description.contains("all other scores: `86.11pp`"));
+    }
+}
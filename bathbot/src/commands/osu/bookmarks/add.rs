@@ -0,0 +1,146 @@
+use bathbot_macros::command;
+use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::MapIdType, CowUtils, MessageBuilder};
+use eyre::{Report, Result};
+use rosu_v2::prelude::OsuError;
+use twilight_model::guild::Permissions;
+
+use crate::{
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        Context,
+    },
+    util::CheckPermissions,
+};
+
+#[command]
+#[desc("Bookmark a map")]
+#[help(
+    "Add a map to your bookmarks.\n\
+    If no map is given, I will choose the last map I can find in the embeds of this channel.\n\
+    Your bookmarks can be listed and searched through with the `/bookmarks` command."
+)]
+#[usage("[map url / map id]")]
+#[examples("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
+#[aliases("bm", "addbookmark")]
+#[group(AllModes)]
+async fn prefix_bookmark(
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let map = args.next();
+
+    add_bookmark(CommandOrigin::from_msg(msg, permissions), map).await
+}
+
+async fn add_bookmark(orig: CommandOrigin<'_>, map: Option<&str>) -> Result<()> {
+    let map_id = match map {
+        Some(map) => match matcher::get_osu_map_id(map) {
+            Some(map_id) => map_id,
+            None if matcher::get_osu_mapset_id(map).is_some() => {
+                let content = "Looks like you gave me a mapset id, I need a map id though";
+
+                return orig.error(content).await;
+            }
+            None => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return orig.error(content).await;
+            }
+        },
+        None if orig.can_read_history() => {
+            let msgs = match Context::retrieve_channel_history(orig.channel_id()).await {
+                Ok(msgs) => msgs,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to retrieve channel history"));
+                }
+            };
+
+            match Context::find_map_id_in_msgs(&msgs, 0).await {
+                Some(MapIdType::Map(map_id)) => map_id,
+                None | Some(MapIdType::Set(_)) => {
+                    let content =
+                        "No beatmap specified and none found in recent channel history.\n\
+                        Try specifying a map either by url to the map, or just by map id.";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+        None => {
+            let content =
+                "No beatmap specified and lacking permission to search the channel history for maps.\n\
+                Try specifying a map either by url to the map, or just by map id, \
+                or give me the \"Read Message History\" permission.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let mapset = match Context::osu().beatmapset_from_map_id(map_id).await {
+        Ok(mapset) => mapset,
+        Err(OsuError::NotFound) => {
+            let content = format!("Could not find beatmap with id `{map_id}`");
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get mapset"));
+        }
+    };
+
+    // Bookmarks are joined with the stored map data so it must be available
+    Context::osu_map().store_required(&mapset).await;
+
+    let map_opt = mapset
+        .maps
+        .as_ref()
+        .and_then(|maps| maps.iter().find(|map| map.map_id == map_id));
+
+    let Some(map) = map_opt else {
+        let content = format!("Could not find beatmap with id `{map_id}` in its mapset");
+
+        return orig.error(content).await;
+    };
+
+    let user_id = orig.user_id()?;
+
+    let bookmarks = match Context::bookmarks().get(user_id).await {
+        Ok(bookmarks) => bookmarks,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = if bookmarks.iter().any(|bookmark| bookmark.map_id == map_id) {
+        format!("[This map]({}) is already bookmarked", map.url)
+    } else {
+        if let Err(err) = Context::bookmarks().add(user_id, map_id).await {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+
+        debug!(user = %user_id, map = map_id, "Added bookmarked map");
+
+        format!(
+            "Successfully bookmarked [{artist} - {title} [{version}]]({url})",
+            artist = mapset.artist.cow_escape_markdown(),
+            title = mapset.title.cow_escape_markdown(),
+            version = map.version.cow_escape_markdown(),
+            url = map.url,
+        )
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
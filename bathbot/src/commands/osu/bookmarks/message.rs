@@ -78,7 +78,7 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
     };
 
     let mapset_clone = mapset.clone();
-    tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await });
+    tokio::spawn(async move { Context::osu_map().store_required(&mapset_clone).await });
 
     let map_opt = mapset
         .maps
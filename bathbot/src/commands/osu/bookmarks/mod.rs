@@ -1,2 +1,3 @@
+mod add;
 mod message;
 mod slash;
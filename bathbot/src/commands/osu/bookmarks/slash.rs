@@ -25,7 +25,8 @@ use crate::{
     help = "List all your bookmarked maps. You can bookmark maps by:\n\
     1. Rightclicking a bot message that contains a single map\n\
     2. Click on `Apps`\n\
-    3. Click on `Bookmark map`."
+    3. Click on `Bookmark map`.\n\
+    Alternatively, use the `bookmark` prefix command with a map url or id."
 )]
 #[flags(EPHEMERAL)]
 pub struct Bookmarks {
@@ -117,52 +118,7 @@ fn process_bookmarks(
     }
 
     if let Some(criteria) = criteria {
-        bookmarks.retain(|bookmark| {
-            let mut matches = true;
-
-            matches &= criteria.ar.contains(bookmark.ar);
-            matches &= criteria.cs.contains(bookmark.cs);
-            matches &= criteria.hp.contains(bookmark.hp);
-            matches &= criteria.od.contains(bookmark.od);
-            matches &= criteria.length.contains(bookmark.seconds_drain as f32);
-            matches &= criteria.bpm.contains(bookmark.bpm);
-
-            matches &= criteria.insert_date.contains(bookmark.insert_date.date());
-            matches &= bookmark.ranked_date.map_or(false, |datetime| {
-                criteria.ranked_date.contains(datetime.date())
-            });
-
-            let version = bookmark.version.cow_to_ascii_lowercase();
-            matches &= criteria.version.matches(&version);
-
-            let artist = bookmark.artist.cow_to_ascii_lowercase();
-            matches &= criteria.artist.matches(&artist);
-
-            let title = bookmark.title.cow_to_ascii_lowercase();
-            matches &= criteria.title.matches(&title);
-
-            let language = format!("{:?}", bookmark.language).to_lowercase();
-            matches &= criteria.language.matches(&language);
-
-            let genre = format!("{:?}", bookmark.genre).to_lowercase();
-            matches &= criteria.genre.matches(&genre);
-
-            if matches && criteria.has_search_terms() {
-                let terms = [
-                    artist.as_ref(),
-                    title.as_ref(),
-                    version.as_ref(),
-                    language.as_str(),
-                    genre.as_str(),
-                ];
-
-                matches &= criteria
-                    .search_terms()
-                    .all(|term| terms.iter().any(|searchable| searchable.contains(term)))
-            }
-
-            matches
-        });
+        bookmarks.retain(|bookmark| bookmark_matches(bookmark, criteria));
     }
 
     match args.sort.unwrap_or_default() {
@@ -205,6 +161,59 @@ fn process_bookmarks(
     }
 }
 
+fn bookmark_matches(
+    bookmark: &MapBookmark,
+    criteria: &FilterCriteria<BookmarkCriteria<'_>>,
+) -> bool {
+    let mut matches = true;
+
+    matches &= criteria.ar.contains(bookmark.ar);
+    matches &= criteria.cs.contains(bookmark.cs);
+    matches &= criteria.hp.contains(bookmark.hp);
+    matches &= criteria.od.contains(bookmark.od);
+    matches &= criteria.length.contains(bookmark.seconds_drain as f32);
+    matches &= criteria.bpm.contains(bookmark.bpm);
+
+    matches &= criteria.insert_date.contains(bookmark.insert_date.date());
+    // Maps without ranked date only match if there is no ranked date filter
+    matches &= bookmark
+        .ranked_date
+        .map_or(criteria.ranked_date.is_empty(), |datetime| {
+            criteria.ranked_date.contains(datetime.date())
+        });
+
+    let version = bookmark.version.cow_to_ascii_lowercase();
+    matches &= criteria.version.matches(&version);
+
+    let artist = bookmark.artist.cow_to_ascii_lowercase();
+    matches &= criteria.artist.matches(&artist);
+
+    let title = bookmark.title.cow_to_ascii_lowercase();
+    matches &= criteria.title.matches(&title);
+
+    let language = format!("{:?}", bookmark.language).to_lowercase();
+    matches &= criteria.language.matches(&language);
+
+    let genre = format!("{:?}", bookmark.genre).to_lowercase();
+    matches &= criteria.genre.matches(&genre);
+
+    if matches && criteria.has_search_terms() {
+        let terms = [
+            artist.as_ref(),
+            title.as_ref(),
+            version.as_ref(),
+            language.as_str(),
+            genre.as_str(),
+        ];
+
+        matches &= criteria
+            .search_terms()
+            .all(|term| terms.iter().any(|searchable| searchable.contains(term)))
+    }
+
+    matches
+}
+
 fn msg_content(
     args: &Bookmarks,
     criteria: Option<&FilterCriteria<BookmarkCriteria<'_>>>,
@@ -230,3 +239,81 @@ fn msg_content(
 
     content
 }
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::{Genre, Language, RankStatus};
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    fn bookmark(map_id: u32, ar: f32, seconds_drain: u32, ranked: bool) -> MapBookmark {
+        let datetime = OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap();
+
+        MapBookmark {
+            insert_date: datetime,
+            map_id,
+            mapset_id: map_id,
+            mapper_id: 2,
+            creator_id: 2,
+            creator_name: "peppy".into(),
+            artist: "Camellia".into(),
+            title: "Exit This Earth's Atomosphere".into(),
+            version: format!("Insane {map_id}").into_boxed_str(),
+            mode: GameMode::Osu,
+            hp: 5.0,
+            cs: 4.0,
+            od: 8.0,
+            ar,
+            bpm: 200.0,
+            count_circles: 500,
+            count_sliders: 200,
+            count_spinners: 1,
+            seconds_drain,
+            seconds_total: seconds_drain + 5,
+            status: if ranked {
+                RankStatus::Ranked
+            } else {
+                RankStatus::Graveyard
+            },
+            ranked_date: ranked.then_some(datetime),
+            genre: Genre::Electronic,
+            language: Language::Instrumental,
+            cover_url: "".into(),
+        }
+    }
+
+    fn matching_ids(query: &str) -> Vec<u32> {
+        let criteria = BookmarkCriteria::create(query);
+
+        [
+            bookmark(1, 9.0, 120, true),
+            bookmark(2, 9.6, 240, true),
+            bookmark(3, 10.0, 90, false),
+        ]
+        .iter()
+        .filter(|bookmark| bookmark_matches(bookmark, &criteria))
+        .map(|bookmark| bookmark.map_id)
+        .collect()
+    }
+
+    #[test]
+    fn filter_by_ranges() {
+        assert_eq!(matching_ids("ar>9.5"), [2, 3]);
+        assert_eq!(matching_ids("len>=3m"), [2]);
+        assert_eq!(matching_ids("ar>9.5 length<100"), [3]);
+    }
+
+    #[test]
+    fn unranked_maps_only_excluded_by_ranked_filter() {
+        assert_eq!(matching_ids("artist=camellia"), [1, 2, 3]);
+        assert_eq!(matching_ids("ranked>2000-01-01"), [1, 2]);
+    }
+
+    #[test]
+    fn filter_by_search_terms() {
+        assert_eq!(matching_ids("atomosphere insane 2"), [2]);
+        assert_eq!(matching_ids("electronic"), [1, 2, 3]);
+        assert!(matching_ids("hardcore").is_empty());
+    }
+}
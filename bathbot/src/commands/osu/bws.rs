@@ -6,12 +6,16 @@ use bathbot_util::{
     matcher, MessageBuilder, TourneyBadges,
 };
 use eyre::{Report, Result};
-use rosu_v2::{prelude::OsuError, request::UserId};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{default_mode, require_link, user_not_found};
 use crate::{
+    commands::GameModeOption,
     core::commands::{prefix::Args, CommandOrigin},
     embeds::{BWSEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
@@ -22,7 +26,7 @@ use crate::{
 #[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
 #[command(
     name = "bws",
-    desc = "Show the badge weighted seeding for an osu!standard player",
+    desc = "Show the badge weighted seeding for a player",
     help = "To combat those pesky derank players ruining everyone's tourneys, \
     many tournaments use a \"Badge Weighted Seeding\" system to adjust a player's rank based \
     on the amount of badges they own.\n\
@@ -32,6 +36,8 @@ use crate::{
     There are various formulas around but this command uses `rank^(0.9937^(badges^2))`."
 )]
 pub struct Bws<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
     #[command(
@@ -101,6 +107,7 @@ impl<'m> Bws<'m> {
         }
 
         Ok(Self {
+            mode: None,
             name,
             rank,
             badges,
@@ -120,6 +127,7 @@ async fn slash_bws(mut command: InteractionCommand) -> Result<()> {
 #[help(
     "Show the badge weighted seeding for a player. \n\
     The current formula is `rank^(0.9937^(badges^2))`.\n\
+    The gamemode of the global rank is the one of your config; use `/bws` to pick a different one.\n\
     Next to the player's username, you can specify `rank=integer` \
     to show how the bws value progresses towards that rank.\n\
     Similarly, you can specify `badges=integer` to show how the value \
@@ -142,22 +150,33 @@ async fn prefix_bws(msg: &Message, args: Args<'_>) -> Result<()> {
 const MIN_BADGES_OFFSET: usize = 2;
 
 async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
-        None => match Context::user_config().osu_id(orig.user_id()?).await {
-            Ok(Some(user_id)) => UserId::Id(user_id),
-            Ok(None) => return require_link(&orig).await,
-            Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
-
-                return Err(err);
-            }
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
         },
     };
 
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
+
     let Bws { rank, badges, .. } = args;
 
-    let user_args = UserArgs::rosu_id(&user_id).await;
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
 
     let user = match Context::redis().osu_user(user_args).await {
         Ok(user) => user,
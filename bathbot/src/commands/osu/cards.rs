@@ -15,7 +15,7 @@ use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{default_mode, require_link, user_not_found};
 use crate::{
     commands::GameModeOption,
     core::{commands::CommandOrigin, BotConfig, Context},
@@ -107,11 +107,10 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         },
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let legacy_scores = match config.legacy_scores {
         Some(legacy_scores) => legacy_scores,
@@ -221,21 +220,34 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         .format(DATE_FORMAT)
         .unwrap();
 
-    let card_res = BathbotCard::new(mode, &scores, maps, legacy_scores)
-        .user(user.username(), stats.level().float())
-        .ranks(stats.global_rank(), stats.country_rank())
-        .medals(medals as u32, total_medals as u32)
-        .bytes(&pfp, &flag)
-        .date(&today)
-        .assets(BotConfig::get().paths.assets.clone())
-        .draw();
+    let username = Box::<str>::from(user.username());
+    let level = stats.level().float();
+    let (global_rank, country_rank) = (stats.global_rank(), stats.country_rank());
+    let assets = BotConfig::get().paths.assets.clone();
+
+    let draw_fut = tokio::task::spawn_blocking(move || {
+        BathbotCard::new(mode, &scores, maps, legacy_scores)
+            .user(&username, level)
+            .ranks(global_rank, country_rank)
+            .medals(medals as u32, total_medals as u32)
+            .bytes(&pfp, &flag)
+            .date(&today)
+            .assets(assets)
+            .draw()
+            .map_err(Report::new)
+    });
+
+    let card_res = draw_fut
+        .await
+        .wrap_err("Card task panicked")
+        .and_then(|res| res);
 
     let bytes = match card_res {
         Ok(bytes) => bytes,
         Err(err) => {
             let _ = orig.error("Failed to draw the card :(").await;
 
-            return Err(Report::new(err).wrap_err("Failed to draw card"));
+            return Err(err.wrap_err("Failed to draw card"));
         }
     };
 
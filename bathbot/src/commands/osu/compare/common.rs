@@ -9,7 +9,7 @@ use bathbot_util::{
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
 use rosu_v2::{
-    prelude::{GameMode, OsuError, Score, Username},
+    prelude::{GameMode, GameMods, OsuError, Score, Username},
     request::UserId,
     OsuResult,
 };
@@ -24,12 +24,16 @@ use super::{CompareTop, AT_LEAST_ONE};
 use crate::{
     active::{impls::CompareTopPagination, ActiveMessages},
     commands::{
-        osu::{user_not_found, UserExtraction},
+        osu::{default_mode, user_not_found, UserExtraction},
         GameModeOption,
     },
     core::commands::{prefix::Args, CommandOrigin},
     manager::redis::{osu::UserArgs, RedisData},
-    util::{interaction::InteractionCommand, osu::get_combined_thumbnail, InteractionCommandExt},
+    util::{
+        interaction::InteractionCommand,
+        osu::{get_combined_thumbnail, ThumbnailLayout},
+        InteractionCommandExt,
+    },
     Context,
 };
 
@@ -193,7 +197,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
     let mode = match args.mode {
         Some(mode) => mode.into(),
         None => match Context::user_config().mode(owner).await {
-            Ok(mode) => mode.unwrap_or(GameMode::Osu),
+            Ok(mode) => default_mode(&orig, mode).await,
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -309,7 +313,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
     // Create the combined profile pictures
     let urls = iter::once(user1.avatar_url()).chain(iter::once(user2.avatar_url()));
 
-    let thumbnail = match get_combined_thumbnail(urls, 2, None).await {
+    let thumbnail = match get_combined_thumbnail(urls, 2, None, ThumbnailLayout::Strip).await {
         Ok(thumbnail) => Some(thumbnail),
         Err(err) => {
             warn!(?err, "Failed to combine avatars");
@@ -350,6 +354,7 @@ async fn get_user_and_scores(
 #[derive(PartialEq)]
 pub struct CommonScore {
     pub pp: f32,
+    pub mods: GameMods,
     score: u32,
     ended_at: OffsetDateTime,
 }
@@ -361,6 +366,7 @@ impl From<&Score> for CommonScore {
     fn from(score: &Score) -> Self {
         Self {
             pp: score.pp.unwrap_or(0.0),
+            mods: score.mods.clone(),
             score: score.score,
             ended_at: score.ended_at,
         }
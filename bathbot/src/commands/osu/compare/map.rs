@@ -0,0 +1,407 @@
+use std::{cmp::Ordering, collections::HashSet, fmt::Write};
+
+use bathbot_macros::command;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    matcher,
+    numbers::round,
+    osu::MapIdType,
+    CowUtils, EmbedBuilder, IntHasher, MessageBuilder, ModsFormatter,
+};
+use eyre::{Report, Result};
+use futures::future::join_all;
+use rosu_v2::{
+    prelude::{BeatmapUserScore, GameMode, OsuError, Username},
+    request::UserId,
+};
+use twilight_model::guild::Permissions;
+
+use super::{CompareMap, AT_LEAST_ONE};
+use crate::{
+    commands::osu::user_not_found,
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::{redis::osu::UserArgs, MapError},
+    util::{osu::grade_emote, ChannelExt, CheckPermissions},
+    Context,
+};
+
+#[command]
+#[desc("Compare the scores of multiple users on a map")]
+#[help(
+    "Compare the best score of multiple users on a single map.\n\
+    If no map is given, I will choose the last map \
+    I can find in the embeds of this channel."
+)]
+#[usage("[map url / map id] [name1] [name2] ...")]
+#[examples("badewanne3 \"nathan on osu\"", "2240404 badewanne3 @Badewanne3 mrekk")]
+#[aliases("cmap", "mapcompare")]
+#[group(AllModes)]
+async fn prefix_comparemap(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    match CompareMap::args(args) {
+        Ok(args) => map(CommandOrigin::from_msg(msg, permissions), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+impl<'m> CompareMap<'m> {
+    /// Duplicate names or mentions are only considered once.
+    fn args(args: Args<'m>) -> Result<Self, &'static str> {
+        const TOO_MANY: &str = "You can specify at most three names and three mentions";
+
+        let mut args_ = Self::default();
+
+        for arg in args {
+            if let Some(id) = matcher::get_osu_map_id(arg) {
+                args_.map = Some(id.to_string().into());
+            } else if let Some(id) = matcher::get_mention_user(arg) {
+                let discords = [
+                    &mut args_.discord1,
+                    &mut args_.discord2,
+                    &mut args_.discord3,
+                ];
+
+                if discords.iter().any(|discord| **discord == Some(id)) {
+                    continue;
+                }
+
+                match discords.into_iter().find(|discord| discord.is_none()) {
+                    Some(discord) => *discord = Some(id),
+                    None => return Err(TOO_MANY),
+                }
+            } else {
+                let names = [&mut args_.name1, &mut args_.name2, &mut args_.name3];
+
+                let duplicate = names.iter().any(|name| {
+                    name.as_deref()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(arg))
+                });
+
+                if duplicate {
+                    continue;
+                }
+
+                match names.into_iter().find(|name| name.is_none()) {
+                    Some(name) => *name = Some(arg.into()),
+                    None => return Err(TOO_MANY),
+                }
+            }
+        }
+
+        Ok(args_)
+    }
+}
+
+pub(super) async fn map(orig: CommandOrigin<'_>, args: CompareMap<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let CompareMap {
+        map,
+        name1,
+        name2,
+        name3,
+        discord1,
+        discord2,
+        discord3,
+    } = args;
+
+    let mut user_ids: Vec<_> = [name1, name2, name3]
+        .into_iter()
+        .flatten()
+        .map(|name| UserId::Name(name.as_ref().into()))
+        .collect();
+
+    for discord in [discord1, discord2, discord3].into_iter().flatten() {
+        match Context::user_config().osu_id(discord).await {
+            Ok(Some(user_id)) => user_ids.push(UserId::Id(user_id)),
+            Ok(None) => {
+                let content = format!("<@{discord}> is not linked to an osu!profile");
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        }
+    }
+
+    if user_ids.len() < 2 {
+        match Context::user_config().osu_id(owner).await {
+            Ok(Some(user_id)) if !user_ids.is_empty() => user_ids.insert(0, UserId::Id(user_id)),
+            Ok(_) if user_ids.is_empty() => return orig.error(AT_LEAST_ONE).await,
+            Ok(_) => {
+                let content =
+                    "Since you're not linked with the `/link` command, you must specify two names.";
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        }
+    }
+
+    let map_id = match map {
+        Some(map) => match matcher::get_osu_map_id(&map) {
+            Some(map_id) => map_id,
+            None if matcher::get_osu_mapset_id(&map).is_some() => {
+                let content = "Looks like you gave me a mapset id, I need a map id though";
+
+                return orig.error(content).await;
+            }
+            None => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return orig.error(content).await;
+            }
+        },
+        None if orig.can_read_history() => {
+            let msgs = match Context::retrieve_channel_history(orig.channel_id()).await {
+                Ok(msgs) => msgs,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to retrieve channel history"));
+                }
+            };
+
+            match Context::find_map_id_in_msgs(&msgs, 0).await {
+                Some(MapIdType::Map(id)) => id,
+                None | Some(MapIdType::Set(_)) => {
+                    let content =
+                        "No beatmap specified and none found in recent channel history.\n\
+                        Try specifying a map either by url to the map, or just by map id.";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+        None => {
+            let content =
+                "No beatmap specified and lacking permission to search the channel history for maps.\n\
+                Try specifying a map either by url to the map, or just by map id, \
+                or give me the \"Read Message History\" permission.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?"
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let legacy_scores = match config.legacy_scores {
+        Some(legacy_scores) => legacy_scores,
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.legacy_scores)
+                .await
+                .unwrap_or(false),
+            None => false,
+        },
+    };
+
+    let mode = map.mode();
+
+    let entry_futs = user_ids
+        .iter()
+        .map(|user_id| MapCompareEntry::new(user_id, map_id, mode, legacy_scores));
+
+    let results = join_all(entry_futs).await;
+    let mut entries = Vec::with_capacity(results.len());
+
+    for (res, user_id) in results.into_iter().zip(user_ids) {
+        match res {
+            Ok(entry) => entries.push(entry),
+            Err(OsuError::NotFound) => {
+                let content = user_not_found(user_id).await;
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user score on map");
+
+                return Err(err);
+            }
+        }
+    }
+
+    // Names and mentions may resolve to the same user
+    let mut seen = HashSet::with_capacity_and_hasher(entries.len(), IntHasher);
+    entries.retain(|entry| seen.insert(entry.user_id));
+
+    if entries.len() < 2 {
+        return orig.error("Give at least two different users").await;
+    }
+
+    entries.sort_by(|a, b| cmp_pp(a.pp(), b.pp()));
+
+    let mut description = String::with_capacity(entries.len() * 96);
+
+    for (entry, i) in entries.iter().zip(1..) {
+        let name = entry.name.as_str();
+
+        let Some(ref map_score) = entry.score else {
+            let _ = writeln!(description, "**{i}.** `{name}`: no score");
+
+            continue;
+        };
+
+        let score = &map_score.score;
+
+        let _ = writeln!(
+            description,
+            "**{i}.** `{name}`: {grade} **{pp:.2}pp** • {acc}% • +{mods} • #{pos}",
+            grade = grade_emote(score.grade),
+            pp = score.pp.unwrap_or(0.0),
+            acc = round(score.accuracy),
+            mods = ModsFormatter::new(&score.mods),
+            pos = map_score.pos,
+        );
+    }
+
+    let title = format!(
+        "{artist} - {title} [{version}]",
+        artist = map.artist().cow_escape_markdown(),
+        title = map.title().cow_escape_markdown(),
+        version = map.version().cow_escape_markdown(),
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .thumbnail(map.thumbnail())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+struct MapCompareEntry {
+    user_id: u32,
+    name: Username,
+    score: Option<BeatmapUserScore>,
+}
+
+impl MapCompareEntry {
+    async fn new(
+        user_id: &UserId,
+        map_id: u32,
+        mode: GameMode,
+        legacy_scores: bool,
+    ) -> Result<Self, OsuError> {
+        let user_args = UserArgs::rosu_id(user_id).await.mode(mode);
+        let user = Context::redis().osu_user(user_args).await?;
+
+        let score_fut = Context::osu_scores().user_on_map_single(
+            user.user_id(),
+            map_id,
+            mode,
+            None,
+            legacy_scores,
+        );
+
+        let score = match score_fut.await {
+            Ok(score) => Some(score),
+            Err(OsuError::NotFound) => None,
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self {
+            user_id: user.user_id(),
+            name: user.username().into(),
+            score,
+        })
+    }
+
+    fn pp(&self) -> Option<f32> {
+        self.score
+            .as_ref()
+            .map(|map_score| map_score.score.pp.unwrap_or(0.0))
+    }
+}
+
+/// Orders by pp in descending order while putting entries without score last.
+fn cmp_pp(a: Option<f32>, b: Option<f32>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::Id;
+
+    use super::*;
+    use crate::core::commands::prefix::ArgsNum;
+
+    #[test]
+    fn prefix_args_dedup_and_limit_users() {
+        let args = Args::new(
+            "badewanne3 <@1> Badewanne3 mrekk <@1> 2240404",
+            ArgsNum::None,
+        );
+        let args = CompareMap::args(args).unwrap();
+
+        assert_eq!(args.map.as_deref(), Some("2240404"));
+        assert_eq!(args.name1.as_deref(), Some("badewanne3"));
+        assert_eq!(args.name2.as_deref(), Some("mrekk"));
+        assert_eq!(args.name3, None);
+        assert_eq!(args.discord1, Some(Id::new(1)));
+        assert_eq!(args.discord2, None);
+
+        let args = Args::new("a b c d", ArgsNum::None);
+        assert!(CompareMap::args(args).is_err());
+
+        let args = Args::new("<@1> <@2> <@3> <@4>", ArgsNum::None);
+        assert!(CompareMap::args(args).is_err());
+    }
+
+    #[test]
+    fn sort_by_pp_without_score_last() {
+        let mut pps = [Some(120.5), None, Some(300.0), Some(0.0), None];
+        pps.sort_by(|a, b| cmp_pp(*a, *b));
+
+        assert_eq!(pps, [Some(300.0), Some(120.5), Some(0.0), None, None]);
+    }
+}
@@ -9,6 +9,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 pub use self::{
     common::*,
+    map::*,
     most_played::*,
     profile::*,
     score::{slash_compare as slash_compare_score, *},
@@ -19,6 +20,7 @@ use crate::{
 };
 
 mod common;
+mod map;
 mod most_played;
 mod profile;
 mod score;
@@ -38,6 +40,8 @@ pub enum Compare<'a> {
     Top(CompareTop<'a>),
     #[command(name = "mostplayed")]
     MostPlayed(CompareMostPlayed<'a>),
+    #[command(name = "map")]
+    Map(CompareMap<'a>),
 }
 
 #[derive(CommandModel)]
@@ -50,6 +54,8 @@ pub enum CompareAutocomplete<'a> {
     Top(CompareTop<'a>),
     #[command(name = "mostplayed")]
     MostPlayed(CompareMostPlayed<'a>),
+    #[command(name = "map")]
+    Map(CompareMap<'a>),
 }
 
 #[derive(CreateCommand)]
@@ -218,11 +224,46 @@ pub struct CompareMostPlayed<'a> {
     discord2: Option<Id<UserMarker>>,
 }
 
+#[derive(CommandModel, CreateCommand, Default)]
+#[command(
+    name = "map",
+    desc = "Compare the scores of multiple users on a map",
+    help = "Compare the best score of multiple users on a single map.\n\
+    The users are sorted by the pp of their score."
+)]
+pub struct CompareMap<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name1: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name2: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name3: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` options, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord1: Option<Id<UserMarker>>,
+    #[command(desc = "Specify a linked discord user")]
+    discord2: Option<Id<UserMarker>>,
+    #[command(desc = "Specify a linked discord user")]
+    discord3: Option<Id<UserMarker>>,
+}
+
 async fn slash_compare(mut command: InteractionCommand) -> Result<()> {
     match CompareAutocomplete::from_interaction(command.input_data())? {
         CompareAutocomplete::Score(args) => slash_compare_score(&mut command, args).await,
         CompareAutocomplete::Profile(args) => profile((&mut command).into(), args).await,
         CompareAutocomplete::Top(args) => top((&mut command).into(), args).await,
         CompareAutocomplete::MostPlayed(args) => mostplayed((&mut command).into(), args).await,
+        CompareAutocomplete::Map(args) => map((&mut command).into(), args).await,
     }
 }
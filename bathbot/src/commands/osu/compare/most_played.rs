@@ -1,4 +1,4 @@
-use std::{cmp::Reverse, collections::HashMap, fmt::Write};
+use std::{cmp::Reverse, collections::HashMap, fmt::Write, iter};
 
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::User;
@@ -19,6 +19,7 @@ use crate::{
     commands::osu::{user_not_found, UserExtraction},
     core::commands::CommandOrigin,
     manager::redis::{osu::UserArgs, RedisData},
+    util::osu::{get_combined_thumbnail, ThumbnailLayout},
     Context,
 };
 
@@ -132,6 +133,18 @@ pub(super) async fn mostplayed(
         }
     };
 
+    // Without any most played maps there is nothing to intersect
+    for (user, maps) in [(&user1, &maps1), (&user2, &maps2)] {
+        if maps.is_empty() {
+            let content = format!(
+                "`{}` has no most played maps or their playcounts are not available",
+                user.username()
+            );
+
+            return orig.error(content).await;
+        }
+    }
+
     // Consider only maps that appear in each users map list
     let mut maps: HashMap<_, _, IntHasher> = maps1
         .into_iter()
@@ -173,6 +186,18 @@ pub(super) async fn mostplayed(
         if amount_common > 1 { "s" } else { "" }
     );
 
+    // Create the combined profile pictures
+    let urls = iter::once(user1.avatar_url()).chain(iter::once(user2.avatar_url()));
+
+    let thumbnail = match get_combined_thumbnail(urls, 2, None, ThumbnailLayout::Strip).await {
+        Ok(thumbnail) => Some(thumbnail),
+        Err(err) => {
+            warn!(?err, "Failed to combine avatars");
+
+            None
+        }
+    };
+
     let pagination = CompareMostPlayedPagination::builder()
         .username1(user1.username().into())
         .username2(user2.username().into())
@@ -184,6 +209,7 @@ pub(super) async fn mostplayed(
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
+        .attachment(thumbnail.map(|bytes| ("avatar_fuse.png".to_owned(), bytes)))
         .begin(orig)
         .await
 }
@@ -28,7 +28,10 @@ use twilight_model::{
 
 use super::{CompareProfile, AT_LEAST_ONE};
 use crate::{
-    commands::{osu::UserExtraction, GameModeOption},
+    commands::{
+        osu::{default_mode, UserExtraction},
+        GameModeOption,
+    },
     core::commands::{prefix::Args, CommandOrigin},
     embeds::{EmbedData, ProfileCompareEmbed},
     manager::redis::osu::UserArgs,
@@ -129,7 +132,7 @@ pub(super) async fn profile(orig: CommandOrigin<'_>, mut args: CompareProfile<'_
     let mode = match args.mode {
         Some(mode) => mode.into(),
         None => match Context::user_config().mode(orig.user_id()?).await {
-            Ok(mode) => mode.unwrap_or(GameMode::Osu),
+            Ok(mode) => default_mode(&orig, mode).await,
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -174,7 +174,9 @@ async fn slash_fix(mut command: InteractionCommand) -> Result<()> {
      If no map is given, I will choose the last map \
      I can find in the embeds of this channel.\n\
      Mods can be specified but only if there already is a score \
-     on the map with those mods."
+     on the map with those mods.\n\
+     If the pp of the score as reported by the osu!api seem outdated, \
+     the recalculated value will be shown too."
 )]
 #[alias("fixscore")]
 #[usage("[username] [map url / map id] [+mods]")]
@@ -304,6 +306,8 @@ pub struct FixScore {
     pub score: ScoreSlim,
     pub top: Vec<Score>,
     pub if_fc: Option<IfFc>,
+    /// Only available if the pp of `score` originate from the osu!api
+    pub recalculated_pp: Option<f32>,
 }
 
 // Retrieve user's score on the map, the user itself, and the map including
@@ -404,14 +408,9 @@ async fn request_by_map(
                 .limit(100)
                 .exec(user_args);
 
-            let pp_fut = async {
-                match score.pp {
-                    Some(pp) => pp,
-                    None => Context::pp(&map).score(&score).performance().await.pp() as f32,
-                }
-            };
+            let pp_fut = score_pp(&score, &map);
 
-            let (top_res, pp) = tokio::join!(top_fut, pp_fut);
+            let (top_res, (pp, recalculated_pp)) = tokio::join!(top_fut, pp_fut);
 
             let top = match top_res {
                 Ok(scores) => scores,
@@ -429,7 +428,12 @@ async fn request_by_map(
             // then the map retrieval might happen twice
             let if_fc = IfFc::new(&score, &map).await;
 
-            Some(FixScore { score, top, if_fc })
+            Some(FixScore {
+                score,
+                top,
+                if_fc,
+                recalculated_pp,
+            })
         }
         None => None,
     };
@@ -507,10 +511,7 @@ async fn request_by_score(
         }
     };
 
-    let pp = match score.pp {
-        Some(pp) => pp,
-        None => Context::pp(&map).score(&score).performance().await.pp() as f32,
-    };
+    let (pp, recalculated_pp) = score_pp(&score, &map).await;
 
     let score = ScoreSlim::new(score, pp);
     let if_fc = IfFc::new(&score, &map).await;
@@ -518,8 +519,24 @@ async fn request_by_score(
     let data = FixEntry {
         user,
         map,
-        score: Some(FixScore { score, top, if_fc }),
+        score: Some(FixScore {
+            score,
+            top,
+            if_fc,
+            recalculated_pp,
+        }),
     };
 
     ScoreResult::Entry(data)
 }
+
+/// Returns the pp of the score and, if the osu!api provided pp, the
+/// recalculated pp to compare them with.
+async fn score_pp(score: &Score, map: &OsuMap) -> (f32, Option<f32>) {
+    let calculated = Context::pp(map).score(score).performance().await.pp() as f32;
+
+    match score.pp {
+        Some(pp) => (pp, Some(calculated)),
+        None => (calculated, None),
+    }
+}
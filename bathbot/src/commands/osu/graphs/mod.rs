@@ -29,7 +29,7 @@ use self::{
     top_index::top_graph_index,
     top_time::top_graph_time,
 };
-use super::{require_link, user_not_found, SnipeGameMode};
+use super::{default_mode, require_link, user_not_found, GraphConfig, GraphTheme, SnipeGameMode};
 use crate::{
     commands::{GameModeOption, ShowHideOption, TimezoneOption},
     core::{commands::CommandOrigin, Context},
@@ -131,6 +131,20 @@ pub struct GraphSniped {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Choose the color theme of the graph")]
+    theme: Option<GraphTheme>,
+    #[command(
+        min_value = 400,
+        max_value = 2700,
+        desc = "Specify the width of the graph in pixels"
+    )]
+    width: Option<u32>,
+    #[command(
+        min_value = 200,
+        max_value = 1400,
+        desc = "Specify the height of the graph in pixels"
+    )]
+    height: Option<u32>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -150,6 +164,20 @@ pub struct GraphSnipeCount {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Choose the color theme of the graph")]
+    theme: Option<GraphTheme>,
+    #[command(
+        min_value = 400,
+        max_value = 2700,
+        desc = "Specify the width of the graph in pixels"
+    )]
+    width: Option<u32>,
+    #[command(
+        min_value = 200,
+        max_value = 1400,
+        desc = "Specify the height of the graph in pixels"
+    )]
+    height: Option<u32>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -262,14 +290,18 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
         Graph::Sniped(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
 
-            sniped_graph(&orig, user_id, mode)
+            let config = snipe_graph_config(args.width, args.height, args.theme);
+
+            sniped_graph(&orig, user_id, mode, config)
                 .await
                 .wrap_err("failed to create snipe graph")?
         }
         Graph::SnipeCount(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
 
-            snipe_count_graph(&orig, user_id, mode)
+            let config = snipe_graph_config(args.width, args.height, args.theme);
+
+            snipe_count_graph(&orig, user_id, mode, config)
                 .await
                 .wrap_err("failed to create snipe count graph")?
         }
@@ -285,11 +317,10 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
                 }
             };
 
-            let mode = args
-                .mode
-                .map(GameMode::from)
-                .or(config.mode)
-                .unwrap_or(GameMode::Osu);
+            let mode = match args.mode {
+                Some(mode) => GameMode::from(mode),
+                None => default_mode(&orig, config.mode).await,
+            };
 
             let (user_id, no_user_specified) = match user_id!(orig, args) {
                 Some(user_id) => (user_id, false),
@@ -343,6 +374,18 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
 const W: u32 = 1350;
 const H: u32 = 711;
 
+/// Snipe graphs keep the dimensions of all other graphs unless specified
+/// otherwise.
+fn snipe_graph_config(
+    width: Option<u32>,
+    height: Option<u32>,
+    theme: Option<GraphTheme>,
+) -> GraphConfig {
+    GraphConfig::new(W, H)
+        .dimensions(width, height)
+        .theme(theme)
+}
+
 async fn top_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
@@ -475,3 +518,18 @@ impl<'a, C> Drawable<SkiaBackend<'a>> for BitMapElement<C> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snipe_graph_default_size() {
+        let config = snipe_graph_config(None, None, None);
+        assert_eq!((config.width, config.height), (1350, 711));
+
+        let config = snipe_graph_config(Some(800), None, Some(GraphTheme::Light));
+        assert_eq!((config.width, config.height), (800, 711));
+        assert_eq!(config.theme, GraphTheme::Light);
+    }
+}
@@ -6,9 +6,8 @@ use bathbot_util::{
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 
-use super::{H, W};
 use crate::{
-    commands::osu::{player_snipe_stats, user_not_found},
+    commands::osu::{player_snipe_stats, user_not_found, GraphConfig},
     core::{commands::CommandOrigin, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
@@ -17,6 +16,7 @@ pub async fn snipe_count_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     mode: GameMode,
+    config: GraphConfig,
 ) -> Result<Option<(RedisData<User>, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
 
@@ -93,7 +93,7 @@ pub async fn snipe_count_graph(
         return Ok(None);
     };
 
-    let graph_result = player_snipe_stats::graphs(&history, &player.count_sr_spread, W, H);
+    let graph_result = player_snipe_stats::graphs(history, player.count_sr_spread, config).await;
 
     let bytes = match graph_result {
         Ok(graph) => graph,
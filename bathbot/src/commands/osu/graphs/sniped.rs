@@ -6,9 +6,8 @@ use bathbot_util::{
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 
-use super::{H, W};
 use crate::{
-    commands::osu::{sniped, user_not_found},
+    commands::osu::{sniped, user_not_found, GraphConfig},
     core::{commands::CommandOrigin, Context},
     manager::redis::{osu::UserArgs, RedisData},
 };
@@ -17,6 +16,7 @@ pub async fn sniped_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     mode: GameMode,
+    config: GraphConfig,
 ) -> Result<Option<(RedisData<User>, Vec<u8>)>> {
     let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
 
@@ -77,7 +77,9 @@ pub async fn sniped_graph(
         return Ok(None);
     };
 
-    let bytes = match sniped::graphs(username, &mut sniper, &mut snipee, W, H) {
+    let graph_fut = sniped::graphs(username, &mut sniper, &mut snipee, config);
+
+    let bytes = match graph_fut.await {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let content = format!(
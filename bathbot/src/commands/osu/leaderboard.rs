@@ -2,6 +2,7 @@ use std::{
     borrow::Cow,
     cmp::Reverse,
     collections::{hash_map::Entry, HashMap},
+    fmt::Write,
 };
 
 use bathbot_macros::{command, HasMods, SlashCommand};
@@ -28,7 +29,7 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use super::{HasMods, ModsResult};
+use super::{default_mode, mods_or_default, HasMods, ModsResult};
 use crate::{
     active::{impls::LeaderboardPagination, ActiveMessages},
     core::commands::{prefix::Args, CommandOrigin},
@@ -36,7 +37,11 @@ use crate::{
         redis::{osu::UserArgs, RedisData},
         MapError, Mods, OsuMap,
     },
-    util::{interaction::InteractionCommand, ChannelExt, CheckPermissions, InteractionCommandExt},
+    util::{
+        interaction::InteractionCommand,
+        query::{FilterCriteria, IFilterCriteria, LeaderboardCriteria},
+        ChannelExt, CheckPermissions, InteractionCommandExt,
+    },
     Context,
 };
 
@@ -51,9 +56,11 @@ pub struct Leaderboard<'a> {
     )]
     map: Option<Cow<'a, str>>,
     #[command(
-        desc = "Specify mods e.g. hdhr or nm",
+        desc = "Specify mods e.g. hdhr or nm, or `any` to ignore your default mods",
         help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
-        e.g. `hdhr` or `+hdhr!`, and filter out all scores that don't match those mods."
+        e.g. `hdhr` or `+hdhr!`, and filter out all scores that don't match those mods.\n\
+        If not specified, your default mods of `/config` will be used. \
+        Specify `any` to show the leaderboard without filtering mods."
     )]
     mods: Option<Cow<'a, str>>,
     #[command(
@@ -62,6 +69,14 @@ pub struct Leaderboard<'a> {
         Note that the scores will still be the top pp scores, they'll just be re-ordered."
     )]
     sort: Option<LeaderboardSort>,
+    #[command(
+        desc = "Specify a search query containing AR, BPM, PP, ...",
+        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
+        You can limit values such as ar, cs, hp, od, bpm, length, stars, pp, acc, combo, \
+        misses, or score like for example `ar>9.3 bpm>180`.\n\
+        Map attributes will be adjusted to the mods of each score."
+    )]
+    query: Option<String>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -125,15 +140,20 @@ impl LeaderboardSort {
 struct LeaderboardArgs<'a> {
     map: Option<MapIdType>,
     mods: Option<Cow<'a, str>>,
+    /// Whether `any` mods were specified which overrides the default mods
+    any_mods: bool,
     sort: LeaderboardSort,
+    query: Option<String>,
 }
 
 impl<'m> LeaderboardArgs<'m> {
     async fn args(msg: &Message, args: Args<'m>) -> Result<LeaderboardArgs<'m>, String> {
         let mut map = None;
         let mut mods = None;
+        let mut any_mods = false;
+        let mut query: Option<String> = None;
 
-        for arg in args.take(2) {
+        for arg in args {
             if let Some(id) = matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
@@ -141,10 +161,20 @@ impl<'m> LeaderboardArgs<'m> {
                 map = Some(id);
             } else if matcher::get_mods(arg).is_some() {
                 mods = Some(arg.into());
+            } else if arg.eq_ignore_ascii_case("any") {
+                any_mods = true;
+            } else if matcher::is_query_syntax(arg) {
+                match query {
+                    Some(ref mut query) => {
+                        query.push(' ');
+                        query.push_str(arg);
+                    }
+                    None => query = Some(arg.to_owned()),
+                }
             } else {
                 let content = format!(
                     "Failed to parse `{arg}`.\n\
-                    Must be either a map id, map url, or mods.",
+                    Must be either a map id, map url, mods, `any`, or a filter like `ar>9`.",
                 );
 
                 return Err(content);
@@ -164,7 +194,13 @@ impl<'m> LeaderboardArgs<'m> {
 
         let sort = LeaderboardSort::default();
 
-        Ok(Self { map, mods, sort })
+        Ok(Self {
+            map,
+            mods,
+            any_mods,
+            sort,
+            query,
+        })
     }
 }
 
@@ -188,10 +224,17 @@ impl<'a> TryFrom<Leaderboard<'a>> for LeaderboardArgs<'a> {
             None => None,
         };
 
+        let any_mods = args
+            .mods
+            .as_deref()
+            .is_some_and(|mods| mods.eq_ignore_ascii_case("any"));
+
         Ok(Self {
             map,
-            mods: args.mods,
+            mods: args.mods.filter(|_| !any_mods),
+            any_mods,
             sort: args.sort.unwrap_or_default(),
+            query: args.query,
         })
     }
 }
@@ -202,10 +245,17 @@ impl<'a> TryFrom<Leaderboard<'a>> for LeaderboardArgs<'a> {
     "Display the global leaderboard of a given map.\n\
     If no map is given, I will choose the last map \
     I can find in the embeds of this channel.\n\
-    Mods can be specified."
+    Mods can be specified, otherwise your default mods of `/config` are used.\n\
+    Specify `any` to show the leaderboard without filtering mods.\n\
+    Scores can be filtered through a query like `ar>9.3 bpm>180` \
+    where map attributes are adjusted to the mods of each score."
+)]
+#[usage("[map url / map id] [mods] [query]")]
+#[example(
+    "2240404",
+    "https://osu.ppy.sh/beatmapsets/902425#osu/2240404",
+    "2240404 +hd od>=9 cs<4"
 )]
-#[usage("[map url / map id] [mods]")]
-#[example("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
 #[alias("lb")]
 #[group(AllModes)]
 async fn prefix_leaderboard(
@@ -255,10 +305,24 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
     let map_id_fut = get_map_id(&orig, args.map);
     let config_fut = Context::user_config().with_osu_id(owner);
 
-    let (map_id_res, config_res) = tokio::join!(map_id_fut, config_fut);
+    let (map_res, config_res) = tokio::join!(map_id_fut, config_fut);
+    let config = config_res?;
+
+    let map_id_res = match map_res {
+        Ok(MapIdType::Map(id)) => Ok(id.into()),
+        Ok(MapIdType::Set(mapset_id)) => {
+            let mode = default_mode(&orig, config.mode).await;
+
+            resolve_mapset(mapset_id, mode).await
+        }
+        Err(err) => Err(err),
+    };
 
-    let map_id = match map_id_res {
-        Ok(map_id) => map_id,
+    let ResolvedMapId {
+        map_id,
+        from_mapset,
+    } = match map_id_res {
+        Ok(resolved) => resolved,
         Err(GetMapError::Content(content)) => return orig.error(content).await,
         Err(GetMapError::Err { err, content }) => {
             let _ = orig.error(content).await;
@@ -267,7 +331,11 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         }
     };
 
-    let config = config_res?;
+    let mods = if args.any_mods {
+        None
+    } else {
+        mods_or_default(mods, config.mods.clone())
+    };
 
     // Retrieving the beatmap
     let map = match Context::osu_map().map(map_id, None).await {
@@ -368,28 +436,29 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             ),
         });
 
-    let amount = scores.len();
+    // Included mods are already filtered by the API
+    if let Some(ref selection @ (ModSelection::Exclude(_) | ModSelection::Exact(_))) = mods {
+        scores.retain(|score| selection.filter_mods(&score.mods));
 
-    if let Some(ModSelection::Exclude(ref mods)) = mods {
-        if mods.is_empty() {
-            scores.retain(|score| !score.mods.is_empty());
-
-            if let Some(ref score) = user_score {
-                if score.score.mods.is_empty() {
-                    user_score.take();
-                }
-            }
-        } else {
-            scores.retain(|score| !score.mods.contains_any(mods.iter()));
-
-            if let Some(ref score) = user_score {
-                if score.score.mods.contains_any(mods.iter()) {
-                    user_score.take();
-                }
+        if let Some(ref score) = user_score {
+            if !selection.filter_mods(&score.score.mods) {
+                user_score.take();
             }
         }
     }
 
+    // Not storing `attrs` here in case mods (potentially with clock rate) were
+    // specified
+    let mut attr_map = HashMap::default();
+
+    let criteria = args.query.as_deref().map(LeaderboardCriteria::create);
+
+    if let Some(ref criteria) = criteria {
+        filter_leaderboard_scores(&mut scores, &map, &mut attr_map, criteria).await;
+    }
+
+    let amount = scores.len();
+
     let mut content = if mods.is_some() {
         format!("I found {amount} scores with the specified mods on the map's leaderboard")
     } else {
@@ -399,13 +468,21 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
     let stars = attrs.stars() as f32;
     let max_combo = attrs.max_combo();
 
-    // Not storing `attrs` here in case mods (potentially with clock rate) were
-    // specified
-    let mut attr_map = HashMap::default();
-
     args.sort.sort(&mut scores, &map, &mut attr_map).await;
     args.sort.push_content(&mut content);
 
+    if let Some(ref criteria) = criteria {
+        criteria.display(&mut content);
+    }
+
+    if from_mapset {
+        let _ = write!(
+            content,
+            "\nOnly a mapset was given so I picked its hardest difficulty `[{}]`",
+            map.version()
+        );
+    }
+
     let first_place_icon = scores.first().map(|s| format!("{AVATAR_URL}{}", s.user_id));
 
     let pagination = LeaderboardPagination::builder()
@@ -431,14 +508,29 @@ enum GetMapError {
     Err { err: Report, content: &'static str },
 }
 
-async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<u32, GetMapError> {
-    match map {
-        Some(MapIdType::Map(id)) => Ok(id),
-        Some(MapIdType::Set(_)) => {
-            let content = "Looks like you gave me a mapset id, I need a map id though";
+/// Map id to use for the leaderboard.
+struct ResolvedMapId {
+    map_id: u32,
+    /// Whether only a mapset was available so the hardest difficulty was
+    /// picked
+    from_mapset: bool,
+}
 
-            Err(GetMapError::Content(content))
+impl From<u32> for ResolvedMapId {
+    fn from(map_id: u32) -> Self {
+        Self {
+            map_id,
+            from_mapset: false,
         }
+    }
+}
+
+async fn get_map_id(
+    orig: &CommandOrigin<'_>,
+    map: Option<MapIdType>,
+) -> Result<MapIdType, GetMapError> {
+    match map {
+        Some(map) => Ok(map),
         None if orig.can_read_history() => {
             let msgs = Context::retrieve_channel_history(orig.channel_id())
                 .await
@@ -448,8 +540,8 @@ async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<
                 })?;
 
             match Context::find_map_id_in_msgs(&msgs, 0).await {
-                Some(MapIdType::Map(id)) => Ok(id),
-                None | Some(MapIdType::Set(_)) => {
+                Some(map) => Ok(map),
+                None => {
                     let content = "No beatmap specified and none found in recent channel history. \
                         Try specifying a map either by url to the map, or just by map id.";
 
@@ -468,6 +560,52 @@ async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<
     }
 }
 
+/// Picks the hardest difficulty of the mapset for the given mode.
+async fn resolve_mapset(mapset_id: u32, mode: GameMode) -> Result<ResolvedMapId, GetMapError> {
+    let mapset = match Context::osu().beatmapset(mapset_id).await {
+        Ok(mapset) => mapset,
+        Err(OsuError::NotFound) => {
+            let content = "Could not find the mapset, be sure to specify a valid map id or url";
+
+            return Err(GetMapError::Content(content));
+        }
+        Err(err) => {
+            return Err(GetMapError::Err {
+                err: Report::new(err).wrap_err("Failed to get mapset"),
+                content: GENERAL_ISSUE,
+            })
+        }
+    };
+
+    let maps = mapset
+        .maps
+        .iter()
+        .flatten()
+        .map(|map| (map.map_id, map.mode, map.stars))
+        .collect::<Vec<_>>();
+
+    match hardest_map_id(&maps, mode) {
+        Some(map_id) => Ok(ResolvedMapId {
+            map_id,
+            from_mapset: true,
+        }),
+        None => Err(GetMapError::Content("The mapset does not contain any maps")),
+    }
+}
+
+/// The hardest map of the given mode, or of any mode if the mapset has no
+/// map of that mode.
+fn hardest_map_id(maps: &[(u32, GameMode, f32)], mode: GameMode) -> Option<u32> {
+    let hardest = |mode: Option<GameMode>| {
+        maps.iter()
+            .filter(|(_, map_mode, _)| mode.map_or(true, |mode| *map_mode == mode))
+            .max_by(|(.., a), (.., b)| a.total_cmp(b))
+            .map(|(map_id, ..)| *map_id)
+    };
+
+    hardest(Some(mode)).or_else(|| hardest(None))
+}
+
 async fn get_user_score(
     osu_id: Option<u32>,
     map_id: u32,
@@ -525,6 +663,55 @@ impl LeaderboardScore {
 }
 
 impl LeaderboardScore {
+    /// Whether the score matches the criteria.
+    ///
+    /// Map attributes are adjusted to the score's mods.
+    pub async fn matches(
+        &self,
+        map: &OsuMap,
+        attr_map: &mut AttrMap,
+        criteria: &FilterCriteria<LeaderboardCriteria>,
+    ) -> bool {
+        let mut matches = true;
+
+        matches &= criteria.acc.contains(self.accuracy);
+        matches &= criteria.combo.contains(self.combo);
+        matches &= criteria.miss.contains(self.statistics.count_miss);
+        matches &= criteria.score.contains(self.score);
+
+        let mods = Mods::from(&self.mods);
+        let mut builder = map.attributes().mods(mods.bits);
+
+        if let Some(clock_rate) = mods.clock_rate {
+            builder = builder.clock_rate(f64::from(clock_rate));
+        }
+
+        let attrs = builder.build();
+
+        matches &= criteria.ar.contains(attrs.ar as f32);
+        matches &= criteria.cs.contains(attrs.cs as f32);
+        matches &= criteria.hp.contains(attrs.hp as f32);
+        matches &= criteria.od.contains(attrs.od as f32);
+
+        let clock_rate = attrs.clock_rate as f32;
+        matches &= criteria
+            .length
+            .contains(map.seconds_drain() as f32 / clock_rate);
+        matches &= criteria.bpm.contains(map.bpm() * clock_rate);
+
+        if !matches || (criteria.pp.is_empty() && criteria.stars.is_empty()) {
+            return matches;
+        }
+
+        let (pp, _) = self.pp(map, attr_map).await;
+        matches &= criteria.pp.contains(pp);
+
+        let stars = attr_map.get(&mods).map(|(attrs, _)| attrs.stars() as f32);
+        matches &= stars_match(criteria, stars);
+
+        matches
+    }
+
     pub async fn pp(&self, map: &OsuMap, attr_map: &mut AttrMap) -> (f32, f32) {
         let mods = Mods::from(&self.mods);
 
@@ -565,7 +752,80 @@ impl LeaderboardScore {
     }
 }
 
+/// Without star bounds any score matches but if there are bounds, scores
+/// whose stars are unknown don't match.
+fn stars_match(criteria: &FilterCriteria<LeaderboardCriteria>, stars: Option<f32>) -> bool {
+    criteria.stars.is_empty() || stars.is_some_and(|stars| criteria.stars.contains(stars))
+}
+
+/// Retain only the scores that match the criteria.
+pub async fn filter_leaderboard_scores(
+    scores: &mut Vec<LeaderboardScore>,
+    map: &OsuMap,
+    attr_map: &mut AttrMap,
+    criteria: &FilterCriteria<LeaderboardCriteria>,
+) {
+    let mut filtered = Vec::with_capacity(scores.len());
+
+    for score in scores.drain(..) {
+        if score.matches(map, attr_map, criteria).await {
+            filtered.push(score);
+        }
+    }
+
+    *scores = filtered;
+}
+
 pub struct LeaderboardUserScore {
     pub discord_id: Id<UserMarker>,
     pub score: LeaderboardScore,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapset_resolves_to_hardest_map() {
+        let maps = [
+            (1, GameMode::Osu, 2.5),
+            (2, GameMode::Mania, 6.1),
+            (3, GameMode::Osu, 4.0),
+        ];
+
+        assert_eq!(hardest_map_id(&maps, GameMode::Osu), Some(3));
+        assert_eq!(hardest_map_id(&maps, GameMode::Mania), Some(2));
+
+        // Without a map of the mode, the hardest of any mode is picked
+        assert_eq!(hardest_map_id(&maps, GameMode::Taiko), Some(2));
+
+        assert_eq!(hardest_map_id(&[], GameMode::Osu), None);
+    }
+
+    #[test]
+    fn stars_min_bound() {
+        let criteria = LeaderboardCriteria::create("stars>=5");
+
+        assert!(stars_match(&criteria, Some(5.0)));
+        assert!(stars_match(&criteria, Some(7.2)));
+        assert!(!stars_match(&criteria, Some(4.9)));
+    }
+
+    #[test]
+    fn stars_max_bound() {
+        let criteria = LeaderboardCriteria::create("stars<6");
+
+        assert!(stars_match(&criteria, Some(2.0)));
+        assert!(!stars_match(&criteria, Some(6.0)));
+        assert!(!stars_match(&criteria, Some(8.5)));
+    }
+
+    #[test]
+    fn stars_missing_attributes() {
+        let criteria = LeaderboardCriteria::create("stars>5 stars<8");
+        assert!(!stars_match(&criteria, None));
+
+        let criteria = LeaderboardCriteria::create("pp>100");
+        assert!(stars_match(&criteria, None));
+    }
+}
@@ -63,6 +63,14 @@ pub struct Map<'a> {
     cs: Option<f64>,
     #[command(desc = "Specify an HP value to override the actual one")]
     hp: Option<f64>,
+    #[command(
+        min_value = 0.5,
+        max_value = 2.0,
+        desc = "Specify a custom clock rate that overwrites mods",
+        help = "Specify a custom clock rate that overwrites the clock rate of mods.\n\
+        Length, BPM, and pp values will be adjusted to it."
+    )]
+    clock_rate: Option<f64>,
 }
 
 #[derive(HasMods)]
@@ -78,11 +86,16 @@ pub struct CustomAttrs {
     pub cs: Option<f64>,
     pub hp: Option<f64>,
     pub od: Option<f64>,
+    pub clock_rate: Option<f64>,
 }
 
 impl CustomAttrs {
     fn content(&self) -> Option<String> {
-        self.ar.or(self.cs).or(self.hp).or(self.od)?;
+        self.ar
+            .or(self.cs)
+            .or(self.hp)
+            .or(self.od)
+            .or(self.clock_rate)?;
 
         let mut content = "Custom attributes: ".to_owned();
         let mut pushed = false;
@@ -116,6 +129,15 @@ impl CustomAttrs {
             }
 
             let _ = write!(content, "`OD: {od:.2}`");
+            pushed = true;
+        }
+
+        if let Some(clock_rate) = self.clock_rate {
+            if pushed {
+                content.push_str(" ~ ");
+            }
+
+            let _ = write!(content, "`Clock rate: {clock_rate:.2}`");
         }
 
         Some(content)
@@ -126,9 +148,24 @@ impl<'m> MapArgs<'m> {
     async fn args(msg: &Message, args: Args<'m>) -> Result<MapArgs<'m>, String> {
         let mut map = None;
         let mut mods = None;
+        let mut clock_rate = None;
 
-        for arg in args.take(2) {
-            if let Some(id) = matcher::get_osu_map_id(arg)
+        for arg in args.take(3) {
+            if let Some(rate) = arg
+                .split_once('=')
+                .filter(|(key, _)| matches!(*key, "clockrate" | "cr" | "rate"))
+                .map(|(_, value)| value)
+            {
+                match rate.parse::<f64>() {
+                    Ok(rate) if (0.5..=2.0).contains(&rate) => clock_rate = Some(rate),
+                    Ok(_) => return Err("Clock rate must be between 0.5 and 2.0".to_owned()),
+                    Err(_) => {
+                        let content = format!("Failed to parse `{rate}` as clock rate");
+
+                        return Err(content);
+                    }
+                }
+            } else if let Some(id) = matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
             {
@@ -138,7 +175,8 @@ impl<'m> MapArgs<'m> {
             } else {
                 let content = format!(
                     "Failed to parse `{arg}`.\n\
-                    Be sure you specify either a valid map id, map url, or mod combination."
+                    Be sure you specify either a valid map id, map url, mod combination, \
+                    or clock rate e.g. `rate=1.3`."
                 );
 
                 return Err(content);
@@ -159,7 +197,10 @@ impl<'m> MapArgs<'m> {
         Ok(Self {
             map,
             mods,
-            attrs: CustomAttrs::default(),
+            attrs: CustomAttrs {
+                clock_rate,
+                ..Default::default()
+            },
         })
     }
 }
@@ -175,6 +216,7 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             od,
             cs,
             hp,
+            clock_rate,
         } = args;
 
         let map = match map.map(|arg| {
@@ -192,7 +234,13 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             None => None,
         };
 
-        let attrs = CustomAttrs { ar, cs, hp, od };
+        let attrs = CustomAttrs {
+            ar,
+            cs,
+            hp,
+            od,
+            clock_rate,
+        };
 
         Ok(Self { map, mods, attrs })
     }
@@ -202,13 +250,18 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
 #[desc("Display a bunch of stats about a map(set)")]
 #[help(
     "Display stats about a beatmap. Mods can be specified.\n\
+    A custom clock rate between 0.5 and 2.0 can be specified through `rate=[number]`.\n\
     If no map(set) is specified by either url or id, I will choose the last map \
     I can find in the embeds of this channel.\n\
     If the mapset is specified by id but there is some map with the same id, \
     I will choose the latter."
 )]
-#[usage("[map(set) url / map(set) id] [+mods]")]
-#[examples("2240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
+#[usage("[map(set) url / map(set) id] [+mods] [rate=number]")]
+#[examples(
+    "2240404 +hddt",
+    "https://osu.ppy.sh/beatmapsets/902425 +hr",
+    "2240404 rate=1.3"
+)]
 #[aliases("m", "beatmap", "maps", "beatmaps", "mapinfo")]
 #[group(AllModes)]
 async fn prefix_map(msg: &Message, args: Args<'_>, permissions: Option<Permissions>) -> Result<()> {
@@ -18,7 +18,7 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found, ScoreOrder, TopEntry};
+use super::{default_mode, require_link, user_not_found, ScoreOrder, TopEntry};
 use crate::{
     active::{impls::TopPagination, ActiveMessages},
     commands::GameModeOption,
@@ -218,11 +218,10 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -26,7 +26,7 @@ use crate::{
     commands::osu::UserExtraction,
     core::commands::CommandOrigin,
     manager::redis::{osu::UserArgs, RedisData},
-    util::osu::get_combined_thumbnail,
+    util::osu::{get_combined_thumbnail, ThumbnailLayout},
     Context,
 };
 
@@ -294,7 +294,7 @@ pub(super) async fn common(orig: CommandOrigin<'_>, mut args: MedalCommon<'_>) -
 
     let urls = [user1.avatar_url(), user2.avatar_url()];
 
-    let thumbnail = match get_combined_thumbnail(urls, 2, None).await {
+    let thumbnail = match get_combined_thumbnail(urls, 2, None, ThumbnailLayout::Strip).await {
         Ok(thumbnail) => Some(thumbnail),
         Err(err) => {
             warn!(?err, "Failed to combine avatars");
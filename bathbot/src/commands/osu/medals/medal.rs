@@ -35,7 +35,7 @@ use crate::{
 #[desc("Display info about an osu! medal")]
 #[help(
     "Display info about an osu! medal.\n\
-    The given name must be exact (but case-insensitive).\n\
+    The given name is case-insensitive and may be partial as long as it only matches one medal.\n\
     All data originates from [osekai](https://osekai.net/medals/), \
     check it out for more info."
 )]
@@ -84,20 +84,16 @@ pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Resul
     let name = name.cow_to_ascii_lowercase();
 
     let medal = match medals {
-        RedisData::Original(ref mut original) => match original
-            .iter()
-            .position(|m| m.name.to_ascii_lowercase() == name)
-        {
-            Some(idx) => original.swap_remove(idx),
-            None => return no_medal(&orig, name.as_ref(), medals).await,
-        },
+        RedisData::Original(ref mut original) => {
+            match find_medal(&name, original.iter().map(|m| m.name.as_ref())) {
+                Ok(idx) => original.swap_remove(idx),
+                Err(suggestions) => return no_medal(&orig, name.as_ref(), suggestions).await,
+            }
+        }
         RedisData::Archive(ref archived) => {
-            match archived
-                .iter()
-                .position(|m| m.name.to_ascii_lowercase() == name)
-            {
-                Some(idx) => archived[idx].deserialize(&mut Infallible).unwrap(),
-                None => return no_medal(&orig, name.as_ref(), medals).await,
+            match find_medal(&name, archived.iter().map(|m| m.name.as_ref())) {
+                Ok(idx) => archived[idx].deserialize(&mut Infallible).unwrap(),
+                Err(suggestions) => return no_medal(&orig, name.as_ref(), suggestions).await,
             }
         }
     };
@@ -148,43 +144,55 @@ pub(super) async fn info(orig: CommandOrigin<'_>, args: MedalInfo_<'_>) -> Resul
 
 const SIMILARITY_THRESHOLD: f32 = 0.6;
 
-async fn no_medal(
-    orig: &CommandOrigin<'_>,
+/// Returns the index of the medal whose name matches `name` exactly or, if
+/// there is none, the index of the only medal whose name contains `name`.
+///
+/// Otherwise returns the names of the most similar medals.
+fn find_medal<'n>(
     name: &str,
-    medals: RedisData<Vec<OsekaiMedal>>,
-) -> Result<()> {
-    let mut medals: Vec<_> = match medals {
-        RedisData::Original(original) => original
-            .iter()
-            .map(|medal| {
-                let medal = medal.name.to_ascii_lowercase();
-
-                (levenshtein_similarity(name, &medal), medal)
-            })
-            .collect(),
-        RedisData::Archive(archived) => archived
-            .iter()
-            .map(|medal| {
-                let medal = medal.name.to_ascii_lowercase();
-
-                (levenshtein_similarity(name, &medal), medal)
-            })
-            .collect(),
-    };
+    medal_names: impl Iterator<Item = &'n str>,
+) -> Result<usize, Vec<String>> {
+    let medal_names: Vec<_> = medal_names.map(str::to_ascii_lowercase).collect();
 
-    medals.sort_unstable_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+    if let Some(idx) = medal_names.iter().position(|medal| medal == name) {
+        return Ok(idx);
+    }
 
-    let mut content = format!("No medal found with the name `{name}`.");
+    let mut partial = medal_names
+        .iter()
+        .enumerate()
+        .filter(|(_, medal)| medal.contains(name));
+
+    if let (Some((idx, _)), None) = (partial.next(), partial.next()) {
+        return Ok(idx);
+    }
 
-    let mut iter = medals
+    let mut medals: Vec<_> = medal_names
+        .into_iter()
+        .map(|medal| (levenshtein_similarity(name, &medal), medal))
+        .collect();
+
+    medals.sort_unstable_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    let suggestions = medals
         .into_iter()
         .take(5)
-        .take_while(|(similarity, _)| *similarity >= SIMILARITY_THRESHOLD);
+        .take_while(|(similarity, _)| *similarity >= SIMILARITY_THRESHOLD)
+        .map(|(_, medal)| medal)
+        .collect();
 
-    if let Some((_, first)) = iter.next() {
+    Err(suggestions)
+}
+
+async fn no_medal(orig: &CommandOrigin<'_>, name: &str, suggestions: Vec<String>) -> Result<()> {
+    let mut content = format!("No medal found with the name `{name}`.");
+
+    let mut iter = suggestions.into_iter();
+
+    if let Some(first) = iter.next() {
         let _ = write!(content, "\nDid you mean `{first}`");
 
-        for (_, medal) in iter {
+        for medal in iter {
             let _ = write!(content, ", `{medal}`");
         }
 
@@ -438,3 +446,32 @@ impl MedalEmbed {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: [&str; 4] = ["50,000 Plays", "500,000 Plays", "Any%", "Jackpot"];
+
+    #[test]
+    fn exact_name_is_preferred() {
+        assert_eq!(find_medal("50,000 plays", NAMES.into_iter()), Ok(0));
+    }
+
+    #[test]
+    fn ambiguous_partial_name() {
+        assert!(find_medal("000 plays", NAMES.into_iter()).is_err());
+    }
+
+    #[test]
+    fn unique_partial_name() {
+        assert_eq!(find_medal("jack", NAMES.into_iter()), Ok(3));
+    }
+
+    #[test]
+    fn typo_suggests_closest() {
+        let suggestions = find_medal("50,000 plys", NAMES.into_iter()).unwrap_err();
+
+        assert_eq!(suggestions, ["50,000 plays", "500,000 plays"]);
+    }
+}
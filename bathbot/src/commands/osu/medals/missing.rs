@@ -4,7 +4,7 @@ use bathbot_macros::command;
 use bathbot_model::{MedalGroup, OsekaiMedal, MEDAL_GROUPS};
 use bathbot_util::{
     constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
-    matcher, IntHasher,
+    matcher, IntHasher, MessageBuilder,
 };
 use eyre::{Report, Result};
 use hashbrown::HashSet;
@@ -101,7 +101,10 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
 
     let (medal_count, mut medals): (_, Vec<_>) = match all_medals {
         RedisData::Original(all_medals) => {
-            let medal_count = (all_medals.len() - user_medals_count, all_medals.len());
+            let medal_count = (
+                all_medals.len().saturating_sub(user_medals_count),
+                all_medals.len(),
+            );
 
             let medals = all_medals
                 .into_iter()
@@ -112,7 +115,10 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
             (medal_count, medals)
         }
         RedisData::Archive(all_medals) => {
-            let medal_count = (all_medals.len() - user_medals_count, all_medals.len());
+            let medal_count = (
+                all_medals.len().saturating_sub(user_medals_count),
+                all_medals.len(),
+            );
 
             let medals = all_medals
                 .iter()
@@ -125,6 +131,18 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
         }
     };
 
+    if medals.is_empty() {
+        let content = format!(
+            "`{}` is not missing any medals, they have collected them all!",
+            user.username()
+        );
+
+        let builder = MessageBuilder::new().embed(content);
+        orig.create_message(builder).await?;
+
+        return Ok(());
+    }
+
     medals.extend(MEDAL_GROUPS.iter().copied().map(MedalType::Group));
 
     let sort = args.sort.unwrap_or_default();
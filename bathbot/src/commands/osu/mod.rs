@@ -25,7 +25,8 @@ macro_rules! user_id {
 /// Tries to extract the username and mode from args.
 /// If either fails, it checks the user config.
 /// If the osu user is still not found, return the linking error.
-/// If the mode is still not found, pick GameMode::Osu.
+/// If the mode is still not found, pick the guild's default mode or
+/// GameMode::Osu.
 ///
 /// Only use this when the user config is not needed otherwise,
 /// else you'll have to query multiple times from the DB.
@@ -37,10 +38,11 @@ macro_rules! user_id_mode {
             if let Some(mode) = mode {
                 (user_id, mode)
             } else {
-                let mode = crate::core::Context::user_config()
+                let user_mode = crate::core::Context::user_config()
                     .mode($orig.user_id()?)
-                    .await?
-                    .unwrap_or(rosu_v2::prelude::GameMode::Osu);
+                    .await?;
+
+                let mode = crate::commands::osu::default_mode(&$orig, user_mode).await;
 
                 (user_id, mode)
             }
@@ -49,9 +51,10 @@ macro_rules! user_id_mode {
                 .with_osu_id($orig.user_id()?)
                 .await?;
 
-            let mode = mode
-                .or(config.mode)
-                .unwrap_or(rosu_v2::prelude::GameMode::Osu);
+            let mode = match mode {
+                Some(mode) => mode,
+                None => crate::commands::osu::default_mode(&$orig, config.mode).await,
+            };
 
             match config.osu {
                 Some(user_id) => (rosu_v2::request::UserId::Id(user_id), mode),
@@ -65,7 +68,10 @@ use std::{future::Future, pin::Pin};
 
 use bathbot_util::osu::ModSelection;
 use eyre::{Report, Result, WrapErr};
-use rosu_v2::request::UserId;
+use rosu_v2::{
+    prelude::{GameMode, GameModsIntermode},
+    request::UserId,
+};
 use twilight_interactions::command::{CommandOption, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
@@ -82,6 +88,7 @@ use crate::{
 mod attributes;
 mod avatar;
 mod badges;
+mod bonus_pp;
 mod bookmarks;
 mod bws;
 mod cards;
@@ -103,12 +110,16 @@ mod osustats;
 mod pinned;
 mod pp;
 mod profile;
+mod profile_card;
 mod rank;
+mod rank_milestones;
 mod ranking;
 mod ratios;
 mod recent;
+mod refresh;
 mod region_top;
 mod render;
+mod score_rank;
 mod scores;
 mod serverleaderboard;
 mod simulate;
@@ -122,6 +133,9 @@ mod link;
 #[cfg(feature = "matchlive")]
 mod match_live;
 
+#[cfg(feature = "osutracking")]
+mod top_diff;
+
 pub trait HasMods {
     fn mods(&self) -> ModsResult;
 }
@@ -162,6 +176,34 @@ pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
         .wrap_err("Failed to send require-link message")
 }
 
+/// Resolves the mode for commands in which no mode was specified.
+///
+/// The member's configured mode takes precedence over the guild's default
+/// mode which in turn takes precedence over osu!standard.
+pub async fn default_mode(orig: &CommandOrigin<'_>, user_mode: Option<GameMode>) -> GameMode {
+    let guild_mode = match user_mode {
+        Some(_) => None,
+        None => Context::guild_config().default_mode(orig.guild_id()).await,
+    };
+
+    resolve_mode(user_mode, guild_mode)
+}
+
+fn resolve_mode(user_mode: Option<GameMode>, guild_mode: Option<GameMode>) -> GameMode {
+    user_mode.or(guild_mode).unwrap_or(GameMode::Osu)
+}
+
+/// Resolves the mods for commands that fall back to the user's default mods.
+///
+/// Explicitly specified mods take precedence over the user's configured
+/// default mods which in turn take precedence over no mods at all.
+pub fn mods_or_default(
+    mods: Option<ModSelection>,
+    user_mods: Option<GameModsIntermode>,
+) -> Option<ModSelection> {
+    mods.or_else(|| user_mods.map(ModSelection::Exact))
+}
+
 pub async fn user_not_found(user_id: UserId) -> String {
     let user_id = match user_id {
         user_id @ UserId::Name(_) => user_id,
@@ -219,3 +261,35 @@ enum UserExtraction {
     Content(String),
     None,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_precedence() {
+        assert_eq!(resolve_mode(None, None), GameMode::Osu);
+        assert_eq!(resolve_mode(None, Some(GameMode::Mania)), GameMode::Mania);
+        assert_eq!(
+            resolve_mode(Some(GameMode::Taiko), Some(GameMode::Mania)),
+            GameMode::Taiko
+        );
+        assert_eq!(resolve_mode(Some(GameMode::Osu), None), GameMode::Osu);
+    }
+
+    #[test]
+    fn mods_precedence() {
+        let hd = GameModsIntermode::try_from_acronyms("HD").unwrap();
+        let hrdt = GameModsIntermode::try_from_acronyms("HRDT").unwrap();
+
+        assert_eq!(mods_or_default(None, None), None);
+        assert_eq!(
+            mods_or_default(None, Some(hd.clone())),
+            Some(ModSelection::Exact(hd.clone()))
+        );
+        assert_eq!(
+            mods_or_default(Some(ModSelection::Include(hrdt.clone())), Some(hd)),
+            Some(ModSelection::Include(hrdt))
+        );
+    }
+}
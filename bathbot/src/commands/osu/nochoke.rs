@@ -17,7 +17,7 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{default_mode, require_link, user_not_found};
 use crate::{
     active::{impls::NoChokePagination, ActiveMessages},
     core::commands::{prefix::Args, CommandOrigin},
@@ -206,9 +206,13 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None | Some(GameMode::Mania) => GameMode::Osu,
-        Some(mode) => mode,
+    // Mania scores can't be unchoked so fall back to osu!standard instead
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => match default_mode(&orig, config.mode).await {
+            GameMode::Mania => GameMode::Osu,
+            mode => mode,
+        },
     };
 
     let legacy_scores = match config.legacy_scores {
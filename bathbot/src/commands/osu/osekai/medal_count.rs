@@ -7,14 +7,11 @@ use eyre::Result;
 use super::OsekaiMedalCount;
 use crate::{
     active::{impls::MedalCountPagination, ActiveMessages},
-    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    core::commands::CommandOrigin,
     Context,
 };
 
-pub(super) async fn medal_count(
-    mut command: InteractionCommand,
-    args: OsekaiMedalCount,
-) -> Result<()> {
+pub(super) async fn medal_count(orig: CommandOrigin<'_>, args: OsekaiMedalCount) -> Result<()> {
     let country_code = match args.country {
         Some(country) => {
             if country.len() == 2 {
@@ -25,15 +22,13 @@ pub(super) async fn medal_count(
                 let content =
                     format!("Looks like `{country}` is neither a country name nor a country code");
 
-                command.error(content).await?;
-
-                return Ok(());
+                return orig.error(content).await;
             }
         }
         None => None,
     };
 
-    let owner = command.user_id()?;
+    let owner = orig.user_id()?;
     let ranking_fut = Context::redis().osekai_ranking::<MedalCount>();
     let config_fut = Context::user_config().osu_name(owner);
 
@@ -42,7 +37,7 @@ pub(super) async fn medal_count(
     let mut ranking = match osekai_res {
         Ok(ranking) => ranking.into_original(),
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(OSEKAI_ISSUE).await;
 
             return Err(err.wrap_err("failed to get cached medal count ranking"));
         }
@@ -75,6 +70,6 @@ pub(super) async fn medal_count(
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
-        .begin(&mut command)
+        .begin(orig)
         .await
 }
@@ -1,16 +1,26 @@
-use bathbot_macros::SlashCommand;
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::{
     Badges, LovedMapsets, RankedMapsets, Replays, StandardDeviation, Subscribers, TotalPp,
 };
+use bathbot_util::matcher;
 use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
 
 use self::{
     medal_count::medal_count,
     rarity::rarity,
     user_value::{count, pp},
 };
-use crate::util::{interaction::InteractionCommand, InteractionCommandExt};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
 
 mod medal_count;
 mod rarity;
@@ -23,7 +33,7 @@ mod user_value;
     help = "Various leaderboard stats. \
     All data is provided by [osekai](https://osekai.net/)."
 )]
-pub enum Osekai {
+pub enum Osekai<'a> {
     #[command(name = "badges")]
     Badges(OsekaiBadges),
     #[command(name = "loved_mapsets")]
@@ -33,7 +43,7 @@ pub enum Osekai {
     #[command(name = "ranked_mapsets")]
     RankedMapsets(OsekaiRankedMapsets),
     #[command(name = "rarity")]
-    Rarity(OsekaiRarity),
+    Rarity(OsekaiRarity<'a>),
     #[command(name = "replays")]
     Replays(OsekaiReplays),
     #[command(name = "standard_deviation")]
@@ -72,9 +82,24 @@ pub struct OsekaiRankedMapsets {
     country: Option<String>,
 }
 
-#[derive(CommandModel, CreateCommand)]
-#[command(name = "rarity", desc = "What are the rarest medals?")]
-pub struct OsekaiRarity;
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "rarity",
+    desc = "What are the rarest medals?",
+    help = "What are the rarest medals?\n\
+    If a user is specified, only the medals that they're missing will be listed."
+)]
+pub struct OsekaiRarity<'a> {
+    #[command(desc = "Only show medals that this user is missing")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Only show medals that this linked discord user is missing",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
 
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "replays", desc = "Who has the most replays watched?")]
@@ -113,15 +138,181 @@ pub struct OsekaiTotalPp {
 }
 
 async fn slash_osekai(mut command: InteractionCommand) -> Result<()> {
-    match Osekai::from_interaction(command.input_data())? {
-        Osekai::Badges(args) => count::<Badges>(command, args.country).await,
-        Osekai::LovedMapsets(args) => count::<LovedMapsets>(command, args.country).await,
-        Osekai::MedalCount(args) => medal_count(command, args).await,
-        Osekai::RankedMapsets(args) => count::<RankedMapsets>(command, args.country).await,
-        Osekai::Rarity(_) => rarity(command).await,
-        Osekai::Replays(args) => count::<Replays>(command, args.country).await,
-        Osekai::StandardDeviation(args) => pp::<StandardDeviation>(command, args.country).await,
-        Osekai::Subscribers(args) => count::<Subscribers>(command, args.country).await,
-        Osekai::TotalPp(args) => pp::<TotalPp>(command, args.country).await,
+    let args = Osekai::from_interaction(command.input_data())?;
+
+    osekai((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Various leaderboards provided by osekai")]
+#[help(
+    "Various leaderboard stats. \
+    All data is provided by [osekai](https://osekai.net/).\n\
+    The first argument must be the ranking, one of `badges`, `loved_mapsets`, `medal_count`, \
+    `ranked_mapsets`, `rarity`, `replays`, `standard_deviation`, `subscribers`, or `total_pp`.\n\
+    For `rarity` you can specify a user to only show the medals they're missing, \
+    for all other rankings you can specify a country name or code."
+)]
+#[usage("[ranking] [country / username]")]
+#[examples(
+    "medal_count",
+    "badges be",
+    "total_pp united states",
+    "rarity badewanne3"
+)]
+#[alias("osekairanking")]
+#[group(AllModes)]
+async fn prefix_osekai(
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, permissions);
+    let ranking = args.next();
+
+    match Osekai::args(ranking, args.rest()) {
+        Ok(args) => osekai(orig, args).await,
+        Err(content) => orig.error(content).await,
+    }
+}
+
+impl<'a> Osekai<'a> {
+    /// Formats the names of all slash subcommands as "`a`, `b`, or `c`".
+    fn rankings() -> String {
+        let options = Self::create_command().options;
+        let mut rankings = String::new();
+
+        for (i, option) in options.iter().enumerate() {
+            if i > 0 {
+                rankings.push_str(", ");
+            }
+
+            if i > 0 && i == options.len() - 1 {
+                rankings.push_str("or ");
+            }
+
+            let _ = write!(rankings, "`{}`", option.name);
+        }
+
+        rankings
+    }
+
+    fn args(ranking: Option<&str>, rest: &'a str) -> Result<Self, String> {
+        let Some(ranking) = ranking else {
+            let rankings = Self::rankings();
+
+            return Err(format!("You must specify a ranking, one of {rankings}"));
+        };
+
+        let rest = rest.trim();
+        let country = (!rest.is_empty()).then(|| rest.to_owned());
+
+        let args = match ranking.to_ascii_lowercase().replace('-', "_").as_str() {
+            "badges" => Self::Badges(OsekaiBadges { country }),
+            "loved_mapsets" | "loved" => Self::LovedMapsets(OsekaiLovedMapsets { country }),
+            "medal_count" | "medals" => Self::MedalCount(OsekaiMedalCount { country }),
+            "ranked_mapsets" | "ranked" => Self::RankedMapsets(OsekaiRankedMapsets { country }),
+            "rarity" => {
+                let mut name = None;
+                let mut discord = None;
+
+                if !rest.is_empty() {
+                    match matcher::get_mention_user(rest) {
+                        Some(id) => discord = Some(id),
+                        None => name = Some(rest.into()),
+                    }
+                }
+
+                Self::Rarity(OsekaiRarity { name, discord })
+            }
+            "replays" => Self::Replays(OsekaiReplays { country }),
+            "standard_deviation" | "stdev" => {
+                Self::StandardDeviation(OsekaiStandardDeviation { country })
+            }
+            "subscribers" | "subs" => Self::Subscribers(OsekaiSubscribers { country }),
+            "total_pp" => Self::TotalPp(OsekaiTotalPp { country }),
+            _ => {
+                let rankings = Self::rankings();
+
+                return Err(format!(
+                    "Unknown ranking `{ranking}`, must be one of {rankings}"
+                ));
+            }
+        };
+
+        Ok(args)
+    }
+}
+
+async fn osekai(orig: CommandOrigin<'_>, args: Osekai<'_>) -> Result<()> {
+    match args {
+        Osekai::Badges(args) => count::<Badges>(orig, args.country).await,
+        Osekai::LovedMapsets(args) => count::<LovedMapsets>(orig, args.country).await,
+        Osekai::MedalCount(args) => medal_count(orig, args).await,
+        Osekai::RankedMapsets(args) => count::<RankedMapsets>(orig, args.country).await,
+        Osekai::Rarity(args) => rarity(orig, args).await,
+        Osekai::Replays(args) => count::<Replays>(orig, args.country).await,
+        Osekai::StandardDeviation(args) => pp::<StandardDeviation>(orig, args.country).await,
+        Osekai::Subscribers(args) => count::<Subscribers>(orig, args.country).await,
+        Osekai::TotalPp(args) => pp::<TotalPp>(orig, args.country).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ranking_args() {
+        let Ok(Osekai::TotalPp(args)) = Osekai::args(Some("Total-PP"), " united states ") else {
+            panic!("expected total_pp ranking");
+        };
+
+        assert_eq!(args.country.as_deref(), Some("united states"));
+
+        let Ok(Osekai::MedalCount(args)) = Osekai::args(Some("medals"), "") else {
+            panic!("expected medal_count ranking");
+        };
+
+        assert!(args.country.is_none());
+
+        let Ok(Osekai::Rarity(args)) = Osekai::args(Some("rarity"), "badewanne3") else {
+            panic!("expected rarity ranking");
+        };
+
+        assert_eq!(args.name.as_deref(), Some("badewanne3"));
+        assert!(args.discord.is_none());
+    }
+
+    #[test]
+    fn unknown_ranking_lists_options() {
+        let Err(content) = Osekai::args(Some("pp"), "") else {
+            panic!("expected error");
+        };
+
+        assert!(content.starts_with("Unknown ranking `pp`"));
+        assert!(content.contains("`medal_count`"));
+
+        let Err(content) = Osekai::args(None, "") else {
+            panic!("expected error");
+        };
+
+        assert!(content.contains("`total_pp`"));
+    }
+
+    #[test]
+    fn help_lists_all_rankings() {
+        let help = OSEKAI_PREFIX.help.unwrap();
+        let rankings = Osekai::rankings();
+
+        assert!(help.contains(&format!("one of {rankings}.")), "{rankings}");
+
+        for option in Osekai::create_command().options {
+            assert!(
+                Osekai::args(Some(&option.name), "").is_ok(),
+                "`{}` can't be parsed",
+                option.name
+            );
+        }
     }
 }
@@ -1,30 +1,114 @@
-use bathbot_model::Rarity;
-use bathbot_util::constants::OSEKAI_ISSUE;
-use eyre::Result;
+use bathbot_model::{OsekaiRarityEntry, Rarity};
+use bathbot_util::{
+    constants::{OSEKAI_ISSUE, OSU_API_ISSUE},
+    IntHasher,
+};
+use eyre::{Report, Result};
+use hashbrown::HashSet;
+use rosu_v2::prelude::{OsuError, Username};
 
+use super::OsekaiRarity;
 use crate::{
     active::{impls::MedalRarityPagination, ActiveMessages},
-    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    commands::osu::user_not_found,
+    core::commands::CommandOrigin,
+    manager::redis::{osu::UserArgs, RedisData},
     Context,
 };
 
-pub(super) async fn rarity(mut command: InteractionCommand) -> Result<()> {
-    let ranking = match Context::redis().osekai_ranking::<Rarity>().await {
+pub(super) async fn rarity(orig: CommandOrigin<'_>, args: OsekaiRarity<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let mut ranking = match Context::redis().osekai_ranking::<Rarity>().await {
         Ok(ranking) => ranking.into_original(),
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(OSEKAI_ISSUE).await;
 
             return Err(err.wrap_err("failed to get cached rarity ranking"));
         }
     };
 
+    let username = match user_id!(orig, args) {
+        Some(user_id) => {
+            let user_args = UserArgs::rosu_id(&user_id).await;
+
+            let user = match Context::redis().osu_user(user_args).await {
+                Ok(user) => user,
+                Err(OsuError::NotFound) => {
+                    let content = user_not_found(user_id).await;
+
+                    return orig.error(content).await;
+                }
+                Err(err) => {
+                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let err = Report::new(err).wrap_err("failed to get user");
+
+                    return Err(err);
+                }
+            };
+
+            let owned: HashSet<_, IntHasher> = match &user {
+                RedisData::Original(user) => user.medals.iter().map(|m| m.medal_id).collect(),
+                RedisData::Archive(user) => user.medals.iter().map(|m| m.medal_id).collect(),
+            };
+
+            ranking.retain(|entry| !owned.contains(&entry.medal_id));
+
+            Some(Username::from(user.username()))
+        }
+        None => None,
+    };
+
+    sort_by_rarity(&mut ranking);
+
+    if ranking.is_empty() {
+        let content = match username {
+            Some(name) => format!("`{name}` is not missing any medals"),
+            None => "No medal rarity data is available right now, try again later".to_owned(),
+        };
+
+        return orig.error(content).await;
+    }
+
     let pagination = MedalRarityPagination::builder()
         .ranking(ranking.into_boxed_slice())
-        .msg_owner(command.user_id()?)
+        .username(username)
+        .msg_owner(owner)
         .build();
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
-        .begin(&mut command)
+        .begin(orig)
         .await
 }
+
+/// Rarest medals first i.e. ascending possession rate.
+fn sort_by_rarity(ranking: &mut [OsekaiRarityEntry]) {
+    ranking.sort_by(|a, b| a.possession_percent.total_cmp(&b.possession_percent));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(medal_id: u32, possession_percent: f32) -> OsekaiRarityEntry {
+        OsekaiRarityEntry {
+            rank: 0,
+            icon_url: Box::from(""),
+            medal_name: Box::from("medal"),
+            medal_id,
+            description: Box::from(""),
+            possession_percent,
+            mode: None,
+        }
+    }
+
+    #[test]
+    fn rarest_first() {
+        let mut ranking = vec![entry(1, 12.5), entry(2, 0.01), entry(3, 3.0)];
+        sort_by_rarity(&mut ranking);
+
+        let ids: Vec<_> = ranking.iter().map(|entry| entry.medal_id).collect();
+        assert_eq!(ids, [2, 3, 1]);
+    }
+}
@@ -10,12 +10,12 @@ use rosu_v2::prelude::Username;
 
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
+    core::commands::CommandOrigin,
     manager::redis::RedisData,
-    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
 
-pub(super) async fn count<R>(command: InteractionCommand, country: Option<String>) -> Result<()>
+pub(super) async fn count<R>(orig: CommandOrigin<'_>, country: Option<String>) -> Result<()>
 where
     R: OsekaiRanking<Entry = OsekaiRankingEntry<usize>>,
 {
@@ -29,15 +29,13 @@ where
                 let content =
                     format!("Looks like `{country}` is neither a country name nor a country code");
 
-                command.error(content).await?;
-
-                return Ok(());
+                return orig.error(content).await;
             }
         }
         None => None,
     };
 
-    let owner = command.user_id()?;
+    let owner = orig.user_id()?;
     let ranking_fut = Context::redis().osekai_ranking::<R>();
     let name_fut = Context::user_config().osu_name(owner);
 
@@ -46,7 +44,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(OSEKAI_ISSUE).await;
 
             return Err(err.wrap_err("failed to get cached osekai ranking"));
         }
@@ -66,10 +64,10 @@ where
     let entries = RankingEntries::Amount(entries);
     let data = <R as OsekaiRanking>::RANKING;
 
-    send_response(command, entries, data, name_res).await
+    send_response(orig, entries, data, name_res).await
 }
 
-pub(super) async fn pp<R>(command: InteractionCommand, country: Option<String>) -> Result<()>
+pub(super) async fn pp<R>(orig: CommandOrigin<'_>, country: Option<String>) -> Result<()>
 where
     R: OsekaiRanking<Entry = OsekaiRankingEntry<u32>>,
 {
@@ -83,15 +81,13 @@ where
                 let content =
                     format!("Looks like `{country}` is neither a country name nor a country code");
 
-                command.error(content).await?;
-
-                return Ok(());
+                return orig.error(content).await;
             }
         }
         None => None,
     };
 
-    let owner = command.user_id()?;
+    let owner = orig.user_id()?;
     let ranking_fut = Context::redis().osekai_ranking::<R>();
     let name_fut = Context::user_config().osu_name(owner);
 
@@ -100,7 +96,7 @@ where
     let ranking = match osekai_res {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = command.error(OSEKAI_ISSUE).await;
+            let _ = orig.error(OSEKAI_ISSUE).await;
 
             return Err(err.wrap_err("failed to get cached osekai ranking"));
         }
@@ -119,7 +115,7 @@ where
     let entries = RankingEntries::PpU32(entries);
     let data = <R as OsekaiRanking>::RANKING;
 
-    send_response(command, entries, data, name_res).await
+    send_response(orig, entries, data, name_res).await
 }
 
 fn prepare_amount_users(
@@ -181,7 +177,7 @@ fn prepare_pp_users(
 }
 
 async fn send_response(
-    mut command: InteractionCommand,
+    orig: CommandOrigin<'_>,
     entries: RankingEntries,
     data: RankingKind,
     name_res: Result<Option<Username>>,
@@ -202,11 +198,61 @@ async fn send_response(
         .author_idx(author_idx)
         .kind(data)
         .defer(false)
-        .msg_owner(command.user_id()?)
+        .msg_owner(orig.user_id()?)
         .build();
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
-        .begin(&mut command)
+        .begin(orig)
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use bathbot_model::{Badges, OsekaiRankingEntries};
+
+    use super::*;
+
+    const BADGES: &str = r#"[
+        {"rank":"1","countrycode":"US","country":"United States","username":"mrekk","userid":"7562902","badges":"12"},
+        {"rank":"2","countrycode":"BE","country":"Belgium","username":"Badewanne3","userid":"2211396","badges":"7"},
+        {"rank":"3","countrycode":"BE","country":"Belgium","username":"peppy","userid":"2","badges":"3"}
+    ]"#;
+
+    fn badges() -> RedisData<Vec<OsekaiRankingEntry<usize>>> {
+        let entries: OsekaiRankingEntries<Badges> = serde_json::from_str(BADGES).unwrap();
+
+        RedisData::Original(entries.into())
+    }
+
+    #[test]
+    fn country_page_is_reindexed() {
+        let ranking = badges();
+        let code = String::from("BE");
+        let entries = prepare_amount_users(
+            &ranking,
+            |entry| entry.country_code == code,
+            |entry| entry.country_code == code,
+        );
+
+        let page: Vec<_> = entries
+            .iter()
+            .map(|(idx, entry)| (*idx, entry.name.as_str(), entry.value))
+            .collect();
+
+        assert_eq!(page, [(0, "Badewanne3", 7), (1, "peppy", 3)]);
+        assert!(entries
+            .values()
+            .all(|entry| entry.country.as_deref() == Some("BE")));
+    }
+
+    #[test]
+    fn global_page_keeps_order() {
+        let ranking = badges();
+        let entries = prepare_amount_users(&ranking, |_| true, |_| true);
+
+        let names: Vec<_> = entries.values().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["mrekk", "Badewanne3", "peppy"]);
+        assert_eq!(entries[&0].value, 12);
+    }
+}
@@ -1,12 +1,9 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
-use bathbot_util::{
-    constants::{OSUSTATS_API_ISSUE, OSU_API_ISSUE},
-    matcher, MessageBuilder,
-};
+use bathbot_util::{constants::OSU_API_ISSUE, matcher, MessageBuilder};
 use eyre::{Report, Result};
-use rosu_v2::prelude::OsuError;
+use rosu_v2::prelude::{GameMode, OsuError};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
@@ -169,14 +166,25 @@ pub(super) async fn count(orig: CommandOrigin<'_>, args: OsuStatsCount<'_>) -> R
         }
     };
 
-    let counts = match TopCounts::request(&user, mode).await {
-        Ok(counts) => counts,
-        Err(err) => {
-            let _ = orig.error(OSUSTATS_API_ISSUE).await;
+    let counts = TopCounts::request(&user, mode).await;
+
+    if counts.is_empty() {
+        let content = format!(
+            "`{}` does not have any scores in the top100 of a {mode}map leaderboard yet",
+            user.username(),
+            mode = match mode {
+                GameMode::Osu => "",
+                GameMode::Taiko => "taiko ",
+                GameMode::Catch => "ctb ",
+                GameMode::Mania => "mania ",
+            },
+        );
 
-            return Err(err.wrap_err("failed to get top counts"));
-        }
-    };
+        let builder = MessageBuilder::new().embed(content);
+        orig.create_message(builder).await?;
+
+        return Ok(());
+    }
 
     let embed_data = OsuStatsCountsEmbed::new(&user, mode, counts);
     let embed = embed_data.build();
@@ -499,6 +499,7 @@ async fn process_scores(
             score: score.score,
             score_id: 0,
             legacy_id: None,
+            slider_hits: None,
             statistics: LegacyScoreStatistics {
                 count_geki: score.count_geki,
                 count_300: score.count300,
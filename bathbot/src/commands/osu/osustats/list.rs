@@ -232,14 +232,16 @@ fn insert(
     sorted by their amounts of scores on a map's global leaderboard.\n\
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
+    A plain number like `50` or `top50` is the same as `rank=50`.\n\
     The rank range default to 1..100.\n\
+    Optionally specify a mode, otherwise your linked mode will be used.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
-#[examples("rankr=42 be", "rank=1..5", "fr")]
-#[aliases("osl")]
+#[usage("[mode] [rank=[num..]num / top N] [country acronym]")]
+#[examples("rankr=42 be", "rank=1..5", "fr", "mania 8", "taiko top25 be")]
+#[aliases("osl", "osustatsrankings", "osustatsranking", "osr")]
 #[group(Osu)]
 async fn prefix_osustatslist(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsPlayers::args(None, args) {
@@ -259,12 +261,13 @@ async fn prefix_osustatslist(msg: &Message, args: Args<'_>) -> Result<()> {
     sorted by their amounts of scores on a map's global leaderboard.\n\
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
+    A plain number like `50` or `top50` is the same as `rank=50`.\n\
     The rank range default to 1..100.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num / top N] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslm")]
 #[group(Mania)]
@@ -286,12 +289,13 @@ async fn prefix_osustatslistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     sorted by their amounts of scores on a map's global leaderboard.\n\
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
+    A plain number like `50` or `top50` is the same as `rank=50`.\n\
     The rank range default to 1..100.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num / top N] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslt")]
 #[group(Taiko)]
@@ -313,12 +317,13 @@ async fn prefix_osustatslisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     sorted by their amounts of scores on a map's global leaderboard.\n\
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
+    A plain number like `50` or `top50` is the same as `rank=50`.\n\
     The rank range default to 1..100.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num / top N] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslc", "osustatslistcatch")]
 #[group(Catch)]
@@ -340,12 +345,31 @@ impl<'m> OsuStatsPlayers<'m> {
     const MAX_RANK: u32 = 100;
     const MIN_RANK: u32 = 1;
 
-    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
+    /// Parses `[mode] [rank=[num..]num / top N] [country]` in any order.
+    ///
+    /// A positional mode is only considered if no `mode` is given already.
+    fn args(mut mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
+        let fixed_mode = mode.is_some();
         let mut country = None;
         let mut min_rank = None;
         let mut max_rank = None;
 
-        for arg in args.take(2).map(|arg| arg.cow_to_ascii_lowercase()) {
+        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
+            let parsed_mode = match arg.as_ref() {
+                _ if fixed_mode => None,
+                "o" | "osu" | "std" | "standard" => Some(GameModeOption::Osu),
+                "t" | "tko" | "taiko" => Some(GameModeOption::Taiko),
+                "c" | "ctb" | "fruits" | "catch" => Some(GameModeOption::Catch),
+                "m" | "mna" | "mania" => Some(GameModeOption::Mania),
+                _ => None,
+            };
+
+            if parsed_mode.is_some() {
+                mode = parsed_mode;
+
+                continue;
+            }
+
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -375,7 +399,10 @@ impl<'m> OsuStatsPlayers<'m> {
                             min_rank = Some(min.min(max));
                             max_rank = Some(min.max(max));
                         }
-                        None => max_rank = Some(value.parse().map_err(|_| Self::ERR_PARSE_RANK)?),
+                        None => {
+                            let max = value.parse::<u32>().map_err(|_| Self::ERR_PARSE_RANK)?;
+                            max_rank = Some(max.clamp(Self::MIN_RANK, Self::MAX_RANK));
+                        }
                     },
                     _ => {
                         let content =
@@ -384,13 +411,15 @@ impl<'m> OsuStatsPlayers<'m> {
                         return Err(content.into());
                     }
                 }
+            } else if let Ok(top_n) = arg.trim_start_matches("top").parse::<u32>() {
+                max_rank = Some(top_n.clamp(Self::MIN_RANK, Self::MAX_RANK));
             } else if arg.len() == 2 && arg.is_ascii() {
                 country = Some(arg);
             } else if let Some(code) = Countries::name(arg.as_ref()).to_code() {
                 country = Some(code.into());
             } else {
                 let content = format!(
-                    "Failed to parse `{arg}` as either rank or country.\n\
+                    "Failed to parse `{arg}` as either mode, rank, or country.\n\
                     Be sure to specify valid country or two ASCII letter country code.\n\
                     A rank range can be specified like `rank=2..45`."
                 );
@@ -407,3 +436,51 @@ impl<'m> OsuStatsPlayers<'m> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::commands::prefix::ArgsNum;
+
+    fn parse(
+        mode: Option<GameModeOption>,
+        args: &str,
+    ) -> Result<OsuStatsPlayers<'_>, Cow<'static, str>> {
+        OsuStatsPlayers::args(mode, Args::new(args, ArgsNum::None))
+    }
+
+    #[test]
+    fn positional_mode_and_top_n() {
+        let args = parse(None, "mania top50 BE").unwrap();
+        assert!(matches!(args.mode, Some(GameModeOption::Mania)));
+        assert_eq!(args.max_rank, Some(50));
+        assert_eq!(args.country.as_deref(), Some("be"));
+
+        let args = parse(None, "8").unwrap();
+        assert!(args.mode.is_none());
+        assert_eq!(args.max_rank, Some(8));
+        assert!(args.country.is_none());
+
+        assert!(parse(None, "notacountry").is_err());
+    }
+
+    #[test]
+    fn top_n_is_clamped_like_rank_range() {
+        assert_eq!(parse(None, "0").unwrap().max_rank, Some(1));
+        assert_eq!(parse(None, "osu 150").unwrap().max_rank, Some(100));
+
+        let args = parse(None, "rank=5..150").unwrap();
+        assert_eq!((args.min_rank, args.max_rank), (Some(5), Some(100)));
+
+        assert_eq!(parse(None, "rank=150").unwrap().max_rank, Some(100));
+    }
+
+    #[test]
+    fn fixed_mode_ignores_positional_mode() {
+        let args = parse(Some(GameModeOption::Taiko), "rank=1..5 fr").unwrap();
+        assert!(matches!(args.mode, Some(GameModeOption::Taiko)));
+        assert_eq!(args.country.as_deref(), Some("fr"));
+
+        assert!(parse(Some(GameModeOption::Taiko), "mania").is_err());
+    }
+}
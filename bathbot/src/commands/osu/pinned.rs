@@ -26,7 +26,9 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use super::{require_link, user_not_found, HasMods, ModsResult, ScoreOrder, TopEntry};
+use super::{
+    default_mode, require_link, user_not_found, HasMods, ModsResult, ScoreOrder, TopEntry,
+};
 use crate::{
     active::{
         impls::{TopPagination, TopScoreEdit},
@@ -127,11 +129,10 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let GuildValues {
         minimized_pp: guild_minimized_pp,
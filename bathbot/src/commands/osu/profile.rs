@@ -13,7 +13,7 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{default_mode, require_link, user_not_found};
 use crate::{
     active::{impls::ProfileMenu, ActiveMessages},
     commands::GameModeOption,
@@ -181,11 +181,10 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let kind = args.embed.unwrap_or_default();
     let guild = orig.guild_id();
@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_model::rosu_v2::user::UserHighestRank;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+    osu::flag_url,
+    EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result, WrapErr};
+use rkyv::{
+    with::{DeserializeWith, Map},
+    Infallible,
+};
+use rosu_v2::prelude::OsuError;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
+
+use super::user_not_found;
+use crate::{
+    commands::GameModeOption,
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        BotConfig,
+    },
+    embeds::attachment,
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "profilecard",
+    desc = "Render a compact image of someone's osu! profile",
+    help = "Render a compact, shareable image of an osu! profile.\n\
+    The image contains the user's avatar, flag, rank, pp, accuracy, \
+    and how their top grades are distributed."
+)]
+pub struct ProfileCard<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+pub async fn slash_profilecard(mut command: InteractionCommand) -> Result<()> {
+    let args = ProfileCard::from_interaction(command.input_data())?;
+
+    profile_card((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Render a compact image of someone's osu! profile")]
+#[help(
+    "Render a compact, shareable image of an osu! profile.\n\
+    To specify a gamemode, use the `/profilecard` command."
+)]
+#[aliases("pcard")]
+#[usage("[username]")]
+#[example("Badewanne3")]
+#[group(AllModes)]
+async fn prefix_profilecard(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = ProfileCard::args(args);
+
+    profile_card(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+impl<'m> ProfileCard<'m> {
+    fn args(mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode: None,
+            name,
+            discord,
+        }
+    }
+}
+
+async fn profile_card(orig: CommandOrigin<'_>, args: ProfileCard<'_>) -> Result<()> {
+    let (user_id, mode) = user_id_mode!(orig, args);
+
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let client = Context::client();
+    let flag_url = flag_url(user.country_code());
+    let pfp_fut = client.get_avatar(user.avatar_url());
+    let flag_fut = client.get_flag(&flag_url);
+
+    let (pfp, flag) = match tokio::join!(pfp_fut, flag_fut) {
+        (Ok(pfp), flag_res) => {
+            // The card is still fine without a flag so don't bail out over it
+            let flag = match flag_res {
+                Ok(flag) => Some(flag),
+                Err(err) => {
+                    warn!(?err, "Failed to acquire profile card flag");
+
+                    None
+                }
+            };
+
+            (pfp, flag)
+        }
+        (Err(err), _) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to acquire profile card avatar"));
+        }
+    };
+
+    let stats = user.stats();
+    let counts = stats.grade_counts();
+
+    let grades =
+        [counts.ssh, counts.ss, counts.sh, counts.s, counts.a].map(|count| count.max(0) as u32);
+
+    let peak_rank = match user {
+        RedisData::Original(ref user) => user.highest_rank.as_ref().map(|peak| peak.rank),
+        RedisData::Archive(ref user) => {
+            Map::<UserHighestRank>::deserialize_with(&user.highest_rank, &mut Infallible)
+                .unwrap()
+                .map(|peak| peak.rank)
+        }
+    };
+
+    let username = Box::<str>::from(user.username());
+    let (global_rank, country_rank) = (stats.global_rank(), stats.country_rank());
+    let (pp, accuracy) = (stats.pp(), stats.accuracy());
+    let assets = BotConfig::get().paths.assets.clone();
+
+    let draw_fut = tokio::task::spawn_blocking(move || {
+        bathbot_cards::ProfileCard::new(&username, &pfp, assets)
+            .flag(flag.as_deref())
+            .ranks(global_rank, country_rank, peak_rank)
+            .performance(pp, accuracy)
+            .grades(grades)
+            .draw()
+            .map_err(Report::new)
+    });
+
+    let draw_res = draw_fut
+        .await
+        .wrap_err("Profile card task panicked")
+        .and_then(|res| res);
+
+    let bytes = match draw_res {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error("Failed to draw the profile card :(").await;
+
+            return Err(err.wrap_err("Failed to draw profile card"));
+        }
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .image(attachment("profile_card.png"));
+
+    let builder = MessageBuilder::new()
+        .attachment("profile_card.png", bytes)
+        .embed(embed);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
+use self::to::rank_to;
 pub use self::{pp::*, score::*};
 use crate::{
     commands::GameModeOption,
@@ -13,6 +14,16 @@ use crate::{
 
 mod pp;
 mod score;
+mod to;
+
+/// Highest rank for which the pp ranking pages are available
+const RANKING_LIMIT: u32 = 10_000;
+
+/// Amount of entries per pp ranking page
+const PAGE_SIZE: u32 = 50;
+
+const COUNTRY_RANK_LIMIT: &str =
+    "Unfortunately I can only provide data for country ranks up to 10,000 :(";
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "rank", desc = "How much is missing to reach the given rank?")]
@@ -21,6 +32,8 @@ pub enum Rank<'a> {
     Pp(RankPp<'a>),
     #[command(name = "score")]
     Score(RankScore<'a>),
+    #[command(name = "to")]
+    To(RankTo<'a>),
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -79,6 +92,23 @@ pub struct RankScore<'a> {
     discord: Option<Id<UserMarker>>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "to",
+    desc = "How many pp are required for the given rank?",
+    help = "How many pp are required for the given rank?\n\
+    For global ranks over 10,000 the value is an approximation based on cached user data, \
+    country ranks are only available up to 10,000."
+)]
+pub struct RankTo<'a> {
+    #[command(min_value = 1, desc = "Specify the rank")]
+    rank: u32,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a country (code)")]
+    country: Option<Cow<'a, str>>,
+}
+
 #[derive(Copy, Clone)]
 enum RankValue<'a> {
     Delta(u32),
@@ -104,5 +134,71 @@ async fn slash_rank(mut command: InteractionCommand) -> Result<()> {
     match Rank::from_interaction(command.input_data())? {
         Rank::Pp(args) => pp((&mut command).into(), args).await,
         Rank::Score(args) => score((&mut command).into(), args).await,
+        Rank::To(args) => rank_to((&mut command).into(), args).await,
+    }
+}
+
+/// Where the pp for a rank can be looked up.
+#[derive(Debug, PartialEq, Eq)]
+enum RankSource {
+    /// Entry `idx` on page `page` of the pp ranking
+    Ranking {
+        page: u32,
+        idx: usize,
+    },
+    /// Approximation based on cached user data; only available for global
+    /// ranks
+    Approx,
+    Unavailable,
+}
+
+impl RankSource {
+    /// Note that `rank` may be zero for unranked rank holders.
+    fn new(rank: u32, country: bool) -> Self {
+        if rank <= RANKING_LIMIT {
+            Self::Ranking {
+                page: (rank / PAGE_SIZE) + (rank % PAGE_SIZE != 0) as u32,
+                idx: (rank.saturating_sub(1) % PAGE_SIZE) as usize,
+            }
+        } else if country {
+            Self::Unavailable
+        } else {
+            Self::Approx
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_source_lookup() {
+        assert_eq!(
+            RankSource::new(1, false),
+            RankSource::Ranking { page: 1, idx: 0 }
+        );
+        assert_eq!(
+            RankSource::new(50, true),
+            RankSource::Ranking { page: 1, idx: 49 }
+        );
+        assert_eq!(
+            RankSource::new(51, false),
+            RankSource::Ranking { page: 2, idx: 0 }
+        );
+        assert_eq!(
+            RankSource::new(10_000, true),
+            RankSource::Ranking { page: 200, idx: 49 }
+        );
+        assert_eq!(RankSource::new(10_001, false), RankSource::Approx);
+        assert_eq!(RankSource::new(10_001, true), RankSource::Unavailable);
+    }
+
+    #[test]
+    fn rank_source_unranked_holder() {
+        assert_eq!(
+            RankSource::new(0, false),
+            RankSource::Ranking { page: 0, idx: 0 }
+        );
     }
 }
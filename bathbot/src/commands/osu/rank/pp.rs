@@ -17,7 +17,7 @@ use bathbot_util::{
 use eyre::{Report, Result};
 use rosu_v2::prelude::{CountryCode, OsuError, Score, UserId, Username};
 
-use super::{RankPp, RankValue};
+use super::{RankPp, RankSource, RankValue, COUNTRY_RANK_LIMIT};
 use crate::{
     commands::{osu::user_not_found, GameModeOption},
     core::commands::{prefix::Args, CommandOrigin},
@@ -117,22 +117,19 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
     };
 
     let rank = rank_or_holder.rank();
+    let rank_source = RankSource::new(rank, country.is_some());
 
-    if rank_or_holder.rank() > 10_000 && country.is_some() {
-        let content = "Unfortunately I can only provide data for country ranks up to 10,000 :(";
-
-        return orig.error(content).await;
+    if rank_source == RankSource::Unavailable {
+        return orig.error(COUNTRY_RANK_LIMIT).await;
     }
 
     async fn insufficient_ranking_entries(orig: CommandOrigin<'_>) -> Result<()> {
         orig.error("Not enough ranking entries available").await
     }
 
-    let rank_data = match rank_or_holder {
-        RankOrHolder::Rank(rank) if rank <= 10_000 => {
+    let rank_data = match (rank_or_holder, rank_source) {
+        (RankOrHolder::Rank(rank), RankSource::Ranking { page, idx }) => {
             // Retrieve the user and the user thats holding the given rank
-            let page = (rank / 50) + (rank % 50 != 0) as u32;
-
             let rankings_fut =
                 Context::redis().pp_ranking(mode, page, country.as_ref().map(|c| c.as_str()));
 
@@ -150,8 +147,6 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                 }
             };
 
-            let idx = ((rank + 49) % 50) as usize;
-
             let rank_holder = match rankings {
                 RedisData::Original(mut rankings) => {
                     if rankings.ranking.len() <= idx {
@@ -199,7 +194,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                 rank_holder,
             }
         }
-        RankOrHolder::Rank(rank) => {
+        (RankOrHolder::Rank(rank), _) => {
             let required_pp = match Context::approx().pp(rank, mode).await {
                 Ok(pp) => pp,
                 Err(err) => {
@@ -215,10 +210,10 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                 required_pp,
             }
         }
-        RankOrHolder::Holder(rank_holder) if rank <= 10_000 => {
+        (RankOrHolder::Holder(rank_holder), RankSource::Ranking { .. }) => {
             RankData::Sub10kExact { user, rank_holder }
         }
-        RankOrHolder::Holder(rank_holder) => RankData::Over10kExact { user, rank_holder },
+        (RankOrHolder::Holder(rank_holder), _) => RankData::Over10kExact { user, rank_holder },
     };
 
     // Retrieve the user's top scores if required
@@ -0,0 +1,280 @@
+use std::fmt::Write;
+
+use bathbot_macros::command;
+use bathbot_model::Countries;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    numbers::WithComma,
+    CowUtils, EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{CountryCode, GameMode, Username};
+
+use super::{RankSource, RankTo, COUNTRY_RANK_LIMIT};
+use crate::{
+    commands::{osu::default_mode, GameModeOption},
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::RedisData,
+    util::ChannelExt,
+    Context,
+};
+
+pub(super) async fn rank_to(orig: CommandOrigin<'_>, args: RankTo<'_>) -> Result<()> {
+    let RankTo {
+        rank,
+        mode,
+        country,
+    } = args;
+
+    if rank == 0 {
+        return orig.error("Rank must be greater than zero :clown:").await;
+    }
+
+    let country = match country {
+        Some(ref country) => match Countries::name(country).to_code() {
+            Some(code) => Some(CountryCode::from(code)),
+            None if country.len() == 2 => {
+                Some(CountryCode::from(country.cow_to_ascii_uppercase().as_ref()))
+            }
+            None => {
+                let content =
+                    format!("Looks like `{country}` is neither a country name nor a country code");
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
+    let mode = match mode {
+        Some(mode) => GameMode::from(mode),
+        None => match Context::user_config().mode(orig.user_id()?).await {
+            Ok(mode) => default_mode(&orig, mode).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let holder = match RankSource::new(rank, country.is_some()) {
+        RankSource::Ranking { page, idx } => {
+            let country = country.as_ref().map(|code| code.as_str());
+            let rankings_fut = Context::redis().pp_ranking(mode, page, country);
+
+            let rankings = match rankings_fut.await {
+                Ok(rankings) => rankings,
+                Err(err) => {
+                    let _ = orig.error(OSU_API_ISSUE).await;
+
+                    return Err(Report::new(err).wrap_err("Failed to get pp ranking"));
+                }
+            };
+
+            let holder = match rankings {
+                RedisData::Original(rankings) => {
+                    rankings.ranking.get(idx).map(|holder| RankToHolder {
+                        user_id: holder.user_id,
+                        username: holder.username.clone(),
+                        pp: holder.statistics.as_ref().map_or(0.0, |stats| stats.pp),
+                    })
+                }
+                RedisData::Archive(rankings) => {
+                    rankings.ranking.get(idx).map(|holder| RankToHolder {
+                        user_id: holder.user_id,
+                        username: holder.username.as_str().into(),
+                        pp: holder.statistics.as_ref().map_or(0.0, |stats| stats.pp),
+                    })
+                }
+            };
+
+            match holder {
+                Some(holder) => RankToValue::Exact(holder),
+                None => return orig.error("Not enough ranking entries available").await,
+            }
+        }
+        RankSource::Approx => match Context::approx().pp(rank, mode).await {
+            Ok(pp) => RankToValue::Approx(pp),
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+        RankSource::Unavailable => return orig.error(COUNTRY_RANK_LIMIT).await,
+    };
+
+    let mut title = format!("Rank #{} ", WithComma::new(rank));
+
+    if let Some(ref country) = country {
+        let _ = write!(title, "in {country} ");
+    }
+
+    let _ = write!(
+        title,
+        "for osu!{mode}",
+        mode = match mode {
+            GameMode::Osu => "standard",
+            GameMode::Taiko => "taiko",
+            GameMode::Catch => "ctb",
+            GameMode::Mania => "mania",
+        }
+    );
+
+    let description = match holder {
+        RankToValue::Exact(holder) => format!(
+            "Currently held by [{name}]({OSU_BASE}u/{user_id}) with **{pp}pp**",
+            name = holder.username.cow_escape_markdown(),
+            user_id = holder.user_id,
+            pp = WithComma::new(holder.pp),
+        ),
+        RankToValue::Approx(pp) => {
+            format!("Requires approximately **{pp}pp**", pp = WithComma::new(pp),)
+        }
+    };
+
+    let embed = EmbedBuilder::new().description(description).title(title);
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+#[command]
+#[desc("How many pp are required for the given rank?")]
+#[help(
+    "How many pp are required for the given rank?\n\
+    Optionally, specify a country name or code after the rank to get the pp of a country rank.\n\
+    For global ranks over 10,000 the value is an approximation based on cached user data, \
+    country ranks are only available up to 10,000."
+)]
+#[usage("[rank] [country]")]
+#[examples("12345", "100 be", "#5,000 united states")]
+#[group(Osu)]
+async fn prefix_rankto(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RankTo::args(None, args) {
+        Ok(args) => rank_to(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("How many pp are required for the given mania rank?")]
+#[help(
+    "How many pp are required for the given mania rank?\n\
+    Optionally, specify a country name or code after the rank to get the pp of a country rank.\n\
+    For global ranks over 10,000 the value is an approximation based on cached user data, \
+    country ranks are only available up to 10,000."
+)]
+#[usage("[rank] [country]")]
+#[examples("12345", "100 be", "#5,000 united states")]
+#[alias("ranktom")]
+#[group(Mania)]
+async fn prefix_ranktomania(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RankTo::args(Some(GameModeOption::Mania), args) {
+        Ok(args) => rank_to(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("How many pp are required for the given taiko rank?")]
+#[help(
+    "How many pp are required for the given taiko rank?\n\
+    Optionally, specify a country name or code after the rank to get the pp of a country rank.\n\
+    For global ranks over 10,000 the value is an approximation based on cached user data, \
+    country ranks are only available up to 10,000."
+)]
+#[usage("[rank] [country]")]
+#[examples("12345", "100 be", "#5,000 united states")]
+#[alias("ranktot")]
+#[group(Taiko)]
+async fn prefix_ranktotaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RankTo::args(Some(GameModeOption::Taiko), args) {
+        Ok(args) => rank_to(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("How many pp are required for the given ctb rank?")]
+#[help(
+    "How many pp are required for the given ctb rank?\n\
+    Optionally, specify a country name or code after the rank to get the pp of a country rank.\n\
+    For global ranks over 10,000 the value is an approximation based on cached user data, \
+    country ranks are only available up to 10,000."
+)]
+#[usage("[rank] [country]")]
+#[examples("12345", "100 be", "#5,000 united states")]
+#[alias("ranktoc", "ranktocatch")]
+#[group(Catch)]
+async fn prefix_ranktoctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RankTo::args(Some(GameModeOption::Catch), args) {
+        Ok(args) => rank_to(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+impl<'m> RankTo<'m> {
+    fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Result<Self, &'static str> {
+        let rank = args.next().ok_or("You must specify a rank")?;
+        let rank = Self::parse_rank(rank)?;
+        let country = args.rest().trim();
+
+        Ok(Self {
+            rank,
+            mode,
+            country: (!country.is_empty()).then(|| country.into()),
+        })
+    }
+
+    fn parse_rank(rank: &str) -> Result<u32, &'static str> {
+        match rank.trim_start_matches('#').replace(',', "").parse() {
+            Ok(0) => Err("Rank must be greater than zero :clown:"),
+            Ok(rank) => Ok(rank),
+            Err(_) => Err("Failed to parse rank, it must be a positive integer"),
+        }
+    }
+}
+
+enum RankToValue {
+    Exact(RankToHolder),
+    Approx(f32),
+}
+
+struct RankToHolder {
+    user_id: u32,
+    username: Username,
+    pp: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rank_arg() {
+        assert_eq!(RankTo::parse_rank("12345"), Ok(12_345));
+        assert_eq!(RankTo::parse_rank("#12,345"), Ok(12_345));
+        assert!(RankTo::parse_rank("0").is_err());
+        assert!(RankTo::parse_rank("-5").is_err());
+        assert!(RankTo::parse_rank("mrekk").is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_util::{
+    constants::OSU_API_ISSUE, matcher, numbers::WithComma, CowUtils, EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use futures::future;
+use rosu_v2::prelude::OsuError;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use super::user_not_found;
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "rankmilestones",
+    desc = "How many pp are missing to reach the next rank milestones?",
+    help = "Display the pp required to reach the next 100, 1,000, and 10,000 rank boundaries \
+    and how many pp the user is missing for each of them.\n\
+    The required pp are approximated based on data of other users."
+)]
+pub struct RankMilestones<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> RankMilestones<'m> {
+    fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            discord,
+        }
+    }
+}
+
+async fn slash_rankmilestones(mut command: InteractionCommand) -> Result<()> {
+    let args = RankMilestones::from_interaction(command.input_data())?;
+
+    rank_milestones((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("How many pp are missing to reach the next rank milestones?")]
+#[help(
+    "Display the pp required to reach the next 100, 1,000, and 10,000 rank boundaries \
+    and how many pp the user is missing for each of them.\n\
+    The required pp are approximated based on data of other users."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("milestones")]
+#[group(Osu)]
+async fn prefix_rankmilestones(msg: &Message, args: Args<'_>) -> Result<()> {
+    rank_milestones(msg.into(), RankMilestones::args(None, args)).await
+}
+
+#[command]
+#[desc("How many pp are missing to reach the next mania rank milestones?")]
+#[help(
+    "Display the pp required to reach the next 100, 1,000, and 10,000 mania rank boundaries \
+    and how many pp the user is missing for each of them.\n\
+    The required pp are approximated based on data of other users."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("milestonesmania")]
+#[group(Mania)]
+async fn prefix_rankmilestonesmania(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = RankMilestones::args(Some(GameModeOption::Mania), args);
+
+    rank_milestones(msg.into(), args).await
+}
+
+#[command]
+#[desc("How many pp are missing to reach the next taiko rank milestones?")]
+#[help(
+    "Display the pp required to reach the next 100, 1,000, and 10,000 taiko rank boundaries \
+    and how many pp the user is missing for each of them.\n\
+    The required pp are approximated based on data of other users."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("milestonestaiko")]
+#[group(Taiko)]
+async fn prefix_rankmilestonestaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = RankMilestones::args(Some(GameModeOption::Taiko), args);
+
+    rank_milestones(msg.into(), args).await
+}
+
+#[command]
+#[desc("How many pp are missing to reach the next ctb rank milestones?")]
+#[help(
+    "Display the pp required to reach the next 100, 1,000, and 10,000 ctb rank boundaries \
+    and how many pp the user is missing for each of them.\n\
+    The required pp are approximated based on data of other users."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("milestonesctb", "rankmilestonescatch")]
+#[group(Catch)]
+async fn prefix_rankmilestonesctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = RankMilestones::args(Some(GameModeOption::Catch), args);
+
+    rank_milestones(msg.into(), args).await
+}
+
+async fn rank_milestones(orig: CommandOrigin<'_>, args: RankMilestones<'_>) -> Result<()> {
+    let (user_id, mode) = user_id_mode!(orig, args);
+
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get user"));
+        }
+    };
+
+    let stats = user.stats();
+    let rank = stats.global_rank();
+    let user_pp = stats.pp();
+
+    if rank == 0 {
+        let content = format!(
+            "`{}` has no global rank so there are no milestones to display",
+            user.username()
+        );
+
+        return orig.error(content).await;
+    }
+
+    let targets = milestones(rank);
+
+    let description = if targets.is_empty() {
+        format!(
+            "{name} is rank **#{rank}**, there are no further milestones to reach",
+            name = user.username().cow_escape_markdown(),
+        )
+    } else {
+        let pp_futs = targets
+            .iter()
+            .map(|&target| Context::approx().pp(target, mode));
+
+        let mut rows = Vec::with_capacity(targets.len());
+
+        for (target, pp_res) in targets.iter().zip(future::join_all(pp_futs).await) {
+            match pp_res {
+                Ok(pp) => rows.push((*target, pp)),
+                Err(err) => {
+                    warn!(?err, "Failed to get pp for rank milestone");
+
+                    let content = "The rank-pp data is unavailable right now, \
+                        try again later";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+
+        milestones_table(user.username(), rank, user_pp, &rows)
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .thumbnail(user.avatar_url().to_owned());
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// The next 100, 1,000, and 10,000 rank boundaries above `rank`.
+fn milestones(rank: u32) -> Vec<u32> {
+    let mut targets: Vec<_> = [100, 1000, 10_000]
+        .into_iter()
+        .map(|step| ((rank - 1) / step) * step)
+        .filter(|&target| target > 0)
+        .collect();
+
+    targets.dedup();
+
+    targets
+}
+
+fn milestones_table(name: &str, rank: u32, user_pp: f32, rows: &[(u32, f32)]) -> String {
+    let rows: Vec<_> = rows
+        .iter()
+        .map(|&(target, pp)| {
+            (
+                format!("#{}", WithComma::new(target)),
+                WithComma::new(pp).to_string(),
+                WithComma::new((pp - user_pp).max(0.0)).to_string(),
+            )
+        })
+        .collect();
+
+    let rank_len = rows.iter().map(|(rank, ..)| rank.len()).fold(4, usize::max);
+    let pp_len = rows.iter().map(|(_, pp, _)| pp.len()).fold(9, usize::max);
+
+    let mut description = format!(
+        "{name} is rank **#{rank}** with **{pp}pp**\n```\n\
+        {:<rank_len$} | {:>pp_len$} | Gap\n",
+        "Rank",
+        "PP needed",
+        name = name.cow_escape_markdown(),
+        rank = WithComma::new(rank),
+        pp = WithComma::new(user_pp),
+    );
+
+    for (rank, pp, gap) in rows {
+        let _ = writeln!(description, "{rank:<rank_len$} | {pp:>pp_len$} | {gap}");
+    }
+
+    description.push_str("```");
+
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn milestones_above_rank() {
+        assert_eq!(milestones(12_345), vec![12_300, 12_000, 10_000]);
+        assert_eq!(milestones(12_050), vec![12_000, 10_000]);
+        assert_eq!(milestones(10_000), vec![9900, 9000]);
+        assert_eq!(milestones(150), vec![100]);
+        assert!(milestones(100).is_empty());
+        assert!(milestones(1).is_empty());
+    }
+}
@@ -3,12 +3,11 @@ use std::collections::BTreeMap;
 use bathbot_macros::command;
 use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
 use eyre::{Report, Result};
-use rosu_v2::prelude::GameMode;
 
 use super::RankingCountry;
 use crate::{
     active::{impls::RankingCountriesPagination, ActiveMessages},
-    commands::GameModeOption,
+    commands::{osu::default_mode, GameModeOption},
     core::commands::CommandOrigin,
     Context,
 };
@@ -51,7 +50,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
     let mode = match args.mode {
         Some(mode) => mode.into(),
         None => match Context::user_config().mode(owner).await {
-            Ok(mode) => mode.unwrap_or(GameMode::Osu),
+            Ok(mode) => default_mode(&orig, mode).await,
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
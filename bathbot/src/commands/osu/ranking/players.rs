@@ -11,7 +11,7 @@ use rosu_v2::prelude::{CountryCode, GameMode, OsuResult, Rankings as RosuRanking
 use super::{RankingPp, RankingScore};
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
-    commands::GameModeOption,
+    commands::{osu::default_mode, GameModeOption},
     core::commands::CommandOrigin,
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
@@ -43,7 +43,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => (default_mode(&orig, config.mode).await, config.osu),
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -130,7 +130,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore) -> Result
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => (default_mode(&orig, config.mode).await, config.osu),
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
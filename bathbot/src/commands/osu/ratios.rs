@@ -12,6 +12,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use super::{require_link, user_not_found};
 use crate::{
+    commands::GameModeOption,
     core::commands::CommandOrigin,
     embeds::{EmbedData, RatioEmbed},
     manager::redis::osu::UserArgs,
@@ -33,6 +34,11 @@ use crate::{
     The fourth column shows the average percentual miss amount for scores in the corresponding row."
 )]
 pub struct Ratios<'a> {
+    #[command(
+        desc = "Specify a gamemode",
+        help = "Ratios only make sense for mania so any other mode will be refused."
+    )]
+    mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
     #[command(
@@ -60,10 +66,12 @@ async fn prefix_ratios(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
         Some(arg) => match matcher::get_mention_user(arg) {
             Some(id) => Ratios {
+                mode: None,
                 name: None,
                 discord: Some(id),
             },
             None => Ratios {
+                mode: None,
                 name: Some(Cow::Borrowed(arg)),
                 discord: None,
             },
@@ -81,6 +89,10 @@ async fn slash_ratios(mut command: InteractionCommand) -> Result<()> {
 }
 
 async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
+    if let Some(content) = args.mode.map(GameMode::from).and_then(mania_only) {
+        return orig.error(content).await;
+    }
+
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
@@ -141,3 +153,26 @@ async fn ratios(orig: CommandOrigin<'_>, args: Ratios<'_>) -> Result<()> {
 
     Ok(())
 }
+
+fn mania_only(mode: GameMode) -> Option<&'static str> {
+    (mode != GameMode::Mania).then_some(
+        "Ratios are only available for mania since other modes don't distinguish \
+        between perfects and greats",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refuse_non_mania() {
+        assert!(mania_only(GameMode::Mania).is_none());
+
+        for mode in [GameMode::Osu, GameMode::Taiko, GameMode::Catch] {
+            assert!(mania_only(mode)
+                .unwrap()
+                .starts_with("Ratios are only available"));
+        }
+    }
+}
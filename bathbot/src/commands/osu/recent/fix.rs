@@ -10,7 +10,7 @@ use rosu_v2::{
     request::UserId,
 };
 
-use super::RecentFix;
+use super::{history_too_short, RecentFix};
 use crate::{
     commands::osu::{require_link, user_not_found, FixEntry, FixScore},
     core::{commands::CommandOrigin, Context},
@@ -134,13 +134,7 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
         None => {
             let username = user.username();
 
-            let content = format!(
-                "There {verb} only {num} score{plural} in `{username}`'{genitive} recent history.",
-                verb = if scores_len != 1 { "are" } else { "is" },
-                num = scores_len,
-                plural = if scores_len != 1 { "s" } else { "" },
-                genitive = if username.ends_with('s') { "" } else { "s" }
-            );
+            let content = history_too_short(username, scores_len);
 
             return orig.error(content).await;
         }
@@ -13,18 +13,19 @@ use rosu_v2::{
     request::UserId,
 };
 
-use super::RecentLeaderboard;
+use super::{history_too_short, RecentLeaderboard};
 use crate::{
     active::{impls::LeaderboardPagination, ActiveMessages},
     commands::{
         osu::{
-            require_link, user_not_found, HasMods, LeaderboardScore, LeaderboardUserScore,
-            ModsResult,
+            default_mode, filter_leaderboard_scores, require_link, user_not_found, HasMods,
+            LeaderboardScore, LeaderboardUserScore, ModsResult,
         },
         GameModeOption,
     },
     core::commands::{prefix::Args, CommandOrigin},
     manager::{redis::osu::UserArgs, Mods},
+    util::query::{IFilterCriteria, LeaderboardCriteria},
     Context,
 };
 
@@ -33,10 +34,11 @@ use crate::{
 #[help(
     "Display the global leaderboard of a map that a user recently played.\n\
     Mods can be specified.\n\
+    Scores can be filtered through a query like `ar>9.3 bpm>180`.\n\
     To get a previous recent map, you can add a number right after the command,\n\
     e.g. `rlb42 badewanne3` to get the 42nd most recent map."
 )]
-#[usage("[username] [+mods]")]
+#[usage("[username] [+mods] [query]")]
 #[example("badewanne3 +hdhr")]
 #[aliases("rlb", "rglb", "recentgloballeaderboard")]
 #[group(Osu)]
@@ -51,10 +53,11 @@ async fn prefix_recentleaderboard(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Display the global leaderboard of a mania map that a user recently played.\n\
     Mods can be specified.\n\
+    Scores can be filtered through a query like `ar>9.3 bpm>180`.\n\
     To get a previous recent map, you can add a number right after the command,\n\
     e.g. `rmlb42 badewanne3` to get the 42nd most recent map."
 )]
-#[usage("[username] [+mods]")]
+#[usage("[username] [+mods] [query]")]
 #[example("badewanne3 +hdhr")]
 #[aliases("rmlb", "rmglb", "recentmaniagloballeaderboard")]
 #[group(Mania)]
@@ -69,10 +72,11 @@ async fn prefix_recentmanialeaderboard(msg: &Message, args: Args<'_>) -> Result<
 #[help(
     "Display the global leaderboard of a taiko map that a user recently played.\n\
     Mods can be specified.\n\
+    Scores can be filtered through a query like `ar>9.3 bpm>180`.\n\
     To get a previous recent map, you can add a number right after the command,\n\
     e.g. `rtlb42 badewanne3` to get the 42nd most recent map."
 )]
-#[usage("[username] [+mods]")]
+#[usage("[username] [+mods] [query]")]
 #[example("badewanne3 +hdhr")]
 #[aliases("rtlb", "rtglb", "recenttaikogloballeaderboard")]
 #[group(Taiko)]
@@ -87,10 +91,11 @@ async fn prefix_recenttaikoleaderboard(msg: &Message, args: Args<'_>) -> Result<
 #[help(
     "Display the global leaderboard of a ctb map that a user recently played.\n\
     Mods can be specified.\n\
+    Scores can be filtered through a query like `ar>9.3 bpm>180`.\n\
     To get a previous recent map, you can add a number right after the command,\n\
     e.g. `rclb42 badewanne3` to get the 42nd most recent map."
 )]
-#[usage("[username] [+mods]")]
+#[usage("[username] [+mods] [query]")]
 #[example("badewanne3 +hdhr")]
 #[aliases(
     "rclb",
@@ -110,14 +115,23 @@ impl<'m> RecentLeaderboard<'m> {
         let mut name = None;
         let mut discord = None;
         let mut mods = None;
+        let mut query: Option<String> = None;
         let num = args.num;
 
-        for arg in args.take(2) {
+        for arg in args {
             if matcher::get_mods(arg).is_some() {
                 mods = Some(arg.into());
+            } else if matcher::is_query_syntax(arg) {
+                match query {
+                    Some(ref mut query) => {
+                        query.push(' ');
+                        query.push_str(arg);
+                    }
+                    None => query = Some(arg.to_owned()),
+                }
             } else if let Some(id) = matcher::get_mention_user(arg) {
                 discord = Some(id);
-            } else {
+            } else if name.is_none() {
                 name = Some(arg.into());
             }
         }
@@ -127,6 +141,7 @@ impl<'m> RecentLeaderboard<'m> {
             name,
             mods,
             sort: None,
+            query,
             index: num.to_string_opt().map(String::into),
             discord,
         }
@@ -150,15 +165,18 @@ pub(super) async fn leaderboard(
         }
     };
 
-    let limit = match args.index.as_deref() {
-        Some("random" | "?") => thread_rng().gen_range(1..=100),
+    // For random indices, fetch the whole history and pick among the scores
+    // that actually exist
+    let (limit, random) = match args.index.as_deref() {
+        Some("random" | "?") => (100, true),
         Some(n) => match n.parse::<usize>() {
             Ok(n) if n > 100 => {
-                let content = "Recent history goes only 100 scores back.";
+                let content = "Recent history goes only 100 scores back, \
+                pick an index between 1 and 100.";
 
                 return orig.error(content).await;
             }
-            Ok(n) => n,
+            Ok(n) => (n, false),
             Err(_) => {
                 let content = "Failed to parse index. \
                 Must be an integer between 1 and 100 or `random` / `?`.";
@@ -166,7 +184,7 @@ pub(super) async fn leaderboard(
                 return orig.error(content).await;
             }
         },
-        None => 1,
+        None => (1, false),
     };
 
     let owner = orig.user_id()?;
@@ -180,11 +198,10 @@ pub(super) async fn leaderboard(
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let user_id = if let Some(user_id) = user_id!(orig, args) {
         user_id
@@ -215,40 +232,44 @@ pub(super) async fn leaderboard(
         .exec_with_user(user_args);
 
     let (map_id, checksum, user) = match scores_fut.await {
-        Ok((user, scores)) if scores.len() < limit => {
-            let username = user.username();
-
-            let content = format!(
-                "There are only {} many scores in `{username}`'{} recent history.",
-                scores.len(),
-                if username.ends_with('s') { "" } else { "s" }
-            );
+        Ok((user, scores)) if !random && !scores.is_empty() && scores.len() < limit => {
+            let content = history_too_short(user.username(), scores.len());
 
             return orig.error(content).await;
         }
-        Ok((user, mut scores)) => match scores.pop() {
-            Some(score) => {
-                let Score { map, .. } = score;
-                let map = map.unwrap();
+        Ok((user, mut scores)) => {
+            let score = if random && !scores.is_empty() {
+                let idx = thread_rng().gen_range(0..scores.len());
 
-                (map.map_id, map.checksum, user)
-            }
-            None => {
-                let username = user.username();
-
-                let content = format!(
-                    "No recent {}plays found for user `{username}`",
-                    match mode {
-                        GameMode::Osu => "",
-                        GameMode::Taiko => "taiko ",
-                        GameMode::Catch => "ctb ",
-                        GameMode::Mania => "mania ",
-                    },
-                );
+                Some(scores.swap_remove(idx))
+            } else {
+                scores.pop()
+            };
 
-                return orig.error(content).await;
+            match score {
+                Some(score) => {
+                    let Score { map, .. } = score;
+                    let map = map.unwrap();
+
+                    (map.map_id, map.checksum, user)
+                }
+                None => {
+                    let username = user.username();
+
+                    let content = format!(
+                        "No recent {}plays found for user `{username}`",
+                        match mode {
+                            GameMode::Osu => "",
+                            GameMode::Taiko => "taiko ",
+                            GameMode::Catch => "ctb ",
+                            GameMode::Mania => "mania ",
+                        },
+                    );
+
+                    return orig.error(content).await;
+                }
             }
-        },
+        }
         Err(OsuError::NotFound) => {
             let content = user_not_found(user_id).await;
 
@@ -344,26 +365,27 @@ pub(super) async fn leaderboard(
     let mut calc = Context::pp(&map).mode(map.mode()).mods(mods_);
     let attrs = calc.performance().await;
 
-    if let Some(ModSelection::Exclude(ref mods)) = mods {
-        if mods.is_empty() {
-            scores.retain(|score| !score.mods.is_empty());
-
-            if let Some(ref score) = user_score {
-                if score.score.mods.is_empty() {
-                    user_score.take();
-                }
-            }
-        } else {
-            scores.retain(|score| !score.mods.contains_any(mods.iter()));
+    // Included mods are already filtered by the API
+    if let Some(ref selection @ (ModSelection::Exclude(_) | ModSelection::Exact(_))) = mods {
+        scores.retain(|score| selection.filter_mods(&score.mods));
 
-            if let Some(ref score) = user_score {
-                if score.score.mods.contains_any(mods.iter()) {
-                    user_score.take();
-                }
+        if let Some(ref score) = user_score {
+            if !selection.filter_mods(&score.score.mods) {
+                user_score.take();
             }
         }
     }
 
+    // Not storing `attrs` here in case mods (potentially with clock rate) were
+    // specified
+    let mut attr_map = HashMap::default();
+
+    let criteria = args.query.as_deref().map(LeaderboardCriteria::create);
+
+    if let Some(ref criteria) = criteria {
+        filter_leaderboard_scores(&mut scores, &map, &mut attr_map, criteria).await;
+    }
+
     let amount = scores.len();
 
     let mut content = if mods.is_some() {
@@ -375,14 +397,14 @@ pub(super) async fn leaderboard(
     let stars = attrs.stars() as f32;
     let max_combo = attrs.max_combo();
 
-    // Not storing `attrs` here in case mods (potentially with clock rate) were
-    // specified
-    let mut attr_map = HashMap::default();
-
     let order = args.sort.unwrap_or_default();
     order.sort(&mut scores, &map, &mut attr_map).await;
     order.push_content(&mut content);
 
+    if let Some(ref criteria) = criteria {
+        criteria.display(&mut content);
+    }
+
     let first_place_icon = scores
         .first()
         .map(|_| format!("{AVATAR_URL}{}", user.user_id()));
@@ -433,3 +455,19 @@ async fn get_user_score(
         Err(err) => Err(Report::new(err).wrap_err("Failed to get score")),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::commands::prefix::ArgsNum;
+
+    #[test]
+    fn first_free_arg_is_name() {
+        let args = Args::new("badewanne3 ar>9 mrekk +hd", ArgsNum::None);
+        let args = RecentLeaderboard::args(None, args);
+
+        assert_eq!(args.name.as_deref(), Some("badewanne3"));
+        assert_eq!(args.query.as_deref(), Some("ar>9"));
+        assert_eq!(args.mods.as_deref(), Some("+hd"));
+    }
+}
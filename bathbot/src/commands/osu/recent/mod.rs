@@ -196,6 +196,14 @@ pub struct RecentLeaderboard<'a> {
         Note that the scores will still be the top pp scores, they'll just be re-ordered."
     )]
     sort: Option<LeaderboardSort>,
+    #[command(
+        desc = "Specify a search query containing AR, BPM, PP, ...",
+        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
+        You can limit values such as ar, cs, hp, od, bpm, length, stars, pp, acc, combo, \
+        misses, or score like for example `ar>9.3 bpm>180`.\n\
+        Map attributes will be adjusted to the mods of each score."
+    )]
+    query: Option<String>,
     #[command(
         desc = "Choose the recent score's index or `random`",
         help = "By default the leaderboard of the very last score will be displayed.\n\
@@ -224,7 +232,7 @@ pub struct RecentList<'a> {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        ar, cs, hp, od, bpm, length, stars, or ranked like for example `fdfd ar>10 ranked>2019`.\n\
         While ar & co will be adjusted to mods, stars will not."
     )]
     query: Option<String>,
@@ -426,3 +434,44 @@ async fn slash_rb(mut command: InteractionCommand) -> Result<()> {
         }
     }
 }
+
+/// Reply for when the requested index goes further back than the user's
+/// recent history.
+fn history_too_short(username: &str, len: usize) -> String {
+    let genitive = if username.ends_with('s') { "" } else { "s" };
+
+    match len {
+        0 => format!("There are no scores in `{username}`'{genitive} recent history."),
+        1 => format!(
+            "There is only 1 score in `{username}`'{genitive} recent history \
+            so the index must be 1."
+        ),
+        _ => format!(
+            "There are only {len} scores in `{username}`'{genitive} recent history, \
+            pick an index between 1 and {len}."
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_too_short_mentions_valid_range() {
+        assert_eq!(
+            history_too_short("mrekk", 42),
+            "There are only 42 scores in `mrekk`'s recent history, \
+            pick an index between 1 and 42."
+        );
+        assert_eq!(
+            history_too_short("Badewanne3", 1),
+            "There is only 1 score in `Badewanne3`'s recent history so the index must be 1."
+        );
+        assert_eq!(
+            history_too_short("chocomint", 0),
+            "There are no scores in `chocomint`'s recent history."
+        );
+        assert!(history_too_short("Vaxei", 3).contains("`Vaxei`' recent"));
+    }
+}
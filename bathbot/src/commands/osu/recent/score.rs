@@ -23,11 +23,11 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use super::RecentScore;
+use super::{history_too_short, RecentScore};
 use crate::{
     active::{impls::RecentScoreEdit, ActiveMessages},
     commands::{
-        osu::{require_link, user_not_found},
+        osu::{default_mode, require_link, user_not_found},
         GameModeOption, GradeOption,
     },
     core::commands::{prefix::Args, CommandOrigin},
@@ -361,11 +361,10 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         legacy_scores: guild_legacy_scores,
     } = guild_values;
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -414,16 +413,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
 
     let (user, mut scores) = match scores_res {
         Ok((user, scores)) if scores.is_empty() => {
-            let username = user.username();
-            let content = format!(
-                "No recent {}plays found for user `{username}`",
-                match mode {
-                    GameMode::Osu => "",
-                    GameMode::Taiko => "taiko ",
-                    GameMode::Catch => "ctb ",
-                    GameMode::Mania => "mania ",
-                },
-            );
+            let content = no_recent_scores(user.username(), mode, passes);
 
             return orig.error(content).await;
         }
@@ -451,14 +441,12 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         }
     };
 
-    if let Some(grade) = grade {
-        if let Grade::F = grade {
-            scores.retain(|score| !score.passed);
-        } else {
-            scores.retain(|score| score.grade.eq_letter(grade));
-        }
-    } else if let Some(passed) = passes {
-        scores.retain(|score| passed == score.passed);
+    scores.retain(|score| matches_filter(score.grade, score.passed, grade, passes));
+
+    if grade.is_none() && passes.is_some() && scores.is_empty() {
+        let content = no_recent_scores(user.username(), mode, passes);
+
+        return orig.error(content).await;
     }
 
     let num = match index.as_deref() {
@@ -490,12 +478,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         let Some(score) = iter.next() else {
             let username = user.username();
 
-            let content = format!(
-                "There {verb} only {len} score{plural} in `{username}`'{genitive} recent history.",
-                verb = if len != 1 { "are" } else { "is" },
-                plural = if len != 1 { "s" } else { "" },
-                genitive = if username.ends_with('s') { "" } else { "s" }
-            );
+            let content = history_too_short(username, len);
 
             return orig.error(content).await;
         };
@@ -514,55 +497,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
 
         let mods = &score.mods;
 
-        let tries = match retries {
-            Retries::Hide => None,
-            Retries::ConsiderMods => {
-                fn same_mods(a: &GameMods, b: &GameMods) -> bool {
-                    a.iter().zip(b.iter()).all(|(a, b)| match (a, b) {
-                        (GameMod::DoubleTimeOsu(a), GameMod::NightcoreOsu(b))
-                        | (GameMod::NightcoreOsu(b), GameMod::DoubleTimeOsu(a)) => {
-                            a.speed_change.eq(&b.speed_change)
-                        }
-                        (GameMod::SuddenDeathOsu(a), GameMod::PerfectOsu(b))
-                        | (GameMod::PerfectOsu(b), GameMod::SuddenDeathOsu(a)) => {
-                            a.restart.eq(&b.restart)
-                        }
-                        (GameMod::DoubleTimeTaiko(a), GameMod::NightcoreTaiko(b))
-                        | (GameMod::NightcoreTaiko(b), GameMod::DoubleTimeTaiko(a)) => {
-                            a.speed_change.eq(&b.speed_change)
-                        }
-                        (GameMod::SuddenDeathTaiko(a), GameMod::PerfectTaiko(b))
-                        | (GameMod::PerfectTaiko(b), GameMod::SuddenDeathTaiko(a)) => {
-                            a.restart.eq(&b.restart)
-                        }
-                        (GameMod::DoubleTimeCatch(a), GameMod::NightcoreCatch(b))
-                        | (GameMod::NightcoreCatch(b), GameMod::DoubleTimeCatch(a)) => {
-                            a.speed_change.eq(&b.speed_change)
-                        }
-                        (GameMod::SuddenDeathCatch(a), GameMod::PerfectCatch(b))
-                        | (GameMod::PerfectCatch(b), GameMod::SuddenDeathCatch(a)) => {
-                            a.restart.eq(&b.restart)
-                        }
-                        (GameMod::DoubleTimeMania(a), GameMod::NightcoreMania(b))
-                        | (GameMod::NightcoreMania(b), GameMod::DoubleTimeMania(a)) => {
-                            a.speed_change.eq(&b.speed_change)
-                        }
-                        (GameMod::SuddenDeathMania(a), GameMod::PerfectMania(b))
-                        | (GameMod::PerfectMania(b), GameMod::SuddenDeathMania(a)) => {
-                            a.restart.eq(&b.restart)
-                        }
-                        (a, b) => a.eq(b),
-                    })
-                }
-
-                Some(
-                    1 + iter
-                        .take_while(|s| same_mods(&s.mods, mods) && s.map_id == map_id)
-                        .count(),
-                )
-            }
-            Retries::IgnoreMods => Some(1 + iter.take_while(|s| s.map_id == map_id).count()),
-        };
+        let prev_scores = iter.map(|score| (score.map_id, score.mods));
+        let tries = count_tries(map_id, mods, prev_scores, retries);
 
         (score, map, tries)
     };
@@ -1008,3 +944,183 @@ async fn get_guild_values(orig: &CommandOrigin<'_>) -> GuildValues {
         None => GuildValues::default(),
     }
 }
+
+fn no_recent_scores(username: &str, mode: GameMode, passes: Option<bool>) -> String {
+    format!(
+        "No recent {mode}{kind} found for user `{username}`",
+        mode = match mode {
+            GameMode::Osu => "",
+            GameMode::Taiko => "taiko ",
+            GameMode::Catch => "ctb ",
+            GameMode::Mania => "mania ",
+        },
+        kind = match passes {
+            Some(true) => "passes",
+            Some(false) => "fails",
+            None => "plays",
+        },
+    )
+}
+
+/// Whether a recent score passes the grade filter or, if no grade was
+/// specified, the passes filter.
+fn matches_filter(
+    score_grade: Grade,
+    score_passed: bool,
+    grade: Option<Grade>,
+    passes: Option<bool>,
+) -> bool {
+    match (grade, passes) {
+        (Some(Grade::F), _) => !score_passed,
+        (Some(grade), _) => score_grade.eq_letter(grade),
+        (None, Some(passes)) => score_passed == passes,
+        (None, None) => true,
+    }
+}
+
+/// Counts how many consecutive attempts on the same map, including the current
+/// one, are at the start of the given recent scores.
+fn count_tries(
+    map_id: u32,
+    mods: &GameMods,
+    prev_scores: impl Iterator<Item = (u32, GameMods)>,
+    retries: Retries,
+) -> Option<usize> {
+    match retries {
+        Retries::Hide => None,
+        Retries::ConsiderMods => Some(
+            1 + prev_scores
+                .take_while(|(id, prev_mods)| *id == map_id && same_mods(prev_mods, mods))
+                .count(),
+        ),
+        Retries::IgnoreMods => Some(1 + prev_scores.take_while(|(id, _)| *id == map_id).count()),
+    }
+}
+
+fn same_mods(a: &GameMods, b: &GameMods) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b.iter()).all(|(a, b)| match (a, b) {
+            (GameMod::DoubleTimeOsu(a), GameMod::NightcoreOsu(b))
+            | (GameMod::NightcoreOsu(b), GameMod::DoubleTimeOsu(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathOsu(a), GameMod::PerfectOsu(b))
+            | (GameMod::PerfectOsu(b), GameMod::SuddenDeathOsu(a)) => a.restart.eq(&b.restart),
+            (GameMod::DoubleTimeTaiko(a), GameMod::NightcoreTaiko(b))
+            | (GameMod::NightcoreTaiko(b), GameMod::DoubleTimeTaiko(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathTaiko(a), GameMod::PerfectTaiko(b))
+            | (GameMod::PerfectTaiko(b), GameMod::SuddenDeathTaiko(a)) => a.restart.eq(&b.restart),
+            (GameMod::DoubleTimeCatch(a), GameMod::NightcoreCatch(b))
+            | (GameMod::NightcoreCatch(b), GameMod::DoubleTimeCatch(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathCatch(a), GameMod::PerfectCatch(b))
+            | (GameMod::PerfectCatch(b), GameMod::SuddenDeathCatch(a)) => a.restart.eq(&b.restart),
+            (GameMod::DoubleTimeMania(a), GameMod::NightcoreMania(b))
+            | (GameMod::NightcoreMania(b), GameMod::DoubleTimeMania(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathMania(a), GameMod::PerfectMania(b))
+            | (GameMod::PerfectMania(b), GameMod::SuddenDeathMania(a)) => a.restart.eq(&b.restart),
+            (a, b) => a.eq(b),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::GameModsIntermode;
+
+    use super::*;
+
+    fn mods(acronyms: &str) -> GameMods {
+        GameModsIntermode::try_from_acronyms(acronyms)
+            .unwrap()
+            .with_mode(GameMode::Osu)
+    }
+
+    #[test]
+    fn count_consecutive_tries() {
+        let recent = || {
+            [
+                (1, mods("HD")),
+                (1, mods("HDDT")),
+                (1, mods("HD")),
+                (2, mods("HD")),
+                (1, mods("HD")),
+            ]
+            .into_iter()
+        };
+
+        let current = mods("HD");
+
+        assert_eq!(count_tries(1, &current, recent(), Retries::Hide), None);
+        assert_eq!(
+            count_tries(1, &current, recent(), Retries::IgnoreMods),
+            Some(4)
+        );
+        assert_eq!(
+            count_tries(1, &current, recent(), Retries::ConsiderMods),
+            Some(2)
+        );
+        assert_eq!(
+            count_tries(3, &current, recent(), Retries::IgnoreMods),
+            Some(1)
+        );
+    }
+
+    /// Ids of the scores that pass the filter, most recent first
+    fn filtered(
+        recent: &[(u32, Grade, bool)],
+        grade: Option<Grade>,
+        passes: Option<bool>,
+    ) -> Vec<u32> {
+        recent
+            .iter()
+            .filter(|(_, score_grade, passed)| matches_filter(*score_grade, *passed, grade, passes))
+            .map(|(id, ..)| *id)
+            .collect()
+    }
+
+    #[test]
+    fn nth_recent_pass() {
+        // (score id, grade, passed), most recent first
+        let recent = [
+            (1, Grade::F, false),
+            (2, Grade::A, true),
+            (3, Grade::F, false),
+            (4, Grade::F, false),
+            (5, Grade::SH, true),
+            (6, Grade::S, true),
+        ];
+
+        let passes = filtered(&recent, None, Some(true));
+        assert_eq!(passes, [2, 5, 6]);
+
+        // `rp2` skips the most recent pass
+        assert_eq!(passes.get(1), Some(&5));
+
+        assert_eq!(filtered(&recent, None, Some(false)), [1, 3, 4]);
+        assert_eq!(filtered(&recent, None, None).len(), 6);
+
+        // The grade filter takes precedence
+        assert_eq!(filtered(&recent, Some(Grade::S), Some(false)), [5, 6]);
+        assert_eq!(filtered(&recent, Some(Grade::F), None), [1, 3, 4]);
+    }
+
+    #[test]
+    fn only_fails_yields_no_passes() {
+        let recent = [(1, Grade::F, false), (2, Grade::F, false)];
+
+        assert!(filtered(&recent, None, Some(true)).is_empty());
+        assert_eq!(
+            no_recent_scores("badewanne3", GameMode::Taiko, Some(true)),
+            "No recent taiko passes found for user `badewanne3`"
+        );
+        assert_eq!(
+            no_recent_scores("badewanne3", GameMode::Osu, None),
+            "No recent plays found for user `badewanne3`"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+    numbers::WithComma,
+    EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
+
+use super::{default_mode, require_link, user_not_found};
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "refresh",
+    desc = "Refresh the cached data of a user",
+    help = "Refresh the cached data of a user.\n\
+    Users are cached for a few minutes so commands may show outdated stats right after a new play. \
+    This command discards the cached user so that the next commands use fresh data."
+)]
+pub struct Refresh<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+pub async fn slash_refresh(mut command: InteractionCommand) -> Result<()> {
+    let args = Refresh::from_interaction(command.input_data())?;
+
+    refresh((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Refresh the cached data of a user")]
+#[help(
+    "Refresh the cached data of a user.\n\
+    Users are cached for a few minutes so commands may show outdated stats right after a new play. \
+    This command discards the cached user so that the next commands use fresh data.\n\
+    To specify a gamemode, use the `/refresh` command."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[group(AllModes)]
+async fn prefix_refresh(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = Refresh::args(args);
+
+    refresh(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+impl<'m> Refresh<'m> {
+    fn args(mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode: None,
+            name,
+            discord,
+        }
+    }
+}
+
+async fn refresh(orig: CommandOrigin<'_>, args: Refresh<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
+
+    // Users that were just requested from the osu!api are fresh already
+    if let UserArgs::Args(ref args) = user_args {
+        if let Err(err) = Context::redis()
+            .invalidate_osu_user(args.user_id, mode)
+            .await
+        {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to invalidate cached user"));
+        }
+    }
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let stats = user.stats();
+
+    let description = format!(
+        "Refreshed the cached data of this user\n\
+        PP: `{pp}pp` • Global rank: `#{rank}`",
+        pp = WithComma::new(stats.pp()),
+        rank = WithComma::new(stats.global_rank()),
+    );
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .thumbnail(user.avatar_url());
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -4,18 +4,30 @@ use std::{
 };
 
 use bathbot_macros::SlashCommand;
+use bathbot_model::ScoreSlim;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE},
-    EmbedBuilder, MessageBuilder,
+    matcher, EmbedBuilder, MessageBuilder, ModsFormatter,
 };
-use eyre::{Report, Result, WrapErr};
+use eyre::{ContextCompat, Report, Result, WrapErr};
+use image::{
+    imageops::{self, FilterType},
+    DynamicImage, Rgba, RgbaImage,
+};
+use plotters::{
+    prelude::IntoDrawingArea,
+    style::{Color, FontDesc, FontFamily, RGBColor, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
 use rosu_render::{
     client::error::{
         ApiError as OrdrApiError, ClientError as OrdrError, ErrorCode as OrdrErrorCode,
     },
     model::RenderDone,
 };
-use rosu_v2::prelude::{GameMode, OsuError};
+use rosu_v2::prelude::{GameMode, OsuError, Score};
+use skia_safe::{surfaces, EncodedImageFormat};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     channel::Attachment,
@@ -27,8 +39,9 @@ use crate::{
         impls::{CachedRender, RenderSettingsActive, SettingsImport},
         ActiveMessages,
     },
-    core::{buckets::BucketName, commands::OwnedCommandOrigin, Context},
-    manager::{ReplayScore, ReplaySettings},
+    commands::osu::BitMapElement,
+    core::{buckets::BucketName, commands::OwnedCommandOrigin, BotConfig, Context},
+    manager::{OsuMap, ReplayScore, ReplaySettings},
     tracking::OrdrReceivers,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
 };
@@ -101,7 +114,14 @@ pub struct RenderSettingsCopy {
 pub struct RenderSettingsDefault;
 
 pub async fn slash_render(mut command: InteractionCommand) -> Result<()> {
+    let args = Render::from_interaction(command.input_data())?;
+
     if Context::ordr().is_none() {
+        // Scores can still be previewed as a static image
+        if let Render::Score(args) = args {
+            return preview_score(command, args).await;
+        }
+
         command
             .error_callback("Rendering is currently unavailable")
             .await?;
@@ -109,7 +129,7 @@ pub async fn slash_render(mut command: InteractionCommand) -> Result<()> {
         return Ok(());
     };
 
-    match Render::from_interaction(command.input_data())? {
+    match args {
         Render::Replay(args) => render_replay(command, args).await,
         Render::Score(args) => render_score(command, args).await,
         Render::Settings(RenderSettings::Modify(_)) => render_settings_modify(&mut command).await,
@@ -336,7 +356,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
     let render = match render_fut.await {
         Ok(render) => render,
         Err(err) => {
-            let _ = command.error(ORDR_ISSUE).await;
+            let _ = error_with_preview(&command, ORDR_ISSUE, score).await;
 
             return Err(Report::new(err).wrap_err("Failed to commission render"));
         }
@@ -691,3 +711,170 @@ async fn render_settings_default(command: &mut InteractionCommand) -> Result<()>
         .begin(command)
         .await
 }
+
+async fn preview_score(command: InteractionCommand, score: RenderScore) -> Result<()> {
+    command.defer(false).await?;
+
+    let score = match Context::osu().score(score.score_id, GameMode::Osu).await {
+        Ok(score) => score,
+        Err(OsuError::NotFound) => {
+            let content = "Found no osu!standard score with that id";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get score"));
+        }
+    };
+
+    error_with_preview(&command, "Rendering is currently unavailable", score).await
+}
+
+const PREVIEW_W: u32 = 900;
+const PREVIEW_H: u32 = 250;
+const PREVIEW_GRADE_SIZE: u32 = 128;
+
+/// Update the response to an error embed that includes a static preview of
+/// the score or just the error if the preview could not be created.
+async fn error_with_preview(
+    command: &InteractionCommand,
+    content: &'static str,
+    score: Score,
+) -> Result<()> {
+    let checksum = score.map.as_ref().and_then(|map| map.checksum.as_deref());
+
+    let map = match Context::osu_map().map(score.map_id, checksum).await {
+        Ok(map) => map,
+        Err(err) => {
+            command.error(content).await?;
+
+            return Err(Report::new(err).wrap_err("Failed to get map for score preview"));
+        }
+    };
+
+    let pp = match score.pp {
+        Some(pp) => pp,
+        None => Context::pp(&map).score(&score).performance().await.pp() as f32,
+    };
+
+    let score = ScoreSlim::new(score, pp);
+
+    let bytes = match score_preview(&score, &map).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            command.error(content).await?;
+
+            return Err(err.wrap_err("Failed to create score preview"));
+        }
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(format!("{content}\nHere's a preview of the score instead:"))
+        .image("attachment://score_preview.png")
+        .color_red();
+
+    let builder = MessageBuilder::new()
+        .embed(embed)
+        .attachment("score_preview.png", bytes);
+
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Static PNG of a score for when it can't be rendered as a video.
+///
+/// Composites the map background, grade emote, mods, and pp. If the background
+/// is unavailable, a solid color is used instead.
+pub async fn score_preview(score: &ScoreSlim, map: &OsuMap) -> Result<Vec<u8>> {
+    let bg_fut = async {
+        let bytes = Context::client().get_mapset_cover(map.cover()).await?;
+
+        let cover =
+            image::load_from_memory(&bytes).wrap_err("Failed to load mapset cover from memory")?;
+
+        Ok::<_, Report>(cover.resize_to_fill(PREVIEW_W, PREVIEW_H, FilterType::Lanczos3))
+    };
+
+    let grade_fut = async {
+        let emote = BotConfig::get().grade(score.grade);
+        let emote_id = matcher::get_emote_id(emote).wrap_err("Missing grade emote id")?;
+        let bytes = Context::client().get_discord_emoji(emote_id).await?;
+
+        let grade =
+            image::load_from_memory(&bytes).wrap_err("Failed to load grade emote from memory")?;
+
+        Ok::<_, Report>(grade.resize(PREVIEW_GRADE_SIZE, PREVIEW_GRADE_SIZE, FilterType::Lanczos3))
+    };
+
+    let (bg_res, grade_res) = tokio::join!(bg_fut, grade_fut);
+
+    let mut canvas = match bg_res {
+        Ok(bg) => bg.blur(2.0).brighten(-80),
+        Err(err) => {
+            warn!(?err, "Failed to get score preview background");
+
+            let pixel = Rgba([19, 43, 33, 255]);
+
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(PREVIEW_W, PREVIEW_H, pixel))
+        }
+    };
+
+    match grade_res {
+        Ok(grade) => {
+            let x = (PREVIEW_W - grade.width() - 24) as i64;
+            let y = ((PREVIEW_H - grade.height()) / 2) as i64;
+            imageops::overlay(&mut canvas, &grade, x, y);
+        }
+        Err(err) => warn!(?err, "Failed to get grade emote for score preview"),
+    }
+
+    let mut surface = surfaces::raster_n32_premul((PREVIEW_W as i32, PREVIEW_H as i32))
+        .wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), PREVIEW_W, PREVIEW_H).into_drawing_area();
+
+        root.draw(&BitMapElement::new(canvas, (0, 0)))
+            .wrap_err("Failed to draw canvas")?;
+
+        let title_style = FontDesc::new(FontFamily::SansSerif, 28.0, FontStyle::Bold).color(&WHITE);
+        let text_style =
+            FontDesc::new(FontFamily::SansSerif, 22.0, FontStyle::Normal).color(&WHITE.mix(0.9));
+        let pp_style = FontDesc::new(FontFamily::SansSerif, 48.0, FontStyle::Bold)
+            .color(&RGBColor(255, 204, 34));
+
+        let title = format!("{} - {}", map.artist(), map.title());
+        let version = format!("[{}]", map.version());
+
+        let stats = format!(
+            "{acc:.2}% • {combo}x • {miss} miss • +{mods}",
+            acc = score.accuracy,
+            combo = score.max_combo,
+            miss = score.statistics.count_miss,
+            mods = ModsFormatter::new(&score.mods),
+        );
+
+        let pp = format!("{:.2}pp", score.pp);
+
+        root.draw_text(&title, &title_style, (24, 24))
+            .wrap_err("Failed to draw title")?;
+        root.draw_text(&version, &text_style, (24, 64))
+            .wrap_err("Failed to draw version")?;
+        root.draw_text(&stats, &text_style, (24, 120))
+            .wrap_err("Failed to draw score stats")?;
+        root.draw_text(&pp, &pp_style, (24, 168))
+            .wrap_err("Failed to draw pp")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
@@ -0,0 +1,176 @@
+use std::{borrow::Cow, iter};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_util::{
+    constants::OSU_API_ISSUE, matcher, numbers::WithComma, CowUtils, EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::OsuError;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use super::user_not_found;
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "scorerank",
+    desc = "Display a user's rank in the ranked score leaderboard",
+    help = "Display a user's rank in the ranked score leaderboard.\n\
+    The rank is provided by [respektive](https://github.com/respektive/osu-score-rank-api) \
+    and only available for the top 10,000 users."
+)]
+pub struct ScoreRank<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> ScoreRank<'m> {
+    fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            discord,
+        }
+    }
+}
+
+async fn slash_scorerank(mut command: InteractionCommand) -> Result<()> {
+    let args = ScoreRank::from_interaction(command.input_data())?;
+
+    score_rank((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Display a user's rank in the ranked score leaderboard")]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("sr")]
+#[group(Osu)]
+async fn prefix_scorerank(msg: &Message, args: Args<'_>) -> Result<()> {
+    score_rank(msg.into(), ScoreRank::args(None, args)).await
+}
+
+#[command]
+#[desc("Display a user's rank in the mania ranked score leaderboard")]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("srm")]
+#[group(Mania)]
+async fn prefix_scorerankmania(msg: &Message, args: Args<'_>) -> Result<()> {
+    score_rank(
+        msg.into(),
+        ScoreRank::args(Some(GameModeOption::Mania), args),
+    )
+    .await
+}
+
+#[command]
+#[desc("Display a user's rank in the taiko ranked score leaderboard")]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("srt")]
+#[group(Taiko)]
+async fn prefix_scoreranktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    score_rank(
+        msg.into(),
+        ScoreRank::args(Some(GameModeOption::Taiko), args),
+    )
+    .await
+}
+
+#[command]
+#[desc("Display a user's rank in the ctb ranked score leaderboard")]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("src", "scorerankcatch")]
+#[group(Catch)]
+async fn prefix_scorerankctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    score_rank(
+        msg.into(),
+        ScoreRank::args(Some(GameModeOption::Catch), args),
+    )
+    .await
+}
+
+async fn score_rank(orig: CommandOrigin<'_>, args: ScoreRank<'_>) -> Result<()> {
+    let (user_id, mode) = user_id_mode!(orig, args);
+
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get user"));
+        }
+    };
+
+    // Same source as the profile's score rank
+    let rank_fut = Context::client().get_respektive_users(iter::once(user.user_id()), mode);
+
+    let rank = match rank_fut.await {
+        Ok(mut iter) => iter.next().flatten().and_then(|user| user.rank),
+        Err(err) => {
+            warn!(?err, "Failed to get respektive user");
+
+            None
+        }
+    };
+
+    let description = match rank {
+        Some(rank) => format!(
+            "{name} is rank **#{rank}** with **{score}** ranked score",
+            name = user.username().cow_escape_markdown(),
+            rank = WithComma::new(rank.get()),
+            score = WithComma::new(user.stats().ranked_score()),
+        ),
+        None => format!(
+            "{name} has **{score}** ranked score, their score rank is unavailable.\n\
+            Score ranks are only provided for the top 10,000 users.",
+            name = user.username().cow_escape_markdown(),
+            score = WithComma::new(user.stats().ranked_score()),
+        ),
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .thumbnail(user.avatar_url().to_owned());
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
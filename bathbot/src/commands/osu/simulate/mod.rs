@@ -50,7 +50,11 @@ pub struct Simulate<'m> {
     combo: Option<u32>,
     #[command(min_value = 0.0, max_value = 100.0, desc = "Specify an accuracy")]
     acc: Option<f32>,
-    #[command(desc = "Specify a custom clock rate that overwrites mods")]
+    #[command(
+        min_value = 0.5,
+        max_value = 2.0,
+        desc = "Specify a custom clock rate that overwrites mods"
+    )]
     clock_rate: Option<f32>,
     #[command(desc = "Specify a BPM value instead of a clock rate")]
     bpm: Option<f32>,
@@ -110,7 +114,26 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         SimulateMap::Attached(ref map) => map.max_combo,
     };
 
-    let mods = match args.mods.map(|mods| mods.try_with_mode(mode)) {
+    if let Some(combo) = args.combo.filter(|&combo| combo > max_combo) {
+        let content =
+            format!("Combo must not exceed the map's max combo but {combo} > {max_combo}");
+
+        return orig.error(content).await;
+    }
+
+    let mods = match args.mods {
+        Some(mods) => Some(mods),
+        None => match Context::user_config().with_osu_id(orig.user_id()?).await {
+            Ok(config) => config.mods,
+            Err(err) => {
+                warn!(?err, "Failed to get user config for default mods");
+
+                None
+            }
+        },
+    };
+
+    let mods = match mods.map(|mods| mods.try_with_mode(mode)) {
         Some(mods @ Some(_)) => mods,
         None => None,
         Some(None) => {
@@ -453,10 +476,16 @@ impl SimulateArgs {
             }
 
             match SimulateArg::parse(arg).map_err(ParseError::into_str)? {
-                SimulateArg::Acc(val) => simulate.acc = Some(val.clamp(0.0, 100.0)),
+                SimulateArg::Acc(val) if (0.0..=100.0).contains(&val) => simulate.acc = Some(val),
+                SimulateArg::Acc(_) => return Err("Accuracy must be between 0 and 100".into()),
                 SimulateArg::Bpm(val) => simulate.bpm = Some(val),
                 SimulateArg::Combo(val) => simulate.combo = Some(val),
-                SimulateArg::ClockRate(val) => simulate.clock_rate = Some(val),
+                SimulateArg::ClockRate(val) if (0.5..=2.0).contains(&val) => {
+                    simulate.clock_rate = Some(val)
+                }
+                SimulateArg::ClockRate(_) => {
+                    return Err("Clock rate must be between 0.5 and 2.0".into())
+                }
                 SimulateArg::N300(val) => simulate.n300 = Some(val),
                 SimulateArg::N100(val) => simulate.n100 = Some(val),
                 SimulateArg::N50(val) => simulate.n50 = Some(val),
@@ -0,0 +1,238 @@
+use std::cmp::Reverse;
+
+use bathbot_model::{Countries, SnipeCountryPlayer, SnipeRecent};
+use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use eyre::{Report, Result};
+use futures::{stream, StreamExt, TryStreamExt};
+use time::{Duration, OffsetDateTime};
+
+use super::{country_target, SnipeCountryGains};
+use crate::{
+    active::{
+        impls::{SnipeCountryGainsPagination, SnipeNetGain},
+        ActiveMessages,
+    },
+    core::commands::CommandOrigin,
+    Context,
+};
+
+/// How many of the country's players with the most #1s are ranked. There is
+/// no country-wide feed so only their own gains and losses are requested.
+const TOP_PLAYERS: usize = 25;
+
+/// How many snipe feeds are requested at the same time
+const CONCURRENT_REQUESTS: usize = 5;
+
+pub(super) async fn country_gains(
+    orig: CommandOrigin<'_>,
+    args: SnipeCountryGains<'_>,
+) -> Result<()> {
+    let author_id = orig.user_id()?;
+
+    let SnipeCountryGains {
+        mode,
+        country,
+        weeks,
+    } = args;
+
+    let weeks = weeks.unwrap_or(1).clamp(1, 8);
+
+    let target = country_target(&orig, mode, country.as_deref()).await?;
+
+    let Some((osu_user, mode, country_code)) = target else {
+        return Ok(());
+    };
+
+    let client = Context::client();
+
+    let players = match Context::redis().snipe_country(&country_code, mode).await {
+        Ok(players) => players.into_original(),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get snipe country"));
+        }
+    };
+
+    let since = OffsetDateTime::now_utc() - Duration::weeks(weeks as i64);
+    let sampled = &players[..players.len().min(TOP_PLAYERS)];
+
+    let snipes_fut = stream::iter(sampled)
+        .map(|player| async move {
+            let gains_fut = client.get_national_snipes(player.user_id, true, since, mode);
+            let losses_fut = client.get_national_snipes(player.user_id, false, since, mode);
+            let (gains, losses) = tokio::try_join!(gains_fut, losses_fut)?;
+
+            Ok::<_, Report>((player, gains, losses))
+        })
+        .buffer_unordered(CONCURRENT_REQUESTS)
+        .try_collect::<Vec<_>>();
+
+    let snipes = match snipes_fut.await {
+        Ok(snipes) => snipes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get snipes"));
+        }
+    };
+
+    let gains = net_gains(
+        snipes
+            .iter()
+            .map(|(player, gains, losses)| (*player, gains.as_slice(), losses.as_slice())),
+    );
+
+    let country_name = Countries::code(&country_code).to_name();
+
+    if gains.is_empty() {
+        let content = format!(
+            "None of the top players of {country} sniped or got sniped \
+            within the last {weeks} week{plural}",
+            country = match country_name {
+                Some(ref name) => name.to_string(),
+                None => country_code.to_string(),
+            },
+            plural = if weeks == 1 { "" } else { "s" },
+        );
+
+        let builder = MessageBuilder::new().embed(content);
+        orig.create_message(builder).await?;
+
+        return Ok(());
+    }
+
+    let author_idx = osu_user.as_ref().and_then(|user| {
+        let user_id = user.user_id();
+
+        gains.iter().position(|player| player.user_id == user_id)
+    });
+
+    let pagination = SnipeCountryGainsPagination::builder()
+        .players(gains.into_boxed_slice())
+        .country_code(country_code)
+        .country_name(country_name)
+        .weeks(weeks)
+        .sampled(sampled.len())
+        .author_idx(author_idx)
+        .msg_owner(author_id)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Counts the gained and lost #1s of every player and sorts them by their net
+/// gain. Players without any snipes are skipped.
+fn net_gains<'s, I>(players: I) -> Vec<SnipeNetGain>
+where
+    I: IntoIterator<Item = (&'s SnipeCountryPlayer, &'s [SnipeRecent], &'s [SnipeRecent])>,
+{
+    let mut players: Vec<_> = players
+        .into_iter()
+        .filter(|(_, gains, losses)| !(gains.is_empty() && losses.is_empty()))
+        .map(|(player, gains, losses)| SnipeNetGain {
+            user_id: player.user_id,
+            username: Some(player.username.clone()),
+            gains: gains.len() as u32,
+            losses: losses.len() as u32,
+        })
+        .collect();
+
+    players.sort_unstable_by_key(|player| {
+        (Reverse(player.net()), Reverse(player.gains), player.user_id)
+    });
+
+    players
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::Username;
+
+    use super::*;
+
+    fn snipe(sniper: (u32, &str), sniped: Option<(u32, &str)>) -> SnipeRecent {
+        SnipeRecent {
+            map_id: 1,
+            user_id: sniper.0,
+            pp: None,
+            stars: None,
+            accuracy: 100.0,
+            date: None,
+            mods: None,
+            max_combo: None,
+            artist: Box::from("artist"),
+            title: Box::from("title"),
+            version: Box::from("version"),
+            sniper: Some(Username::from(sniper.1)),
+            sniper_id: sniper.0,
+            sniped: sniped.map(|(_, name)| Username::from(name)),
+            sniped_id: sniped.map(|(user_id, _)| user_id),
+        }
+    }
+
+    fn gain(user_id: u32, name: &str, gains: u32, losses: u32) -> SnipeNetGain {
+        SnipeNetGain {
+            user_id,
+            username: Some(Username::from(name)),
+            gains,
+            losses,
+        }
+    }
+
+    fn player(user_id: u32, name: &str) -> SnipeCountryPlayer {
+        SnipeCountryPlayer {
+            username: Username::from(name),
+            user_id,
+            avg_pp: None,
+            avg_sr: 5.0,
+            pp: 100.0,
+            count_first: 100,
+        }
+    }
+
+    #[test]
+    fn empty_window() {
+        let a = player(1, "a");
+        let players = [(&a, &[][..], &[][..])];
+
+        assert!(net_gains(players).is_empty());
+    }
+
+    #[test]
+    fn aggregate_net_gains() {
+        let a = (1, "a");
+        let b = (2, "b");
+        let c = (3, "c");
+        let d = (4, "d");
+
+        let (player_a, player_b, player_c, player_d) = (
+            player(1, "a"),
+            player(2, "b"),
+            player(3, "c"),
+            player(4, "d"),
+        );
+
+        // Snipes against or by players outside of the sample still count
+        let gains_a = [snipe(a, Some(b)), snipe(a, Some(b)), snipe(a, Some(d))];
+        let losses_a = [snipe(b, Some(a))];
+        let gains_b = [snipe(b, Some(a))];
+        let losses_b = [snipe(a, Some(b)), snipe(a, Some(b)), snipe(c, Some(b))];
+        let gains_c = [snipe(c, None), snipe(c, Some(b))];
+        let losses_c = [snipe(d, Some(c))];
+
+        let players = [
+            (&player_a, &gains_a[..], &losses_a[..]),
+            (&player_b, &gains_b[..], &losses_b[..]),
+            (&player_c, &gains_c[..], &losses_c[..]),
+            (&player_d, &[][..], &[][..]),
+        ];
+
+        let expected = vec![gain(1, "a", 3, 1), gain(3, "c", 2, 1), gain(2, "b", 1, 3)];
+
+        assert_eq!(net_gains(players), expected);
+    }
+}
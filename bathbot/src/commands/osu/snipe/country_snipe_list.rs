@@ -2,23 +2,17 @@ use std::borrow::Cow;
 
 use bathbot_macros::command;
 use bathbot_model::{Countries, SnipeCountryListOrder};
-use bathbot_util::{
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    CowUtils,
-};
-use eyre::{Report, Result};
-use rosu_v2::{
-    model::GameMode,
-    prelude::{CountryCode, OsuError},
-    request::UserId,
-};
+use bathbot_util::{constants::GENERAL_ISSUE, CowUtils};
+use eyre::Result;
+use rosu_v2::model::GameMode;
 
-use super::{SnipeCountryList, SnipeGameMode};
+use super::{country_target, SnipeCountryList, SnipeGameMode};
 use crate::{
-    active::{impls::SnipeCountryListPagination, ActiveMessages},
-    commands::osu::user_not_found,
+    active::{
+        impls::{SnipeCountryListColumns, SnipeCountryListPagination},
+        ActiveMessages,
+    },
     core::commands::{prefix::Args, CommandOrigin},
-    manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
     Context,
 };
@@ -34,12 +28,20 @@ use crate::{
      - `stars` to sort by average star rating of #1 scores\n\
      - `weighted` to sort by pp gained only from #1 scores\n\
     If no ordering is specified, it defaults to `count`.\n\
+    To only show players with at least a certain amount of #1s, provide `min=...`.\n\
+    To choose the shown columns, provide `columns=...` with a comma-separated list of \
+    `count`, `pp`, `stars`, and `weighted`.\n\
     If no country is specified either, I will take the country of the linked user.\n\
     Data for osu!standard originates from [Mr Helix](https://osu.ppy.sh/users/2330619)'s \
     [huismetbenen](https://snipe.huismetbenen.nl/)."
 )]
-#[usage("[country acronym] [sort=count/pp/stars/weighted]")]
-#[example("sort=stars", "fr sort=weighted", "sort=pp")]
+#[usage("[country acronym] [sort=count/pp/stars/weighted] [min=number] [columns=...]")]
+#[example(
+    "sort=stars",
+    "fr sort=weighted",
+    "fr min=50 sort=pp",
+    "columns=count,weighted"
+)]
 #[aliases("csl", "countrysnipeleaderboard", "cslb")]
 #[group(Osu)]
 async fn prefix_countrysnipelist(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -64,12 +66,20 @@ async fn prefix_countrysnipelist(msg: &Message, args: Args<'_>) -> Result<()> {
      - `stars` to sort by average star rating of #1 scores\n\
      - `weighted` to sort by pp gained only from #1 scores\n\
     If no ordering is specified, it defaults to `count`.\n\
+    To only show players with at least a certain amount of #1s, provide `min=...`.\n\
+    To choose the shown columns, provide `columns=...` with a comma-separated list of \
+    `count`, `pp`, `stars`, and `weighted`.\n\
     If no country is specified either, I will take the country of the linked user.\n\
     Data for osu!catch originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[country acronym] [sort=count/pp/stars/weighted]")]
-#[example("sort=stars", "fr sort=weighted", "sort=pp")]
+#[usage("[country acronym] [sort=count/pp/stars/weighted] [min=number] [columns=...]")]
+#[example(
+    "sort=stars",
+    "fr sort=weighted",
+    "fr min=50 sort=pp",
+    "columns=count,weighted"
+)]
 #[aliases(
     "cslc",
     "countrysnipelistcatch",
@@ -100,12 +110,20 @@ async fn prefix_countrysnipelistctb(msg: &Message, args: Args<'_>) -> Result<()>
      - `stars` to sort by average star rating of #1 scores\n\
      - `weighted` to sort by pp gained only from #1 scores\n\
     If no ordering is specified, it defaults to `count`.\n\
+    To only show players with at least a certain amount of #1s, provide `min=...`.\n\
+    To choose the shown columns, provide `columns=...` with a comma-separated list of \
+    `count`, `pp`, `stars`, and `weighted`.\n\
     If no country is specified either, I will take the country of the linked user.\n\
     Data for osu!mania originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[country acronym] [sort=count/pp/stars/weighted]")]
-#[example("sort=stars", "fr sort=weighted", "sort=pp")]
+#[usage("[country acronym] [sort=count/pp/stars/weighted] [min=number] [columns=...]")]
+#[example(
+    "sort=stars",
+    "fr sort=weighted",
+    "fr min=50 sort=pp",
+    "columns=count,weighted"
+)]
 #[aliases("cslm", "countrysnipeleaderboardmania", "cslbm")]
 #[group(Mania)]
 async fn prefix_countrysnipelistmania(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -129,84 +147,27 @@ pub(super) async fn country_list(
         mode,
         country,
         sort,
+        min,
+        columns,
     } = args;
 
-    let (osu_user, mode) = match Context::user_config().with_osu_id(author_id).await {
-        Ok(config) => {
-            let mode = match mode {
-                Some(mode) => mode.into(),
-                None => config.mode.unwrap_or(GameMode::Osu),
-            };
-
-            match config.osu {
-                Some(user_id) => {
-                    let user_args = UserArgs::user_id(user_id).mode(mode);
-
-                    match Context::redis().osu_user(user_args).await {
-                        Ok(user) => (Some(user), mode),
-                        Err(OsuError::NotFound) => {
-                            let content = user_not_found(UserId::Id(user_id)).await;
-
-                            return orig.error(content).await;
-                        }
-                        Err(err) => {
-                            let _ = orig.error(OSU_API_ISSUE).await;
-                            let err = Report::new(err).wrap_err("failed to get user");
-
-                            return Err(err);
-                        }
-                    }
-                }
-                None => (None, mode),
-            }
-        }
-        Err(err) => {
-            warn!("{err:?}");
-
-            (None, GameMode::Osu)
-        }
+    let columns = match columns.as_deref().map(str::parse) {
+        Some(Ok(columns)) => columns,
+        Some(Err(content)) => return orig.error(content).await,
+        None => SnipeCountryListColumns::default(),
     };
 
-    let country_code = match country {
-        Some(ref country) => match Countries::name(country).to_code() {
-            Some(code) => CountryCode::from(code),
-            None if country.len() == 2 => CountryCode::from(country.as_ref()),
-            None => {
-                let content =
-                    format!("Looks like `{country}` is neither a country name nor a country code");
+    let target = country_target(&orig, mode, country.as_deref()).await?;
 
-                return orig.error(content).await;
-            }
-        },
-        None => match &osu_user {
-            Some(RedisData::Original(user)) => user.country_code.as_str().into(),
-            Some(RedisData::Archive(user)) => user.country_code.as_str().into(),
-            None => {
-                let content = "Since you're not linked, you must specify a country (code)";
-
-                return orig.error(content).await;
-            }
-        },
+    let Some((osu_user, mode, country_code)) = target else {
+        return Ok(());
     };
 
-    // Check if huisemetbenen supports the country
-    if !Context::huismetbenen()
-        .is_supported(country_code.as_str(), mode)
-        .await
-    {
-        let content = format!("The country code `{country_code}` is not supported :(",);
-
-        return orig.error(content).await;
-    }
-
     let sort = sort.unwrap_or_default();
 
-    // Request players
-    let players = match Context::client()
-        .get_snipe_country(&country_code, sort, mode)
-        .await
-    {
-        Ok(players) => players,
+    // Request players; the cached list is shared by all orderings
+    let mut players = match Context::redis().snipe_country(&country_code, mode).await {
+        Ok(players) => players.into_original(),
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -214,6 +175,12 @@ pub(super) async fn country_list(
         }
     };
 
+    sort.sort_players(&mut players);
+
+    if let Some(min) = min {
+        players.retain(|player| player.count_first >= min);
+    }
+
     // Try to find author in list
     let author_idx = osu_user.as_ref().and_then(|user| {
         let author_name = user.username();
@@ -238,6 +205,7 @@ pub(super) async fn country_list(
         .players(players.into_boxed_slice())
         .country(country)
         .order(sort)
+        .columns(columns)
         .author_idx(author_idx)
         .msg_owner(author_id)
         .build();
@@ -252,8 +220,10 @@ impl<'m> SnipeCountryList<'m> {
     fn args(args: Args<'m>, mode: GameMode) -> Result<Self, Cow<'static, str>> {
         let mut country = None;
         let mut sort = None;
+        let mut min = None;
+        let mut columns = None;
 
-        for arg in args.take(2).map(CowUtils::cow_to_ascii_lowercase) {
+        for arg in args.take(4).map(CowUtils::cow_to_ascii_lowercase) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -273,9 +243,26 @@ impl<'m> SnipeCountryList<'m> {
                             }
                         };
                     }
+                    "min" => match value.parse::<u32>() {
+                        Ok(value) if value > 0 => min = Some(value),
+                        _ => {
+                            let content = "Failed to parse `min`. Must be a positive integer.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "columns" => {
+                        if let Err(content) = value.parse::<SnipeCountryListColumns>() {
+                            return Err(content.into());
+                        }
+
+                        columns = Some(value.to_owned());
+                    }
                     _ => {
-                        let content =
-                            format!("Unrecognized option `{key}`.\nAvailable options are: `sort`.");
+                        let content = format!(
+                            "Unrecognized option `{key}`.\n\
+                            Available options are: `sort`, `min`, or `columns`."
+                        );
 
                         return Err(content.into());
                     }
@@ -289,6 +276,62 @@ impl<'m> SnipeCountryList<'m> {
             mode: SnipeGameMode::try_from_mode(mode),
             country,
             sort,
+            min,
+            columns,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bathbot_model::SnipeCountryPlayer;
+    use rosu_v2::prelude::Username;
+
+    use super::*;
+    use crate::core::commands::prefix::ArgsNum;
+
+    fn player(user_id: u32, count_first: u32, avg_pp: Option<f32>) -> SnipeCountryPlayer {
+        SnipeCountryPlayer {
+            username: Username::from("player"),
+            user_id,
+            avg_pp,
+            avg_sr: user_id as f32,
+            pp: (count_first * 10) as f32,
+            count_first,
+        }
+    }
+
+    fn sorted_ids(sort: SnipeCountryListOrder) -> Vec<u32> {
+        // Cached players are ordered by count; any ordering must be derivable
+        let mut players = vec![
+            player(1, 300, None),
+            player(2, 200, Some(400.0)),
+            player(3, 100, Some(250.0)),
+        ];
+
+        sort.sort_players(&mut players);
+
+        players.into_iter().map(|player| player.user_id).collect()
+    }
+
+    #[test]
+    fn sort_cached_players() {
+        assert_eq!(sorted_ids(SnipeCountryListOrder::Count), [1, 2, 3]);
+        assert_eq!(sorted_ids(SnipeCountryListOrder::AvgPp), [2, 3, 1]);
+        assert_eq!(sorted_ids(SnipeCountryListOrder::AvgStars), [3, 2, 1]);
+        assert_eq!(sorted_ids(SnipeCountryListOrder::WeightedPp), [1, 2, 3]);
+    }
+
+    #[test]
+    fn prefix_args_min() {
+        let args = Args::new("fr min=50", ArgsNum::None);
+        let args = SnipeCountryList::args(args, GameMode::Osu).unwrap();
+        assert_eq!(args.min, Some(50));
+
+        let args = Args::new("min=0", ArgsNum::None);
+        assert!(SnipeCountryList::args(args, GameMode::Osu).is_err());
+
+        let args = Args::new("min=-5", ArgsNum::None);
+        assert!(SnipeCountryList::args(args, GameMode::Osu).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use plotters::style::{Color, RGBAColor, RGBColor, BLACK, WHITE};
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Dimensions and colors of the snipe graphs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GraphConfig {
+    pub width: u32,
+    pub height: u32,
+    pub theme: GraphTheme,
+}
+
+impl GraphConfig {
+    pub const DEFAULT_H: u32 = 350;
+    pub const DEFAULT_W: u32 = 1350;
+
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            theme: GraphTheme::default(),
+        }
+    }
+
+    /// Overwrite the width and height if they're specified.
+    pub fn dimensions(mut self, width: Option<u32>, height: Option<u32>) -> Self {
+        if let Some(width) = width {
+            self.width = width;
+        }
+
+        if let Some(height) = height {
+            self.height = height;
+        }
+
+        self
+    }
+
+    pub fn theme(mut self, theme: Option<GraphTheme>) -> Self {
+        if let Some(theme) = theme {
+            self.theme = theme;
+        }
+
+        self
+    }
+}
+
+impl Default for GraphConfig {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_W, Self::DEFAULT_H)
+    }
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Debug, Default, PartialEq, Eq)]
+pub enum GraphTheme {
+    #[default]
+    #[option(name = "Dark", value = "dark")]
+    Dark,
+    #[option(name = "Light", value = "light")]
+    Light,
+}
+
+impl GraphTheme {
+    pub fn colors(self) -> GraphColors {
+        match self {
+            Self::Dark => GraphColors {
+                background: RGBColor(19, 43, 33),
+                text: WHITE,
+                mesh: WHITE.mix(0.3),
+                axis: RGBColor(7, 18, 14),
+                legend_background: RGBColor(7, 23, 17),
+                legend_border: WHITE.mix(0.6),
+                area: RGBColor(2, 186, 213),
+                area_border: RGBColor(0, 208, 138),
+                line: RGBColor(255, 204, 34),
+                block_lightness: (0.5, 0.3),
+            },
+            Self::Light => GraphColors {
+                background: WHITE,
+                text: BLACK,
+                mesh: BLACK.mix(0.2),
+                axis: RGBColor(140, 140, 140),
+                legend_background: RGBColor(240, 240, 240),
+                legend_border: BLACK.mix(0.4),
+                area: RGBColor(0, 150, 180),
+                area_border: RGBColor(0, 120, 80),
+                line: RGBColor(220, 120, 0),
+                block_lightness: (0.6, 0.4),
+            },
+        }
+    }
+}
+
+pub struct GraphColors {
+    pub background: RGBColor,
+    pub text: RGBColor,
+    pub mesh: RGBAColor,
+    pub axis: RGBColor,
+    pub legend_background: RGBColor,
+    pub legend_border: RGBAColor,
+    pub area: RGBColor,
+    pub area_border: RGBColor,
+    pub line: RGBColor,
+    /// Lightness of the histogram blocks' fill and border
+    pub block_lightness: (f64, f64),
+}
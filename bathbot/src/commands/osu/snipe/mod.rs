@@ -1,20 +1,33 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{HasMods, HasName, SlashCommand};
-use bathbot_model::{SnipeCountryListOrder, SnipePlayerListOrder};
-use eyre::Result;
-use rosu_v2::model::GameMode;
+use bathbot_model::{rosu_v2::user::User, Countries, SnipeCountryListOrder, SnipePlayerListOrder};
+use bathbot_util::constants::OSU_API_ISSUE;
+use eyre::{Report, Result};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{CountryCode, OsuError},
+    request::UserId,
+};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
 pub use self::{
-    country_snipe_list::*, country_snipe_stats::*, player_snipe_list::*, player_snipe_stats::*,
-    sniped::*, sniped_difference::*,
+    country_snipe_gains::*, country_snipe_list::*, country_snipe_stats::*, graph_config::*,
+    player_snipe_list::*, player_snipe_stats::*, sniped::*, sniped_difference::*,
+};
+use crate::{
+    commands::osu::{default_mode, user_not_found},
+    core::commands::CommandOrigin,
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
 };
-use crate::util::{interaction::InteractionCommand, InteractionCommandExt};
 
+mod country_snipe_gains;
 mod country_snipe_list;
 mod country_snipe_stats;
+mod graph_config;
 mod player_snipe_list;
 mod sniped_difference;
 
@@ -40,6 +53,8 @@ pub enum Snipe<'a> {
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "country", desc = "Country related snipe stats")]
 pub enum SnipeCountry<'a> {
+    #[command(name = "gains")]
+    Gains(SnipeCountryGains<'a>),
     #[command(name = "list")]
     List(SnipeCountryList<'a>),
     #[command(name = "stats")]
@@ -97,6 +112,36 @@ pub struct SnipeCountryList<'a> {
         would have if only their #1s would count towards it."
     )]
     sort: Option<SnipeCountryListOrder>,
+    #[command(min_value = 1, desc = "Only show players with at least this many #1s")]
+    min: Option<u32>,
+    #[command(
+        desc = "Specify which columns to show e.g. `count,weighted`",
+        help = "Specify which columns to show as a comma-separated list.\n\
+        Available columns are `count`, `pp`, `stars`, and `weighted`.\n\
+        If not specified, all columns will be shown."
+    )]
+    columns: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "gains",
+    desc = "Rank a country's players by their net #1 gains",
+    help = "Rank players of a country by the amount of national #1s they gained minus \
+    the amount they lost within the last few weeks.\n\
+    Only the country's top 25 players in terms of #1 count are ranked."
+)]
+pub struct SnipeCountryGains<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<SnipeGameMode>,
+    #[command(desc = "Specify a country (code)")]
+    country: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 1,
+        max_value = 8,
+        desc = "Specify the amount of weeks to consider, defaults to 1"
+    )]
+    weeks: Option<u32>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -203,6 +248,26 @@ pub struct SnipePlayerStats<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Choose the color theme of the graph")]
+    theme: Option<GraphTheme>,
+    #[command(
+        min_value = 400,
+        max_value = 2700,
+        desc = "Specify the width of the graph in pixels"
+    )]
+    width: Option<u32>,
+    #[command(
+        min_value = 200,
+        max_value = 1400,
+        desc = "Specify the height of the graph in pixels"
+    )]
+    height: Option<u32>,
+    #[command(
+        desc = "Also show how many #1s were gained since this date (YYYY-MM-DD)",
+        help = "Specify a date of the form `YYYY-MM-DD` to additionally show \
+        how many national #1s the user gained since then."
+    )]
+    since: Option<Cow<'a, str>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName, SlashCommand)]
@@ -223,10 +288,27 @@ pub struct SnipePlayerSniped<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Choose the color theme of the graph")]
+    theme: Option<GraphTheme>,
+    #[command(
+        min_value = 400,
+        max_value = 2700,
+        desc = "Specify the width of the graph in pixels"
+    )]
+    width: Option<u32>,
+    #[command(
+        min_value = 200,
+        max_value = 1400,
+        desc = "Specify the height of the graph in pixels"
+    )]
+    height: Option<u32>,
 }
 
 async fn slash_snipe(mut command: InteractionCommand) -> Result<()> {
     match Snipe::from_interaction(command.input_data())? {
+        Snipe::Country(SnipeCountry::Gains(args)) => {
+            country_gains((&mut command).into(), args).await
+        }
         Snipe::Country(SnipeCountry::List(args)) => country_list((&mut command).into(), args).await,
         Snipe::Country(SnipeCountry::Stats(args)) => {
             country_stats((&mut command).into(), args).await
@@ -246,3 +328,94 @@ async fn slash_snipeplayersniped(mut command: InteractionCommand) -> Result<()>
 
     player_sniped((&mut command).into(), args).await
 }
+
+/// Resolves the author's osu! user, the mode, and the country which defaults
+/// to the author's country. Also checks whether the country is supported.
+///
+/// Returns `None` if the author was already notified about an issue.
+async fn country_target(
+    orig: &CommandOrigin<'_>,
+    mode: Option<SnipeGameMode>,
+    country: Option<&str>,
+) -> Result<Option<(Option<RedisData<User>>, GameMode, CountryCode)>> {
+    let author_id = orig.user_id()?;
+
+    let (osu_user, mode) = match Context::user_config().with_osu_id(author_id).await {
+        Ok(config) => {
+            let mode = match mode {
+                Some(mode) => mode.into(),
+                None => default_mode(orig, config.mode).await,
+            };
+
+            match config.osu {
+                Some(user_id) => {
+                    let user_args = UserArgs::user_id(user_id).mode(mode);
+
+                    match Context::redis().osu_user(user_args).await {
+                        Ok(user) => (Some(user), mode),
+                        Err(OsuError::NotFound) => {
+                            let content = user_not_found(UserId::Id(user_id)).await;
+                            orig.error(content).await?;
+
+                            return Ok(None);
+                        }
+                        Err(err) => {
+                            let _ = orig.error(OSU_API_ISSUE).await;
+                            let err = Report::new(err).wrap_err("failed to get user");
+
+                            return Err(err);
+                        }
+                    }
+                }
+                None => (None, mode),
+            }
+        }
+        Err(err) => {
+            warn!("{err:?}");
+
+            let mode = match mode {
+                Some(mode) => mode.into(),
+                None => default_mode(orig, None).await,
+            };
+
+            (None, mode)
+        }
+    };
+
+    let country_code = match country {
+        Some(country) => match Countries::name(country).to_code() {
+            Some(code) => CountryCode::from(code),
+            None if country.len() == 2 => CountryCode::from(country),
+            None => {
+                let content =
+                    format!("Looks like `{country}` is neither a country name nor a country code");
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+        },
+        None => match &osu_user {
+            Some(RedisData::Original(user)) => user.country_code.as_str().into(),
+            Some(RedisData::Archive(user)) => user.country_code.as_str().into(),
+            None => {
+                let content = "Since you're not linked, you must specify a country (code)";
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+        },
+    };
+
+    // Check if huisemetbenen supports the country
+    if !Context::huismetbenen()
+        .is_supported(country_code.as_str(), mode)
+        .await
+    {
+        let content = format!("The country code `{country_code}` is not supported :(",);
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    Ok(Some((osu_user, mode, country_code)))
+}
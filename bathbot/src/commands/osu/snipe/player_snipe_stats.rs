@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use bathbot_macros::command;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    datetime::DATE_FORMAT,
     matcher, MessageBuilder,
 };
 use eyre::{ContextCompat, Report, Result, WrapErr};
@@ -10,12 +11,12 @@ use plotters::prelude::*;
 use plotters_skia::SkiaBackend;
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use skia_safe::{surfaces, EncodedImageFormat};
-use time::Date;
+use time::{Date, OffsetDateTime};
 use twilight_model::guild::Permissions;
 
-use super::{SnipeGameMode, SnipePlayerStats};
+use super::{GraphConfig, SnipeGameMode, SnipePlayerStats};
 use crate::{
-    commands::osu::require_link,
+    commands::osu::{default_mode, require_link},
     core::commands::{prefix::Args, CommandOrigin},
     embeds::{EmbedData, PlayerSnipeStatsEmbed},
     manager::redis::{osu::UserArgs, RedisData},
@@ -27,11 +28,12 @@ use crate::{
 #[desc("Stats about a user's #1 scores in their country leaderboards")]
 #[help(
     "Stats about a user's #1 scores in their country leaderboards.\n\
+    To also show how many #1s were gained since a date, specify `since=YYYY-MM-DD`.\n\
     Data for osu!standard originates from [Mr Helix](https://osu.ppy.sh/users/2330619)'s \
     [huismetbenen](https://snipe.huismetbenen.nl/)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=YYYY-MM-DD]")]
+#[example("badewanne3", "badewanne3 since=2024-01-01")]
 #[alias("pss")]
 #[group(Osu)]
 async fn prefix_playersnipestats(
@@ -48,11 +50,12 @@ async fn prefix_playersnipestats(
 #[desc("Stats about a user's #1 ctb scores in their country leaderboards")]
 #[help(
     "Stats about a user's #1 ctb scores in their country leaderboards.\n\
+    To also show how many #1s were gained since a date, specify `since=YYYY-MM-DD`.\n\
     Data for osu!catch originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=YYYY-MM-DD]")]
+#[example("badewanne3", "badewanne3 since=2024-01-01")]
 #[alias("pssc", "playersnipestatscatch")]
 #[group(Catch)]
 async fn prefix_playersnipestatsctb(
@@ -69,11 +72,12 @@ async fn prefix_playersnipestatsctb(
 #[desc("Stats about a user's #1 mania scores in their country leaderboards")]
 #[help(
     "Stats about a user's #1 mania scores in their country leaderboards.\n\
+    To also show how many #1s were gained since a date, specify `since=YYYY-MM-DD`.\n\
     Data for osu!mania originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=YYYY-MM-DD]")]
+#[example("badewanne3", "badewanne3 since=2024-01-01")]
 #[alias("pssm")]
 #[group(Mania)]
 async fn prefix_playersnipestatsmania(
@@ -90,6 +94,12 @@ pub(super) async fn player_stats(
     orig: CommandOrigin<'_>,
     args: SnipePlayerStats<'_>,
 ) -> Result<()> {
+    let since = match args.since.as_deref().map(parse_since) {
+        Some(Ok(since)) => Some(since),
+        Some(Err(content)) => return orig.error(content).await,
+        None => None,
+    };
+
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
@@ -101,11 +111,10 @@ pub(super) async fn player_stats(
         },
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
 
     let legacy_scores = match config.legacy_scores {
         Some(legacy_scores) => legacy_scores,
@@ -195,7 +204,14 @@ pub(super) async fn player_stats(
         }
     };
 
-    let graph = match graphs(&history, &player.count_sr_spread, W, H) {
+    let gained_since = since.map(|since| (since, gained_since(&history, since)));
+
+    let graph_config = GraphConfig::default()
+        .dimensions(args.width, args.height)
+        .theme(args.theme);
+    let graph_fut = graphs(history, player.count_sr_spread.clone(), graph_config);
+
+    let graph = match graph_fut.await {
         Ok(graph) => Some(graph),
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -227,7 +243,7 @@ pub(super) async fn player_stats(
         None
     };
 
-    let embed = PlayerSnipeStatsEmbed::new(&user, player, oldest.as_ref())
+    let embed = PlayerSnipeStatsEmbed::new(&user, player, oldest.as_ref(), gained_since)
         .await
         .build();
 
@@ -242,15 +258,31 @@ pub(super) async fn player_stats(
     Ok(())
 }
 
-const W: u32 = 1350;
-const H: u32 = 350;
+/// Draws the graphs on a blocking thread so the rendering and encoding don't
+/// stall the runtime.
+pub async fn graphs(
+    history: BTreeMap<Date, u32>,
+    stars: BTreeMap<i8, u32>,
+    config: GraphConfig,
+) -> Result<Vec<u8>> {
+    tokio::task::spawn_blocking(move || draw_graphs(&history, &stars, config))
+        .await
+        .wrap_err("Graph task panicked")?
+}
 
-pub fn graphs(
+fn draw_graphs(
     history: &BTreeMap<Date, u32>,
     stars: &BTreeMap<i8, u32>,
-    w: u32,
-    h: u32,
+    config: GraphConfig,
 ) -> Result<Vec<u8>> {
+    let GraphConfig {
+        width: w,
+        height: h,
+        theme,
+    } = config;
+
+    let colors = theme.colors();
+
     let mut surface =
         surfaces::raster_n32_premul((w as i32, h as i32)).wrap_err("Failed to create surface")?;
 
@@ -263,13 +295,14 @@ pub fn graphs(
     {
         let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
-        root.fill(&background)
+        root.fill(&colors.background)
             .wrap_err("failed to fill background")?;
 
         let star_canvas = if history.len() > 1 {
             let (left, right) = root.split_horizontally(3 * w / 5);
 
+            let gains = cumulative_gains(history);
+
             let (min, max) = history
                 .iter()
                 .map(|(_, n)| *n)
@@ -277,6 +310,9 @@ pub fn graphs(
                     (min.min(curr), max.max(curr))
                 });
 
+            // The gains never go below the count so they only affect the max
+            let max = gains.last().map_or(max, |(_, n)| max.max(*n));
+
             let min = match min < 20 {
                 true => 0,
                 false => min - min / 11,
@@ -287,7 +323,10 @@ pub fn graphs(
 
             let mut chart = ChartBuilder::on(&left)
                 .margin(9)
-                .caption("National #1 Count History", ("sans-serif", 30, &WHITE))
+                .caption(
+                    "National #1 Count History",
+                    ("sans-serif", 30, &colors.text),
+                )
                 .x_label_area_size(20)
                 .y_label_area_size(40)
                 .build_cartesian_2d(Monthly(first..last), min..max + 1)
@@ -299,21 +338,41 @@ pub fn graphs(
                 .disable_x_mesh()
                 .x_labels(8)
                 .x_label_formatter(&|d| format!("{}-{}", d.year(), d.month() as u8))
-                .label_style(("sans-serif", 15, &WHITE))
-                .bold_line_style(WHITE.mix(0.3))
-                .axis_style(RGBColor(7, 18, 14))
-                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+                .label_style(("sans-serif", 15, &colors.text))
+                .bold_line_style(colors.mesh)
+                .axis_style(colors.axis)
+                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &colors.text))
                 .draw()
                 .wrap_err("failed to draw left mesh")?;
 
             // Draw area
             let iter = history.iter().map(|(date, n)| (*date, *n));
-            let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-            let border_style = style(RGBColor(0, 208, 138)).stroke_width(3);
+            let area_style = colors.area.mix(0.7).filled();
+            let border_style = style(colors.area_border).stroke_width(3);
             let series = AreaSeries::new(iter, 0, area_style).border_style(border_style);
             chart
                 .draw_series(series)
-                .wrap_err("failed to draw left series")?;
+                .wrap_err("failed to draw left series")?
+                .label("National #1s")
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 15, y + 5)], area_style));
+
+            // Draw cumulative gains
+            let line_style = style(colors.line).stroke_width(2);
+            let series = LineSeries::new(gains, line_style);
+            chart
+                .draw_series(series)
+                .wrap_err("failed to draw gains series")?
+                .label("Total gained")
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 15, y)], line_style));
+
+            chart
+                .configure_series_labels()
+                .border_style(colors.legend_border.stroke_width(1))
+                .background_style(colors.legend_background)
+                .position(SeriesLabelPosition::UpperLeft)
+                .label_font(("sans-serif", 15, FontStyle::Bold, &colors.text))
+                .draw()
+                .wrap_err("failed to draw left legend")?;
 
             right
         } else {
@@ -339,7 +398,7 @@ pub fn graphs(
             .x_label_area_size(30)
             .y_label_area_size(40)
             .margin_right(15)
-            .caption("Star rating spread", ("sans-serif", 30, &WHITE))
+            .caption("Star rating spread", ("sans-serif", 30, &colors.text))
             .build_cartesian_2d((first..last).into_segmented(), 0..max + 1)
             .wrap_err("failed to build right chart")?;
 
@@ -348,15 +407,15 @@ pub fn graphs(
             .configure_mesh()
             .disable_x_mesh()
             .x_labels(15)
-            .label_style(("sans-serif", 15, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 15, &colors.text))
+            .bold_line_style(colors.mesh)
+            .axis_style(colors.axis)
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &colors.text))
             .draw()
             .wrap_err("failed to draw right mesh")?;
 
         // Histogram bars
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
+        let area_style = colors.area.mix(0.7).filled();
 
         let iter = stars
             .iter()
@@ -382,15 +441,66 @@ pub fn graphs(
     Ok(png_bytes)
 }
 
+/// Accumulates all increases of the count while ignoring decreases i.e. the
+/// total amount of national #1s that were gained up to each date.
+fn cumulative_gains(history: &BTreeMap<Date, u32>) -> Vec<(Date, u32)> {
+    let mut prev = None;
+    let mut total = 0;
+
+    history
+        .iter()
+        .map(|(date, &count)| {
+            total += prev.map_or(count, |prev| count.saturating_sub(prev));
+            prev = Some(count);
+
+            (*date, total)
+        })
+        .collect()
+}
+
+/// How many #1s were gained after the given date, ignoring losses.
+///
+/// Dates before the history count from its first entry since the count
+/// at that point was not necessarily gained after `since`.
+fn gained_since(history: &BTreeMap<Date, u32>, since: Date) -> u32 {
+    let gains = cumulative_gains(history);
+    let total = gains.last().map_or(0, |(_, n)| *n);
+
+    let before = gains
+        .iter()
+        .take_while(|(date, _)| *date <= since)
+        .last()
+        .or(gains.first())
+        .map_or(0, |(_, n)| *n);
+
+    total - before
+}
+
+fn parse_since(since: &str) -> Result<Date, &'static str> {
+    let date = Date::parse(since.trim(), &DATE_FORMAT)
+        .map_err(|_| "Failed to parse date, it must be of the form `YYYY-MM-DD`")?;
+
+    if date > OffsetDateTime::now_utc().date() {
+        return Err("The date must not be in the future");
+    }
+
+    Ok(date)
+}
+
 impl<'m> SnipePlayerStats<'m> {
-    fn args(mut args: Args<'m>, mode: Option<GameMode>) -> Self {
+    fn args(args: Args<'m>, mode: Option<GameMode>) -> Self {
         let mut name = None;
         let mut discord = None;
-
-        if let Some(arg) = args.next() {
-            match matcher::get_mention_user(arg) {
-                Some(id) => discord = Some(id),
-                None => name = Some(arg.into()),
+        let mut since = None;
+
+        for arg in args {
+            if let Some(value) = arg.strip_prefix("since=") {
+                since = Some(value.into());
+            } else if name.is_none() && discord.is_none() {
+                match matcher::get_mention_user(arg) {
+                    Some(id) => discord = Some(id),
+                    None => name = Some(arg.into()),
+                }
             }
         }
 
@@ -398,6 +508,88 @@ impl<'m> SnipePlayerStats<'m> {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            theme: None,
+            width: None,
+            height: None,
+            since,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+    use crate::commands::osu::GraphTheme;
+
+    #[tokio::test]
+    async fn graph_dimensions_follow_config() {
+        let date = |day| Date::from_calendar_date(2024, Month::May, day).unwrap();
+        let history: BTreeMap<_, _> = [(date(1), 10), (date(8), 14)].into_iter().collect();
+        let stars: BTreeMap<_, _> = [(2, 3), (5, 8)].into_iter().collect();
+
+        let configs = [
+            GraphConfig::default(),
+            GraphConfig::new(800, 400).theme(Some(GraphTheme::Light)),
+            GraphConfig::new(600, 300).theme(Some(GraphTheme::Dark)),
+            GraphConfig::default().dimensions(Some(1000), None),
+        ];
+
+        for config in configs {
+            let bytes = graphs(history.clone(), stars.clone(), config)
+                .await
+                .unwrap();
+            let img = image::load_from_memory(&bytes).unwrap();
+
+            assert_eq!(img.width(), config.width);
+            assert_eq!(img.height(), config.height);
+        }
+    }
+
+    #[test]
+    fn gains_ignore_losses() {
+        let date = |day| Date::from_calendar_date(2024, Month::May, day).unwrap();
+
+        let history: BTreeMap<_, _> = [(1, 10), (2, 12), (3, 7), (4, 9), (5, 9)]
+            .into_iter()
+            .map(|(day, count)| (date(day), count))
+            .collect();
+
+        let gains: Vec<_> = cumulative_gains(&history)
+            .into_iter()
+            .map(|(_, n)| n)
+            .collect();
+
+        assert_eq!(gains, [10, 12, 12, 14, 14]);
+    }
+
+    #[test]
+    fn gains_since_date() {
+        let date = |day| Date::from_calendar_date(2024, Month::May, day).unwrap();
+
+        let history: BTreeMap<_, _> = [(1, 10), (3, 12), (5, 7), (7, 9), (9, 15)]
+            .into_iter()
+            .map(|(day, count)| (date(day), count))
+            .collect();
+
+        assert_eq!(gained_since(&history, date(3)), 8);
+        assert_eq!(gained_since(&history, date(4)), 8);
+        assert_eq!(gained_since(&history, date(8)), 6);
+        assert_eq!(gained_since(&history, date(9)), 0);
+        assert_eq!(gained_since(&BTreeMap::new(), date(1)), 0);
+
+        let before_history = Date::from_calendar_date(2024, Month::April, 1).unwrap();
+        assert_eq!(gained_since(&history, before_history), 10);
+    }
+
+    #[test]
+    fn parse_since_date() {
+        let expected = Date::from_calendar_date(2024, Month::January, 15).unwrap();
+
+        assert_eq!(parse_since("2024-01-15"), Ok(expected));
+        assert!(parse_since("15.01.2024").is_err());
+        assert!(parse_since("2024-13-01").is_err());
+        assert!(parse_since("9999-01-01").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops;
+use std::{mem, ops};
 
 use bathbot_macros::command;
 use bathbot_model::SnipedWeek;
@@ -22,7 +22,7 @@ use skia_safe::{surfaces, EncodedImageFormat};
 use time::Date;
 use twilight_model::guild::Permissions;
 
-use super::{SnipeGameMode, SnipePlayerSniped};
+use super::{GraphColors, GraphConfig, SnipeGameMode, SnipePlayerSniped};
 use crate::{
     core::commands::{prefix::Args, CommandOrigin},
     embeds::{EmbedData, SnipedEmbed},
@@ -97,6 +97,10 @@ pub(super) async fn player_sniped(
     orig: CommandOrigin<'_>,
     args: SnipePlayerSniped<'_>,
 ) -> Result<()> {
+    let config = GraphConfig::default()
+        .dimensions(args.width, args.height)
+        .theme(args.theme);
+
     let (user_id, mode) = user_id_mode!(orig, args);
     let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
 
@@ -154,7 +158,7 @@ pub(super) async fn player_sniped(
         return orig.error(content).await;
     };
 
-    let graph = match graphs(username, &mut sniper, &mut snipee, W, H) {
+    let graph = match graphs(username, &mut sniper, &mut snipee, config).await {
         Ok(graph_option) => graph_option,
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -175,9 +179,6 @@ pub(super) async fn player_sniped(
     Ok(())
 }
 
-const W: u32 = 1350;
-const H: u32 = 350;
-
 fn accumulate_counts(weeks: &mut [SnipedWeek]) {
     for week in weeks {
         for i in (1..week.players.len()).rev() {
@@ -186,37 +187,76 @@ fn accumulate_counts(weeks: &mut [SnipedWeek]) {
     }
 }
 
-pub fn graphs(
+/// Draws the graphs on a blocking thread so the rendering and encoding don't
+/// stall the runtime.
+///
+/// The weeks are moved into the blocking task and handed back afterwards with
+/// their counts accumulated.
+pub async fn graphs(
     name: &str,
-    sniper: &mut [SnipedWeek],
-    snipee: &mut [SnipedWeek],
-    w: u32,
-    h: u32,
+    sniper: &mut Vec<SnipedWeek>,
+    snipee: &mut Vec<SnipedWeek>,
+    config: GraphConfig,
 ) -> Result<Option<Vec<u8>>> {
     if sniper.is_empty() && snipee.is_empty() {
         return Ok(None);
     }
 
+    let name = name.to_owned();
+    let mut sniper_ = mem::take(sniper);
+    let mut snipee_ = mem::take(snipee);
+
+    let (res, sniper_, snipee_) = tokio::task::spawn_blocking(move || {
+        let res = draw_graphs(&name, &mut sniper_, &mut snipee_, config);
+
+        (res, sniper_, snipee_)
+    })
+    .await
+    .wrap_err("Graph task panicked")?;
+
+    *sniper = sniper_;
+    *snipee = snipee_;
+
+    res.map(Some)
+}
+
+fn draw_graphs(
+    name: &str,
+    sniper: &mut [SnipedWeek],
+    snipee: &mut [SnipedWeek],
+    config: GraphConfig,
+) -> Result<Vec<u8>> {
     accumulate_counts(sniper);
     accumulate_counts(snipee);
 
+    let GraphConfig {
+        width: w,
+        height: h,
+        theme,
+    } = config;
+
+    let colors = theme.colors();
+
     let mut surface =
         surfaces::raster_n32_premul((w as i32, h as i32)).wrap_err("Failed to create surface")?;
 
     {
         let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
-        root.fill(&background)
+        root.fill(&colors.background)
             .wrap_err("failed to fill background")?;
 
         match (sniper.is_empty(), snipee.is_empty()) {
-            (false, true) => draw_sniper(&root, name, sniper).wrap_err("failed to draw sniper")?,
-            (true, false) => draw_snipee(&root, name, snipee).wrap_err("failed to draw snipee")?,
+            (false, true) => {
+                draw_sniper(&root, name, sniper, &colors).wrap_err("failed to draw sniper")?
+            }
+            (true, false) => {
+                draw_snipee(&root, name, snipee, &colors).wrap_err("failed to draw snipee")?
+            }
             (false, false) => {
                 let (left, right) = root.split_horizontally(w / 2);
-                draw_sniper(&left, name, sniper).wrap_err("failed to draw sniper")?;
-                draw_snipee(&right, name, snipee).wrap_err("failed to draw snipee")?
+                draw_sniper(&left, name, sniper, &colors).wrap_err("failed to draw sniper")?;
+                draw_snipee(&right, name, snipee, &colors).wrap_err("failed to draw snipee")?
             }
             (true, true) => unreachable!(),
         }
@@ -228,7 +268,7 @@ pub fn graphs(
         .wrap_err("Failed to encode image")?
         .to_vec();
 
-    Ok(Some(png_bytes))
+    Ok(png_bytes)
 }
 
 type ContextType<'a> = Cartesian2d<SegmentedCoord<SnipedWeeksCoord<'a>>, RangedCoordu32>;
@@ -237,6 +277,7 @@ fn draw_sniper<DB: DrawingBackend>(
     root: &DrawingArea<DB, Shift>,
     name: &str,
     sniper: &[SnipedWeek],
+    colors: &GraphColors,
 ) -> Result<()> {
     let max = sniper[0].players[0].count;
 
@@ -244,14 +285,18 @@ fn draw_sniper<DB: DrawingBackend>(
         .x_label_area_size(30)
         .y_label_area_size(35)
         .margin_right(5)
-        .caption(format!("Sniped by {name}"), ("sans-serif", 25, &WHITE))
+        .caption(
+            format!("Sniped by {name}"),
+            ("sans-serif", 25, &colors.text),
+        )
         .build_cartesian_2d(SnipedWeeksCoord::new(sniper).into_segmented(), 0..max + 1)
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to build chart")?;
 
-    draw_mesh(&mut chart)?;
-    draw_histogram_blocks(sniper, &mut chart).wrap_err("Failed to draw histogram blocks")?;
-    draw_legend(&mut chart)?;
+    draw_mesh(&mut chart, colors)?;
+    draw_histogram_blocks(sniper, &mut chart, colors)
+        .wrap_err("Failed to draw histogram blocks")?;
+    draw_legend(&mut chart, colors)?;
 
     Ok(())
 }
@@ -260,6 +305,7 @@ fn draw_snipee<DB: DrawingBackend>(
     root: &DrawingArea<DB, Shift>,
     name: &str,
     snipee: &[SnipedWeek],
+    colors: &GraphColors,
 ) -> Result<()> {
     let max = snipee[0].players[0].count;
 
@@ -267,19 +313,23 @@ fn draw_snipee<DB: DrawingBackend>(
         .x_label_area_size(30)
         .y_label_area_size(35)
         .margin_right(5)
-        .caption(format!("Sniped {name}"), ("sans-serif", 25, &WHITE))
+        .caption(format!("Sniped {name}"), ("sans-serif", 25, &colors.text))
         .build_cartesian_2d(SnipedWeeksCoord::new(snipee).into_segmented(), 0..max + 1)
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to build chart")?;
 
-    draw_mesh(&mut chart)?;
-    draw_histogram_blocks(snipee, &mut chart).wrap_err("Failed to draw histogram blocks")?;
-    draw_legend(&mut chart)?;
+    draw_mesh(&mut chart, colors)?;
+    draw_histogram_blocks(snipee, &mut chart, colors)
+        .wrap_err("Failed to draw histogram blocks")?;
+    draw_legend(&mut chart, colors)?;
 
     Ok(())
 }
 
-fn draw_mesh<DB: DrawingBackend>(chart: &mut ChartContext<'_, DB, ContextType<'_>>) -> Result<()> {
+fn draw_mesh<DB: DrawingBackend>(
+    chart: &mut ChartContext<'_, DB, ContextType<'_>>,
+    colors: &GraphColors,
+) -> Result<()> {
     chart
         .configure_mesh()
         .disable_x_mesh()
@@ -289,10 +339,10 @@ fn draw_mesh<DB: DrawingBackend>(chart: &mut ChartContext<'_, DB, ContextType<'_
             }
             _ => unreachable!(),
         })
-        .label_style(("sans-serif", 15, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 15, &colors.text))
+        .bold_line_style(colors.mesh)
+        .axis_style(colors.axis)
+        .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &colors.text))
         .draw()
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to draw mesh")
@@ -301,7 +351,10 @@ fn draw_mesh<DB: DrawingBackend>(chart: &mut ChartContext<'_, DB, ContextType<'_
 fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
     weeks: &'a [SnipedWeek],
     chart: &mut ChartContext<'a, DB, ContextType<'a>>,
+    colors: &GraphColors,
 ) -> Result<()> {
+    let (fill_lightness, border_lightness) = colors.block_lightness;
+
     for (i, player) in weeks[0].players.iter().enumerate() {
         let count_iter = || {
             weeks.iter().rev().filter_map(|week| {
@@ -315,7 +368,7 @@ fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
         };
 
         // Draw block
-        let color = HSLColor(i as f64 * 0.1, 0.5, 0.5);
+        let color = HSLColor(i as f64 * 0.1, 0.5, fill_lightness);
 
         let series = Histogram::vertical(chart)
             .data(count_iter())
@@ -329,7 +382,7 @@ fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
             .legend(move |(x, y)| Circle::new((x, y), 4, color.filled()));
 
         // Draw border
-        let color = HSLColor(i as f64 * 0.1, 0.5, 0.3);
+        let color = HSLColor(i as f64 * 0.1, 0.5, border_lightness);
 
         let series = Histogram::vertical(chart).data(count_iter()).style(color);
 
@@ -344,14 +397,15 @@ fn draw_histogram_blocks<'a, DB: DrawingBackend + 'a>(
 
 fn draw_legend<'a, DB: DrawingBackend + 'a>(
     chart: &mut ChartContext<'a, DB, ContextType<'_>>,
+    colors: &GraphColors,
 ) -> Result<()> {
     chart
         .configure_series_labels()
-        .border_style(WHITE.mix(0.6).stroke_width(1))
-        .background_style(RGBColor(7, 23, 17))
+        .border_style(colors.legend_border.stroke_width(1))
+        .background_style(colors.legend_background)
         .position(SeriesLabelPosition::UpperLeft)
         .legend_area_size(13)
-        .label_font(("sans-serif", 15, FontStyle::Bold, &WHITE))
+        .label_font(("sans-serif", 15, FontStyle::Bold, &colors.text))
         .draw()
         .map_err(|e| Report::msg(e.to_string()))
         .wrap_err("Failed to draw legend")
@@ -455,6 +509,72 @@ impl<'m> SnipePlayerSniped<'m> {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            theme: None,
+            width: None,
+            height: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_model::SnipedPlayer;
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    fn week(counts: &[u32]) -> SnipedWeek {
+        let now = OffsetDateTime::now_utc();
+
+        SnipedWeek {
+            from: now - time::Duration::WEEK,
+            until: now,
+            players: counts
+                .iter()
+                .zip(["a", "b", "c"])
+                .map(|(&count, name)| SnipedPlayer {
+                    username: name.into(),
+                    count,
+                })
+                .collect(),
+            total: counts.iter().sum(),
+            unique: counts.len() as u32,
         }
     }
+
+    #[tokio::test]
+    async fn no_weeks_no_graph() {
+        let graph = graphs(
+            "name",
+            &mut Vec::new(),
+            &mut Vec::new(),
+            GraphConfig::default(),
+        )
+        .await;
+
+        assert!(matches!(graph, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn graph_returns_png_and_weeks() {
+        let mut sniper = vec![week(&[3, 2, 1])];
+        let mut snipee = Vec::new();
+
+        let bytes = graphs("name", &mut sniper, &mut snipee, GraphConfig::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(bytes.starts_with(&PNG_SIGNATURE));
+        assert_eq!(sniper.len(), 1);
+
+        let counts: Vec<_> = sniper[0]
+            .players
+            .iter()
+            .map(|player| player.count)
+            .collect();
+        assert_eq!(counts, [6, 3, 1]);
+    }
 }
@@ -15,7 +15,7 @@ use rand::{thread_rng, Rng};
 use rosu_pp::model::beatmap::BeatmapAttributes;
 use rosu_v2::{
     prelude::{
-        GameModIntermode, GameMode, Grade, OsuError,
+        GameModIntermode, GameMode, GameMods, Grade, OsuError,
         RankStatus::{Approved, Loved, Qualified, Ranked},
         Score,
     },
@@ -28,7 +28,7 @@ use twilight_model::{
 };
 
 pub use self::{if_::*, old::*};
-use super::{require_link, user_not_found, HasMods, ModsResult, ScoreOrder};
+use super::{default_mode, require_link, user_not_found, HasMods, ModsResult, ScoreOrder};
 use crate::{
     active::{
         impls::{TopPagination, TopScoreEdit},
@@ -167,13 +167,14 @@ impl From<ScoreOrder> for TopScoreOrder {
      - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
      - `sort`: `acc`, `combo`, `date` (= `rb` command), `length`, or `position` (default)\n\
      - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
      \n\
      Instead of showing the scores in a list, you can also __show a single score__ by \
      specifying a number right after the command, e.g. `<top2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -205,13 +206,14 @@ async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbm` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topm2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -243,13 +245,14 @@ async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbt` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topt2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -281,13 +284,14 @@ async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbc` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topc2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-   [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+   [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -318,12 +322,13 @@ async fn prefix_topctb(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rb2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -365,12 +370,13 @@ async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbm2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -405,12 +411,13 @@ async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbt2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -445,12 +452,13 @@ async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    Scores can also be filtered through a query like `pp>500 stars>=7 len<2m`.\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbc2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -533,13 +541,26 @@ impl<'m> TopArgs<'m> {
         let mut reverse = None;
         let mut has_dash_r = None;
         let mut has_dash_p_or_i = None;
+        let mut query: Option<String> = None;
         let num = args.num;
 
+        let mut push_query = |arg: &str| {
+            let query = query.get_or_insert_with(String::new);
+
+            if !query.is_empty() {
+                query.push(' ');
+            }
+
+            query.push_str(arg);
+        };
+
         for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
             if arg.as_ref() == "-r" {
                 has_dash_r = Some(true);
             } else if matches!(arg.as_ref(), "-p" | "-i") {
                 has_dash_p_or_i = Some(true);
+            } else if arg.contains(['<', '>']) && is_query_arg(&arg) {
+                push_query(&arg);
             } else if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -638,10 +659,12 @@ impl<'m> TopArgs<'m> {
                             return Err(content.into());
                         }
                     },
+                    _ if is_query_arg(&arg) => push_query(&arg),
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `acc`, `combo`, `sort`, `grade`, or `reverse`."
+                            Available options are: `acc`, `combo`, `sort`, `grade`, `reverse`, \
+                            or filters like `pp>500`, `stars>=7`, `len<2m`, or `artist=camellia`."
                         );
 
                         return Err(content.into());
@@ -671,7 +694,7 @@ impl<'m> TopArgs<'m> {
             reverse: reverse.unwrap_or(false),
             perfect_combo: None,
             index: num.to_string_opt(),
-            query: None,
+            query,
             size: None,
             has_dash_r: has_dash_r.unwrap_or(false),
             has_dash_p_or_i: has_dash_p_or_i.unwrap_or(false),
@@ -725,7 +748,10 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         }
     };
 
-    let mode = args.mode.or(config.mode).unwrap_or(GameMode::Osu);
+    let mode = match args.mode {
+        Some(mode) => mode,
+        None => default_mode(&orig, config.mode).await,
+    };
 
     if args.sort_by == TopScoreOrder::Pp && args.has_dash_r {
         let mode_long = mode_long(mode);
@@ -820,6 +846,12 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
 
     let username = user.username();
 
+    if args.sort_by == TopScoreOrder::Date && post_len == 0 && pre_len > 0 {
+        let content = no_matches_content(username);
+
+        return orig.error(content).await;
+    }
+
     let index = match args.index.as_deref() {
         Some("random" | "?") => (post_len > 0).then(|| thread_rng().gen_range(1..=post_len)),
         Some(n) => match n.parse::<usize>() {
@@ -1109,6 +1141,29 @@ impl<'q> Searchable<TopCriteria<'q>> for TopEntry {
     }
 }
 
+/// Whether the argument is part of the `/top` query syntax e.g. `pp>500`
+fn is_query_arg(arg: &str) -> bool {
+    TopCriteria::create(arg).any_field()
+}
+
+/// Whether a score passes the `grade=` and `+mods` filters.
+fn grade_and_mods_match(args: &TopArgs<'_>, grade: Grade, mods: &GameMods) -> bool {
+    let grade_match = args.grade.map_or(true, |filter| grade.eq_letter(filter));
+
+    let mods_match = match args.mods {
+        Some(ref selection) => selection.filter_mods(mods),
+        None => true,
+    };
+
+    grade_match && mods_match
+}
+
+/// Reply for when none of the user's recent top scores pass the specified
+/// filters.
+fn no_matches_content(username: &str) -> String {
+    format!("No recent top scores of `{username}` match the specified filters")
+}
+
 async fn process_scores(scores: Vec<Score>, args: &TopArgs<'_>) -> Result<Vec<TopEntry>> {
     let mut entries = Vec::with_capacity(scores.len());
 
@@ -1126,8 +1181,6 @@ async fn process_scores(scores: Vec<Score>, args: &TopArgs<'_>) -> Result<Vec<To
         (Some(min), Some(max)) => Some(min..=max),
     };
 
-    let filter_criteria = args.query.as_deref().map(TopCriteria::create);
-
     let maps_id_checksum = scores
         .iter()
         .filter(|score| match acc_range {
@@ -1138,14 +1191,7 @@ async fn process_scores(scores: Vec<Score>, args: &TopArgs<'_>) -> Result<Vec<To
             Some(ref range) => range.contains(&score.max_combo),
             None => true,
         })
-        .filter(|score| match args.grade {
-            Some(grade) => score.grade.eq_letter(grade),
-            None => true,
-        })
-        .filter(|score| match args.mods {
-            None => true,
-            Some(ref selection) => selection.filter_score(score),
-        })
+        .filter(|score| grade_and_mods_match(args, score.grade, &score.mods))
         .map(|score| {
             (
                 score.map_id as i32,
@@ -1186,13 +1232,20 @@ async fn process_scores(scores: Vec<Score>, args: &TopArgs<'_>) -> Result<Vec<To
             max_combo: attrs.max_combo(),
         };
 
-        if let Some(ref criteria) = filter_criteria {
-            if entry.matches(criteria) {
-                entries.push(entry);
-            }
-        } else {
-            entries.push(entry);
-        }
+        entries.push(entry);
+    }
+
+    filter_sort_entries(&mut entries, args);
+
+    Ok(entries)
+}
+
+/// Retain the entries that pass the filters and sort them as specified.
+fn filter_sort_entries(entries: &mut Vec<TopEntry>, args: &TopArgs<'_>) {
+    entries.retain(|entry| grade_and_mods_match(args, entry.score.grade, &entry.score.mods));
+
+    if let Some(criteria) = args.query.as_deref().map(TopCriteria::create) {
+        entries.retain(|entry| entry.matches(&criteria));
     }
 
     if let Some(perfect_combo) = args.perfect_combo {
@@ -1247,8 +1300,6 @@ async fn process_scores(scores: Vec<Score>, args: &TopArgs<'_>) -> Result<Vec<To
     if args.reverse {
         entries.reverse();
     }
-
-    Ok(entries)
 }
 
 fn mode_long(mode: GameMode) -> &'static str {
@@ -1454,3 +1505,171 @@ impl From<&GuildConfig> for GuildValues {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::{GameModsIntermode, LegacyScoreStatistics};
+    use time::{Duration, OffsetDateTime};
+
+    use super::*;
+    use crate::core::commands::prefix::ArgsNum;
+
+    fn entry(original_idx: usize, grade: Grade, mods: &str, ended_at: OffsetDateTime) -> TopEntry {
+        let score = ScoreSlim {
+            accuracy: 99.0,
+            ended_at,
+            grade,
+            max_combo: 1,
+            mode: GameMode::Osu,
+            mods: GameModsIntermode::try_from_acronyms(mods)
+                .unwrap()
+                .with_mode(GameMode::Osu),
+            pp: 500.0,
+            score: 0,
+            score_id: 0,
+            legacy_id: None,
+            statistics: LegacyScoreStatistics {
+                count_geki: 0,
+                count_300: 1,
+                count_katu: 0,
+                count_100: 0,
+                count_50: 0,
+                count_miss: 0,
+            },
+            slider_hits: None,
+        };
+
+        TopEntry {
+            original_idx,
+            score,
+            map: OsuMap::test_map(GameMode::Osu, [5.0, 4.0, 8.0, 9.0], 100),
+            max_pp: 500.0,
+            stars: 6.0,
+            max_combo: 1,
+            replay: false,
+        }
+    }
+
+    #[test]
+    fn rb_filters_recent_scores() {
+        let now = OffsetDateTime::now_utc();
+
+        // Top scores in pp order with (grade, mods, minutes ago)
+        let top = [
+            (Grade::S, "HDHRDT", 4),
+            (Grade::A, "HDHR", 1),
+            (Grade::SH, "HDHR", 3),
+            (Grade::S, "HD", 2),
+        ];
+
+        // Original indices of the matching scores, most recent first
+        let rb = |args: &str| {
+            let args = Args::new(args, ArgsNum::None);
+            let mut args = TopArgs::args(None, args).unwrap();
+            args.sort_by = TopScoreOrder::Date;
+
+            let mut entries = top
+                .iter()
+                .enumerate()
+                .map(|(i, &(grade, mods, minutes))| {
+                    entry(i, grade, mods, now - Duration::minutes(minutes))
+                })
+                .collect();
+
+            filter_sort_entries(&mut entries, &args);
+
+            entries
+                .iter()
+                .map(|entry| entry.original_idx)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(rb("badewanne3"), [1, 3, 2, 0]);
+        assert_eq!(rb("badewanne3 grade=s"), [3, 2, 0]);
+        assert_eq!(rb("badewanne3 grade=s +hdhr"), [2, 0]);
+        assert_eq!(rb("badewanne3 grade=s +hdhrdt!"), [0]);
+        assert_eq!(rb("badewanne3 grade=s -dt!"), [3, 2]);
+        assert!(rb("badewanne3 grade=x +hdhr").is_empty());
+
+        let content = no_matches_content("badewanne3");
+        assert!(content.starts_with("No recent top scores of `badewanne3`"));
+    }
+
+    #[test]
+    fn prefix_args_collect_query() {
+        let args = Args::new("badewanne3 pp>500 +hd acc=98 len<=2m", ArgsNum::None);
+        let args = TopArgs::args(None, args).unwrap();
+
+        assert_eq!(args.name.as_deref(), Some("badewanne3"));
+        assert_eq!(args.min_acc, Some(98.0));
+        assert_eq!(args.query.as_deref(), Some("pp>500 len<=2m"));
+
+        let args = Args::new("<@123> artist=camellia", ArgsNum::None);
+        let args = TopArgs::args(None, args).unwrap();
+
+        assert_eq!(args.discord, Some(Id::new(123)));
+        assert_eq!(args.query.as_deref(), Some("artist=camellia"));
+
+        let args = Args::new("foo=bar", ArgsNum::None);
+        assert!(TopArgs::args(None, args).is_err());
+    }
+
+    #[test]
+    fn query_ranges_filter_scores() {
+        // (pp, acc, seconds drain)
+        let entries = [
+            (612.3, 98.7, 95),
+            (540.0, 99.4, 210),
+            (498.9, 99.8, 100),
+            (720.0, 96.1, 300),
+        ]
+        .map(|(pp, accuracy, seconds_drain)| {
+            let score = ScoreSlim {
+                accuracy,
+                ended_at: OffsetDateTime::UNIX_EPOCH,
+                grade: Grade::A,
+                max_combo: 1,
+                mode: GameMode::Osu,
+                mods: GameMods::new(),
+                pp,
+                score: 0,
+                score_id: 0,
+                legacy_id: None,
+                statistics: LegacyScoreStatistics {
+                    count_geki: 0,
+                    count_300: 1,
+                    count_katu: 0,
+                    count_100: 0,
+                    count_50: 0,
+                    count_miss: 0,
+                },
+                slider_hits: None,
+            };
+
+            TopEntry {
+                original_idx: 0,
+                score,
+                map: OsuMap::test_map(GameMode::Osu, [5.0, 4.0, 8.0, 9.0], seconds_drain),
+                max_pp: pp,
+                stars: 6.0,
+                max_combo: 1,
+                replay: false,
+            }
+        });
+
+        let filter = |query: &str| {
+            let criteria = TopCriteria::create(query);
+
+            entries
+                .iter()
+                .filter(|entry| entry.matches(&criteria))
+                .count()
+        };
+
+        assert_eq!(filter("pp>500"), 3);
+        assert_eq!(filter("pp>500 acc>=98.7"), 2);
+        assert_eq!(filter("len<120s"), 2);
+        assert_eq!(filter("pp>=540 pp<700 len>200s"), 1);
+        assert_eq!(filter("acc>99.9"), 0);
+    }
+}
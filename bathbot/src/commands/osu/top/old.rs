@@ -934,44 +934,16 @@ async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<Top
                 TopOldCatchVersion::March14May20 => pp_ctb!(fruits_ppv1, rosu_map, score, mods),
                 TopOldCatchVersion::May20Now => use_current_system(&score, &map).await,
             },
-            TopOld::Mania(m) => match m.version {
-                TopOldManiaVersion::March14May18 => {
-                    let max_pp_res = mania_ppv1::ManiaPP::new(rosu_map).mods(mods).calculate();
-
-                    let max_pp = max_pp_res.pp as f32;
-                    let stars = max_pp_res.difficulty.stars as f32;
-
-                    let attrs = mania_ppv1::ManiaPP::new(rosu_map)
-                        .mods(mods)
-                        .attributes(max_pp_res)
-                        .score(score.score)
-                        .accuracy(score.accuracy)
-                        .calculate();
-
-                    let pp = attrs.pp as f32;
-                    let max_combo = Context::pp(&map).difficulty().await.max_combo();
-
-                    (pp, max_pp, stars, max_combo)
+            TopOld::Mania(m) => {
+                match mania_old_pp(m.version, rosu_map, mods, score.score, score.accuracy) {
+                    Some((pp, max_pp, stars)) => {
+                        let max_combo = Context::pp(&map).difficulty().await.max_combo();
+
+                        (pp, max_pp, stars, max_combo)
+                    }
+                    None => use_current_system(&score, &map).await,
                 }
-                TopOldManiaVersion::May18October22 => {
-                    let max_pp_res = mania_2018::ManiaPP::new(rosu_map).mods(mods).calculate();
-
-                    let max_pp = max_pp_res.pp as f32;
-                    let stars = max_pp_res.difficulty.stars as f32;
-
-                    let attrs = mania_2018::ManiaPP::new(rosu_map)
-                        .mods(mods)
-                        .attributes(max_pp_res)
-                        .score(score.score)
-                        .calculate();
-
-                    let pp = attrs.pp as f32;
-                    let max_combo = Context::pp(&map).difficulty().await.max_combo();
-
-                    (pp, max_pp, stars, max_combo)
-                }
-                TopOldManiaVersion::October22Now => use_current_system(&score, &map).await,
-            },
+            }
         };
 
         let old_pp = score.pp.expect("missing pp");
@@ -992,6 +964,51 @@ async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<Top
     Ok(entries)
 }
 
+/// Calculates pp, max pp, and stars of a mania score in an older pp system.
+///
+/// The map must already be converted to mania. Returns `None` if the version
+/// is the current system.
+fn mania_old_pp(
+    version: TopOldManiaVersion,
+    rosu_map: &rosu_pp::Beatmap,
+    mods: u32,
+    score: u32,
+    accuracy: f32,
+) -> Option<(f32, f32, f32)> {
+    match version {
+        TopOldManiaVersion::March14May18 => {
+            let max_pp_res = mania_ppv1::ManiaPP::new(rosu_map).mods(mods).calculate();
+
+            let max_pp = max_pp_res.pp as f32;
+            let stars = max_pp_res.difficulty.stars as f32;
+
+            let attrs = mania_ppv1::ManiaPP::new(rosu_map)
+                .mods(mods)
+                .attributes(max_pp_res)
+                .score(score)
+                .accuracy(accuracy)
+                .calculate();
+
+            Some((attrs.pp as f32, max_pp, stars))
+        }
+        TopOldManiaVersion::May18October22 => {
+            let max_pp_res = mania_2018::ManiaPP::new(rosu_map).mods(mods).calculate();
+
+            let max_pp = max_pp_res.pp as f32;
+            let stars = max_pp_res.difficulty.stars as f32;
+
+            let attrs = mania_2018::ManiaPP::new(rosu_map)
+                .mods(mods)
+                .attributes(max_pp_res)
+                .score(score)
+                .calculate();
+
+            Some((attrs.pp as f32, max_pp, stars))
+        }
+        TopOldManiaVersion::October22Now => None,
+    }
+}
+
 fn plural(name: &str) -> &'static str {
     match name.chars().last() {
         Some('s') => "'",
@@ -1097,3 +1114,30 @@ impl_from! {
     TopOldCatch: Catch,
     TopOldMania: Mania,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mania_converts_are_recalculated() {
+        let map =
+            OsuMap::test_map(GameMode::Osu, [5.0, 4.0, 8.0, 9.0], 60).convert(GameMode::Mania);
+        assert!(map.pp_map.is_convert);
+
+        for version in [
+            TopOldManiaVersion::March14May18,
+            TopOldManiaVersion::May18October22,
+        ] {
+            let (pp, max_pp, stars) =
+                mania_old_pp(version, &map.pp_map, 0, 1_000_000, 100.0).unwrap();
+
+            assert!(pp.is_finite() && pp >= 0.0, "{version:?}: {pp}");
+            assert!(max_pp.is_finite() && max_pp >= pp, "{version:?}: {max_pp}");
+            assert!(stars.is_finite() && stars >= 0.0, "{version:?}: {stars}");
+        }
+
+        let current = TopOldManiaVersion::October22Now;
+        assert!(mania_old_pp(current, &map.pp_map, 0, 1_000_000, 100.0).is_none());
+    }
+}
@@ -0,0 +1,338 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_psql::model::osu::TopSnapshotEntry;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    datetime::HowLongAgoDynamic,
+    matcher,
+    numbers::round,
+    osu::PpListUtil,
+    CowUtils, EmbedBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
+
+use super::{default_mode, require_link, user_not_found};
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "topdiff",
+    desc = "Show how a user's top100 changed since you last checked",
+    help = "Show how a user's top100 changed since the last time you used this command on them.\n\
+    The first usage only saves the current top100 as baseline, \
+    later usages list the gained and lost scores as well as the difference in weighted pp."
+)]
+pub struct TopDiff<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+pub async fn slash_topdiff(mut command: InteractionCommand) -> Result<()> {
+    let args = TopDiff::from_interaction(command.input_data())?;
+
+    topdiff((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Show how a user's top100 changed since you last checked")]
+#[help(
+    "Show how a user's top100 changed since the last time you used this command on them.\n\
+    The first usage only saves the current top100 as baseline, \
+    later usages list the gained and lost scores as well as the difference in weighted pp.\n\
+    To specify a gamemode, use the `/topdiff` command."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[group(AllModes)]
+async fn prefix_topdiff(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let args = TopDiff::args(args);
+
+    topdiff(CommandOrigin::from_msg(msg, permissions), args).await
+}
+
+impl<'m> TopDiff<'m> {
+    fn args(mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode: None,
+            name,
+            discord,
+        }
+    }
+}
+
+/// Maximum amount of gained or lost scores to list
+const LIST_LIMIT: usize = 10;
+
+async fn topdiff(orig: CommandOrigin<'_>, args: TopDiff<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => default_mode(&orig, config.mode).await,
+    };
+
+    let legacy_scores = match config.legacy_scores {
+        Some(legacy_scores) => legacy_scores,
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.legacy_scores)
+                .await
+                .unwrap_or(false),
+            None => false,
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id).await.mode(mode);
+    let scores_fut = Context::osu_scores()
+        .top(legacy_scores)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok((user, scores)) => (user, scores),
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let entries: Vec<_> = scores.iter().map(snapshot_entry).collect();
+    let tracking = Context::osu_tracking();
+
+    let snapshot = match tracking.top_snapshot(owner, user.user_id(), mode).await {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if let Err(err) = tracking
+        .store_top_snapshot(owner, user.user_id(), mode, &entries)
+        .await
+    {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let description = match snapshot {
+        Some(snapshot) => {
+            let diff = TopDiffEntries::new(&snapshot.entries, &entries);
+
+            diff_description(
+                &diff,
+                &scores,
+                HowLongAgoDynamic::new(&snapshot.last_update),
+            )
+        }
+        None => "Baseline saved.\n\
+            Use this command again later to see how the top100 changed in the meanwhile."
+            .to_owned(),
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .thumbnail(user.avatar_url());
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+fn snapshot_entry(score: &Score) -> TopSnapshotEntry {
+    TopSnapshotEntry {
+        score_id: score.id,
+        map_id: score.map_id,
+        pp: score.pp.unwrap_or(0.0),
+    }
+}
+
+fn diff_description(diff: &TopDiffEntries, scores: &[Score], ago: HowLongAgoDynamic) -> String {
+    let mut description = format!(
+        "Changes since {ago}:\nWeighted top100 pp: **{delta:+}pp**\n",
+        delta = round(diff.pp_delta),
+    );
+
+    if diff.gained.is_empty() && diff.lost.is_empty() {
+        description.push_str("\nNo gained or lost scores");
+
+        return description;
+    }
+
+    if !diff.gained.is_empty() {
+        let _ = writeln!(description, "\n__Gained {}:__", diff.gained.len());
+
+        for entry in diff.gained.iter().take(LIST_LIMIT) {
+            let score = scores.iter().find(|score| score.id == entry.score_id);
+            let map = score.and_then(|score| score.map.as_ref());
+            let mapset = score.and_then(|score| score.mapset.as_ref());
+
+            let _ = write!(description, "`+{}pp` ", round(entry.pp));
+
+            let _ = match (mapset, map) {
+                (Some(mapset), Some(map)) => writeln!(
+                    description,
+                    "[{title} [{version}]]({OSU_BASE}b/{map_id})",
+                    title = mapset.title.cow_escape_markdown(),
+                    version = map.version.cow_escape_markdown(),
+                    map_id = entry.map_id,
+                ),
+                _ => writeln!(description, "{OSU_BASE}b/{}", entry.map_id),
+            };
+        }
+    }
+
+    if !diff.lost.is_empty() {
+        let _ = writeln!(description, "\n__Lost {}:__", diff.lost.len());
+
+        for entry in diff.lost.iter().take(LIST_LIMIT) {
+            let _ = writeln!(
+                description,
+                "`-{pp}pp` {OSU_BASE}b/{map_id}",
+                pp = round(entry.pp),
+                map_id = entry.map_id,
+            );
+        }
+    }
+
+    description
+}
+
+/// The difference between two top100 snapshots.
+struct TopDiffEntries {
+    gained: Vec<TopSnapshotEntry>,
+    lost: Vec<TopSnapshotEntry>,
+    pp_delta: f32,
+}
+
+impl TopDiffEntries {
+    fn new(old: &[TopSnapshotEntry], new: &[TopSnapshotEntry]) -> Self {
+        fn missing_in(
+            entries: &[TopSnapshotEntry],
+            others: &[TopSnapshotEntry],
+        ) -> Vec<TopSnapshotEntry> {
+            entries
+                .iter()
+                .filter(|entry| !others.iter().any(|other| other.score_id == entry.score_id))
+                .copied()
+                .collect()
+        }
+
+        Self {
+            gained: missing_in(new, old),
+            lost: missing_in(old, new),
+            pp_delta: weighted_pp(new) - weighted_pp(old),
+        }
+    }
+}
+
+fn weighted_pp(entries: &[TopSnapshotEntry]) -> f32 {
+    let pps: Vec<_> = entries.iter().map(|entry| entry.pp).collect();
+
+    pps.accum_weighted()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score_id: u64, pp: f32) -> TopSnapshotEntry {
+        TopSnapshotEntry {
+            score_id,
+            map_id: score_id as u32 * 10,
+            pp,
+        }
+    }
+
+    #[test]
+    fn diff_gained_and_lost() {
+        let old = [entry(1, 100.0), entry(2, 90.0), entry(3, 80.0)];
+        let new = [entry(4, 120.0), entry(1, 100.0), entry(2, 90.0)];
+
+        let diff = TopDiffEntries::new(&old, &new);
+
+        assert_eq!(diff.gained, [entry(4, 120.0)]);
+        assert_eq!(diff.lost, [entry(3, 80.0)]);
+
+        // (120 + 100 * 0.95 + 90 * 0.95^2) - (100 + 90 * 0.95 + 80 * 0.95^2)
+        assert!((diff.pp_delta - 38.525).abs() < 0.001);
+    }
+
+    #[test]
+    fn diff_unchanged() {
+        let scores = [entry(1, 100.0), entry(2, 90.0)];
+
+        let diff = TopDiffEntries::new(&scores, &scores);
+
+        assert!(diff.gained.is_empty());
+        assert!(diff.lost.is_empty());
+        assert_eq!(diff.pp_delta, 0.0);
+    }
+}
@@ -4,7 +4,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::Attachment;
 
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{add_bg::*, cache::*, reload_config::*, request_members::*};
 #[cfg(feature = "osutracking")]
 use self::{tracking_interval::*, tracking_stats::*};
 use super::GameModeOption;
@@ -17,6 +17,7 @@ use crate::{
 
 mod add_bg;
 mod cache;
+mod reload_config;
 mod request_members;
 mod reshard;
 
@@ -35,6 +36,8 @@ pub enum Owner {
     AddBg(OwnerAddBg),
     #[command(name = "cache")]
     Cache(OwnerCache),
+    #[command(name = "reloadconfig")]
+    ReloadConfig(OwnerReloadConfig),
     #[command(name = "requestmembers")]
     RequestMembers(OwnerRequestMembers),
     #[command(name = "reshard")]
@@ -57,6 +60,13 @@ pub struct OwnerAddBg {
 #[command(name = "cache", desc = "Display stats about the internal cache")]
 pub struct OwnerCache;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "reloadconfig",
+    desc = "Reload grade emotes, embed color, and bucket ratelimits from the .env file"
+)]
+pub struct OwnerReloadConfig;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "requestmembers",
@@ -105,6 +115,7 @@ async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(command, bg).await,
         Owner::Cache(_) => cache(command).await,
+        Owner::ReloadConfig(_) => reload_config(command).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
         #[cfg(feature = "osutracking")]
@@ -0,0 +1,54 @@
+use std::fmt::Write;
+
+use bathbot_util::{constants::GENERAL_ISSUE, EmbedBuilder, MessageBuilder};
+use eyre::Result;
+
+use crate::{
+    core::{BotConfig, ConfigReload},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+pub async fn reload_config(command: InteractionCommand) -> Result<()> {
+    let ConfigReload { changed, ignored } = match BotConfig::reload() {
+        Ok(reload) => reload,
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to reload config"));
+        }
+    };
+
+    Context::get()
+        .buckets
+        .reconfigure(&BotConfig::get().reloadable().buckets);
+
+    let mut description = String::new();
+
+    if changed.is_empty() {
+        description.push_str("Nothing changed");
+    } else {
+        description.push_str("**Changed:**\n");
+
+        for change in changed {
+            let _ = writeln!(description, "- {change}");
+        }
+    }
+
+    if !ignored.is_empty() {
+        description.push_str("\n**Requires a restart:**\n");
+
+        for name in ignored {
+            let _ = writeln!(description, "- `{name}`");
+        }
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Reloaded config");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use eyre::Result;
 use rosu_v2::prelude::{GameMode, OsuError, Username};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-pub use self::{track::*, track_list::*, untrack::*, untrack_all::*};
+pub use self::{track::*, track_limit::*, track_list::*, untrack::*, untrack_all::*};
 use super::GameModeOption;
 use crate::{
     core::commands::prefix::{Args, ArgsNum},
@@ -16,6 +16,7 @@ use crate::{
 };
 
 mod track;
+mod track_limit;
 mod track_list;
 mod untrack;
 mod untrack_all;
@@ -34,6 +35,8 @@ pub enum Track {
     Remove(TrackRemove),
     #[command(name = "list")]
     List(TrackList),
+    #[command(name = "limit")]
+    Limit(TrackLimit),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -103,6 +106,24 @@ pub struct TrackRemoveAll {
 )]
 pub struct TrackList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "limit",
+    desc = "Adjust the default tracking limit for this channel",
+    help = "Adjust the default tracking limit for this channel.\n\
+    The limit applies to all users that are tracked in this channel \
+    without a specified limit, including users that are tracked later on.\n\
+    If no limit is specified, the channel falls back to the server's default limit."
+)]
+pub struct TrackLimit {
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Between 1-100, notify on updates of the users' top X scores"
+    )]
+    limit: Option<u8>,
+}
+
 async fn slash_track(mut command: InteractionCommand) -> Result<()> {
     match Track::from_interaction(command.input_data())? {
         Track::Add(add) => track((&mut command).into(), add.into()).await,
@@ -111,6 +132,7 @@ async fn slash_track(mut command: InteractionCommand) -> Result<()> {
             untrackall((&mut command).into(), all.mode.map(GameMode::from)).await
         }
         Track::List(_) => tracklist((&mut command).into()).await,
+        Track::Limit(limit) => tracklimit((&mut command).into(), limit.limit).await,
     }
 }
 
@@ -1,4 +1,5 @@
 use bathbot_macros::command;
+use bathbot_psql::model::osu::TrackedOsuUserKey;
 use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{GameMode, OsuError};
@@ -8,6 +9,7 @@ use super::TrackArgs;
 use crate::{
     core::commands::CommandOrigin,
     embeds::{EmbedData, TrackEmbed},
+    tracking::resolve_limit,
     util::ChannelExt,
     Context,
 };
@@ -28,8 +30,14 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         return orig.error(content).await;
     }
 
-    let limit = match limit {
-        Some(limit @ 1..=100) => limit,
+    let explicit = limit.is_some();
+    let channel = orig.channel_id();
+    let tracking = Context::tracking();
+
+    // Without an explicit limit, the guild's limit is stored and the
+    // channel's limit is applied when notifying
+    let (limit, shown_limit) = match limit {
+        Some(limit @ 1..=100) => (limit, limit),
         Some(_) => {
             let content = "The given limit must be between 1 and 100";
 
@@ -38,10 +46,16 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         None => {
             let guild = orig.guild_id().unwrap();
 
-            Context::guild_config()
+            let guild_limit = Context::guild_config()
                 .peek(guild, |config| config.track_limit)
-                .await
-                .unwrap_or(50)
+                .await;
+
+            let channel_limit = tracking.channel_limit(channel);
+
+            (
+                resolve_limit(None, None, guild_limit),
+                resolve_limit(None, channel_limit, guild_limit),
+            )
         }
     };
 
@@ -62,21 +76,36 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         }
     };
 
-    let channel = orig.channel_id();
     let mut success = Vec::with_capacity(users.len());
     let mut failure = Vec::new();
-    let tracking = Context::tracking();
+
+    // Notifications look up the guild's config through the channel
+    if let Some(guild) = orig.guild_id() {
+        if let Err(err) = tracking.set_channel_guild(channel, guild).await {
+            warn!(?err, "Failed to store guild of tracking channel");
+        }
+    }
 
     for (username, user_id) in users {
         let add_fut = tracking.add(user_id, mode, OffsetDateTime::now_utc(), channel, limit);
+        let key = TrackedOsuUserKey { user_id, mode };
+
+        let res = match add_fut.await {
+            Ok(added) => tracking
+                .set_explicit_limit(key, channel, explicit)
+                .await
+                .map(|_| added),
+            Err(err) => Err(err),
+        };
 
-        match add_fut.await {
+        match res {
             Ok(true) => success.push(username),
             Ok(false) => failure.push(username),
             Err(err) => {
                 warn!(?err, "Failed to add tracked entry");
 
-                let embed = TrackEmbed::new(mode, success, failure, Some(username), limit).build();
+                let embed =
+                    TrackEmbed::new(mode, success, failure, Some(username), shown_limit).build();
 
                 let builder = MessageBuilder::new().embed(embed);
                 orig.create_message(builder).await?;
@@ -86,7 +115,7 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         }
     }
 
-    let embed = TrackEmbed::new(mode, success, failure, None, limit);
+    let embed = TrackEmbed::new(mode, success, failure, None, shown_limit);
     let builder = MessageBuilder::new().embed(embed.build());
     orig.create_message(builder).await?;
 
@@ -104,7 +133,8 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `track limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100. If none is given, it defaults to the \
+    channel's limit (see `tracklimit`), the server's limit, or **50**."
 )]
 #[usage("[limit=number] [username1] [username2] ...")]
 #[examples(
@@ -136,7 +166,8 @@ async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackmania limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100. If none is given, it defaults to the \
+    channel's limit (see `tracklimit`), the server's limit, or **50**."
 )]
 #[usage("[limit=number] [username1] [username2] ...")]
 #[examples(
@@ -168,7 +199,8 @@ pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `tracktaiko limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100. If none is given, it defaults to the \
+    channel's limit (see `tracklimit`), the server's limit, or **50**."
 )]
 #[usage("[limit=number] [username1] [username2] ...")]
 #[examples(
@@ -200,7 +232,8 @@ pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackctb limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100. If none is given, it defaults to the \
+    channel's limit (see `tracklimit`), the server's limit, or **50**."
 )]
 #[usage("[limit=number] [username1] [username2] ...")]
 #[examples(
@@ -0,0 +1,59 @@
+use bathbot_macros::command;
+use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use eyre::Result;
+
+use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
+
+#[command]
+#[desc("Adjust the default tracking limit for this channel")]
+#[help(
+    "Adjust the default tracking limit for this channel.\n\
+    The limit applies to all users that are tracked in this channel \
+    without a specified limit, including users that are tracked later on.\n\
+    The limit must be between 1 and 100. \
+    If none is given, the channel falls back to the server's default limit."
+)]
+#[usage("[limit]")]
+#[example("", "25")]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+#[group(Tracking)]
+async fn prefix_tracklimit(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    let limit = match args.next().map(str::parse) {
+        Some(Ok(limit @ 1..=100)) => Some(limit),
+        Some(_) => {
+            msg.error("The limit must be an integer between 1 and 100")
+                .await?;
+
+            return Ok(());
+        }
+        None => None,
+    };
+
+    tracklimit(msg.into(), limit).await
+}
+
+pub async fn tracklimit(orig: CommandOrigin<'_>, limit: Option<u8>) -> Result<()> {
+    let channel = orig.channel_id();
+
+    // The tracking loop resolves the channel's limit when notifying
+    if let Err(err) = Context::tracking().set_channel_limit(channel, limit).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let content = match limit {
+        Some(limit) => format!(
+            "The tracking limit of this channel is now {limit}.\n\
+            It applies to all users tracked in this channel without an explicit limit."
+        ),
+        None => "Removed the tracking limit of this channel.\n\
+            Users tracked without an explicit limit use the server's limit again."
+            .to_owned(),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use bathbot_macros::command;
 use bathbot_psql::model::osu::TrackedOsuUserKey;
 use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
-use eyre::{Report, Result};
+use eyre::{Report, Result, WrapErr};
 use rosu_v2::{
     prelude::{GameMode, OsuError, Username},
     OsuResult,
@@ -11,8 +11,8 @@ use rosu_v2::{
 use twilight_model::id::{marker::ChannelMarker, Id};
 
 use crate::{
+    active::{impls::TrackListPagination, ActiveMessages},
     core::commands::CommandOrigin,
-    embeds::{EmbedData, TrackListEmbed},
     manager::redis::osu::UserArgs,
     Context,
 };
@@ -25,7 +25,7 @@ pub struct TracklistUserEntry {
 
 #[command]
 #[desc("Display tracked users of a channel")]
-#[alias("tl")]
+#[alias("tl", "trackings")]
 #[group(Tracking)]
 #[flags(AUTHORITY, ONLY_GUILDS)]
 async fn prefix_tracklist(msg: &Message) -> Result<()> {
@@ -51,21 +51,24 @@ pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
             .then(a.name.cmp(&b.name))
     });
 
-    let embeds = TrackListEmbed::new(users);
-
-    if embeds.is_empty() {
+    if users.is_empty() {
         let content = "No tracked users in this channel";
         let builder = MessageBuilder::new().embed(content);
         orig.create_message(builder).await?;
-    } else {
-        for embed_data in embeds {
-            let embed = embed_data.build();
-            let builder = MessageBuilder::new().embed(embed);
-            orig.create_message(builder).await?;
-        }
+
+        return Ok(());
     }
 
-    Ok(())
+    let pagination = TrackListPagination::builder()
+        .entries(users.into_boxed_slice())
+        .msg_owner(orig.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+        .wrap_err("Failed to begin active message")
 }
 
 async fn get_users(
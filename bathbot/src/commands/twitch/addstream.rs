@@ -1,6 +1,8 @@
+use std::fmt::Write;
+
 use bathbot_macros::command;
 use bathbot_util::{
-    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
+    constants::{GENERAL_ISSUE, TWITCH_API_ISSUE, TWITCH_BASE},
     CowUtils, MessageBuilder,
 };
 use eyre::Result;
@@ -43,12 +45,30 @@ pub async fn addstream(orig: CommandOrigin<'_>, name: &'_ str) -> Result<()> {
         }
     };
 
+    let stream_res = Context::client().get_twitch_stream(twitch_id).await;
+
     let channel = orig.channel_id();
     Context::add_tracking(twitch_id, channel);
 
     match Context::twitch().track(channel, twitch_id).await {
         Ok(true) => {
-            let content = format!("I'm now tracking `{name}`'s twitch stream in this channel");
+            let mut content = format!("I'm now tracking `{name}`'s twitch stream in this channel");
+
+            match stream_res {
+                Ok(Some(stream)) if stream.live => {
+                    let _ = write!(
+                        content,
+                        "\n\n`{name}` is currently live: [{title}]({TWITCH_BASE}{login})",
+                        title = stream.title.cow_escape_markdown(),
+                        login = stream.login,
+                    );
+                }
+                Ok(_) => {
+                    let _ = write!(content, "\n\n`{name}` is currently offline");
+                }
+                Err(err) => warn!(?err, "Failed to get twitch stream"),
+            }
+
             let builder = MessageBuilder::new().embed(content);
 
             trace!("Now tracking twitch stream {name} for channel {channel}");
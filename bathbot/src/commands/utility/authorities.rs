@@ -1,12 +1,15 @@
 use std::fmt::Write;
 
 use bathbot_macros::command;
-use bathbot_psql::model::configs::{Authorities, GuildConfig};
+use bathbot_psql::model::configs::{Authorities, Authority, GuildConfig};
 use bathbot_util::{constants::GENERAL_ISSUE, matcher, MessageBuilder};
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use twilight_model::{
     guild::Permissions,
-    id::{marker::RoleMarker, Id},
+    id::{
+        marker::{GuildMarker, RoleMarker},
+        Id,
+    },
 };
 
 use crate::{
@@ -22,7 +25,7 @@ use crate::{
 #[help(
     "Decide which roles should be considered authority roles. \n\
     Authority roles enable the usage of certain commands like \
-    `addstream` or `prune`.\n\
+    `addstream` or `track`.\n\
     Roles can be given as mention or as role id (up to 10 roles possible).\n\
     If you want to see the current authority roles, just pass \
     `-show` as argument"
@@ -47,6 +50,8 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
     let guild_id = orig.guild_id().unwrap();
     let cache = Context::cache();
 
+    let mut pruned = Vec::new();
+
     let mut content = match args {
         AuthorityCommandKind::Add(role_id) => {
             let roles = Context::guild_config()
@@ -73,7 +78,14 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
 
             "Successfully added authority role. Authority roles now are: ".to_owned()
         }
-        AuthorityCommandKind::List => "Current authority roles for this server: ".to_owned(),
+        AuthorityCommandKind::List => {
+            match prune_deleted_roles(guild_id).await {
+                Ok(deleted) => pruned = deleted,
+                Err(err) => warn!(?err, "Failed to prune deleted authority roles"),
+            }
+
+            "Current authority roles for this server: ".to_owned()
+        }
         AuthorityCommandKind::Remove(role_id) => {
             let author_id = orig.user_id()?;
             let roles = Context::guild_config()
@@ -196,12 +208,73 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
         .peek(guild_id, |config| config.authorities.clone())
         .await;
     role_string(&roles, &mut content);
+
+    if let Some((first, rest)) = pruned.split_first() {
+        let _ = write!(content, "\n\nRemoved deleted roles: `{first}`");
+
+        for role in rest {
+            let _ = write!(content, ", `{role}`");
+        }
+    }
+
     let builder = MessageBuilder::new().embed(content);
     orig.callback(builder).await?;
 
     Ok(())
 }
 
+/// Removes authority roles that no longer exist in the guild and returns them.
+///
+/// Nothing is removed unless the guild's roles are fully cached.
+async fn prune_deleted_roles(guild_id: Id<GuildMarker>) -> Result<Vec<Authority>> {
+    let cache = Context::cache();
+
+    if cache.guild(guild_id).await?.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let cached_roles = cache.guild_roles(guild_id).await?;
+
+    let deleted = Context::guild_config()
+        .peek(guild_id, |config| {
+            deleted_roles(guild_id, &config.authorities, &cached_roles)
+        })
+        .await;
+
+    if deleted.is_empty() {
+        return Ok(deleted);
+    }
+
+    let f = |config: &mut GuildConfig| config.authorities.retain(|id| !deleted.contains(id));
+
+    Context::guild_config()
+        .update(guild_id, f)
+        .await
+        .wrap_err("Failed to update guild config")?;
+
+    Ok(deleted)
+}
+
+/// Returns all authorities that are not among the cached roles.
+///
+/// Every guild has an @everyone role whose id matches the guild id so if that
+/// one is missing, the roles are not fully cached and nothing is returned.
+fn deleted_roles(
+    guild_id: Id<GuildMarker>,
+    authorities: &[Authority],
+    cached_roles: &[u64],
+) -> Vec<Authority> {
+    if !cached_roles.contains(&guild_id.get()) {
+        return Vec::new();
+    }
+
+    authorities
+        .iter()
+        .filter(|role| !cached_roles.contains(&role.get()))
+        .copied()
+        .collect()
+}
+
 fn role_string(roles: &Authorities, content: &mut String) {
     let mut iter = roles.iter();
 
@@ -244,3 +317,27 @@ impl AuthorityCommandKind {
         Ok(Self::Replace(roles))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deleted_authority_roles() {
+        let guild_id = Id::new(1);
+        let authorities = [Id::new(10), Id::new(20), Id::new(30)];
+
+        let cached_roles = [1, 10, 30, 40];
+        assert_eq!(
+            deleted_roles(guild_id, &authorities, &cached_roles),
+            [Id::new(20)]
+        );
+
+        let cached_roles = [1, 10, 20, 30];
+        assert!(deleted_roles(guild_id, &authorities, &cached_roles).is_empty());
+
+        // Roles are not fully cached yet
+        let cached_roles = [10];
+        assert!(deleted_roles(guild_id, &authorities, &cached_roles).is_empty());
+    }
+}
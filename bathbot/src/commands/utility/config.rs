@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 use ::time::UtcOffset;
 use bathbot_macros::{command, SlashCommand};
 use bathbot_psql::model::configs::{
@@ -5,11 +7,11 @@ use bathbot_psql::model::configs::{
 };
 #[cfg(feature = "server")]
 use bathbot_server::AuthenticationStandbyError;
-use bathbot_util::constants::GENERAL_ISSUE;
 #[cfg(feature = "server")]
-use bathbot_util::{EmbedBuilder, MessageBuilder};
+use bathbot_util::EmbedBuilder;
+use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
 use eyre::{Report, Result};
-use rosu_v2::prelude::GameMode;
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
@@ -63,7 +65,9 @@ pub struct Config {
         help = "Some embeds are pretty chunky and show too much data.\n\
         With this option you can make those embeds minimized by default.\n\
         Affected commands are: `compare score`, `recent score`, `recent simulate`, \
-        and any command showing top scores when the `index` option is specified."
+        and any command showing top scores when the `index` option is specified.\n\
+        `Maximize notable scores` shows scores maximized if they're in the top50 of \
+        the user's top scores or of the map's global leaderboard and minimized otherwise."
     )]
     score_embeds: Option<ScoreSize>,
     #[command(
@@ -105,6 +109,20 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify mods that commands like simulate or leaderboard should use by default",
+        help = "Specify mods that commands like `/simulate` or `/leaderboard` should use \
+        if you don't specify any mods yourself e.g. `hdhr`.\n\
+        Mods specified in a command always take precedence over these.\n\
+        Use `none` to clear your default mods."
+    )]
+    mods: Option<String>,
+    #[command(
+        desc = "Reset all of your preferences to their default",
+        help = "Reset all of your preferences to their default.\n\
+        Linked accounts are kept and all other options are ignored when resetting."
+    )]
+    reset: Option<bool>,
 }
 
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
@@ -129,7 +147,9 @@ pub struct Config {
         help = "Some embeds are pretty chunky and show too much data.\n\
         With this option you can make those embeds minimized by default.\n\
         Affected commands are: `compare score`, `recent score`, `recent simulate`, \
-        and any command showing top scores when the `index` option is specified."
+        and any command showing top scores when the `index` option is specified.\n\
+        `Maximize notable scores` shows scores maximized if they're in the top50 of \
+        the user's top scores or of the map's global leaderboard and minimized otherwise."
     )]
     score_embeds: Option<ScoreSize>,
     #[command(
@@ -171,6 +191,20 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify mods that commands like simulate or leaderboard should use by default",
+        help = "Specify mods that commands like `/simulate` or `/leaderboard` should use \
+        if you don't specify any mods yourself e.g. `hdhr`.\n\
+        Mods specified in a command always take precedence over these.\n\
+        Use `none` to clear your default mods."
+    )]
+    mods: Option<String>,
+    #[command(
+        desc = "Reset all of your preferences to their default",
+        help = "Reset all of your preferences to their default.\n\
+        Linked accounts are kept and all other options are ignored when resetting."
+    )]
+    reset: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -236,8 +270,14 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        mods,
+        reset,
     } = config;
 
+    if reset == Some(true) {
+        return reset_config(command).await;
+    }
+
     if let Some(ref skin_url) = skin_url {
         match SkinValidation::check(&command, skin_url).await? {
             ValidationStatus::Continue => {}
@@ -293,6 +333,22 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.legacy_scores = Some(matches!(score_data, ScoreData::Stable));
     }
 
+    match mods.as_deref().map(|mods| mods.trim_start_matches('+')) {
+        None => {}
+        Some(mods) if mods.eq_ignore_ascii_case("none") => config.mods = None,
+        Some(mods) => match GameModsIntermode::try_from_acronyms(mods) {
+            Some(mods) => config.mods = Some(mods),
+            None => {
+                let content = "Failed to parse mods. \
+                    Be sure to specify mod acronyms e.g. `hdhr`, or `none` to clear them.";
+
+                command.error(content).await?;
+
+                return Ok(());
+            }
+        },
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -354,6 +410,43 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
     }
 }
 
+async fn reset_config(command: InteractionCommand) -> Result<()> {
+    let author_id = command.user_id()?;
+
+    let cleared = match Context::user_config().reset(author_id).await {
+        Ok(cleared) => cleared,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if cleared.is_empty() {
+        let content = "You don't have any preferences set so there is nothing to reset";
+        let builder = MessageBuilder::new().embed(content);
+        command.update(builder).await?;
+
+        return Ok(());
+    }
+
+    let mut content = String::from("Reset your config, cleared ");
+    let mut iter = cleared.into_iter();
+
+    if let Some(first) = iter.next() {
+        let _ = write!(content, "`{first}`");
+
+        for name in iter {
+            let _ = write!(content, ", `{name}`");
+        }
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
 #[cfg(feature = "server")]
 const MSG_BADE: &str = "Contact Badewanne3 if you encounter issues with the website";
 
@@ -625,6 +718,7 @@ async fn convert_config(
         timezone,
         render_button,
         legacy_scores,
+        mods,
     } = config;
 
     UserConfig {
@@ -638,6 +732,7 @@ async fn convert_config(
         timezone,
         render_button,
         legacy_scores,
+        mods,
     }
 }
 
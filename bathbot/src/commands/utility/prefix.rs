@@ -19,10 +19,11 @@ use crate::{core::commands::checks::check_authority, util::ChannelExt, Context};
     Otherwise, the first argument must be either `add` or `remove`.\n\
     Following that must be a space-separated list of \
     characters or strings you want to add or remove as prefix.\n\
-    Servers must have between one and five prefixes."
+    Servers must have between one and five prefixes \
+    and each prefix can be at most five characters long."
 )]
 #[usage("[add / remove] [prefix]")]
-#[example("add $ 🍆 new_pref", "remove < !!")]
+#[example("add $ 🍆 pref", "remove < !!")]
 #[alias("prefixes")]
 #[flags(ONLY_GUILDS, SKIP_DEFER)] // authority check is done manually
 #[group(Utility)]
@@ -90,6 +91,43 @@ async fn prefix_prefix(
         return Ok(());
     }
 
+    if let Action::Add = action {
+        let too_long: Vec<_> = args
+            .iter()
+            .filter(|prefix| check_prefix(prefix) == PrefixCheck::TooLong)
+            .collect();
+
+        if !too_long.is_empty() {
+            let mut content = format!(
+                "Prefixes can be at most {MAX_PREFIX_LEN} characters long, \
+                the following are too long: "
+            );
+
+            let mut too_long = too_long.into_iter();
+
+            if let Some(first) = too_long.next() {
+                let _ = write!(content, "`{first}`");
+
+                for prefix in too_long {
+                    let _ = write!(content, ", `{prefix}`");
+                }
+            }
+
+            msg.error(content).await?;
+
+            return Ok(());
+        }
+    }
+
+    let shadowing: Vec<_> = match action {
+        Action::Add => args
+            .iter()
+            .filter(|prefix| check_prefix(prefix) == PrefixCheck::Shadowing)
+            .cloned()
+            .collect(),
+        Action::Remove => Vec::new(),
+    };
+
     enum UpdateResult {
         Ok,
         FullCapacity,
@@ -143,6 +181,14 @@ async fn prefix_prefix(
 
             Context::guild_config().peek(guild_id, f).await;
 
+            for prefix in shadowing {
+                let _ = write!(
+                    content,
+                    "\n:warning: The prefix `{prefix}` is a single letter or digit so \
+                    regular messages starting with it may trigger commands"
+                );
+            }
+
             let builder = MessageBuilder::new().embed(content);
             msg.create_message(builder, permissions).await?;
 
@@ -170,6 +216,26 @@ enum Action {
     Remove,
 }
 
+const MAX_PREFIX_LEN: usize = 5;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum PrefixCheck {
+    Valid,
+    /// Single alphanumeric character which many regular messages start with
+    Shadowing,
+    TooLong,
+}
+
+fn check_prefix(prefix: &str) -> PrefixCheck {
+    let mut chars = prefix.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_alphanumeric() => PrefixCheck::Shadowing,
+        _ if prefix.chars().count() > MAX_PREFIX_LEN => PrefixCheck::TooLong,
+        _ => PrefixCheck::Valid,
+    }
+}
+
 fn current_prefixes(content: &mut String, prefixes: &[Prefix]) {
     content.push_str("Prefixes for this server: ");
     let len = prefixes.iter().map(|p| p.len() + 4).sum();
@@ -212,3 +278,29 @@ impl PrefixValidator {
 }
 
 static VALIDATOR: OnceCell<AhoCorasick> = OnceCell::new();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepted_prefixes() {
+        for prefix in ["<", "!!", "$", "🍆", "bb!", "pref_"] {
+            assert_eq!(check_prefix(prefix), PrefixCheck::Valid, "{prefix}");
+        }
+    }
+
+    #[test]
+    fn shadowing_prefixes() {
+        for prefix in ["a", "Z", "7", "ö"] {
+            assert_eq!(check_prefix(prefix), PrefixCheck::Shadowing, "{prefix}");
+        }
+    }
+
+    #[test]
+    fn rejected_prefixes() {
+        for prefix in ["new_pref", "bathbot", "!!!!!!", "🍆🍆🍆🍆🍆🍆"] {
+            assert_eq!(check_prefix(prefix), PrefixCheck::TooLong, "{prefix}");
+        }
+    }
+}
@@ -1,15 +1,19 @@
 use bathbot_macros::{command, SlashCommand};
 use bathbot_psql::model::configs::{
-    GuildConfig, HideSolutions, ListSize, MinimizedPp, Retries, ScoreSize,
+    GuildConfig, HideSolutions, ListSize, MinimizedPp, Retries, ScoreSize, TrackTemplate,
 };
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
 use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{marker::RoleMarker, Id};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, RoleMarker},
+    Id,
+};
 
-use super::{AuthorityCommandKind, ScoreData};
+use super::{AuthorityCommandKind, ConfigGameMode, ScoreData};
 use crate::{
     commands::{EnableDisable, ShowHideOption},
+    core::commands::checks::command_channels_content,
     embeds::{EmbedData, ServerConfigEmbed},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -25,6 +29,8 @@ use crate::{
 pub enum ServerConfig {
     #[command(name = "authorities")]
     Authorities(ServerConfigAuthorities),
+    #[command(name = "channels")]
+    Channels(ServerConfigChannels),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
 }
@@ -36,7 +42,7 @@ pub enum ServerConfig {
     help = "To use certain commands, users require a special status.\n\
     This command adjusts the authority status of roles.\n\
     Any member with an authority role can use these higher commands.\n\n\
-    Authority commands: `matchlive`, `prune`, `roleassign`, \
+    Authority commands: `matchlive`, \
     `serverconfig`, `track`, `trackstream`."
 )]
 pub enum ServerConfigAuthorities {
@@ -87,6 +93,49 @@ pub struct ServerConfigAuthoritiesRemove {
 #[command(name = "list", desc = "Display all current authority roles")]
 pub struct ServerConfigAuthoritiesList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "channels",
+    desc = "Restrict commands to certain channels",
+    help = "Restrict the usage of commands to certain channels.\n\
+    If no channels are specified, commands can be used in all channels.\n\
+    Authority commands can always be used in any channel."
+)]
+pub enum ServerConfigChannels {
+    #[command(name = "add")]
+    Add(ServerConfigChannelsAdd),
+    #[command(name = "remove")]
+    Remove(ServerConfigChannelsRemove),
+    #[command(name = "list")]
+    List(ServerConfigChannelsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Allow commands in a channel")]
+pub struct ServerConfigChannelsAdd {
+    #[command(desc = "Specify the channel in which commands should be allowed")]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Disallow commands in a channel",
+    help = "Disallow commands in a channel.\n\
+    If this removes the last channel, commands will be allowed in all channels again."
+)]
+pub struct ServerConfigChannelsRemove {
+    #[command(desc = "Specify the channel in which commands should no longer be allowed")]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "Display all channels in which commands are allowed"
+)]
+pub struct ServerConfigChannelsList;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "edit", desc = "Adjust configurations for a server")]
 pub struct ServerConfigEdit {
@@ -98,6 +147,8 @@ pub struct ServerConfigEdit {
         With this option you can make those embeds minimized by default.\n\
         Affected commands are: `compare score`, `recent score`, `recent simulate`, \
         and any command showing top scores when the `index` option is specified.\n\
+        `Maximize notable scores` shows scores maximized if they're in the top50 of \
+        the user's top scores or of the map's global leaderboard and minimized otherwise.\n\
         Applies only if the member has not specified a config for themselves."
     )]
     score_embeds: Option<ScoreSize>,
@@ -122,6 +173,13 @@ pub struct ServerConfigEdit {
         The value must be between 1 and 100, defaults to 50."
     )]
     track_limit: Option<i64>,
+    #[command(
+        desc = "Choose how osu! tracking notifications should look",
+        help = "Choose how osu! tracking notifications should look.\n\
+        `Full score embed` shows the score with all its details, \
+        `Compact one-liner` only mentions the map, grade, pp, and accuracy in a single line."
+    )]
+    track_template: Option<TrackTemplate>,
     #[command(
         desc = "Specify whether the recent command should show max or if-fc pp when minimized"
     )]
@@ -149,6 +207,13 @@ pub struct ServerConfigEdit {
         Applies only if the member has not specified a config for themselves."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify a default gamemode for commands",
+        help = "Specify a default gamemode for commands.\n\
+        When the `mode` option is not specified in a command, this mode will be chosen.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    default_mode: Option<ConfigGameMode>,
 }
 
 impl ServerConfigEdit {
@@ -159,11 +224,13 @@ impl ServerConfigEdit {
             list_embeds,
             retries,
             track_limit,
+            track_template,
             minimized_pp,
             render_button,
             allow_custom_skins,
             hide_medal_solutions,
             score_data,
+            default_mode,
         } = self;
 
         song_commands.is_some()
@@ -171,11 +238,13 @@ impl ServerConfigEdit {
             || list_embeds.is_some()
             || retries.is_some()
             || track_limit.is_some()
+            || track_template.is_some()
             || minimized_pp.is_some()
             || render_button.is_some()
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
             || score_data.is_some()
+            || default_mode.is_some()
     }
 }
 
@@ -203,6 +272,7 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
         ServerConfig::Authorities(args) => {
             return super::authorities((&mut command).into(), args.into()).await
         }
+        ServerConfig::Channels(args) => return command_channels(command, guild_id, args).await,
         ServerConfig::Edit(edit) => edit,
     };
 
@@ -215,10 +285,12 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
                 retries,
                 song_commands,
                 track_limit,
+                track_template,
                 render_button,
                 allow_custom_skins,
                 hide_medal_solutions,
                 score_data,
+                default_mode,
             } = args;
 
             if let Some(score_embeds) = score_embeds {
@@ -241,6 +313,10 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
                 config.track_limit = Some(limit as u8);
             }
 
+            if let Some(track_template) = track_template {
+                config.track_template = Some(track_template);
+            }
+
             if let Some(with_lyrics) = song_commands {
                 config.allow_songs = Some(with_lyrics == EnableDisable::Enable);
             }
@@ -260,6 +336,10 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
             if let Some(score_data) = score_data {
                 config.legacy_scores = Some(score_data == ScoreData::Stable);
             }
+
+            if let Some(default_mode) = default_mode {
+                config.default_mode = default_mode.into();
+            }
         };
 
         if let Err(err) = Context::guild_config().update(guild_id, f).await {
@@ -287,3 +367,53 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
 
     Ok(())
 }
+
+async fn command_channels(
+    command: InteractionCommand,
+    guild_id: Id<GuildMarker>,
+    args: ServerConfigChannels,
+) -> Result<()> {
+    let channels = match args {
+        ServerConfigChannels::List(_) => {
+            Context::guild_config()
+                .peek(guild_id, |config| config.command_channels.clone())
+                .await
+        }
+        args => {
+            let f = |config: &mut GuildConfig| {
+                match args {
+                    ServerConfigChannels::Add(args) => {
+                        if !config.command_channels.contains(&args.channel) {
+                            config.command_channels.push(args.channel);
+                        }
+                    }
+                    ServerConfigChannels::Remove(args) => {
+                        config
+                            .command_channels
+                            .retain(|&channel| channel != args.channel);
+                    }
+                    ServerConfigChannels::List(_) => {}
+                }
+
+                config.command_channels.clone()
+            };
+
+            match Context::guild_config().update(guild_id, f).await {
+                Ok(channels) => channels,
+                Err(err) => {
+                    let _ = command.error_callback(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to update guild config"));
+                }
+            }
+        }
+    };
+
+    let mut content = command_channels_content(&channels);
+    content.push_str("\nAuthority commands can be used in any channel.");
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, hash::Hash, sync::Mutex};
+use std::{collections::HashMap, hash::Hash, str::FromStr, sync::Mutex};
 
 use bathbot_util::IntHasher;
 use time::OffsetDateTime;
@@ -6,39 +6,73 @@ use time::OffsetDateTime;
 pub struct Buckets([Mutex<Bucket>; 8]);
 
 impl Buckets {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        let make_bucket = |delay, time_span, limit| {
-            let ratelimit = Ratelimit {
-                delay,
-                limit: Some((time_span, limit)),
-            };
-
-            Mutex::new(Bucket::new(ratelimit))
+    /// Create all buckets, using the given configs over the default ones.
+    pub fn new(configs: &HashMap<BucketName, BucketConfig, IntHasher>) -> Self {
+        let make_bucket = |bucket: BucketName| {
+            let config = bucket.config(configs);
+
+            Mutex::new(Bucket::new(config.into()))
         };
 
-        Self([
-            make_bucket(0, 9, 4),    // All
-            make_bucket(1, 8, 2),    // BgBigger
-            make_bucket(0, 10, 4),   // BgHint
-            make_bucket(2, 20, 3),   // BgSkip
-            make_bucket(15, 0, 1),   // MatchCompare
-            make_bucket(5, 900, 3),  // MatchLive
-            make_bucket(60, 600, 2), // Render
-            make_bucket(20, 0, 1),   // Songs
-        ])
+        Self(BucketName::ALL.map(make_bucket))
+    }
+
+    /// Swap the ratelimits of all buckets while keeping their current users.
+    pub fn reconfigure(&self, configs: &HashMap<BucketName, BucketConfig, IntHasher>) {
+        for bucket in BucketName::ALL {
+            self.get(bucket).lock().unwrap().ratelimit = bucket.config(configs).into();
+        }
     }
 
     pub fn get(&self, bucket: BucketName) -> &Mutex<Bucket> {
-        match bucket {
-            BucketName::All => &self.0[0],
-            BucketName::BgBigger => &self.0[1],
-            BucketName::BgHint => &self.0[2],
-            BucketName::BgSkip => &self.0[3],
-            BucketName::MatchCompare => &self.0[4],
-            BucketName::MatchLive => &self.0[5],
-            BucketName::Render => &self.0[6],
-            BucketName::Songs => &self.0[7],
+        &self.0[bucket as usize]
+    }
+}
+
+/// Parameters of a bucket's ratelimit.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BucketConfig {
+    /// Seconds between two uses
+    pub delay: i64,
+    /// Seconds in which at most `limit` uses are allowed
+    pub time_span: i64,
+    pub limit: i32,
+}
+
+impl BucketConfig {
+    const fn new(delay: i64, time_span: i64, limit: i32) -> Self {
+        Self {
+            delay,
+            time_span,
+            limit,
+        }
+    }
+}
+
+impl FromStr for BucketConfig {
+    type Err = ();
+
+    /// Parses the form `delay,time_span,limit` e.g. `60,600,2`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = s.split(',').map(str::trim);
+
+        let delay = values.next().and_then(|n| n.parse().ok()).ok_or(())?;
+        let time_span = values.next().and_then(|n| n.parse().ok()).ok_or(())?;
+        let limit = values.next().and_then(|n| n.parse().ok()).ok_or(())?;
+
+        if values.next().is_some() || delay < 0 || time_span < 0 || limit < 1 {
+            return Err(());
+        }
+
+        Ok(Self::new(delay, time_span, limit))
+    }
+}
+
+impl From<BucketConfig> for Ratelimit {
+    fn from(config: BucketConfig) -> Self {
+        Self {
+            delay: config.delay,
+            limit: Some((config.time_span, config.limit)),
         }
     }
 }
@@ -116,3 +150,94 @@ pub enum BucketName {
     Render,
     Songs,
 }
+
+impl BucketName {
+    pub const ALL: [Self; 8] = [
+        Self::All,
+        Self::BgBigger,
+        Self::BgHint,
+        Self::BgSkip,
+        Self::MatchCompare,
+        Self::MatchLive,
+        Self::Render,
+        Self::Songs,
+    ];
+
+    fn config(self, configs: &HashMap<BucketName, BucketConfig, IntHasher>) -> BucketConfig {
+        configs
+            .get(&self)
+            .copied()
+            .unwrap_or_else(|| self.default_config())
+    }
+
+    const fn default_config(self) -> BucketConfig {
+        match self {
+            Self::All => BucketConfig::new(0, 9, 4),
+            Self::BgBigger => BucketConfig::new(1, 8, 2),
+            Self::BgHint => BucketConfig::new(0, 10, 4),
+            Self::BgSkip => BucketConfig::new(2, 20, 3),
+            Self::MatchCompare => BucketConfig::new(15, 0, 1),
+            Self::MatchLive => BucketConfig::new(5, 900, 3),
+            Self::Render => BucketConfig::new(60, 600, 2),
+            Self::Songs => BucketConfig::new(20, 0, 1),
+        }
+    }
+
+    /// Name of the env variable through which the bucket can be configured
+    pub const fn env_var(self) -> &'static str {
+        match self {
+            Self::All => "BUCKET_ALL",
+            Self::BgBigger => "BUCKET_BG_BIGGER",
+            Self::BgHint => "BUCKET_BG_HINT",
+            Self::BgSkip => "BUCKET_BG_SKIP",
+            Self::MatchCompare => "BUCKET_MATCH_COMPARE",
+            Self::MatchLive => "BUCKET_MATCH_LIVE",
+            Self::Render => "BUCKET_RENDER",
+            Self::Songs => "BUCKET_SONGS",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bucket_config() {
+        assert_eq!("60,600,2".parse(), Ok(BucketConfig::new(60, 600, 2)));
+        assert_eq!(" 5, 0 ,1".parse(), Ok(BucketConfig::new(5, 0, 1)));
+        assert!("60,600".parse::<BucketConfig>().is_err());
+        assert!("60,600,2,1".parse::<BucketConfig>().is_err());
+        assert!("60,600,0".parse::<BucketConfig>().is_err());
+        assert!("-1,600,2".parse::<BucketConfig>().is_err());
+    }
+
+    #[test]
+    fn configured_bucket_overrides_default() {
+        let mut configs = HashMap::default();
+        configs.insert(BucketName::Render, BucketConfig::new(5, 50, 10));
+
+        let buckets = Buckets::new(&configs);
+
+        let render = buckets.get(BucketName::Render).lock().unwrap();
+        assert_eq!(render.ratelimit.delay, 5);
+        assert_eq!(render.ratelimit.limit, Some((50, 10)));
+
+        let songs = buckets.get(BucketName::Songs).lock().unwrap();
+        assert_eq!(songs.ratelimit.delay, 20);
+        assert_eq!(songs.ratelimit.limit, Some((0, 1)));
+    }
+
+    #[test]
+    fn reconfigure_resets_to_default() {
+        let mut configs = HashMap::default();
+        configs.insert(BucketName::Songs, BucketConfig::new(1, 2, 3));
+
+        let buckets = Buckets::new(&configs);
+        buckets.reconfigure(&HashMap::default());
+
+        let songs = buckets.get(BucketName::Songs).lock().unwrap();
+        assert_eq!(songs.ratelimit.delay, 20);
+        assert_eq!(songs.ratelimit.limit, Some((0, 1)));
+    }
+}
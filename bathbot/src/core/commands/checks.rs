@@ -81,6 +81,59 @@ pub async fn check_authority(
     Ok(None)
 }
 
+/// Allowed channel -> None
+/// Restricted channel -> Some(message to user)
+pub async fn check_command_channel(
+    guild: Option<Id<GuildMarker>>,
+    channel: Id<ChannelMarker>,
+    authority: bool,
+) -> Option<String> {
+    let guild_id = guild?;
+
+    let allowed = Context::guild_config()
+        .peek(guild_id, |config| {
+            if is_command_channel(&config.command_channels, channel, authority) {
+                None
+            } else {
+                Some(config.command_channels.clone())
+            }
+        })
+        .await?;
+
+    Some(command_channels_content(&allowed))
+}
+
+/// Lists the channels in which commands may be used
+pub fn command_channels_content(channels: &[Id<ChannelMarker>]) -> String {
+    if channels.is_empty() {
+        return "Commands can be used in all channels".to_owned();
+    }
+
+    let mut content = String::from("Commands can only be used in these channels: ");
+    content.reserve(channels.len() * 24);
+    let mut channels = channels.iter();
+
+    if let Some(first) = channels.next() {
+        let _ = write!(content, "<#{first}>");
+
+        for channel in channels {
+            let _ = write!(content, ", <#{channel}>");
+        }
+    }
+
+    content
+}
+
+/// Authority commands may be used anywhere, other commands only in the
+/// configured channels or, if none are configured, in all channels.
+fn is_command_channel(
+    allowed: &[Id<ChannelMarker>],
+    channel: Id<ChannelMarker>,
+    authority: bool,
+) -> bool {
+    authority || allowed.is_empty() || allowed.contains(&channel)
+}
+
 pub async fn check_guild_permissions(
     user: Id<UserMarker>,
     guild: Id<GuildMarker>,
@@ -217,3 +270,18 @@ pub enum RolesLookup {
     NotChecked,
     NotFound,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_channel_gating() {
+        let allowed = [Id::new(1), Id::new(2)];
+
+        assert!(is_command_channel(&[], Id::new(3), false));
+        assert!(is_command_channel(&allowed, Id::new(2), false));
+        assert!(!is_command_channel(&allowed, Id::new(3), false));
+        assert!(is_command_channel(&allowed, Id::new(3), true));
+    }
+}
@@ -1,6 +1,16 @@
-use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Debug,
+    mem::MaybeUninit,
+    path::PathBuf,
+    str::FromStr,
+    sync::{RwLock, RwLockReadGuard},
+    time::Duration,
+};
 
-use eyre::Result;
+use bathbot_util::{constants::DARK_GREEN, EmbedBuilder, IntHasher};
+use eyre::{Result, WrapErr};
 use once_cell::sync::OnceCell;
 use rosu_v2::model::Grade;
 use twilight_model::id::{
@@ -8,6 +18,7 @@ use twilight_model::id::{
     Id,
 };
 
+use super::buckets::{BucketConfig, BucketName};
 use crate::util::{CustomEmote, Emote};
 
 static CONFIG: OnceCell<BotConfig> = OnceCell::new();
@@ -19,7 +30,6 @@ pub struct BotConfig {
     pub paths: Paths,
     #[cfg(feature = "server")]
     pub server: Server,
-    grades: Box<[Box<str>; 9]>,
     emotes: Box<[CustomEmote; 13]>,
     pub redis_host: Box<str>,
     pub redis_port: u16,
@@ -27,6 +37,42 @@ pub struct BotConfig {
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
+    pub member_chunk_timeout: Duration,
+    /// Seconds for which cold resume data is kept in redis
+    pub cold_resume_duration: usize,
+    /// Whether retrieved mapsets are stored in the database.
+    ///
+    /// Disabling it saves space but repeated lookups of the same map will
+    /// have to request the osu!api every time. Score listings from the
+    /// database such as `/scores server` only include scores whose map was
+    /// stored so they will be missing scores on maps that were retrieved
+    /// while this was disabled.
+    pub store_maps: bool,
+    /// The `.env` file that was loaded on startup
+    env_path: PathBuf,
+    /// Variables that were set before the `.env` file was loaded
+    process_vars: HashSet<String>,
+    reloadable: RwLock<ReloadableConfig>,
+}
+
+/// Config values that can be swapped at runtime through
+/// [`BotConfig::reload`].
+#[derive(Debug, PartialEq)]
+pub struct ReloadableConfig {
+    /// Custom grade emotes; unconfigured ones are `None`
+    grades: [Option<&'static str>; 9],
+    /// Color of embeds that don't specify one themselves
+    pub embed_color: u32,
+    /// Ratelimits of buckets that should differ from their default
+    pub buckets: HashMap<BucketName, BucketConfig, IntHasher>,
+}
+
+/// Outcome of [`BotConfig::reload`]
+pub struct ConfigReload {
+    /// Descriptions of the values that were swapped
+    pub changed: Vec<String>,
+    /// Env variables that were modified but require a restart
+    pub ignored: Vec<&'static str>,
 }
 
 #[derive(Debug)]
@@ -60,32 +106,66 @@ pub struct Tokens {
 }
 
 impl BotConfig {
+    const DEFAULT_COLD_RESUME_DURATION: usize = 240;
+    const DEFAULT_MEMBER_CHUNK_TIMEOUT: Duration = Duration::from_secs(120);
+    const DEFAULT_STORE_MAPS: bool = true;
+    const EMOTES: [&'static str; 13] = [
+        "osu",
+        "osu_std",
+        "osu_taiko",
+        "osu_ctb",
+        "osu_mania",
+        "twitch",
+        "tracking",
+        "jump_start",
+        "single_step_back",
+        "my_position",
+        "single_step",
+        "jump_end",
+        "miss",
+    ];
+    /// Env variables that are only read on startup
+    const RESTART_REQUIRED: &'static [&'static str] = &[
+        "DATABASE_URL",
+        "DISCORD_TOKEN",
+        "OSU_CLIENT_ID",
+        "OSU_CLIENT_SECRET",
+        "OSU_API_KEY",
+        "ORDR_KEY",
+        "GITHUB_TOKEN",
+        #[cfg(feature = "twitch")]
+        "TWITCH_CLIENT_ID",
+        #[cfg(feature = "twitch")]
+        "TWITCH_TOKEN",
+        "BG_PATH",
+        "ASSETS_PATH",
+        "MAP_PATH",
+        #[cfg(feature = "server")]
+        "WEBSITE_PATH",
+        #[cfg(feature = "server")]
+        "SERVER_PORT",
+        #[cfg(feature = "server")]
+        "PUBLIC_URL",
+        "REDIS_HOST",
+        "REDIS_PORT",
+        "REDIS_DB_IDX",
+        "OWNER_USER_ID",
+        "DEV_GUILD_ID",
+        "HL_IMAGE_CHANNEL",
+        "MEMBER_CHUNK_TIMEOUT",
+        "COLD_RESUME_DURATION",
+        "STORE_MAPS",
+    ];
+
     pub fn get() -> &'static Self {
         CONFIG
             .get()
             .expect("`BotConfig::init` must be called first")
     }
 
-    pub fn init() -> Result<()> {
-        let grade_strs = ["F", "D", "C", "B", "A", "S", "X", "SH", "XH"];
-        let grades = Self::parse_emotes::<Grade, _, 9>(grade_strs)?;
-
-        let emote_strs = [
-            "osu",
-            "osu_std",
-            "osu_taiko",
-            "osu_ctb",
-            "osu_mania",
-            "twitch",
-            "tracking",
-            "jump_start",
-            "single_step_back",
-            "my_position",
-            "single_step",
-            "jump_end",
-            "miss",
-        ];
-        let emotes = Self::parse_emotes::<Emote, _, 13>(emote_strs)?;
+    pub fn init(env_path: PathBuf, process_vars: HashSet<String>) -> Result<()> {
+        let reloadable = ReloadableConfig::from_vars(|name| env::var(name).ok(), None)?;
+        let emotes = Self::parse_emotes::<Emote, _, 13>(Self::EMOTES)?;
 
         let config = BotConfig {
             database_url: env_var("DATABASE_URL")?,
@@ -113,7 +193,6 @@ impl BotConfig {
                 port: env_var("SERVER_PORT")?,
                 public_url: env_var("PUBLIC_URL")?,
             },
-            grades,
             emotes,
             redis_host: env_var("REDIS_HOST")?,
             redis_port: env_var("REDIS_PORT")?,
@@ -121,8 +200,18 @@ impl BotConfig {
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
+            member_chunk_timeout: opt_env_var("MEMBER_CHUNK_TIMEOUT")?
+                .map_or(Self::DEFAULT_MEMBER_CHUNK_TIMEOUT, Duration::from_secs),
+            cold_resume_duration: opt_env_var("COLD_RESUME_DURATION")?
+                .unwrap_or(Self::DEFAULT_COLD_RESUME_DURATION),
+            store_maps: opt_env_var("STORE_MAPS")?.unwrap_or(Self::DEFAULT_STORE_MAPS),
+            env_path,
+            process_vars,
+            reloadable: RwLock::new(reloadable),
         };
 
+        EmbedBuilder::set_default_color(config.reloadable().embed_color);
+
         if CONFIG.set(config).is_err() {
             warn!("CONFIG was already set");
         }
@@ -130,6 +219,97 @@ impl BotConfig {
         Ok(())
     }
 
+    /// Initialize the config with placeholder values so that tests can use
+    /// [`BotConfig::get`].
+    #[cfg(test)]
+    pub(crate) fn init_test(store_maps: bool) {
+        let _ = CONFIG.set(BotConfig {
+            database_url: Box::default(),
+            tokens: Tokens {
+                discord: Box::default(),
+                osu_client_id: 0,
+                osu_client_secret: Box::default(),
+                osu_key: Box::default(),
+                ordr_key: Box::default(),
+                github_token: Box::default(),
+                #[cfg(feature = "twitch")]
+                twitch_client_id: Box::default(),
+                #[cfg(feature = "twitch")]
+                twitch_token: Box::default(),
+            },
+            paths: Paths {
+                backgrounds: PathBuf::new(),
+                assets: PathBuf::new(),
+                maps: PathBuf::new(),
+                #[cfg(feature = "server")]
+                website: PathBuf::new(),
+            },
+            #[cfg(feature = "server")]
+            server: Server {
+                port: 0,
+                public_url: Box::default(),
+            },
+            emotes: Box::new(Self::EMOTES.map(|name| CustomEmote::new(1, name.into()))),
+            redis_host: Box::default(),
+            redis_port: 0,
+            redis_db_idx: 0,
+            owner: Id::new(1),
+            dev_guild: Id::new(1),
+            hl_channel: Id::new(1),
+            member_chunk_timeout: Self::DEFAULT_MEMBER_CHUNK_TIMEOUT,
+            cold_resume_duration: Self::DEFAULT_COLD_RESUME_DURATION,
+            store_maps,
+            env_path: PathBuf::new(),
+            process_vars: HashSet::new(),
+            reloadable: RwLock::new(ReloadableConfig::from_vars(|_| None, None).unwrap()),
+        });
+    }
+
+    /// Re-read the `.env` file and swap all values of [`ReloadableConfig`].
+    ///
+    /// The process' environment is left untouched; modifications of other
+    /// variables are only reported.
+    ///
+    /// Same as on startup, variables that were set before the `.env` file was
+    /// loaded take precedence over the file. All others are taken from the
+    /// file only so removing them from it unsets them.
+    pub fn reload() -> Result<ConfigReload> {
+        let config = Self::get();
+
+        let mut vars = HashMap::new();
+
+        let iter =
+            dotenvy::from_path_iter(&config.env_path).wrap_err("Failed to read .env file")?;
+
+        for item in iter {
+            let (name, value) = item.wrap_err("Failed to parse .env file")?;
+            vars.insert(name, value);
+        }
+
+        let restart_vars = Self::RESTART_REQUIRED.iter().chain(Self::EMOTES.iter());
+        let ignored = restart_required(restart_vars, &vars, &config.process_vars, |name| {
+            env::var(name).ok()
+        });
+
+        let lookup = |name: &str| {
+            reload_var(name, &vars, &config.process_vars, |name| {
+                env::var(name).ok()
+            })
+        };
+
+        let mut curr = config.reloadable.write().unwrap();
+        let reloadable = ReloadableConfig::from_vars(lookup, Some(&curr))?;
+        let changed = curr.changes(&reloadable);
+        EmbedBuilder::set_default_color(reloadable.embed_color);
+        *curr = reloadable;
+
+        Ok(ConfigReload { changed, ignored })
+    }
+
+    pub fn reloadable(&self) -> RwLockReadGuard<'_, ReloadableConfig> {
+        self.reloadable.read().unwrap()
+    }
+
     fn parse_emotes<K, V, const N: usize>(names: [&str; N]) -> Result<Box<[V; N]>>
     where
         K: FromStr + AsUsize,
@@ -150,8 +330,10 @@ impl BotConfig {
         Ok(unsafe { Box::from_raw(Box::into_raw(emotes) as *mut [V; N]) })
     }
 
-    pub fn grade(&self, grade: Grade) -> &str {
-        self.grades[grade as usize].as_ref()
+    /// The configured emote for the grade or a plain-text label if there
+    /// is none.
+    pub fn grade(&self, grade: Grade) -> &'static str {
+        self.reloadable().grade(grade)
     }
 
     pub fn emote(&self, emote: Emote) -> &CustomEmote {
@@ -159,6 +341,121 @@ impl BotConfig {
     }
 }
 
+impl ReloadableConfig {
+    const GRADES: [(Grade, &'static str); 9] = [
+        (Grade::F, "F"),
+        (Grade::D, "D"),
+        (Grade::C, "C"),
+        (Grade::B, "B"),
+        (Grade::A, "A"),
+        (Grade::S, "S"),
+        (Grade::X, "X"),
+        (Grade::SH, "SH"),
+        (Grade::XH, "XH"),
+    ];
+
+    /// Parse the values through `vars`.
+    ///
+    /// Grade emotes are handed out as `&'static str` so they're leaked;
+    /// emotes that are unchanged compared to `prev` are reused instead.
+    fn from_vars(vars: impl Fn(&str) -> Option<String>, prev: Option<&Self>) -> Result<Self> {
+        let mut grades = [None; 9];
+
+        for (grade, name) in Self::GRADES {
+            let prev = prev.and_then(|prev| prev.grades[grade as usize]);
+
+            grades[grade as usize] =
+                parse_var::<Box<str>>(name, vars(name))?.map(|emote| match prev {
+                    Some(prev) if *prev == *emote => prev,
+                    _ => &*Box::leak(emote),
+                });
+        }
+
+        let embed_color = parse_var::<EmbedColor>("EMBED_COLOR", vars("EMBED_COLOR"))?
+            .map_or(DARK_GREEN, |color| color.0);
+
+        let mut buckets = HashMap::default();
+
+        for bucket in BucketName::ALL {
+            let name = bucket.env_var();
+
+            if let Some(config) = parse_var(name, vars(name))? {
+                buckets.insert(bucket, config);
+            }
+        }
+
+        Ok(Self {
+            grades,
+            embed_color,
+            buckets,
+        })
+    }
+
+    fn grade(&self, grade: Grade) -> &'static str {
+        self.grades[grade as usize].unwrap_or_else(|| Self::grade_label(grade))
+    }
+
+    /// Plain-text fallback for grades without custom emote
+    fn grade_label(grade: Grade) -> &'static str {
+        match grade {
+            Grade::F => "F",
+            Grade::D => "D",
+            Grade::C => "C",
+            Grade::B => "B",
+            Grade::A => "A",
+            Grade::S => "S",
+            Grade::SH => "SH",
+            Grade::X => "SS",
+            Grade::XH => "SSH",
+        }
+    }
+
+    /// Describe all values that differ between `self` and `new`.
+    fn changes(&self, new: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        for (grade, name) in Self::GRADES {
+            let old = self.grades[grade as usize];
+            let new = new.grades[grade as usize];
+
+            if old != new {
+                let old = old.unwrap_or("unset");
+                let new = new.unwrap_or("unset");
+                changes.push(format!("Grade emote {name}: {old} -> {new}"));
+            }
+        }
+
+        if self.embed_color != new.embed_color {
+            changes.push(format!(
+                "Embed color: #{:06X} -> #{:06X}",
+                self.embed_color, new.embed_color
+            ));
+        }
+
+        for bucket in BucketName::ALL {
+            let old = self.buckets.get(&bucket);
+            let new = new.buckets.get(&bucket);
+
+            if old == new {
+                continue;
+            }
+
+            let fmt = |config: Option<&BucketConfig>| match config {
+                Some(BucketConfig {
+                    delay,
+                    time_span,
+                    limit,
+                }) => format!("{delay},{time_span},{limit}"),
+                None => "default".to_owned(),
+            };
+
+            changes.push(format!("Bucket {bucket:?}: {} -> {}", fmt(old), fmt(new)));
+        }
+
+        changes
+    }
+}
+
 trait EnvKind: Sized {
     const EXPECTED: &'static str;
 
@@ -181,15 +478,42 @@ macro_rules! env_kind {
 
 env_kind! {
     Box<str>: |s| { Ok(s.into_boxed_str()) },
+    bool: |s| { s.parse().map_err(|_| s) },
     u8: |s| { s.parse().map_err(|_| s) },
     u16: |s| { s.parse().map_err(|_| s) },
     u64: |s| { s.parse().map_err(|_| s) },
+    usize: |s| { s.parse().map_err(|_| s) },
     PathBuf: |s| { s.parse().map_err(|_| s) },
     Id<UserMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<GuildMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<ChannelMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
 }
 
+/// Hex color such as `1F8B4C` or `#1F8B4C`
+struct EmbedColor(u32);
+
+impl EnvKind for EmbedColor {
+    const EXPECTED: &'static str = "a non-zero hex color such as `#1F8B4C`";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        let hex = s.trim_start_matches('#');
+
+        match u32::from_str_radix(hex, 16) {
+            Ok(color @ 1..=0xFFFFFF) => Ok(Self(color)),
+            _ => Err(s),
+        }
+    }
+}
+
+impl EnvKind for BucketConfig {
+    const EXPECTED: &'static str =
+        "a bucket config of the form `delay,time_span,limit` such as `60,600,2`";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        s.parse().map_err(|_| s)
+    }
+}
+
 impl EnvKind for CustomEmote {
     const EXPECTED: &'static str = "an emote of the form `<:name:id>`";
 
@@ -206,9 +530,17 @@ impl EnvKind for CustomEmote {
 }
 
 fn env_var<T: EnvKind>(name: &str) -> Result<T> {
-    let value = env::var(name).map_err(|_| eyre!("missing env variable `{name}`"))?;
+    opt_env_var(name)?.ok_or_else(|| eyre!("missing env variable `{name}`"))
+}
+
+fn opt_env_var<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    parse_var(name, env::var(name).ok())
+}
 
-    T::from_str(value).map_err(|value| {
+fn parse_var<T: EnvKind>(name: &str, value: Option<String>) -> Result<Option<T>> {
+    let Some(value) = value else { return Ok(None) };
+
+    T::from_str(value).map(Some).map_err(|value| {
         eyre!(
             "failed to parse env variable `{name}={value}`; expected {expected}",
             expected = T::EXPECTED
@@ -216,6 +548,41 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+/// The value of a variable after reloading the `.env` file.
+fn reload_var(
+    name: &str,
+    file: &HashMap<String, String>,
+    process_vars: &HashSet<String>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    if process_vars.contains(name) {
+        env(name)
+    } else {
+        file.get(name).cloned()
+    }
+}
+
+/// Names of the variables that have a new value in `new` compared to `curr`.
+///
+/// Variables that were set before the `.env` file was loaded are skipped
+/// since the file doesn't affect them.
+fn restart_required<'n>(
+    names: impl IntoIterator<Item = &'n &'static str>,
+    new: &HashMap<String, String>,
+    process_vars: &HashSet<String>,
+    curr: impl Fn(&str) -> Option<String>,
+) -> Vec<&'static str> {
+    names
+        .into_iter()
+        .copied()
+        .filter(|name| !process_vars.contains(*name))
+        .filter(|name| {
+            new.get(*name)
+                .is_some_and(|value| curr(name).as_ref() != Some(value))
+        })
+        .collect()
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
@@ -231,3 +598,169 @@ impl AsUsize for Emote {
         self as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_embed_color() {
+        let parse = |s: &str| EmbedColor::from_str(s.to_owned()).map(|color| color.0);
+
+        assert_eq!(parse("#1F8B4C"), Ok(DARK_GREEN));
+        assert_eq!(parse("ff0000"), Ok(0xFF0000));
+        assert!(parse("000000").is_err());
+        assert!(parse("1000000").is_err());
+        assert!(parse("green").is_err());
+    }
+
+    #[test]
+    fn parse_store_maps() {
+        let parse = |value: Option<&str>| {
+            parse_var("STORE_MAPS", value.map(str::to_owned))
+                .map(|store_maps| store_maps.unwrap_or(BotConfig::DEFAULT_STORE_MAPS))
+        };
+
+        assert!(parse(None).unwrap());
+        assert!(parse(Some("true")).unwrap());
+        assert!(!parse(Some("false")).unwrap());
+        assert!(parse(Some("no")).is_err());
+    }
+
+    #[test]
+    fn reloadable_changes() {
+        let mut grades = [Some("<:F:1>"); 9];
+
+        let old = ReloadableConfig {
+            grades,
+            embed_color: DARK_GREEN,
+            buckets: HashMap::default(),
+        };
+
+        assert!(old.changes(&old).is_empty());
+
+        grades[Grade::X as usize] = Some("<:X:2>");
+        grades[Grade::F as usize] = None;
+        let mut buckets = HashMap::default();
+        buckets.insert(BucketName::Render, "5,50,10".parse().unwrap());
+
+        let new = ReloadableConfig {
+            grades,
+            embed_color: 0xFF0000,
+            buckets,
+        };
+
+        assert_eq!(
+            old.changes(&new),
+            [
+                "Grade emote F: <:F:1> -> unset",
+                "Grade emote X: <:F:1> -> <:X:2>",
+                "Embed color: #1F8B4C -> #FF0000",
+                "Bucket Render: default -> 5,50,10",
+            ]
+        );
+
+        assert_eq!(new.changes(&old)[3], "Bucket Render: 5,50,10 -> default");
+    }
+
+    #[test]
+    fn restart_required_vars() {
+        let new: HashMap<_, _> = [
+            ("DISCORD_TOKEN", "new_token"),
+            ("REDIS_PORT", "6379"),
+            ("osu", "<:osu:2>"),
+            ("EMBED_COLOR", "#FF0000"),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+        .collect();
+
+        let curr = |name: &str| match name {
+            "DISCORD_TOKEN" => Some("old_token".to_owned()),
+            "REDIS_PORT" => Some("6379".to_owned()),
+            "EMBED_COLOR" => Some("#1F8B4C".to_owned()),
+            _ => None,
+        };
+
+        let names = || {
+            BotConfig::RESTART_REQUIRED
+                .iter()
+                .chain(BotConfig::EMOTES.iter())
+        };
+
+        assert_eq!(
+            restart_required(names(), &new, &HashSet::new(), curr),
+            ["DISCORD_TOKEN", "osu"]
+        );
+
+        // Variables of the process take precedence over the file
+        let process_vars: HashSet<_> = ["DISCORD_TOKEN".to_owned()].into_iter().collect();
+
+        assert_eq!(
+            restart_required(names(), &new, &process_vars, curr),
+            ["osu"]
+        );
+    }
+
+    #[test]
+    fn reload_var_precedence() {
+        let file: HashMap<_, _> = [("EMBED_COLOR", "#FF0000"), ("A", "<:A:2>")]
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value.to_owned()))
+            .collect();
+
+        let process_vars: HashSet<_> = ["EMBED_COLOR".to_owned()].into_iter().collect();
+
+        // Values copied from the previous .env file on startup
+        let env = |name: &str| match name {
+            "EMBED_COLOR" => Some("#1F8B4C".to_owned()),
+            "A" => Some("<:A:1>".to_owned()),
+            "X" => Some("<:X:1>".to_owned()),
+            _ => None,
+        };
+
+        let var = |name: &str| reload_var(name, &file, &process_vars, env);
+
+        assert_eq!(var("EMBED_COLOR").as_deref(), Some("#1F8B4C"));
+        assert_eq!(var("A").as_deref(), Some("<:A:2>"));
+        assert_eq!(var("X"), None);
+    }
+
+    #[test]
+    fn reload_reuses_unchanged_grades() {
+        let vars = |emote: &'static str| {
+            move |name: &str| match name {
+                "A" => Some(emote.to_owned()),
+                "X" => Some("<:X:1>".to_owned()),
+                _ => None,
+            }
+        };
+
+        let prev = ReloadableConfig::from_vars(vars("<:A:1>"), None).unwrap();
+        let next = ReloadableConfig::from_vars(vars("<:A:2>"), Some(&prev)).unwrap();
+
+        let prev_x = prev.grade(Grade::X);
+        let next_x = next.grade(Grade::X);
+        assert!(std::ptr::eq(prev_x, next_x));
+
+        assert_eq!(next.grade(Grade::A), "<:A:2>");
+        assert_eq!(next.grade(Grade::F), "F");
+        assert_eq!(prev.changes(&next), ["Grade emote A: <:A:1> -> <:A:2>"]);
+    }
+
+    #[test]
+    fn unconfigured_grade_label() {
+        let mut grades = [None; 9];
+        grades[Grade::A as usize] = Some("<:A:1>");
+
+        let config = ReloadableConfig {
+            grades,
+            embed_color: DARK_GREEN,
+            buckets: HashMap::default(),
+        };
+
+        assert_eq!(config.grade(Grade::A), "<:A:1>");
+        assert_eq!(config.grade(Grade::X), "SS");
+        assert_eq!(config.grade(Grade::SH), "SH");
+    }
+}
@@ -1,8 +1,59 @@
+use bathbot_util::IntHasher;
+use flexmap::tokio::TokioRwLockMap;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
 use super::BgGames;
-use crate::Context;
+use crate::{active::impls::BackgroundGame, core::BotMetrics, Context};
+
+type GameMap<T> = TokioRwLockMap<Id<ChannelMarker>, T, IntHasher>;
 
 impl Context {
     pub fn bg_games() -> &'static BgGames {
         &Context::get().data.games.bg
     }
+
+    pub async fn insert_bg_game(channel: Id<ChannelMarker>, game: BackgroundGame) {
+        insert_game(Context::bg_games(), channel, game).await;
+    }
+
+    pub async fn remove_bg_game(channel: Id<ChannelMarker>) -> Option<BackgroundGame> {
+        remove_game(Context::bg_games(), channel).await
+    }
+}
+
+async fn insert_game<T>(games: &GameMap<T>, channel: Id<ChannelMarker>, game: T) {
+    games.own(channel).await.insert(game);
+    BotMetrics::set_bg_games(games.len().await);
+}
+
+async fn remove_game<T>(games: &GameMap<T>, channel: Id<ChannelMarker>) -> Option<T> {
+    let game = games.write(&channel).await.remove();
+    BotMetrics::set_bg_games(games.len().await);
+
+    game
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::metrics::tests::bg_games_gauge;
+
+    #[tokio::test]
+    async fn bg_game_gauge() {
+        let games = GameMap::with_shard_amount_and_hasher(16, IntHasher);
+
+        insert_game(&games, Id::new(1), ()).await;
+        insert_game(&games, Id::new(2), ()).await;
+        assert_eq!(bg_games_gauge(), 2.0);
+
+        // Overwriting a game must not drift
+        insert_game(&games, Id::new(2), ()).await;
+        assert_eq!(bg_games_gauge(), 2.0);
+
+        assert!(remove_game(&games, Id::new(1)).await.is_some());
+        assert_eq!(bg_games_gauge(), 1.0);
+
+        assert!(remove_game(&games, Id::new(1)).await.is_none());
+        assert_eq!(bg_games_gauge(), 1.0);
+    }
 }
@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex, OnceLock, RwLock},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use bathbot_cache::Cache;
@@ -266,7 +266,7 @@ impl Context {
             clients,
             shard_senders,
             data,
-            buckets: Buckets::new(),
+            buckets: Buckets::new(&config.reloadable().buckets),
             member_requests: MemberRequests::new(tx),
             active_msgs: ActiveMessages::new(),
             start_time,
@@ -349,13 +349,108 @@ pub type ContextResult = (Vec<Shard>, tokio::sync::oneshot::Sender<()>);
 pub struct MemberRequests {
     pub tx: UnboundedSender<(Id<GuildMarker>, u64)>,
     pub pending_guilds: Mutex<HashSet<Id<GuildMarker>, IntHasher>>,
+    /// Guilds whose members were requested but whose last chunk has not
+    /// arrived yet.
+    in_flight: Mutex<HashMap<Id<GuildMarker>, InFlightRequest, IntHasher>>,
+}
+
+struct InFlightRequest {
+    /// `None` while the request is queued again after expiring
+    requested_at: Option<Instant>,
+    shard_id: u64,
+    attempts: u8,
 }
 
 impl MemberRequests {
+    /// How often members of a guild are requested before giving up
+    const MAX_ATTEMPTS: u8 = 3;
+
     fn new(tx: UnboundedSender<(Id<GuildMarker>, u64)>) -> Self {
         Self {
             tx,
             pending_guilds: Mutex::new(HashSet::default()),
+            in_flight: Mutex::new(HashMap::default()),
+        }
+    }
+
+    /// Mark the members of a guild as requested.
+    pub fn requested(&self, guild_id: Id<GuildMarker>, shard_id: u64) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+
+        let attempts = in_flight.get(&guild_id).map_or(0, |req| req.attempts);
+
+        let req = InFlightRequest {
+            requested_at: Some(Instant::now()),
+            shard_id,
+            attempts: attempts + 1,
+        };
+
+        in_flight.insert(guild_id, req);
+    }
+
+    /// Process a received member chunk.
+    ///
+    /// Returns `true` if it was the last chunk of a request that has not yet
+    /// completed or been dropped.
+    pub fn chunk_received(
+        &self,
+        guild_id: Id<GuildMarker>,
+        chunk_index: u32,
+        chunk_count: u32,
+    ) -> bool {
+        chunk_index + 1 >= chunk_count && self.in_flight.lock().unwrap().remove(&guild_id).is_some()
+    }
+
+    /// Queue all requests that were made at least `timeout` ago again.
+    ///
+    /// Requests that already used up all their attempts are dropped instead
+    /// and their guild ids are returned.
+    pub fn expire(&self, timeout: Duration) -> Vec<Id<GuildMarker>> {
+        let mut dropped = Vec::new();
+        let mut in_flight = self.in_flight.lock().unwrap();
+
+        in_flight.retain(|guild_id, req| {
+            let Some(requested_at) = req.requested_at else {
+                return true;
+            };
+
+            if requested_at.elapsed() < timeout {
+                return true;
+            }
+
+            if req.attempts >= Self::MAX_ATTEMPTS {
+                dropped.push(*guild_id);
+
+                return false;
+            }
+
+            self.pending_guilds.lock().unwrap().insert(*guild_id);
+
+            if let Err(err) = self.tx.send((*guild_id, req.shard_id)) {
+                warn!(?err, "Failed to re-forward member request");
+                dropped.push(*guild_id);
+
+                return false;
+            }
+
+            req.requested_at = None;
+
+            true
+        });
+
+        dropped
+    }
+
+    /// Periodically retry member requests whose chunks never arrived.
+    pub async fn expire_loop(&self, timeout: Duration) {
+        let mut interval = tokio::time::interval(timeout.min(Duration::from_secs(30)));
+
+        loop {
+            interval.tick().await;
+
+            for guild_id in self.expire(timeout) {
+                warn!(%guild_id, "Member chunks did not complete in time, giving up");
+            }
         }
     }
 }
@@ -531,6 +626,10 @@ async fn discord_gateway(
     let event_types = EventTypeFlags::CHANNEL_CREATE
         | EventTypeFlags::CHANNEL_DELETE
         | EventTypeFlags::CHANNEL_UPDATE
+        | EventTypeFlags::GATEWAY_CLOSE
+        | EventTypeFlags::GATEWAY_HELLO
+        | EventTypeFlags::GATEWAY_INVALIDATE_SESSION
+        | EventTypeFlags::GATEWAY_RECONNECT
         | EventTypeFlags::GUILD_CREATE
         | EventTypeFlags::GUILD_DELETE
         | EventTypeFlags::GUILD_UPDATE
@@ -543,6 +642,7 @@ async fn discord_gateway(
         | EventTypeFlags::MESSAGE_DELETE
         | EventTypeFlags::MESSAGE_DELETE_BULK
         | EventTypeFlags::READY
+        | EventTypeFlags::RESUMED
         | EventTypeFlags::ROLE_CREATE
         | EventTypeFlags::ROLE_DELETE
         | EventTypeFlags::ROLE_UPDATE
@@ -605,3 +705,47 @@ async fn bathbot_server(
 
     Ok((standby, tx))
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn never_completing_guild_expires() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let requests = MemberRequests::new(tx);
+
+        let completing = Id::new(1);
+        let stuck = Id::new(2);
+
+        requests.requested(completing, 0);
+        requests.requested(stuck, 3);
+
+        assert!(!requests.chunk_received(completing, 0, 2));
+        assert!(requests.chunk_received(completing, 1, 2));
+
+        assert!(requests.expire(Duration::from_secs(60)).is_empty());
+
+        for _ in 1..MemberRequests::MAX_ATTEMPTS {
+            assert!(requests.expire(Duration::ZERO).is_empty());
+            assert_eq!(rx.try_recv().unwrap(), (stuck, 3));
+            assert!(requests.pending_guilds.lock().unwrap().remove(&stuck));
+
+            // Queued again but not yet requested so it must not be re-queued
+            assert!(requests.expire(Duration::ZERO).is_empty());
+            assert!(rx.try_recv().is_err());
+
+            requests.requested(stuck, 3);
+        }
+
+        assert_eq!(requests.expire(Duration::ZERO), vec![stuck]);
+        assert!(requests.in_flight.lock().unwrap().is_empty());
+        assert!(rx.try_recv().is_err());
+
+        // A late chunk must not complete the request a second time
+        assert!(!requests.chunk_received(stuck, 0, 1));
+        assert!(requests.expire(Duration::ZERO).is_empty());
+    }
+}
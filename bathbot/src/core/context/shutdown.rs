@@ -10,7 +10,7 @@ use rkyv::{
 use twilight_gateway::Shard;
 use twilight_model::id::{marker::GuildMarker, Id};
 
-use crate::{util::ChannelExt, Context};
+use crate::{core::BotConfig, util::ChannelExt, Context};
 
 impl Context {
     #[cold]
@@ -38,19 +38,18 @@ impl Context {
         }
 
         let resume_data = Self::down_resumable(shards).await;
+        let store_duration = BotConfig::get().cold_resume_duration;
 
-        if let Err(err) = Context::cache().freeze(&resume_data).await {
+        if let Err(err) = Context::cache().freeze(&resume_data, store_duration).await {
             error!(?err, "Failed to freeze cache");
         }
 
-        const STORE_DURATION: usize = 240;
-
-        match this.store_guild_shards(STORE_DURATION).await {
+        match this.store_guild_shards(store_duration).await {
             Ok(len) => info!("Stored {len} guild shards"),
             Err(err) => error!(?err, "Failed to store guild shards"),
         }
 
-        match Context::store_miss_analyzer_guilds(STORE_DURATION).await {
+        match Context::store_miss_analyzer_guilds(store_duration).await {
             Ok(len) => info!("Stored {len} miss analyzer guilds"),
             Err(err) => error!(?err, "Failed to store miss analyzer guilds"),
         }
@@ -59,17 +58,16 @@ impl Context {
     /// Notify all active bg games that they'll be aborted due to a bot restart
     #[cold]
     async fn stop_all_games() -> usize {
-        let mut active_games = Vec::new();
+        let mut channels = Vec::new();
         let mut stream = Context::bg_games().iter();
 
         while let Some(guard) = stream.next().await {
-            let key = *guard.key();
-            let value = guard.value().to_owned();
-
-            active_games.push((key, value));
+            channels.push(*guard.key());
         }
 
-        if active_games.is_empty() {
+        drop(stream);
+
+        if channels.is_empty() {
             return 0;
         }
 
@@ -78,7 +76,11 @@ impl Context {
         let content = "I'll abort this game because I'm about to reboot, \
             you can start a new game again in just a moment...";
 
-        for (channel, game) in active_games {
+        for channel in channels {
+            let Some(game) = Context::remove_bg_game(channel).await else {
+                continue;
+            };
+
             match game.stop() {
                 Ok(_) => {
                     let _ = channel.plain_message(content).await;
@@ -5,7 +5,7 @@ use eyre::Result;
 use crate::{
     core::{
         commands::{
-            checks::check_authority,
+            checks::{check_authority, check_command_channel},
             interaction::{InteractionCommandKind, InteractionCommands, SlashCommand},
         },
         events::{EventKind, ProcessResult},
@@ -51,6 +51,17 @@ async fn process_command(
     command: InteractionCommand,
     cmd: InteractionCommandKind,
 ) -> Result<ProcessResult> {
+    // Restricted to certain channels?
+    let authority = cmd.flags().authority();
+
+    if let Some(content) =
+        check_command_channel(command.guild_id, command.channel_id, authority).await
+    {
+        command.error_callback(content).await?;
+
+        return Ok(ProcessResult::NoCommandChannel);
+    }
+
     match cmd {
         InteractionCommandKind::Chat(cmd) => match pre_process_command(&command, cmd).await? {
             Some(result) => return Ok(result),
@@ -13,7 +13,7 @@ use super::{EventKind, ProcessResult};
 use crate::{
     core::{
         buckets::BucketName,
-        commands::checks::{check_authority, check_channel_permissions},
+        commands::checks::{check_authority, check_channel_permissions, check_command_channel},
         BotMetrics, Context,
     },
     util::ChannelExt,
@@ -84,6 +84,15 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
         return Ok(ProcessResult::NoDM);
     }
 
+    // Restricted to certain channels?
+    // * Silently ignored so as not to spam other channels
+    if check_command_channel(msg.guild_id, msg.channel_id, cmd.flags.authority())
+        .await
+        .is_some()
+    {
+        return Ok(ProcessResult::NoCommandChannel);
+    }
+
     // Only for owner?
     // * Not necessary since there are no owner-only prefix commands
 
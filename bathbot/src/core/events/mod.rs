@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Instant,
+};
 
 use bathbot_cache::model::CachedArchive;
 use bathbot_model::twilight_model::{channel::Channel, guild::Guild};
@@ -10,7 +13,7 @@ use twilight_gateway::{error::ReceiveMessageErrorType, stream::ShardEventStream,
 use twilight_model::{gateway::CloseCode, user::User};
 
 use self::{interaction::handle_interaction, message::handle_message};
-use super::{buckets::BucketName, BotMetrics, Context};
+use super::{buckets::BucketName, BotMetrics, Context, ShardState};
 use crate::util::Authored;
 
 mod interaction;
@@ -27,6 +30,7 @@ enum ProcessResult {
     ),
     NoOwner,
     NoAuthority,
+    NoCommandChannel,
 }
 
 pub enum EventKind {
@@ -136,8 +140,20 @@ pub async fn event_loop(shards: &mut Vec<Shard>, mut reshard_rx: Receiver<()>) {
                         BotMetrics::event(&event, change);
                         let shard_id = shard.id().number();
 
+                        // Update the state before spawning so that
+                        // consecutive events can't be applied out of order
+                        if let Some(state) = shard_state(&event) {
+                            BotMetrics::shard_state(shard_id, state);
+                        }
+
+                        let kind = event.kind();
+
                         tokio::spawn(async move {
-                            if let Err(err) = handle_event(event, shard_id).await {
+                            let start = Instant::now();
+                            let res = handle_event(event, shard_id).await;
+                            BotMetrics::observe_event(kind, start.elapsed());
+
+                            if let Err(err) = res {
                                 error!(?err, "Failed to handle event");
                             }
                         });
@@ -193,21 +209,15 @@ async fn handle_event(event: Event, shard_id: u64) -> Result<()> {
                 "Received closing frame"
             )
         }
-        Event::GatewayClose(None) => {
-            warn!(shard_id, "Received closing frame")
-        }
-        Event::GatewayInvalidateSession(true) => {
-            warn!(
-                shard_id,
-                "Gateway has invalidated session but its reconnectable"
-            )
-        }
+        Event::GatewayClose(None) => warn!(shard_id, "Received closing frame"),
+        Event::GatewayInvalidateSession(true) => warn!(
+            shard_id,
+            "Gateway has invalidated session but its reconnectable"
+        ),
         Event::GatewayInvalidateSession(false) => {
             warn!(shard_id, "Gateway has invalidated session")
         }
-        Event::GatewayReconnect => {
-            info!(shard_id, "Gateway requested shard to reconnect")
-        }
+        Event::GatewayReconnect => info!(shard_id, "Gateway requested shard to reconnect"),
         Event::GuildCreate(e) => {
             let ctx = Context::get();
 
@@ -227,6 +237,16 @@ async fn handle_event(event: Event, shard_id: u64) -> Result<()> {
             Context::miss_analyzer_guilds().pin().insert(e.guild_id);
         }
         Event::MemberChunk(e) => {
+            let completed = Context::get().member_requests.chunk_received(
+                e.guild_id,
+                e.chunk_index,
+                e.chunk_count,
+            );
+
+            if completed {
+                trace!(guild_id = %e.guild_id, "Received all member chunks");
+            }
+
             if e.members
                 .iter()
                 .any(|member| member.user.id == MISS_ANALYZER_ID)
@@ -253,3 +273,18 @@ async fn handle_event(event: Event, shard_id: u64) -> Result<()> {
 
     Ok(())
 }
+
+/// The state a shard transitions into when receiving the event, if any.
+fn shard_state(event: &Event) -> Option<ShardState> {
+    match event {
+        Event::GatewayClose(_) => Some(ShardState::Disconnected),
+        Event::GatewayHello(_) | Event::GatewayInvalidateSession(false) => {
+            Some(ShardState::Connecting)
+        }
+        Event::GatewayInvalidateSession(true) | Event::GatewayReconnect => {
+            Some(ShardState::Resuming)
+        }
+        Event::Ready(_) | Event::Resumed => Some(ShardState::Connected),
+        _ => None,
+    }
+}
@@ -1,17 +1,22 @@
-use std::time::Duration;
+use std::{collections::HashMap, sync::Mutex, time::Duration};
 
 use bathbot_cache::{model::CacheChange, Cache};
+use bathbot_util::IntHasher;
 use metrics::{
-    describe_counter, describe_gauge, describe_histogram, gauge, histogram, increment_counter,
-    increment_gauge, SharedString, Unit,
+    decrement_gauge, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+    increment_counter, increment_gauge, SharedString, Unit,
 };
-use twilight_gateway::Event;
+use twilight_gateway::{Event, EventType};
 
 const GATEWAY_EVENTS: &str = "gateway_events";
 const COMMANDS_PROCESS_TIME: &str = "commands_process_time";
+const EVENTS_PROCESS_TIME: &str = "events_process_time";
 const COMMAND_ERRORS: &str = "command_errors";
 const CACHE_ENTRIES: &str = "cache_entries";
 const REDIS_CACHE_HITS: &str = "redis_cache_hits";
+const ACTIVE_MESSAGES: &str = "active_messages";
+const BG_GAMES: &str = "bg_games";
+const SHARD_STATES: &str = "shard_states";
 
 pub struct BotMetrics;
 
@@ -23,6 +28,11 @@ impl BotMetrics {
             Unit::Seconds,
             "Time to process a command in seconds"
         );
+        describe_histogram!(
+            EVENTS_PROCESS_TIME,
+            Unit::Seconds,
+            "Time to handle a gateway event in seconds"
+        );
         describe_counter!(
             COMMAND_ERRORS,
             Unit::Count,
@@ -34,6 +44,17 @@ impl BotMetrics {
             Unit::Count,
             "Number of times redis contained a cached value"
         );
+        describe_gauge!(
+            ACTIVE_MESSAGES,
+            Unit::Count,
+            "Number of active messages such as paginations"
+        );
+        describe_gauge!(BG_GAMES, Unit::Count, "Number of running background games");
+        describe_gauge!(
+            SHARD_STATES,
+            Unit::Count,
+            "Number of shards in each connection state"
+        );
 
         let stats = cache.stats();
 
@@ -80,10 +101,30 @@ impl BotMetrics {
         );
     }
 
+    pub fn observe_event(kind: EventType, duration: Duration) {
+        if let Some(name) = kind.name() {
+            histogram!(EVENTS_PROCESS_TIME, duration, "event" => name);
+        }
+    }
+
     pub fn inc_redis_hit(kind: impl Into<SharedString>) {
         increment_counter!(REDIS_CACHE_HITS, "kind" => kind);
     }
 
+    pub fn set_active_messages(len: usize) {
+        gauge!(ACTIVE_MESSAGES, len as f64);
+    }
+
+    pub fn set_bg_games(len: usize) {
+        gauge!(BG_GAMES, len as f64);
+    }
+
+    pub fn shard_state(shard_id: u64, state: ShardState) {
+        static SHARD_STATES: ShardStates = ShardStates::new();
+
+        SHARD_STATES.update(shard_id, state);
+    }
+
     pub fn event(event: &Event, change: Option<CacheChange>) {
         if let Some(change) = change {
             increment_gauge!(CACHE_ENTRIES, change.guilds as f64, "kind" => "Guilds");
@@ -98,3 +139,117 @@ impl BotMetrics {
         }
     }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShardState {
+    Connecting,
+    Connected,
+    Resuming,
+    Disconnected,
+}
+
+impl ShardState {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Connecting => "Connecting",
+            Self::Connected => "Connected",
+            Self::Resuming => "Resuming",
+            Self::Disconnected => "Disconnected",
+        }
+    }
+}
+
+/// Latest known state of each shard so that every shard is only counted
+/// towards a single state gauge.
+struct ShardStates {
+    inner: Mutex<Option<HashMap<u64, ShardState, IntHasher>>>,
+}
+
+impl ShardStates {
+    const fn new() -> Self {
+        Self {
+            inner: Mutex::new(None),
+        }
+    }
+
+    fn update(&self, shard_id: u64, state: ShardState) {
+        let mut guard = self.inner.lock().unwrap();
+        let states = guard.get_or_insert_with(|| HashMap::with_hasher(IntHasher));
+
+        match states.insert(shard_id, state) {
+            Some(prev) if prev == state => return,
+            Some(prev) => decrement_gauge!(SHARD_STATES, 1.0, "state" => prev.name()),
+            None => {}
+        }
+
+        increment_gauge!(SHARD_STATES, 1.0, "state" => state.name());
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::OnceLock;
+
+    use bathbot_util::MetricsReader;
+    use metrics::{Key, Label};
+
+    use super::*;
+
+    /// The recorder can only be installed once per process
+    fn reader() -> &'static MetricsReader {
+        static READER: OnceLock<MetricsReader> = OnceLock::new();
+
+        READER.get_or_init(|| {
+            let reader = MetricsReader::new();
+            metrics::set_boxed_recorder(Box::new(reader.clone())).unwrap();
+
+            reader
+        })
+    }
+
+    pub(crate) fn bg_games_gauge() -> f64 {
+        reader().gauge_value(&Key::from_static_name(BG_GAMES))
+    }
+
+    #[test]
+    fn event_process_time() {
+        let reader = reader();
+
+        let labels = vec![Label::new("event", "TYPING_START")];
+        let key = Key::from_parts(EVENTS_PROCESS_TIME, labels);
+        assert_eq!(reader.sum_histograms(&key), 0);
+
+        BotMetrics::observe_event(EventType::TypingStart, Duration::from_millis(3));
+        BotMetrics::observe_event(EventType::TypingStart, Duration::from_millis(5));
+        assert_eq!(reader.sum_histograms(&key), 2);
+    }
+
+    #[test]
+    fn shard_state_gauges() {
+        let reader = reader();
+
+        let gauge = |state: ShardState| {
+            let labels = vec![Label::new("state", state.name())];
+
+            reader.gauge_value(&Key::from_parts(SHARD_STATES, labels))
+        };
+
+        let states = ShardStates::new();
+
+        states.update(0, ShardState::Connecting);
+        states.update(1, ShardState::Connecting);
+        assert_eq!(gauge(ShardState::Connecting), 2.0);
+
+        states.update(0, ShardState::Connected);
+        states.update(0, ShardState::Connected);
+        assert_eq!(gauge(ShardState::Connecting), 1.0);
+        assert_eq!(gauge(ShardState::Connected), 1.0);
+
+        states.update(0, ShardState::Resuming);
+        states.update(1, ShardState::Disconnected);
+        assert_eq!(gauge(ShardState::Connecting), 0.0);
+        assert_eq!(gauge(ShardState::Connected), 0.0);
+        assert_eq!(gauge(ShardState::Resuming), 1.0);
+        assert_eq!(gauge(ShardState::Disconnected), 1.0);
+    }
+}
@@ -1,8 +1,8 @@
 pub use self::{
-    config::BotConfig,
+    config::{BotConfig, ConfigReload},
     context::Context,
     events::{event_loop, EventKind},
-    metrics::BotMetrics,
+    metrics::{BotMetrics, ShardState},
 };
 
 mod config;
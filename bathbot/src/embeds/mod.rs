@@ -12,6 +12,12 @@ mod tracking;
 
 pub trait EmbedData {
     fn build(self) -> EmbedBuilder;
+
+    /// Color that should be used for the embed instead of the builder's
+    /// default.
+    fn color(&self) -> Option<u32> {
+        None
+    }
 }
 
 pub fn attachment(filename: impl AsRef<str>) -> String {
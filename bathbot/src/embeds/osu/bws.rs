@@ -168,9 +168,10 @@ impl BWSEmbed {
         };
 
         let title = format!(
-            "Current BWS for {badges_curr} badge{}: {}",
+            "Current BWS for {badges_curr} badge{}: {} (rank #{})",
             if badges_curr == 1 { "" } else { "s" },
-            WithComma::new(bws(global_rank, badges_curr))
+            WithComma::new(bws(global_rank, badges_curr)),
+            WithComma::new(global_rank),
         );
 
         Self {
@@ -188,3 +189,16 @@ fn bws(rank: u32, badges: usize) -> u64 {
 
     rank.powf(0.9937_f64.powi(badges * badges)).round() as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bws_formula() {
+        assert_eq!(bws(10_000, 0), 10_000);
+        assert_eq!(bws(10_000, 5), 2603);
+        assert_eq!(bws(50_000, 3), 27_489);
+        assert_eq!(bws(1234, 10), 44);
+    }
+}
@@ -42,10 +42,15 @@ impl FixScoreEmbed {
 
         // The user has a score on the map
         let description = if let Some(fix_score) = score {
-            let FixScore { score, top, if_fc } = fix_score;
+            let FixScore {
+                score,
+                top,
+                if_fc,
+                recalculated_pp,
+            } = fix_score;
 
             // The score can be unchoked
-            if let Some(if_fc) = if_fc {
+            let mut description = if let Some(if_fc) = if_fc {
                 let mut description = format!(
                     "A {mods} FC would have improved the score from {from} to **{to}pp**. ",
                     mods = fix_score.score.mods,
@@ -123,7 +128,23 @@ impl FixScoreEmbed {
                     pp = round(score.pp),
                     mods = fix_score.score.mods
                 )
+            };
+
+            let discrepancy = recalculated_pp.and_then(|recalculated| {
+                pp_discrepancy(score.pp, recalculated).zip(Some(recalculated))
+            });
+
+            if let Some((delta, recalculated)) = discrepancy {
+                let _ = write!(
+                    description,
+                    "\n\nThe osu!api reports {reported}pp for the score \
+                    but it should be worth **{recalculated}pp** ({delta:+.2}pp).",
+                    reported = round(score.pp),
+                    recalculated = round(recalculated),
+                );
             }
+
+            description
         } else if let Some(mods) = mods {
             format!("No {mods} score on the map")
         } else {
@@ -140,6 +161,16 @@ impl FixScoreEmbed {
     }
 }
 
+/// Maximum difference between reported and recalculated pp that is not
+/// considered a discrepancy.
+const PP_TOLERANCE: f32 = 1.0;
+
+fn pp_discrepancy(reported: f32, recalculated: f32) -> Option<f32> {
+    let delta = recalculated - reported;
+
+    (delta.abs() > PP_TOLERANCE).then_some(delta)
+}
+
 struct NewPp {
     old_pos: Option<usize>,
     new_pos: usize,
@@ -182,3 +213,21 @@ impl NewPp {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outdated_pp_are_flagged() {
+        // Reported pp before a rework vs pp after recalculation
+        assert_eq!(pp_discrepancy(727.31, 652.81), Some(652.81 - 727.31));
+        assert_eq!(pp_discrepancy(400.0, 420.5), Some(20.5));
+    }
+
+    #[test]
+    fn rounding_differences_are_tolerated() {
+        assert_eq!(pp_discrepancy(727.31, 727.3), None);
+        assert_eq!(pp_discrepancy(500.0, 500.99), None);
+    }
+}
@@ -17,6 +17,7 @@ mod match_live;
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use bathbot_model::SliderHits;
 use rosu_v2::prelude::{GameModIntermode, GameMode, GameMods, LegacyScoreStatistics};
 
 #[cfg(feature = "matchlive")]
@@ -137,22 +138,51 @@ impl Display for KeyFormatter<'_> {
     }
 }
 
+/// Formats the hitresults of a score as `{300/100/50/miss}`, including the
+/// mode-specific judgements.
+///
+/// The compact variant shows the same judgements without braces which is
+/// handy for dense lists.
 #[derive(Clone)]
 pub struct HitResultFormatter {
     mode: GameMode,
     stats: LegacyScoreStatistics,
+    slider_hits: Option<SliderHits>,
+    compact: bool,
 }
 
 impl HitResultFormatter {
     pub fn new(mode: GameMode, stats: LegacyScoreStatistics) -> Self {
-        Self { mode, stats }
+        Self {
+            mode,
+            stats,
+            slider_hits: None,
+            compact: false,
+        }
+    }
+
+    /// Slider ends and large ticks of lazer scores as hits out of their
+    /// maximum. Ignored when compact.
+    pub fn slider_hits(mut self, slider_hits: Option<SliderHits>) -> Self {
+        self.slider_hits = slider_hits;
+
+        self
+    }
+
+    /// Omit the braces around the judgements for dense lists.
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+
+        self
     }
 }
 
 impl Display for HitResultFormatter {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_str("{")?;
+        if !self.compact {
+            f.write_str("{")?;
+        }
 
         if self.mode == GameMode::Mania {
             write!(f, "{}/", self.stats.count_geki)?;
@@ -170,6 +200,107 @@ impl Display for HitResultFormatter {
             write!(f, "{}/", self.stats.count_50)?;
         }
 
-        write!(f, "{}}}", self.stats.count_miss)
+        write!(f, "{}", self.stats.count_miss)?;
+
+        if self.compact {
+            return Ok(());
+        }
+
+        f.write_str("}")?;
+
+        if let Some(hits) = self.slider_hits {
+            write!(f, " (slider ends: {}/{}", hits.ends, hits.max_ends)?;
+
+            if hits.max_large_ticks > 0 {
+                write!(f, " • ticks: {}/{}", hits.large_ticks, hits.max_large_ticks)?;
+            }
+
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> LegacyScoreStatistics {
+        LegacyScoreStatistics {
+            count_geki: 6,
+            count_300: 5,
+            count_katu: 4,
+            count_100: 3,
+            count_50: 2,
+            count_miss: 1,
+        }
+    }
+
+    fn hits(mode: GameMode) -> (String, String) {
+        let formatter = HitResultFormatter::new(mode, stats());
+
+        (formatter.to_string(), formatter.compact().to_string())
+    }
+
+    #[test]
+    fn hitresults_osu() {
+        assert_eq!(
+            hits(GameMode::Osu),
+            ("{5/3/2/1}".to_owned(), "5/3/2/1".to_owned())
+        );
+    }
+
+    #[test]
+    fn hitresults_osu_lazer() {
+        let slider_hits = SliderHits {
+            ends: 7,
+            max_ends: 8,
+            large_ticks: 12,
+            max_large_ticks: 14,
+        };
+
+        let formatter =
+            HitResultFormatter::new(GameMode::Osu, stats()).slider_hits(Some(slider_hits));
+
+        assert_eq!(
+            formatter.to_string(),
+            "{5/3/2/1} (slider ends: 7/8 • ticks: 12/14)"
+        );
+        assert_eq!(formatter.compact().to_string(), "5/3/2/1");
+
+        let slider_hits = SliderHits {
+            max_large_ticks: 0,
+            large_ticks: 0,
+            ..slider_hits
+        };
+
+        let formatter =
+            HitResultFormatter::new(GameMode::Osu, stats()).slider_hits(Some(slider_hits));
+        assert_eq!(formatter.to_string(), "{5/3/2/1} (slider ends: 7/8)");
+    }
+
+    #[test]
+    fn hitresults_taiko() {
+        assert_eq!(
+            hits(GameMode::Taiko),
+            ("{5/3/1}".to_owned(), "5/3/1".to_owned())
+        );
+    }
+
+    #[test]
+    fn hitresults_catch() {
+        assert_eq!(
+            hits(GameMode::Catch),
+            ("{5/3/2/1}".to_owned(), "5/3/2/1".to_owned())
+        );
+    }
+
+    #[test]
+    fn hitresults_mania() {
+        assert_eq!(
+            hits(GameMode::Mania),
+            ("{6/5/4/3/2/1}".to_owned(), "6/5/4/3/2/1".to_owned())
+        );
     }
 }
@@ -21,26 +21,11 @@ pub struct OsuStatsCountsEmbed {
 
 impl OsuStatsCountsEmbed {
     pub fn new(user: &RedisData<User>, mode: GameMode, counts: TopCounts) -> Self {
-        let count_len = counts.count_len();
-
         let footer_timestamp = counts
             .last_update
             .map(|datetime| (FooterBuilder::new("Last Update"), datetime));
 
-        let mut description = String::with_capacity(64);
-        description.push_str("```\n");
-
-        for TopCount { top_n, count, rank } in counts {
-            let _ = write!(description, "Top {top_n:<3}:  {count:>count_len$}");
-
-            if let Some(rank) = rank {
-                let _ = writeln!(description, "   #{rank}");
-            } else {
-                description.push('\n');
-            }
-        }
-
-        description.push_str("```");
+        let description = description(counts);
 
         let mode = match mode {
             GameMode::Osu => "",
@@ -62,6 +47,27 @@ impl OsuStatsCountsEmbed {
     }
 }
 
+fn description(counts: TopCounts) -> String {
+    let count_len = counts.count_len();
+
+    let mut description = String::with_capacity(64);
+    description.push_str("```\n");
+
+    for TopCount { top_n, count, rank } in counts {
+        let _ = write!(description, "Top {top_n:<3}:  {count:>count_len$}");
+
+        if let Some(rank) = rank {
+            let _ = writeln!(description, "   #{rank}");
+        } else {
+            description.push('\n');
+        }
+    }
+
+    description.push_str("```");
+
+    description
+}
+
 impl EmbedData for OsuStatsCountsEmbed {
     #[inline]
     fn build(self) -> EmbedBuilder {
@@ -78,3 +84,40 @@ impl EmbedData for OsuStatsCountsEmbed {
         builder
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unavailable_counts_description() {
+        let unavailable = || "-".into();
+
+        let counts = TopCounts {
+            top1s: "1,234".into(),
+            top1s_rank: None,
+            top8s: unavailable(),
+            top8s_rank: None,
+            top15s: unavailable(),
+            top15s_rank: None,
+            top25s: unavailable(),
+            top25s_rank: None,
+            top50s: unavailable(),
+            top50s_rank: None,
+            top100s: unavailable(),
+            top100s_rank: None,
+            last_update: None,
+        };
+
+        let expected = "```\n\
+            Top 1  :  1,234\n\
+            Top 8  :      -\n\
+            Top 15 :      -\n\
+            Top 25 :      -\n\
+            Top 50 :      -\n\
+            Top 100:      -\n\
+            ```";
+
+        assert_eq!(description(counts), expected);
+    }
+}
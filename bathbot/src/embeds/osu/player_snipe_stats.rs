@@ -4,13 +4,14 @@ use bathbot_macros::EmbedData;
 use bathbot_model::{rosu_v2::user::User, SnipePlayer};
 use bathbot_util::{
     constants::OSU_BASE,
-    datetime::HowLongAgoDynamic,
+    datetime::{HowLongAgoDynamic, DATE_FORMAT},
     fields,
     numbers::{round, WithComma},
     AuthorBuilder, CowUtils, FooterBuilder,
 };
 use osu::{ComboFormatter, HitResultFormatter, PpFormatter};
 use rosu_v2::prelude::{GameMode, Score};
+use time::Date;
 use twilight_model::channel::message::embed::EmbedField;
 
 use crate::{
@@ -37,6 +38,7 @@ impl PlayerSnipeStatsEmbed {
         user: &RedisData<User>,
         player: SnipePlayer,
         oldest: Option<&(Score, OsuMap)>,
+        gained_since: Option<(Date, u32)>,
     ) -> Self {
         let footer_text = format!(
             "{:+} #1{} since last update",
@@ -56,6 +58,14 @@ impl PlayerSnipeStatsEmbed {
                 player.count_first, player.count_ranked, player.count_loved
             );
 
+            if let Some((since, gained)) = gained_since {
+                let _ = writeln!(
+                    description,
+                    "Gained since {since}: **{gained}**",
+                    since = since.format(DATE_FORMAT).unwrap(),
+                );
+            }
+
             fields![fields {
                 "Average PP:", WithComma::new(player.avg_pp).to_string(), true;
                 "Average acc:", format!("{:.2}%", player.avg_acc), true;
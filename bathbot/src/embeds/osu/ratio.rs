@@ -21,6 +21,7 @@ impl RatioEmbed {
     pub fn new(user: &RedisData<User>, scores: Vec<Score>) -> Self {
         let accs = [0, 90, 95, 97, 99];
         let mut categories: BTreeMap<u8, RatioCategory> = BTreeMap::new();
+        let mut score_ratios = Vec::with_capacity(scores.len());
 
         for &acc in accs.iter() {
             categories.insert(acc, RatioCategory::default());
@@ -30,6 +31,8 @@ impl RatioEmbed {
 
         for score in scores {
             let acc = score.accuracy;
+            let stats = score.statistics.as_legacy(GameMode::Mania);
+            score_ratios.push(ratio(stats.count_geki, stats.count_300));
 
             for &curr in accs.iter() {
                 if acc > curr as f32 {
@@ -74,7 +77,17 @@ impl RatioEmbed {
             }
         }
 
-        description.push_str("```");
+        description.push_str("```\n");
+
+        if let Some(summary) = RatioSummary::new(&score_ratios) {
+            let _ = write!(
+                description,
+                "Ratio per score: min `{min:.3}` • avg `{avg:.3}` • max `{max:.3}`",
+                min = summary.min,
+                avg = summary.avg,
+                max = summary.max,
+            );
+        }
 
         Self {
             description,
@@ -110,11 +123,7 @@ impl RatioCategory {
     }
 
     fn ratio(&self) -> f32 {
-        if self.count_300 == 0 {
-            self.count_geki as f32
-        } else {
-            self.count_geki as f32 / self.count_300 as f32
-        }
+        ratio(self.count_geki, self.count_300)
     }
 
     fn miss_percent(&self) -> f32 {
@@ -122,3 +131,76 @@ impl RatioCategory {
             / self.count_objects as f32
     }
 }
+
+/// Ratio of perfects (geki) to greats (300s). Without any greats, the amount
+/// of perfects is used as is.
+fn ratio(count_geki: u32, count_300: u32) -> f32 {
+    if count_300 == 0 {
+        count_geki as f32
+    } else {
+        count_geki as f32 / count_300 as f32
+    }
+}
+
+struct RatioSummary {
+    min: f32,
+    avg: f32,
+    max: f32,
+}
+
+impl RatioSummary {
+    fn new(ratios: &[f32]) -> Option<Self> {
+        if ratios.is_empty() {
+            return None;
+        }
+
+        let mut min = f32::MAX;
+        let mut max = f32::MIN;
+        let mut sum = 0.0;
+
+        for &ratio in ratios {
+            min = min.min(ratio);
+            max = max.max(ratio);
+            sum += ratio;
+        }
+
+        Some(Self {
+            min,
+            avg: sum / ratios.len() as f32,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_of_statistics() {
+        assert_eq!(ratio(1000, 250), 4.0);
+        assert_eq!(ratio(37, 0), 37.0);
+        assert_eq!(ratio(0, 0), 0.0);
+
+        let category = RatioCategory {
+            scores: 2,
+            count_geki: 1500,
+            count_300: 500,
+            count_miss: 10,
+            count_objects: 2000,
+        };
+
+        assert_eq!(category.ratio(), 3.0);
+        assert_eq!(category.miss_percent(), 0.5);
+    }
+
+    #[test]
+    fn ratio_summary() {
+        assert!(RatioSummary::new(&[]).is_none());
+
+        let summary = RatioSummary::new(&[4.0, 1.5, 3.5]).unwrap();
+        assert_eq!(summary.min, 1.5);
+        assert_eq!(summary.avg, 3.0);
+        assert_eq!(summary.max, 4.0);
+    }
+}
@@ -1,9 +1,7 @@
-mod list;
 mod notification;
 mod track;
 mod untrack;
 
-pub use list::TrackListEmbed;
 pub use notification::TrackNotificationEmbed;
 pub use track::TrackEmbed;
 pub use untrack::UntrackEmbed;
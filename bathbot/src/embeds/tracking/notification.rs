@@ -1,10 +1,10 @@
-use bathbot_macros::EmbedData;
 use bathbot_model::rosu_v2::user::User;
+use bathbot_psql::model::configs::TrackTemplate;
 use bathbot_util::{
     constants::OSU_BASE,
     fields,
     numbers::{round, WithComma},
-    AuthorBuilder, CowUtils, FooterBuilder,
+    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder,
 };
 use osu::{ComboFormatter, HitResultFormatter, KeyFormatter, PpFormatter};
 use rosu_v2::prelude::{GameMode, Grade, Score};
@@ -13,14 +13,18 @@ use twilight_model::channel::message::embed::EmbedField;
 
 use crate::{
     core::Context,
-    embeds::osu,
+    embeds::{osu, EmbedData},
     manager::{redis::RedisData, OsuMap},
-    util::{osu::grade_completion_mods, Emote},
+    util::{
+        osu::{grade_color, grade_completion_mods},
+        Emote,
+    },
 };
 
-#[derive(EmbedData)]
 pub struct TrackNotificationEmbed {
     author: AuthorBuilder,
+    color: u32,
+    compact: String,
     description: String,
     fields: Vec<EmbedField>,
     footer: FooterBuilder,
@@ -90,17 +94,112 @@ impl TrackNotificationEmbed {
                 HitResultFormatter::new(score.mode, score.statistics.as_legacy(score.mode)),
         );
 
+        let url = format!("{OSU_BASE}b/{}", map.map_id());
+
+        let compact = format!(
+            "**#{idx}** [{title}]({url}) {grade} **{pp}pp** ({acc}%)",
+            grade = grade_completion_mods(score, map.mode(), map.n_objects()),
+            pp = round(score.pp.unwrap_or(0.0)),
+            acc = round(score.accuracy),
+        );
+
         let footer = FooterBuilder::new(map.footer_text()).icon_url(Emote::from(score.mode).url());
 
         Self {
             author: user.author_builder(),
+            color: grade_color(score.grade),
+            compact,
             description,
             fields: fields![name, value, false],
             footer,
             thumbnail: map.thumbnail().to_owned(),
             timestamp: score.ended_at,
             title,
-            url: format!("{OSU_BASE}b/{}", map.map_id()),
+            url,
         }
     }
+
+    pub fn build_template(&self, template: TrackTemplate) -> EmbedBuilder {
+        let builder = match template {
+            TrackTemplate::Full => EmbedBuilder::new()
+                .author(self.author.clone())
+                .description(self.description.clone())
+                .fields(self.fields.clone())
+                .footer(self.footer.clone())
+                .thumbnail(self.thumbnail.clone())
+                .title(self.title.clone())
+                .url(self.url.clone()),
+            TrackTemplate::Compact => EmbedBuilder::new()
+                .author(self.author.clone())
+                .description(self.compact.clone()),
+        };
+
+        builder.color(self.color).timestamp(self.timestamp)
+    }
+}
+
+impl EmbedData for TrackNotificationEmbed {
+    fn build(self) -> EmbedBuilder {
+        self.build_template(TrackTemplate::Full)
+    }
+
+    fn color(&self) -> Option<u32> {
+        Some(self.color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn embed() -> TrackNotificationEmbed {
+        TrackNotificationEmbed {
+            author: AuthorBuilder::new("badewanne3"),
+            color: 0xffffff,
+            compact: "**#1** [Artist - Title [Version] [6.12★]](https://osu.ppy.sh/b/1) \
+                S +HD **727pp** (99.1%)"
+                .to_owned(),
+            description: "__**Personal Best #1**__".to_owned(),
+            fields: fields!["S +HD\t1,234,567\t(99.1%)", "**727pp**".to_owned(), false],
+            footer: FooterBuilder::new("footer"),
+            timestamp: OffsetDateTime::UNIX_EPOCH,
+            title: "Artist - Title [Version] [6.12★]".to_owned(),
+            thumbnail: "https://assets.ppy.sh/beatmaps/1/covers/list.jpg".to_owned(),
+            url: "https://osu.ppy.sh/b/1".to_owned(),
+        }
+    }
+
+    #[test]
+    fn full_template() {
+        let embed = embed().build_template(TrackTemplate::Full).build();
+
+        assert_eq!(embed.fields.len(), 1);
+        assert!(embed.title.is_some());
+        assert!(embed.thumbnail.is_some());
+        assert!(embed.footer.is_some());
+        assert_eq!(
+            embed.description.as_deref(),
+            Some("__**Personal Best #1**__")
+        );
+    }
+
+    #[test]
+    fn compact_template() {
+        let embed = embed().build_template(TrackTemplate::Compact).build();
+
+        assert!(embed.fields.is_empty());
+        assert!(embed.title.is_none());
+        assert!(embed.thumbnail.is_none());
+        assert!(embed.footer.is_none());
+        assert!(embed.author.is_some());
+
+        let description = embed.description.unwrap();
+        assert_eq!(description.lines().count(), 1);
+        assert!(description.starts_with("**#1**"));
+    }
+
+    #[test]
+    fn default_template_is_full() {
+        assert_eq!(TrackTemplate::default(), TrackTemplate::Full);
+    }
 }
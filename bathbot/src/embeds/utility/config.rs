@@ -109,6 +109,7 @@ impl ConfigEmbed {
                     (ScoreSize::AlwaysMinimized, "always minimized"),
                     (ScoreSize::AlwaysMaximized, "always maximized"),
                     (ScoreSize::InitialMaximized, "initial maximized"),
+                    (ScoreSize::Auto, "maximize notable"),
                 ],
             ),
             create_field(
@@ -122,6 +123,14 @@ impl ConfigEmbed {
             ),
         ];
 
+        if let Some(ref mods) = config.mods {
+            fields.push(EmbedField {
+                inline: true,
+                name: "Default mods".to_owned(),
+                value: format!("`+{mods}`"),
+            });
+        }
+
         if let Some(skin_url) = skin_url {
             fields.push(EmbedField {
                 inline: false,
@@ -4,9 +4,10 @@ use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
 use bathbot_model::twilight_model::guild::Guild;
 use bathbot_psql::model::configs::{
-    GuildConfig, HideSolutions, ListSize, MinimizedPp, Retries, ScoreSize,
+    GuildConfig, HideSolutions, ListSize, MinimizedPp, Retries, ScoreSize, TrackTemplate,
 };
 use bathbot_util::AuthorBuilder;
+use rosu_v2::prelude::GameMode;
 use twilight_model::channel::message::embed::EmbedField;
 
 use super::config::create_field;
@@ -63,6 +64,14 @@ impl ServerConfigEmbed {
             }
         }
 
+        match config.command_channels.len() {
+            0 => description.push_str("\nCommand channels: All"),
+            1 => description.push_str("\nCommand channels: 1 channel"),
+            n => {
+                let _ = write!(description, "\nCommand channels: {n} channels");
+            }
+        }
+
         let track_limit = config.track_limit.unwrap_or(50);
         let _ = writeln!(description, "\nDefault track limit: {track_limit}\n```");
 
@@ -98,6 +107,7 @@ impl ServerConfigEmbed {
                     (ScoreSize::AlwaysMinimized, "always minimized"),
                     (ScoreSize::AlwaysMaximized, "always maximized"),
                     (ScoreSize::InitialMaximized, "initial maximized"),
+                    (ScoreSize::Auto, "maximize notable"),
                 ],
             ),
             EmbedField {
@@ -117,6 +127,25 @@ impl ServerConfigEmbed {
                 config.legacy_scores.unwrap_or(false),
                 &[(false, "lazer"), (true, "stable")],
             ),
+            create_field(
+                "Default mode*",
+                config.default_mode,
+                &[
+                    (None, "none"),
+                    (Some(GameMode::Osu), "osu"),
+                    (Some(GameMode::Taiko), "taiko"),
+                    (Some(GameMode::Catch), "catch"),
+                    (Some(GameMode::Mania), "mania"),
+                ],
+            ),
+            create_field(
+                "Track notifications",
+                config.track_template.unwrap_or_default(),
+                &[
+                    (TrackTemplate::Full, "full"),
+                    (TrackTemplate::Compact, "compact"),
+                ],
+            ),
             create_field(
                 "Render button",
                 config.render_button.unwrap_or(true),
@@ -15,7 +15,7 @@ mod util;
 #[cfg(feature = "matchlive")]
 mod matchlive;
 
-use std::time::Duration;
+use std::{collections::HashSet, env, path::PathBuf, time::Duration};
 
 use bathbot_model::Countries;
 use eyre::{Report, Result, WrapErr};
@@ -39,20 +39,26 @@ fn main() {
         .build()
         .expect("Could not build runtime");
 
-    if let Err(err) = dotenvy::dotenv() {
-        panic!("Failed to prepare .env variables: {err}");
-    }
+    // Variables that are already set take precedence over the .env file
+    let process_vars = env::vars_os()
+        .filter_map(|(name, _)| name.into_string().ok())
+        .collect();
+
+    let env_path = match dotenvy::dotenv() {
+        Ok(path) => path,
+        Err(err) => panic!("Failed to prepare .env variables: {err}"),
+    };
 
     let _log_worker_guard = logging::init();
 
-    if let Err(source) = runtime.block_on(async_main()) {
+    if let Err(source) = runtime.block_on(async_main(env_path, process_vars)) {
         error!(?source, "Critical error in main");
     }
 }
 
-async fn async_main() -> Result<()> {
+async fn async_main(env_path: PathBuf, process_vars: HashSet<String>) -> Result<()> {
     // Load config file
-    BotConfig::init().context("failed to initialize config")?;
+    BotConfig::init(env_path, process_vars).context("failed to initialize config")?;
     Countries::init();
 
     let (member_tx, mut member_rx) = mpsc::unbounded_channel();
@@ -109,6 +115,12 @@ async fn async_main() -> Result<()> {
         tokio::spawn(Context::match_live_loop());
     }
 
+    // Retry member requests whose chunks never arrived
+    tokio::spawn(async {
+        let timeout = BotConfig::get().member_chunk_timeout;
+        Context::get().member_requests.expire_loop(timeout).await;
+    });
+
     // Request members
     tokio::spawn(async move {
         let ctx = Context::get();
@@ -139,6 +151,8 @@ async fn async_main() -> Result<()> {
             trace!("Member request #{counter} for guild {guild_id}");
             counter += 1;
 
+            ctx.member_requests.requested(guild_id, shard_id);
+
             let command_res = match ctx.shard_senders.read().unwrap().get(&shard_id) {
                 Some(sender) => sender.command(&req),
                 None => {
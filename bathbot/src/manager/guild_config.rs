@@ -5,6 +5,7 @@ use bathbot_psql::{
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
 use flurry::HashMap as FlurryMap;
+use rosu_v2::prelude::GameMode;
 use twilight_model::id::{marker::GuildMarker, Id};
 
 type GuildConfigs = FlurryMap<Id<GuildMarker>, GuildConfig, IntHasher>;
@@ -55,6 +56,13 @@ impl GuildConfigManager {
         prefix_opt.unwrap_or_else(|| DEFAULT_PREFIX.into())
     }
 
+    pub async fn default_mode(self, guild_id: Option<Id<GuildMarker>>) -> Option<GameMode> {
+        match guild_id {
+            Some(guild_id) => self.peek(guild_id, |config| config.default_mode).await,
+            None => None,
+        }
+    }
+
     pub async fn update<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Result<O>
     where
         F: FnOnce(&mut GuildConfig) -> O,
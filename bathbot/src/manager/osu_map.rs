@@ -250,7 +250,17 @@ impl MapManager {
             .wrap_err("Failed to get map checksum")
     }
 
+    /// Store the mapset in the DB unless map storing is disabled in the
+    /// config.
     pub async fn store(&self, mapset: &BeatmapsetExtended) {
+        if BotConfig::get().store_maps {
+            self.store_required(mapset).await;
+        }
+    }
+
+    /// Store the mapset in the DB regardless of the config for features that
+    /// rely on stored maps such as bookmarks.
+    pub async fn store_required(&self, mapset: &BeatmapsetExtended) {
         if let Err(err) = Context::psql().upsert_beatmapset(mapset).await {
             warn!(?err, "Failed to store mapset");
         }
@@ -593,3 +603,115 @@ pub enum MapError {
     #[error(transparent)]
     Report(#[from] Report),
 }
+
+#[cfg(test)]
+impl OsuMap {
+    /// A map with a single note and empty metadata.
+    ///
+    /// The difficulty is given as `[hp, cs, od, ar]`.
+    pub(crate) fn test_map(mode: GameMode, difficulty: [f32; 4], seconds_drain: i32) -> Self {
+        let [hp, cs, od, ar] = difficulty;
+
+        let content = format!(
+            "osu file format v14\n\n\
+            [General]\nMode: {mode}\n\n\
+            [Difficulty]\nHPDrainRate:{hp}\nCircleSize:{cs}\nOverallDifficulty:{od}\n\
+            ApproachRate:{ar}\n\n\
+            [HitObjects]\n256,192,1000,1,0,0:0:0:0:\n",
+            mode = mode as u8,
+        );
+
+        let pp_map = Beatmap::from_bytes(content.as_bytes()).unwrap();
+
+        let map = DbBeatmap {
+            map_id: 0,
+            mapset_id: 0,
+            user_id: 0,
+            map_version: String::new(),
+            seconds_drain,
+            count_circles: 1,
+            count_sliders: 0,
+            count_spinners: 0,
+            bpm: 180.0,
+        };
+
+        let mapset = DbBeatmapset {
+            mapset_id: 0,
+            user_id: 0,
+            artist: String::new(),
+            title: String::new(),
+            creator: String::new(),
+            rank_status: 1,
+            ranked_date: None,
+            thumbnail: String::new(),
+            cover: String::new(),
+        };
+
+        Self::new(OsuMapSlim::new(map, mapset), pp_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn disabled_store_skips_insert() {
+        BotConfig::init_test(false);
+
+        let mapset: BeatmapsetExtended = serde_json::from_str(
+            r#"{
+                "artist": "Artist",
+                "artist_unicode": "Artist",
+                "covers": {
+                    "cover": "", "cover@2x": "", "card": "", "card@2x": "",
+                    "list": "", "list@2x": "", "slimcover": "", "slimcover@2x": ""
+                },
+                "creator": "Mapper",
+                "favourite_count": 0,
+                "hype": null,
+                "id": 1,
+                "nsfw": false,
+                "offset": 0,
+                "play_count": 0,
+                "preview_url": "",
+                "source": "",
+                "spotlight": false,
+                "status": "ranked",
+                "title": "Title",
+                "title_unicode": "Title",
+                "track_id": null,
+                "user_id": 2,
+                "video": false,
+                "availability": { "download_disabled": false, "more_information": null },
+                "bpm": 120.0,
+                "can_be_hyped": false,
+                "deleted_at": null,
+                "discussion_enabled": true,
+                "discussion_locked": false,
+                "is_scoreable": true,
+                "last_updated": "2020-01-01T00:00:00Z",
+                "legacy_thread_url": null,
+                "nominations_summary": { "current": 2, "required": 2 },
+                "ranked": 1,
+                "ranked_date": "2020-01-01T00:00:00Z",
+                "storyboard": false,
+                "submitted_date": "2020-01-01T00:00:00Z",
+                "tags": "",
+                "beatmaps": [],
+                "converts": [],
+                "current_nominations": [],
+                "description": { "description": "" },
+                "genre": { "id": 1, "name": "Unspecified" },
+                "language": { "id": 1, "name": "Unspecified" },
+                "pack_tags": [],
+                "ratings": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                "recent_favourites": []
+            }"#,
+        )
+        .unwrap();
+
+        // The context is not initialized so accessing the database would panic
+        MapManager::new().store(&mapset).await;
+    }
+}
@@ -1,12 +1,16 @@
 use std::{collections::HashMap, num::NonZeroU64};
 
 use bathbot_psql::{
-    model::osu::{TrackedOsuUserKey, TrackedOsuUserValue},
+    model::osu::{Channels, TopSnapshot, TopSnapshotEntry, TrackedOsuUserKey, TrackedOsuUserValue},
     Database,
 };
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
-use twilight_model::id::{marker::ChannelMarker, Id};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
 
 #[derive(Copy, Clone)]
 pub struct OsuTrackingManager<'d> {
@@ -60,6 +64,17 @@ impl<'d> OsuTrackingManager<'d> {
             .wrap_err("failed to remove tracked user by mode")
     }
 
+    /// Removes users without channels and updates the channels of all others.
+    pub async fn remove_entries(
+        self,
+        entries: &[(TrackedOsuUserKey, Option<Channels<IntHasher>>)],
+    ) -> Result<()> {
+        self.psql
+            .remove_osu_tracking_entries(entries)
+            .await
+            .wrap_err("failed to remove tracked entries")
+    }
+
     pub async fn insert_user(
         self,
         key: TrackedOsuUserKey,
@@ -73,4 +88,115 @@ impl<'d> OsuTrackingManager<'d> {
             .await
             .wrap_err("failed to insert tracked user")
     }
+
+    /// Snapshots are stored per invoker so that one user's usage doesn't
+    /// reset the baseline of someone else.
+    pub async fn top_snapshot(
+        self,
+        discord_id: Id<UserMarker>,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<Option<TopSnapshot>> {
+        self.psql
+            .select_osu_top_snapshot(discord_id, user_id, mode)
+            .await
+            .wrap_err("failed to get top snapshot")
+    }
+
+    pub async fn store_top_snapshot(
+        self,
+        discord_id: Id<UserMarker>,
+        user_id: u32,
+        mode: GameMode,
+        entries: &[TopSnapshotEntry],
+    ) -> Result<()> {
+        self.psql
+            .upsert_osu_top_snapshot(discord_id, user_id, mode, entries)
+            .await
+            .wrap_err("failed to store top snapshot")
+    }
+
+    pub async fn get_channel_limits(self) -> Result<Vec<(NonZeroU64, u8)>> {
+        self.psql
+            .select_osu_tracking_channel_limits()
+            .await
+            .wrap_err("failed to get channel limits for tracking")
+    }
+
+    pub async fn set_channel_limit(
+        self,
+        channel: Id<ChannelMarker>,
+        limit: Option<u8>,
+    ) -> Result<()> {
+        let channel = channel.into_nonzero();
+
+        let res = match limit {
+            Some(limit) => {
+                self.psql
+                    .upsert_osu_tracking_channel_limit(channel, limit)
+                    .await
+            }
+            None => self.psql.delete_osu_tracking_channel_limit(channel).await,
+        };
+
+        res.wrap_err("failed to set channel limit for tracking")
+    }
+
+    /// Users and the channels in which their limit was specified explicitly
+    pub async fn get_explicit_limits(self) -> Result<Vec<(TrackedOsuUserKey, NonZeroU64)>> {
+        self.psql
+            .select_osu_tracking_explicit_limits()
+            .await
+            .wrap_err("failed to get explicit limits for tracking")
+    }
+
+    /// Remember whether the user's limit in the channel was specified
+    /// explicitly so that it takes precedence over the channel's limit.
+    pub async fn set_explicit_limit(
+        self,
+        key: TrackedOsuUserKey,
+        channel: Id<ChannelMarker>,
+        explicit: bool,
+    ) -> Result<()> {
+        let TrackedOsuUserKey { user_id, mode } = key;
+        let channel = channel.into_nonzero();
+
+        let res = if explicit {
+            self.psql
+                .upsert_osu_tracking_explicit_limit(user_id, mode, channel)
+                .await
+        } else {
+            self.psql
+                .delete_osu_tracking_explicit_limit(user_id, mode, channel)
+                .await
+        };
+
+        res.wrap_err("failed to set explicit limit for tracking")
+    }
+
+    /// Channels and the guild they belong to
+    pub async fn get_channel_guilds(&self) -> Result<Vec<(NonZeroU64, NonZeroU64)>> {
+        self.psql
+            .select_osu_tracking_channel_guilds()
+            .await
+            .wrap_err("failed to get guilds of tracking channels")
+    }
+
+    pub async fn set_channel_guild(
+        self,
+        channel: Id<ChannelMarker>,
+        guild: Id<GuildMarker>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_osu_tracking_channel_guild(channel.into_nonzero(), guild.into_nonzero())
+            .await
+            .wrap_err("failed to set guild of tracking channel")
+    }
+
+    pub async fn remove_channel_guild(self, channel: Id<ChannelMarker>) -> Result<()> {
+        self.psql
+            .delete_osu_tracking_channel_guild(channel.into_nonzero())
+            .await
+            .wrap_err("failed to remove guild of tracking channel")
+    }
 }
@@ -3,7 +3,8 @@ use std::{borrow::Cow, fmt::Write};
 use bathbot_cache::{Cache, CacheSerializer};
 use bathbot_model::{
     rosu_v2::ranking::Rankings, CountryRegions, OsekaiBadge, OsekaiMedal, OsekaiRanking,
-    OsuStatsBestScores, OsuStatsBestTimeframe, SnipeCountries,
+    OsuStatsBestScores, OsuStatsBestTimeframe, SnipeCountries, SnipeCountryListOrder,
+    SnipeCountryPlayer,
 };
 use bathbot_psql::model::osu::MapVersion;
 use bathbot_util::{matcher, osu::MapIdType};
@@ -233,6 +234,47 @@ impl RedisManager {
         Ok(RedisData::new(countries))
     }
 
+    /// Players of a country, ordered by their #1 count.
+    ///
+    /// Sort the players afterwards to get a different ordering.
+    pub async fn snipe_country(
+        self,
+        country_code: &str,
+        mode: GameMode,
+    ) -> RedisResult<Vec<SnipeCountryPlayer>> {
+        const EXPIRE: usize = 1800; // 30 minutes
+        let key = format!(
+            "snipe_country_{country_code}_{mode}",
+            country_code = country_code.to_ascii_lowercase()
+        );
+
+        let mut conn = match Context::cache().fetch(&key).await {
+            Ok(Ok(players)) => {
+                BotMetrics::inc_redis_hit("Snipe country");
+
+                return Ok(RedisData::Archive(players));
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!("{err:?}");
+
+                None
+            }
+        };
+
+        let players = Context::client()
+            .get_snipe_country(country_code, SnipeCountryListOrder::Count, mode)
+            .await?;
+
+        if let Some(ref mut conn) = conn {
+            if let Err(err) = Cache::store::<_, _, 65_536>(conn, &key, &players, EXPIRE).await {
+                warn!(?err, "Failed to store snipe country");
+            }
+        }
+
+        Ok(RedisData::new(players))
+    }
+
     pub async fn country_regions(self) -> RedisResult<CountryRegions> {
         const EXPIRE: usize = 43_200; // 12 hours
         let key = "country_regions";
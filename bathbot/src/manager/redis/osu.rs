@@ -1,10 +1,11 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, future::Future};
 
-use bathbot_cache::Cache;
+use bathbot_cache::{model::CachedArchive, Cache};
 use bathbot_model::rosu_v2::user::{StatsWrapper, User};
 use bathbot_util::{
     constants::OSU_BASE, numbers::WithComma, osu::flag_url, AuthorBuilder, CowUtils,
 };
+use eyre::Result;
 use rosu_v2::{
     prelude::{GameMode, OsuError},
     request::UserId,
@@ -43,6 +44,12 @@ impl UserArgs {
             Ok(None) => {}
         }
 
+        let missing_key = RedisManager::missing_osu_username_key(name);
+
+        if RedisManager::is_missing_osu_user(Context::cache(), &missing_key).await {
+            return Self::Err(OsuError::NotFound);
+        }
+
         let mode = GameMode::Osu;
 
         match (Context::osu().user(name).mode(mode).await, alt_name) {
@@ -78,9 +85,19 @@ impl UserArgs {
                             mode,
                         }
                     }
+                    Err(OsuError::NotFound) => {
+                        RedisManager::store_missing_osu_user(Context::cache(), &missing_key).await;
+
+                        Self::Err(OsuError::NotFound)
+                    }
                     Err(err) => Self::Err(err),
                 }
             }
+            (Err(OsuError::NotFound), None) => {
+                RedisManager::store_missing_osu_user(Context::cache(), &missing_key).await;
+
+                Self::Err(OsuError::NotFound)
+            }
             (Err(err), _) => Self::Err(err),
         }
     }
@@ -147,64 +164,153 @@ impl TryFrom<UserArgs> for UserArgsSlim {
 
 const EXPIRE: usize = 600;
 
+/// How long users that could not be found are remembered as missing.
+const MISSING_EXPIRE: usize = 60;
+
 impl RedisManager {
     fn osu_user_key(user_id: u32, mode: GameMode) -> String {
         format!("osu_user_{user_id}_{}", mode as u8)
     }
 
-    pub async fn osu_user_from_args(self, args: UserArgsSlim) -> RedisResult<User, User, OsuError> {
-        let UserArgsSlim { user_id, mode } = args;
-        let key = Self::osu_user_key(user_id, mode);
+    fn missing_osu_user_key(user_id: u32) -> String {
+        format!("missing_osu_user_{user_id}")
+    }
 
-        let mut conn = match Context::cache().fetch(&key).await {
-            Ok(Ok(user)) => {
-                BotMetrics::inc_redis_hit("osu! user");
+    fn missing_osu_username_key(name: &str) -> String {
+        format!("missing_osu_username_{}", name.cow_to_ascii_lowercase())
+    }
+
+    /// Whether the user was recently looked up but could not be found.
+    async fn is_missing_osu_user(cache: &impl UserCache, key: &str) -> bool {
+        match cache.exists(key).await {
+            Ok(true) => {
+                BotMetrics::inc_redis_hit("Missing osu! user");
 
-                return Ok(RedisData::Archive(user));
+                true
             }
-            Ok(Err(conn)) => Some(conn),
+            Ok(false) => false,
             Err(err) => {
-                warn!("{err:?}");
+                warn!(?err, "Failed to check for missing user");
 
-                None
+                false
             }
-        };
+        }
+    }
 
-        let mut user = match Context::osu().user(user_id).mode(mode).await {
+    async fn store_missing_osu_user(cache: &impl UserCache, key: &str) {
+        if let Err(err) = cache.store_missing(key, MISSING_EXPIRE).await {
+            warn!(?err, "Failed to store missing user");
+        }
+    }
+
+    /// Keys that must be gone for the next lookup to request the osu!api.
+    fn stale_osu_user_keys(user_id: u32, mode: GameMode) -> [String; 2] {
+        [
+            Self::osu_user_key(user_id, mode),
+            Self::missing_osu_user_key(user_id),
+        ]
+    }
+
+    /// Remove the cached user so that the next lookup retrieves fresh data.
+    pub async fn invalidate_osu_user(self, user_id: u32, mode: GameMode) -> Result<()> {
+        Self::remove_stale_osu_user(Context::cache(), user_id, mode).await
+    }
+
+    async fn remove_stale_osu_user(
+        cache: &impl UserCache,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<()> {
+        for key in Self::stale_osu_user_keys(user_id, mode) {
+            cache.remove(&key).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieve the user from the cache or, unless they're known to be
+    /// missing, through `fetch_fut` in which case the result is cached.
+    async fn lookup_osu_user<C, F>(
+        cache: &C,
+        user_id: u32,
+        mode: GameMode,
+        fetch_fut: F,
+    ) -> Result<UserLookup<C::Cached, C::User>, OsuError>
+    where
+        C: UserCache,
+        F: Future<Output = Result<C::User, OsuError>>,
+    {
+        let key = Self::osu_user_key(user_id, mode);
+
+        match cache.fetch_user(&key).await {
+            Ok(Some(user)) => {
+                BotMetrics::inc_redis_hit("osu! user");
+
+                return Ok(UserLookup::Cached(user));
+            }
+            Ok(None) => {}
+            Err(err) => warn!("{err:?}"),
+        }
+
+        let missing_key = Self::missing_osu_user_key(user_id);
+
+        if Self::is_missing_osu_user(cache, &missing_key).await {
+            return Err(OsuError::NotFound);
+        }
+
+        let user = match fetch_fut.await {
             Ok(user) => user,
             Err(OsuError::NotFound) => {
-                // Remove stats of unknown/restricted users so they don't appear in the
-                // leaderboard
-                if let Err(err) = Context::osu_user().remove_stats_and_scores(user_id).await {
-                    warn!(?err, "Failed to remove stats of unknown user");
-                }
+                Self::store_missing_osu_user(cache, &missing_key).await;
 
                 return Err(OsuError::NotFound);
             }
             Err(err) => return Err(err),
         };
 
-        user.mode = mode;
-        let user_clone = user.clone();
-        let user = User::from(user);
-
-        if let Some(ref mut conn) = conn {
-            // Cache users for 10 minutes
-            if let Err(err) = Cache::store::<_, _, 64>(conn, &key, &user, EXPIRE).await {
-                warn!(?err, "Failed to store user");
-            }
+        // Cache users for 10 minutes
+        if let Err(err) = cache.store_user(&key, &user, EXPIRE).await {
+            warn!(?err, "Failed to store user");
         }
 
-        drop(conn);
+        Ok(UserLookup::Fetched(user))
+    }
+
+    pub async fn osu_user_from_args(self, args: UserArgsSlim) -> RedisResult<User, User, OsuError> {
+        let UserArgsSlim { user_id, mode } = args;
+
+        let fetch_fut = async {
+            let mut user = match Context::osu().user(user_id).mode(mode).await {
+                Ok(user) => user,
+                Err(OsuError::NotFound) => {
+                    // Remove stats of unknown/restricted users so they don't appear in the
+                    // leaderboard
+                    if let Err(err) = Context::osu_user().remove_stats_and_scores(user_id).await {
+                        warn!(?err, "Failed to remove stats of unknown user");
+                    }
+
+                    return Err(OsuError::NotFound);
+                }
+                Err(err) => return Err(err),
+            };
+
+            user.mode = mode;
+            let user_clone = user.clone();
 
-        tokio::spawn(async move {
-            Context::osu_user().store(&user_clone, mode).await;
-            Context::get()
-                .notify_osutrack_of_user_activity(user_clone.user_id, mode)
-                .await;
-        });
+            tokio::spawn(async move {
+                Context::osu_user().store(&user_clone, mode).await;
+                Context::get()
+                    .notify_osutrack_of_user_activity(user_clone.user_id, mode)
+                    .await;
+            });
 
-        Ok(RedisData::new(user))
+            Ok(User::from(user))
+        };
+
+        match Self::lookup_osu_user(Context::cache(), user_id, mode, fetch_fut).await? {
+            UserLookup::Cached(user) => Ok(RedisData::Archive(user)),
+            UserLookup::Fetched(user) => Ok(RedisData::new(user)),
+        }
     }
 
     pub async fn osu_user_from_user(
@@ -235,6 +341,53 @@ impl RedisManager {
     }
 }
 
+enum UserLookup<C, U> {
+    Cached(C),
+    Fetched(U),
+}
+
+/// Cache operations that are involved in looking up osu! users.
+trait UserCache {
+    type Cached;
+    type User;
+
+    async fn fetch_user(&self, key: &str) -> Result<Option<Self::Cached>>;
+
+    async fn store_user(&self, key: &str, user: &Self::User, expire: usize) -> Result<()>;
+
+    async fn exists(&self, key: &str) -> Result<bool>;
+
+    /// Store an empty entry that only marks the key as present.
+    async fn store_missing(&self, key: &str, expire: usize) -> Result<()>;
+
+    async fn remove(&self, key: &str) -> Result<()>;
+}
+
+impl UserCache for Cache {
+    type Cached = CachedArchive<User>;
+    type User = User;
+
+    async fn fetch_user(&self, key: &str) -> Result<Option<Self::Cached>> {
+        self.fetch(key).await.map(Result::ok)
+    }
+
+    async fn store_user(&self, key: &str, user: &Self::User, expire: usize) -> Result<()> {
+        self.store_new::<_, _, 64>(key, user, expire).await
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        self.contains(key).await
+    }
+
+    async fn store_missing(&self, key: &str, expire: usize) -> Result<()> {
+        self.store_new_raw(key, &[], expire).await
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.delete(key).await
+    }
+}
+
 impl RedisData<User> {
     pub fn avatar_url(&self) -> &str {
         match self {
@@ -319,3 +472,208 @@ impl RedisData<User> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        collections::HashMap,
+    };
+
+    use super::*;
+
+    /// In-memory cache whose entries expire according to a manual clock.
+    #[derive(Default)]
+    struct MockCache {
+        /// Values of missing users are `None`
+        entries: RefCell<HashMap<String, (Option<String>, usize)>>,
+        now: Cell<usize>,
+    }
+
+    impl MockCache {
+        fn get(&self, key: &str) -> Option<Option<String>> {
+            self.entries
+                .borrow()
+                .get(key)
+                .filter(|(_, expires_at)| *expires_at > self.now.get())
+                .map(|(value, _)| value.clone())
+        }
+
+        fn insert(&self, key: &str, value: Option<String>, expire: usize) {
+            let expires_at = self.now.get() + expire;
+            self.entries
+                .borrow_mut()
+                .insert(key.to_owned(), (value, expires_at));
+        }
+    }
+
+    impl UserCache for MockCache {
+        type Cached = String;
+        type User = String;
+
+        async fn fetch_user(&self, key: &str) -> Result<Option<Self::Cached>> {
+            Ok(self.get(key).flatten())
+        }
+
+        async fn store_user(&self, key: &str, user: &Self::User, expire: usize) -> Result<()> {
+            self.insert(key, Some(user.clone()), expire);
+
+            Ok(())
+        }
+
+        async fn exists(&self, key: &str) -> Result<bool> {
+            Ok(self.get(key).is_some())
+        }
+
+        async fn store_missing(&self, key: &str, expire: usize) -> Result<()> {
+            self.insert(key, None, expire);
+
+            Ok(())
+        }
+
+        async fn remove(&self, key: &str) -> Result<()> {
+            self.entries.borrow_mut().remove(key);
+
+            Ok(())
+        }
+    }
+
+    /// Mocked osu!api request that counts how often it was awaited.
+    async fn api(calls: &Cell<usize>, name: Option<&str>) -> Result<String, OsuError> {
+        calls.set(calls.get() + 1);
+
+        name.map(str::to_owned).ok_or(OsuError::NotFound)
+    }
+
+    async fn lookup(
+        cache: &MockCache,
+        mode: GameMode,
+        api_fut: impl Future<Output = Result<String, OsuError>>,
+    ) -> Result<UserLookup<String, String>, OsuError> {
+        RedisManager::lookup_osu_user(cache, 2, mode, api_fut).await
+    }
+
+    #[tokio::test]
+    async fn lookup_caches_fetched_user() {
+        let cache = MockCache::default();
+        let calls = Cell::new(0);
+
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Ok(UserLookup::Fetched(name)) if name == "peppy"));
+
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Ok(UserLookup::Cached(name)) if name == "peppy"));
+        assert_eq!(calls.get(), 1);
+
+        // Users are cached per mode
+        let res = lookup(&cache, GameMode::Taiko, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Ok(UserLookup::Fetched(_))));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn lookup_remembers_missing_user() {
+        let cache = MockCache::default();
+        let calls = Cell::new(0);
+
+        let res = lookup(&cache, GameMode::Osu, api(&calls, None)).await;
+        assert!(matches!(res, Err(OsuError::NotFound)));
+
+        // Missing users are remembered for all modes
+        for mode in [GameMode::Osu, GameMode::Mania] {
+            let res = lookup(&cache, mode, api(&calls, Some("peppy"))).await;
+            assert!(matches!(res, Err(OsuError::NotFound)));
+        }
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            cache.get(&RedisManager::osu_user_key(2, GameMode::Osu)),
+            None
+        );
+    }
+
+    #[test]
+    fn missing_keys_are_separate_namespace() {
+        assert_eq!(
+            RedisManager::missing_osu_username_key("Badewanne3"),
+            RedisManager::missing_osu_username_key("badewanne3")
+        );
+
+        assert_ne!(
+            RedisManager::missing_osu_user_key(2),
+            RedisManager::osu_user_key(2, GameMode::Osu)
+        );
+    }
+
+    #[tokio::test]
+    async fn cached_user_expires() {
+        let cache = MockCache::default();
+        let calls = Cell::new(0);
+
+        let _ = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+
+        cache.now.set(EXPIRE - 1);
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Ok(UserLookup::Cached(_))));
+
+        cache.now.set(EXPIRE);
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy2"))).await;
+        assert!(matches!(res, Ok(UserLookup::Fetched(name)) if name == "peppy2"));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn missing_user_expires() {
+        let cache = MockCache::default();
+        let calls = Cell::new(0);
+
+        let _ = lookup(&cache, GameMode::Osu, api(&calls, None)).await;
+
+        cache.now.set(MISSING_EXPIRE - 1);
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Err(OsuError::NotFound)));
+        assert_eq!(calls.get(), 1);
+
+        cache.now.set(MISSING_EXPIRE);
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Ok(UserLookup::Fetched(_))));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn invalidation_refetches_user() {
+        let cache = MockCache::default();
+        let calls = Cell::new(0);
+
+        let _ = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        let _ = lookup(&cache, GameMode::Taiko, api(&calls, Some("peppy"))).await;
+
+        RedisManager::remove_stale_osu_user(&cache, 2, GameMode::Osu)
+            .await
+            .unwrap();
+
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy2"))).await;
+        assert!(matches!(res, Ok(UserLookup::Fetched(name)) if name == "peppy2"));
+
+        // Other modes stay cached
+        let res = lookup(&cache, GameMode::Taiko, api(&calls, Some("peppy2"))).await;
+        assert!(matches!(res, Ok(UserLookup::Cached(name)) if name == "peppy"));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn invalidation_forgets_missing_user() {
+        let cache = MockCache::default();
+        let calls = Cell::new(0);
+
+        let _ = lookup(&cache, GameMode::Osu, api(&calls, None)).await;
+
+        RedisManager::remove_stale_osu_user(&cache, 2, GameMode::Osu)
+            .await
+            .unwrap();
+
+        let res = lookup(&cache, GameMode::Osu, api(&calls, Some("peppy"))).await;
+        assert!(matches!(res, Ok(UserLookup::Fetched(_))));
+        assert_eq!(calls.get(), 2);
+    }
+}
@@ -105,6 +105,28 @@ impl UserConfigManager {
             .wrap_err("failed to update skin")
     }
 
+    /// Clears all preferences of the user and returns the names of those
+    /// that were set.
+    pub async fn reset(self, user_id: Id<UserMarker>) -> Result<Vec<&'static str>> {
+        let reset = self
+            .psql
+            .reset_user_config(user_id)
+            .await
+            .wrap_err("failed to reset user config")?;
+
+        let Some((mut config, has_skin)) = reset else {
+            return Ok(Vec::new());
+        };
+
+        let mut cleared = config.reset_preferences();
+
+        if has_skin {
+            cleared.push("skin_url");
+        }
+
+        Ok(cleared)
+    }
+
     pub async fn store(
         self,
         user_id: Id<UserMarker>,
@@ -1,9 +1,12 @@
 use std::{borrow::Cow, collections::HashMap, num::NonZeroU64, slice};
 
 use bathbot_model::rosu_v2::user::User;
-use bathbot_psql::model::osu::{TrackedOsuUserKey, TrackedOsuUserValue};
-use bathbot_util::{constants::UNKNOWN_CHANNEL, EmbedBuilder, IntHasher};
-use eyre::Report;
+use bathbot_psql::model::{
+    configs::TrackTemplate,
+    osu::{TrackedOsuUserKey, TrackedOsuUserValue},
+};
+use bathbot_util::{constants::UNKNOWN_CHANNEL, IntHasher};
+use eyre::{Report, Result, WrapErr};
 use rosu_v2::{
     prelude::{OsuError, Score},
     OsuResult,
@@ -13,10 +16,10 @@ use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType as TwilightErrorType,
 };
-use twilight_model::id::Id;
+use twilight_model::id::{marker::ChannelMarker, Id};
 
 use crate::{
-    embeds::{EmbedData, TrackNotificationEmbed},
+    embeds::TrackNotificationEmbed,
     manager::{
         redis::{osu::UserArgs, RedisData},
         OsuMap,
@@ -139,6 +142,8 @@ async fn score_loop(
     scores: &[Score],
     channels: &HashMap<NonZeroU64, u8, IntHasher>,
 ) -> OsuResult<()> {
+    let mut templates = None;
+
     for (idx, score) in (1..).zip(scores.iter()).take(max as usize) {
         // Skip if its an older score
         if score.ended_at <= last {
@@ -156,7 +161,17 @@ async fn score_loop(
             }
         };
 
-        let embed = user.embed(score, &map, idx).await?.build();
+        let data = user.embed(score, &map, idx).await?;
+
+        // Only build the variants that are actually sent
+        let mut full = None;
+        let mut compact = None;
+
+        // Only lookup templates once there is a score to notify about
+        if templates.is_none() {
+            templates = Some(channel_templates(channels).await);
+        }
+
         let http = Context::http();
         let tracking = Context::tracking();
 
@@ -166,8 +181,21 @@ async fn score_loop(
                 continue;
             }
 
+            let template = templates
+                .as_ref()
+                .and_then(|templates| templates.get(&channel));
+
+            let template = template.copied().unwrap_or_default();
+
+            let embed = match template {
+                TrackTemplate::Compact => &mut compact,
+                TrackTemplate::Full => &mut full,
+            };
+
+            let embed = embed.get_or_insert_with(|| data.build_template(template).build());
+
             let channel = Id::new(channel.get());
-            let embeds = slice::from_ref(&embed);
+            let embeds = slice::from_ref(embed);
 
             // Try to build and send the message
             match http.create_message(channel).embeds(embeds) {
@@ -206,6 +234,68 @@ async fn score_loop(
     Ok(())
 }
 
+/// Look up the notification template for each channel through the config
+/// of the channel's guild.
+///
+/// Guilds of channels are kept in memory so this only requests channels
+/// whose guild was never stored.
+async fn channel_templates(
+    channels: &HashMap<NonZeroU64, u8, IntHasher>,
+) -> HashMap<NonZeroU64, TrackTemplate, IntHasher> {
+    let mut templates = HashMap::with_capacity_and_hasher(channels.len(), IntHasher);
+
+    for &channel in channels.keys() {
+        let template = match channel_template(Id::new(channel.get())).await {
+            Ok(template) => template,
+            Err(err) => {
+                warn!(%channel, ?err, "Failed to get template for osu notif");
+
+                TrackTemplate::default()
+            }
+        };
+
+        templates.insert(channel, template);
+    }
+
+    templates
+}
+
+async fn channel_template(channel: Id<ChannelMarker>) -> Result<TrackTemplate> {
+    let tracking = Context::tracking();
+
+    let guild = match tracking.channel_guild(channel) {
+        Some(guild) => Some(guild),
+        // Channels that were tracked before their guild was stored are only
+        // requested once
+        None => {
+            let guild = Context::http()
+                .channel(channel)
+                .await
+                .wrap_err("Failed to request channel")?
+                .model()
+                .await
+                .wrap_err("Failed to deserialize channel")?
+                .guild_id;
+
+            if let Some(guild) = guild {
+                tracking.set_channel_guild(channel, guild).await?;
+            }
+
+            guild
+        }
+    };
+
+    let template = match guild {
+        Some(guild_id) => Context::guild_config()
+            .peek(guild_id, |config| config.track_template)
+            .await
+            .unwrap_or_default(),
+        None => TrackTemplate::default(),
+    };
+
+    Ok(template)
+}
+
 struct TrackUser<'u> {
     key: TrackedOsuUserKey,
     user: Option<Cow<'u, RedisData<User>>>,
@@ -220,7 +310,12 @@ impl<'u> TrackUser<'u> {
         }
     }
 
-    async fn embed(&mut self, score: &Score, map: &OsuMap, idx: u8) -> OsuResult<EmbedBuilder> {
+    async fn embed(
+        &mut self,
+        score: &Score,
+        map: &OsuMap,
+        idx: u8,
+    ) -> OsuResult<TrackNotificationEmbed> {
         let data = if let Some(user) = self.user.as_deref() {
             TrackNotificationEmbed::new(user, score, map, idx).await
         } else {
@@ -232,6 +327,6 @@ impl<'u> TrackUser<'u> {
             TrackNotificationEmbed::new(user.as_ref(), score, map, idx).await
         };
 
-        Ok(data.build())
+        Ok(data)
     }
 }
@@ -1,6 +1,7 @@
 use std::{
     cmp::Reverse,
-    collections::HashMap as StdHashMap,
+    collections::{HashMap as StdHashMap, HashSet},
+    num::NonZeroU64,
     sync::{
         atomic::{AtomicBool, Ordering},
         RwLock,
@@ -10,7 +11,7 @@ use std::{
 
 use ::time::{Duration, OffsetDateTime};
 use bathbot_psql::{
-    model::osu::{TrackedOsuUserKey, TrackedOsuUserValue},
+    model::osu::{Channels, TrackedOsuUserKey, TrackedOsuUserValue},
     Database,
 };
 use bathbot_util::IntHasher;
@@ -22,7 +23,10 @@ use once_cell::sync::OnceCell;
 use priority_queue::PriorityQueue;
 use rosu_v2::model::GameMode;
 use tokio::{sync::Mutex, time};
-use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
 
 use crate::{core::Context, manager::OsuTrackingManager};
 
@@ -32,6 +36,17 @@ pub fn default_tracking_interval() -> Duration {
     unsafe { *OSU_TRACKING_INTERVAL.get_unchecked() }
 }
 
+pub const DEFAULT_TRACK_LIMIT: u8 = 50;
+
+/// A limit that was specified explicitly takes precedence over the channel's
+/// limit which takes precedence over the guild's limit.
+pub fn resolve_limit(explicit: Option<u8>, channel: Option<u8>, guild: Option<u8>) -> u8 {
+    explicit
+        .or(channel)
+        .or(guild)
+        .unwrap_or(DEFAULT_TRACK_LIMIT)
+}
+
 type TrackingQueue =
     Mutex<PriorityQueue<TrackedOsuUserKey, Reverse<OffsetDateTime>, DefaultHashBuilder>>;
 
@@ -126,6 +141,7 @@ impl OsuTracking {
     ) -> Result<()> {
         let remove_entries = self.queue.remove_user(user_id, mode, channel).await;
         self.remove(remove_entries).await?;
+        self.forget_channel(channel).await?;
 
         Ok(())
     }
@@ -138,28 +154,31 @@ impl OsuTracking {
         let remove_entries = self.queue.remove_channel(channel, mode).await;
         let len = remove_entries.len();
         self.remove(remove_entries).await?;
+        self.forget_channel(channel).await?;
 
         Ok(len)
     }
 
     async fn remove(&self, remove: Vec<RemoveEntry>) -> Result<()> {
-        let manager = Context::osu_tracking();
+        let mut entries = Vec::with_capacity(remove.len());
 
         for remove_entry in remove {
             if remove_entry.no_longer_tracked {
-                manager.remove_user(remove_entry.key).await?;
+                entries.push((remove_entry.key, None));
             } else {
                 let guard = self.queue.users.lock(&remove_entry.key).await;
 
                 if let Some(user) = guard.get() {
-                    manager
-                        .update_channels(remove_entry.key, &user.channels)
-                        .await?;
+                    entries.push((remove_entry.key, Some(user.channels.clone())));
                 }
             }
         }
 
-        Ok(())
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        Context::osu_tracking().remove_entries(&entries).await
     }
 
     pub async fn add(
@@ -195,6 +214,116 @@ impl OsuTracking {
         self.queue.list(channel).await
     }
 
+    pub fn channel_limit(&self, channel: Id<ChannelMarker>) -> Option<u8> {
+        let limits = self.queue.limits.read().unwrap();
+
+        limits.channels.get(&channel.into_nonzero()).copied()
+    }
+
+    pub async fn set_channel_limit(
+        &self,
+        channel: Id<ChannelMarker>,
+        limit: Option<u8>,
+    ) -> Result<()> {
+        Context::osu_tracking()
+            .set_channel_limit(channel, limit)
+            .await?;
+
+        let mut limits = self.queue.limits.write().unwrap();
+        let channel = channel.into_nonzero();
+
+        match limit {
+            Some(limit) => limits.channels.insert(channel, limit),
+            None => limits.channels.remove(&channel),
+        };
+
+        Ok(())
+    }
+
+    /// Remember whether the user's limit in the channel was specified
+    /// explicitly so that it takes precedence over the channel's limit.
+    pub async fn set_explicit_limit(
+        &self,
+        key: TrackedOsuUserKey,
+        channel: Id<ChannelMarker>,
+        explicit: bool,
+    ) -> Result<()> {
+        Context::osu_tracking()
+            .set_explicit_limit(key, channel, explicit)
+            .await?;
+
+        let mut limits = self.queue.limits.write().unwrap();
+        let channel = channel.into_nonzero();
+
+        if explicit {
+            limits.explicit.insert((key, channel));
+        } else {
+            limits.explicit.remove(&(key, channel));
+        }
+
+        Ok(())
+    }
+
+    pub fn channel_guild(&self, channel: Id<ChannelMarker>) -> Option<Id<GuildMarker>> {
+        let guilds = self.queue.guilds.read().unwrap();
+
+        guilds.get(&channel.into_nonzero()).copied()
+    }
+
+    /// Notifications look up the guild's config through the channel
+    pub async fn set_channel_guild(
+        &self,
+        channel: Id<ChannelMarker>,
+        guild: Id<GuildMarker>,
+    ) -> Result<()> {
+        if self.channel_guild(channel) == Some(guild) {
+            return Ok(());
+        }
+
+        Context::osu_tracking()
+            .set_channel_guild(channel, guild)
+            .await?;
+
+        self.queue
+            .guilds
+            .write()
+            .unwrap()
+            .insert(channel.into_nonzero(), guild);
+
+        Ok(())
+    }
+
+    /// Forget the guild and the track limit of the channel once nobody is
+    /// tracked in it anymore
+    async fn forget_channel(&self, channel: Id<ChannelMarker>) -> Result<()> {
+        if !self.queue.list(channel).await.is_empty() {
+            return Ok(());
+        }
+
+        let channel_id = channel.into_nonzero();
+        let manager = Context::osu_tracking();
+
+        let removed_guild = self.queue.guilds.write().unwrap().remove(&channel_id);
+
+        if removed_guild.is_some() {
+            manager.remove_channel_guild(channel).await?;
+        }
+
+        let removed_limit = self
+            .queue
+            .limits
+            .write()
+            .unwrap()
+            .channels
+            .remove(&channel_id);
+
+        if removed_limit.is_some() {
+            manager.set_channel_limit(channel, None).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn stats(&self) -> TrackingStats {
         self.queue.stats().await
     }
@@ -203,6 +332,8 @@ impl OsuTracking {
 pub struct OsuTrackingQueue {
     queue: TrackingQueue,
     users: TokioMutexMap<TrackedOsuUserKey, TrackedOsuUserValue<IntHasher>>,
+    limits: RwLock<TrackLimits>,
+    guilds: RwLock<StdHashMap<NonZeroU64, Id<GuildMarker>, IntHasher>>,
     last_date: Mutex<OffsetDateTime>,
     pub interval: RwLock<Duration>,
     pub stop_tracking: AtomicBool,
@@ -213,7 +344,21 @@ impl OsuTrackingQueue {
     // reference here.
     #[cold]
     async fn new(psql: &Database) -> Result<Self> {
-        let users = OsuTrackingManager::new(psql).get_users().await?;
+        let manager = OsuTrackingManager::new(psql);
+        let users = manager.get_users().await?;
+
+        let limits = TrackLimits {
+            channels: manager.get_channel_limits().await?.into_iter().collect(),
+            explicit: manager.get_explicit_limits().await?.into_iter().collect(),
+        };
+
+        let guilds = manager
+            .get_channel_guilds()
+            .await?
+            .into_iter()
+            .map(|(channel, guild)| (channel, Id::from(guild)))
+            .collect();
+
         let now = OffsetDateTime::now_utc();
 
         let queue = users
@@ -226,6 +371,8 @@ impl OsuTrackingQueue {
         Ok(Self {
             queue: Mutex::new(queue),
             users,
+            limits: RwLock::new(limits),
+            guilds: RwLock::new(guilds),
             last_date: Mutex::new(now),
             interval: RwLock::new(default_tracking_interval()),
             stop_tracking: AtomicBool::new(false),
@@ -254,14 +401,22 @@ impl OsuTrackingQueue {
             })
     }
 
-    /// Returns all channels in which a user is tracked for a mode
-    /// and also the date time of the user's last top score
+    /// Returns all channels in which a user is tracked for a mode with their
+    /// resolved limit and also the date time of the user's last top score
     async fn get_tracked(&self, key: TrackedOsuUserKey) -> Option<TrackedOsuUserValue<IntHasher>> {
-        self.users
+        let mut value = self
+            .users
             .lock(&key)
             .await
             .get()
-            .map(TrackedOsuUserValue::to_owned)
+            .map(TrackedOsuUserValue::to_owned)?;
+
+        self.limits
+            .read()
+            .unwrap()
+            .resolve(key, &mut value.channels);
+
+        Some(value)
     }
 
     /// Pop a user from the queue to be checked for tracking
@@ -284,7 +439,16 @@ impl OsuTrackingQueue {
             let key = self.queue.lock().await.pop().map(|(key, _)| key)?;
             let guard = self.users.lock(&key).await;
 
-            if let Some(amount) = guard.get().and_then(|u| u.channels.values().max().copied()) {
+            let amount = guard.get().and_then(|user| {
+                let limits = self.limits.read().unwrap();
+
+                user.channels
+                    .iter()
+                    .map(|(&channel, &limit)| limits.limit(key, channel, limit))
+                    .max()
+            });
+
+            if let Some(amount) = amount {
                 return Some((key, amount));
             }
         }
@@ -308,6 +472,12 @@ impl OsuTrackingQueue {
             self.users.lock(&key).await.remove();
         }
 
+        self.limits
+            .write()
+            .unwrap()
+            .explicit
+            .retain(|(key, _)| key.user_id != user_id);
+
         to_remove
     }
 
@@ -334,6 +504,11 @@ impl OsuTrackingQueue {
             }
         }
 
+        self.limits
+            .write()
+            .unwrap()
+            .forget(&removed, channel.into_nonzero());
+
         for user_remove in removed.iter_mut() {
             let mut guard = self.users.own(user_remove.key).await;
 
@@ -360,17 +535,18 @@ impl OsuTrackingQueue {
         let mut stream = self.users.iter_mut();
 
         while let Some(mut guard) = stream.next().await {
-            if mode.map_or(true, |m| guard.key().mode == m)
-                && guard
-                    .value_mut()
-                    .channels
-                    .remove(&channel.into_nonzero())
-                    .is_some()
-            {
-                removed.push(RemoveEntry::from(guard.key()));
+            let key = *guard.key();
+
+            if untrack_channel(key, guard.value_mut(), channel, mode) {
+                removed.push(RemoveEntry::from(&key));
             }
         }
 
+        self.limits
+            .write()
+            .unwrap()
+            .forget(&removed, channel.into_nonzero());
+
         for channel_remove in removed.iter_mut() {
             let mut guard = self.users.own(channel_remove.key).await;
 
@@ -434,16 +610,22 @@ impl OsuTrackingQueue {
         }
     }
 
-    /// Returns all entries that are tracked in the channel
+    /// Returns all entries that are tracked in the channel with their resolved
+    /// limit
     async fn list(&self, channel: Id<ChannelMarker>) -> Vec<(TrackedOsuUserKey, u8)> {
+        let channel = channel.into_nonzero();
+
         self.users
             .iter()
-            .filter_map(
-                |guard| match guard.value().channels.get(&channel.into_nonzero()) {
-                    Some(limit) => future::ready(Some((*guard.key(), *limit))),
-                    None => future::ready(None),
-                },
-            )
+            .filter_map(|guard| match guard.value().channels.get(&channel) {
+                Some(&limit) => {
+                    let key = *guard.key();
+                    let limit = self.limits.read().unwrap().limit(key, channel, limit);
+
+                    future::ready(Some((key, limit)))
+                }
+                None => future::ready(None),
+            })
             .collect()
             .await
     }
@@ -476,6 +658,55 @@ impl OsuTrackingQueue {
     }
 }
 
+/// Limits that are resolved whenever a user's channels are looked up.
+#[derive(Default)]
+struct TrackLimits {
+    /// Default limit of each channel
+    channels: StdHashMap<NonZeroU64, u8, IntHasher>,
+    /// Users and the channels in which their limit was specified explicitly
+    explicit: HashSet<(TrackedOsuUserKey, NonZeroU64)>,
+}
+
+impl TrackLimits {
+    /// The limit that applies for the user in the channel.
+    ///
+    /// Users tracked without an explicit limit store the guild's limit at
+    /// the time of tracking.
+    fn limit(&self, key: TrackedOsuUserKey, channel: NonZeroU64, stored: u8) -> u8 {
+        let explicit = self.explicit.contains(&(key, channel)).then_some(stored);
+        let channel = self.channels.get(&channel).copied();
+
+        resolve_limit(explicit, channel, Some(stored))
+    }
+
+    fn resolve(&self, key: TrackedOsuUserKey, channels: &mut Channels<IntHasher>) {
+        for (&channel, limit) in channels.iter_mut() {
+            *limit = self.limit(key, channel, *limit);
+        }
+    }
+
+    /// Forget the explicit limits of users that are no longer tracked in the
+    /// channel.
+    fn forget(&mut self, removed: &[RemoveEntry], channel: NonZeroU64) {
+        for entry in removed {
+            self.explicit.remove(&(entry.key, channel));
+        }
+    }
+}
+
+/// Removes the channel from the tracked user if the mode matches.
+///
+/// Returns whether the channel was removed.
+fn untrack_channel(
+    key: TrackedOsuUserKey,
+    value: &mut TrackedOsuUserValue<IntHasher>,
+    channel: Id<ChannelMarker>,
+    mode: Option<GameMode>,
+) -> bool {
+    mode.map_or(true, |mode| key.mode == mode)
+        && value.channels.remove(&channel.into_nonzero()).is_some()
+}
+
 pub struct RemoveEntry {
     key: TrackedOsuUserKey,
     no_longer_tracked: bool,
@@ -497,3 +728,176 @@ pub enum AddEntry {
     NotAdded,
     UpdatedLimit,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracked(channels: &[u64]) -> TrackedOsuUserValue<IntHasher> {
+        let channels = channels
+            .iter()
+            .map(|&channel| (Id::<ChannelMarker>::new(channel).into_nonzero(), 50))
+            .collect();
+
+        TrackedOsuUserValue {
+            channels,
+            last_update: OffsetDateTime::now_utc(),
+        }
+    }
+
+    fn key(user_id: u32) -> TrackedOsuUserKey {
+        TrackedOsuUserKey {
+            user_id,
+            mode: GameMode::Osu,
+        }
+    }
+
+    fn channel(channel: u64) -> NonZeroU64 {
+        Id::<ChannelMarker>::new(channel).into_nonzero()
+    }
+
+    fn queue(users: &[(TrackedOsuUserKey, &[u64])]) -> OsuTrackingQueue {
+        let now = OffsetDateTime::now_utc();
+
+        let queue = users.iter().map(|(key, _)| (*key, Reverse(now))).collect();
+
+        let users = users
+            .iter()
+            .map(|(key, channels)| (*key, tracked(channels)))
+            .collect();
+
+        OsuTrackingQueue {
+            queue: Mutex::new(queue),
+            users,
+            limits: RwLock::new(TrackLimits::default()),
+            guilds: RwLock::default(),
+            last_date: Mutex::new(now),
+            interval: RwLock::new(Duration::ZERO),
+            stop_tracking: AtomicBool::new(false),
+        }
+    }
+
+    #[test]
+    fn limit_precedence() {
+        assert_eq!(resolve_limit(Some(10), Some(20), Some(30)), 10);
+        assert_eq!(resolve_limit(None, Some(20), Some(30)), 20);
+        assert_eq!(resolve_limit(None, None, Some(30)), 30);
+        assert_eq!(resolve_limit(None, None, None), DEFAULT_TRACK_LIMIT);
+    }
+
+    #[test]
+    fn channel_limit_overrides_stored_limit() {
+        let mut limits = TrackLimits::default();
+        limits.channels.insert(channel(1), 20);
+        limits.explicit.insert((key(1), channel(1)));
+
+        assert_eq!(limits.limit(key(1), channel(1), 10), 10);
+        assert_eq!(limits.limit(key(2), channel(1), 50), 20);
+        assert_eq!(limits.limit(key(2), channel(2), 50), 50);
+    }
+
+    #[tokio::test]
+    async fn lookups_resolve_channel_limit() {
+        let queue = queue(&[(key(1), &[1, 2])]);
+        queue
+            .limits
+            .write()
+            .unwrap()
+            .channels
+            .insert(channel(1), 100);
+
+        assert_eq!(queue.pop().await, Some((key(1), 100)));
+        assert_eq!(queue.list(Id::new(1)).await, [(key(1), 100)]);
+        assert_eq!(queue.list(Id::new(2)).await, [(key(1), 50)]);
+
+        let tracked = queue.get_tracked(key(1)).await.unwrap();
+        assert_eq!(tracked.channels[&channel(1)], 100);
+        assert_eq!(tracked.channels[&channel(2)], 50);
+    }
+
+    #[tokio::test]
+    async fn untracking_forgets_explicit_limits() {
+        let queue = queue(&[(key(1), &[1, 2]), (key(2), &[1]), (key(3), &[2])]);
+
+        queue.limits.write().unwrap().explicit.extend([
+            (key(1), channel(1)),
+            (key(1), channel(2)),
+            (key(2), channel(1)),
+            (key(3), channel(2)),
+        ]);
+
+        let explicit = || {
+            let mut explicit: Vec<_> = queue
+                .limits
+                .read()
+                .unwrap()
+                .explicit
+                .iter()
+                .copied()
+                .collect();
+            explicit.sort_unstable_by_key(|(key, channel)| (key.user_id, *channel));
+
+            explicit
+        };
+
+        queue.remove_channel(Id::new(1), None).await;
+        assert_eq!(explicit(), [(key(1), channel(2)), (key(3), channel(2))]);
+
+        queue.remove_user(1, None, Id::new(2)).await;
+        assert_eq!(explicit(), [(key(3), channel(2))]);
+
+        queue.remove_user_all(3).await;
+        assert!(explicit().is_empty());
+    }
+
+    #[test]
+    fn untrack_only_target_channel() {
+        let target = Id::new(1);
+        let mut users = [tracked(&[1, 2]), tracked(&[1]), tracked(&[2])];
+
+        let removed: Vec<_> = users
+            .iter_mut()
+            .zip(1..)
+            .map(|(value, user_id)| {
+                let key = TrackedOsuUserKey {
+                    user_id,
+                    mode: GameMode::Osu,
+                };
+
+                untrack_channel(key, value, target, None)
+            })
+            .collect();
+
+        assert_eq!(removed, [true, true, false]);
+
+        let other = Id::<ChannelMarker>::new(2).into_nonzero();
+        assert_eq!(users[0].channels.keys().collect::<Vec<_>>(), [&other]);
+        assert!(users[1].channels.is_empty());
+        assert_eq!(users[2].channels.keys().collect::<Vec<_>>(), [&other]);
+    }
+
+    #[test]
+    fn untrack_respects_mode() {
+        let key = TrackedOsuUserKey {
+            user_id: 2,
+            mode: GameMode::Mania,
+        };
+
+        let mut value = tracked(&[1]);
+
+        assert!(!untrack_channel(
+            key,
+            &mut value,
+            Id::new(1),
+            Some(GameMode::Osu)
+        ));
+        assert_eq!(value.channels.len(), 1);
+        assert!(untrack_channel(
+            key,
+            &mut value,
+            Id::new(1),
+            Some(GameMode::Mania)
+        ));
+        assert!(value.channels.is_empty());
+    }
+}
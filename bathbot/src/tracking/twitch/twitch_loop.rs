@@ -1,10 +1,11 @@
-use std::fmt::Write;
+use std::{fmt::Write, future::Future};
 
-use bathbot_model::TwitchUser;
+use bathbot_model::{TwitchStream, TwitchUser};
 use bathbot_util::{
     constants::{TWITCH_BASE, UNKNOWN_CHANNEL},
     AuthorBuilder, EmbedBuilder, IntHasher,
 };
+use eyre::Result;
 use hashbrown::{HashMap, HashSet};
 use rand::Rng;
 use tokio::time::{interval, Duration};
@@ -18,7 +19,7 @@ use crate::Context;
 
 #[cold]
 pub async fn twitch_tracking_loop() {
-    let mut online_streams = HashSet::with_hasher(IntHasher);
+    let mut tracking = StreamTracking::new();
     let mut interval = interval(Duration::from_secs(10 * 60));
     interval.tick().await;
 
@@ -56,39 +57,6 @@ pub async fn twitch_tracking_loop() {
             });
         }
 
-        let now_online: HashSet<_, IntHasher> =
-            streams.iter().map(|stream| stream.user_id).collect();
-
-        // If there was no activity change since last time, don't do anything
-        if now_online == online_streams {
-            continue;
-        }
-
-        // Filter streams whether its already known they're live
-        streams.retain(|stream| !online_streams.contains(&stream.user_id));
-
-        // Nothing to do if streams is empty
-        // (i.e. the change was that streamers went offline)
-        if streams.is_empty() {
-            online_streams = now_online;
-
-            continue;
-        }
-
-        let ids: Vec<_> = streams.iter().map(|s| s.user_id).collect();
-
-        let users: HashMap<_, _, IntHasher> = match client.get_twitch_users(&ids).await {
-            Ok(users) => users
-                .into_iter()
-                .map(|u| (u.user_id, TwitchUserCompact::from(u)))
-                .collect(),
-            Err(err) => {
-                warn!(?err, "Failed to retrieve twitch users");
-
-                continue;
-            }
-        };
-
         // Generate random width and height to avoid discord caching the thumbnail url
         let (width, height) = {
             let mut rng = rand::thread_rng();
@@ -99,19 +67,24 @@ pub async fn twitch_tracking_loop() {
             (width, height)
         };
 
+        let get_users = |ids: Vec<u64>| async move {
+            client.get_twitch_users(&ids).await.map(|users| {
+                users
+                    .into_iter()
+                    .map(|u| (u.user_id, TwitchUserCompact::from(u)))
+                    .collect::<HashMap<_, _, IntHasher>>()
+            })
+        };
+
         // Process each stream by notifying all corresponding channels
-        for mut stream in streams {
-            let Some(channels) = Context::tracked_channels_for(stream.user_id) else {
-                continue;
-            };
+        let notify = |mut stream: TwitchStream, user: &TwitchUserCompact| {
+            let channels = Context::tracked_channels_for(stream.user_id).unwrap_or_default();
 
             // Adjust streams' thumbnail url
             let url_len = stream.thumbnail_url.len();
             stream.thumbnail_url.truncate(url_len - 20); // cut off "{width}x{height}.jpg"
             let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
 
-            let user = &users[&stream.user_id];
-
             let embed = EmbedBuilder::new()
                 .author(AuthorBuilder::new("Now live on twitch:"))
                 .description(stream.title.as_ref())
@@ -120,20 +93,22 @@ pub async fn twitch_tracking_loop() {
                 .title(stream.username.as_ref())
                 .url(format!("{TWITCH_BASE}{}", user.display_name));
 
-            let mut channels = channels.into_iter();
-            let last = channels.next_back();
+            async move {
+                let mut channels = channels.into_iter();
+                let last = channels.next_back();
 
-            for channel in channels {
-                send_notif(embed.clone(), channel).await;
-            }
+                for channel in channels {
+                    send_notif(embed.clone(), channel).await;
+                }
 
-            // doing last one separately so we don't clone embed
-            if let Some(channel) = last {
-                send_notif(embed, channel).await;
+                // doing last one separately so we don't clone embed
+                if let Some(channel) = last {
+                    send_notif(embed, channel).await;
+                }
             }
-        }
+        };
 
-        online_streams = now_online;
+        tracking.process(streams, get_users, notify).await;
     }
 }
 
@@ -180,6 +155,86 @@ async fn send_notif(embed: EmbedBuilder, channel: Id<ChannelMarker>) {
     }
 }
 
+/// Streamers that were live during the last check alongside the last
+/// notified stream session of each streamer so that a stream which briefly
+/// shows up as offline won't be notified about twice.
+struct StreamTracking {
+    online: HashSet<u64, IntHasher>,
+    notified: HashMap<u64, u64, IntHasher>,
+}
+
+impl StreamTracking {
+    fn new() -> Self {
+        Self {
+            online: HashSet::with_hasher(IntHasher),
+            notified: HashMap::with_hasher(IntHasher),
+        }
+    }
+
+    /// Calls `notify` for each of the live `streams` that was not live during
+    /// the last check and whose stream session was not notified about yet.
+    ///
+    /// Sessions are only marked as notified after `notify` completed so if
+    /// retrieving the users fails, the next check tries again.
+    async fn process<U, UF, N, NF>(
+        &mut self,
+        mut streams: Vec<TwitchStream>,
+        get_users: U,
+        mut notify: N,
+    ) where
+        U: FnOnce(Vec<u64>) -> UF,
+        UF: Future<Output = Result<HashMap<u64, TwitchUserCompact, IntHasher>>>,
+        N: FnMut(TwitchStream, &TwitchUserCompact) -> NF,
+        NF: Future<Output = ()>,
+    {
+        let now_online: HashSet<_, IntHasher> =
+            streams.iter().map(|stream| stream.user_id).collect();
+
+        // If there was no activity change since last time, don't do anything
+        if now_online == self.online {
+            return;
+        }
+
+        // Filter streams whether its already known they're live and whether
+        // the stream session was already notified about in case of flickering
+        streams.retain(|stream| {
+            !self.online.contains(&stream.user_id)
+                && self.notified.get(&stream.user_id) != Some(&stream.stream_id)
+        });
+
+        // Nothing to do if streams is empty
+        // (i.e. the change was that streamers went offline)
+        if streams.is_empty() {
+            self.online = now_online;
+
+            return;
+        }
+
+        let ids: Vec<_> = streams.iter().map(|s| s.user_id).collect();
+
+        let users = match get_users(ids).await {
+            Ok(users) => users,
+            Err(err) => {
+                warn!(?err, "Failed to retrieve twitch users");
+
+                return;
+            }
+        };
+
+        for stream in streams {
+            let Some(user) = users.get(&stream.user_id) else {
+                continue;
+            };
+
+            let (user_id, stream_id) = (stream.user_id, stream.stream_id);
+            notify(stream, user).await;
+            self.notified.insert(user_id, stream_id);
+        }
+
+        self.online = now_online;
+    }
+}
+
 struct TwitchUserCompact {
     display_name: Box<str>,
     image_url: Box<str>,
@@ -193,3 +248,89 @@ impl From<TwitchUser> for TwitchUserCompact {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use eyre::eyre;
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    const USER_ID: u64 = 100;
+
+    fn stream(stream_id: u64) -> TwitchStream {
+        TwitchStream {
+            game_id: None,
+            stream_id,
+            thumbnail_url: String::new(),
+            title: Box::from("title"),
+            user_id: USER_ID,
+            login: Box::from("streamer"),
+            username: Box::from("Streamer"),
+            live: true,
+            started_at: OffsetDateTime::UNIX_EPOCH,
+        }
+    }
+
+    fn users() -> HashMap<u64, TwitchUserCompact, IntHasher> {
+        let user = TwitchUserCompact {
+            display_name: Box::from("streamer"),
+            image_url: Box::from(""),
+        };
+
+        let mut users = HashMap::with_hasher(IntHasher);
+        users.insert(USER_ID, user);
+
+        users
+    }
+
+    /// Runs a check in which the streamer is live with the given stream id
+    /// or offline for `None` and returns the notified stream ids.
+    async fn check(
+        tracking: &mut StreamTracking,
+        stream_id: Option<u64>,
+        users_available: bool,
+    ) -> Vec<u64> {
+        let streams = stream_id.map(stream).into_iter().collect();
+        let mut notified = Vec::new();
+
+        let get_users = |_| async move {
+            if users_available {
+                Ok(users())
+            } else {
+                Err(eyre!("twitch is down"))
+            }
+        };
+
+        let notify = |stream: TwitchStream, _: &TwitchUserCompact| {
+            notified.push(stream.stream_id);
+
+            async {}
+        };
+
+        tracking.process(streams, get_users, notify).await;
+
+        notified
+    }
+
+    #[tokio::test]
+    async fn flapping_stream_notifies_once_per_session() {
+        let mut tracking = StreamTracking::new();
+        let mut notifications = Vec::new();
+
+        for stream_id in [Some(1), None, Some(1), None, Some(2), Some(2)] {
+            notifications.extend(check(&mut tracking, stream_id, true).await);
+        }
+
+        assert_eq!(notifications, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn failed_user_request_is_retried() {
+        let mut tracking = StreamTracking::new();
+
+        assert!(check(&mut tracking, Some(1), false).await.is_empty());
+        assert_eq!(check(&mut tracking, Some(1), true).await, [1]);
+        assert!(check(&mut tracking, Some(1), true).await.is_empty());
+    }
+}
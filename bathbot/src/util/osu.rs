@@ -3,31 +3,33 @@ use std::{
     borrow::Cow,
     cmp::Ordering,
     convert::identity,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite},
+    future::Future,
     io::Cursor,
-    mem::MaybeUninit,
 };
 
 use bathbot_model::{rosu_v2::user::User, OsuStatsParams, ScoreSlim};
 use bathbot_util::{
     datetime::SecToMinSec,
     numbers::{round, WithComma},
+    osu::{BonusPP, DifficultyAdjustSettings, UserStats},
     MessageOrigin, ModsFormatter, ScoreExt,
 };
 use eyre::{Result, WrapErr};
-use futures::{stream::FuturesOrdered, StreamExt};
+use futures::{stream::FuturesOrdered, Stream, StreamExt};
 use image::{
     imageops::FilterType, DynamicImage, GenericImage, GenericImageView, ImageOutputFormat,
 };
 use rosu_pp::{
-    any::DifficultyAttributes, catch::CatchPerformance, osu::OsuPerformance,
+    any::DifficultyAttributes,
+    catch::CatchPerformance,
+    mania::{ManiaDifficultyAttributes, ManiaPerformance},
+    model::beatmap::BeatmapAttributes,
+    osu::OsuPerformance,
     taiko::TaikoPerformance,
 };
 use rosu_v2::{
-    model::mods::{
-        DifficultyAdjustCatch, DifficultyAdjustMania, DifficultyAdjustOsu, DifficultyAdjustTaiko,
-        GameMod, GameMods,
-    },
+    model::mods::GameMods,
     prelude::{GameModIntermode, GameMode, Grade, LegacyScoreStatistics, RankStatus, Score},
 };
 use time::OffsetDateTime;
@@ -42,6 +44,18 @@ pub fn grade_emote(grade: Grade) -> &'static str {
     BotConfig::get().grade(grade)
 }
 
+/// Embed color to tint a score by its grade
+pub fn grade_color(grade: Grade) -> u32 {
+    match grade {
+        Grade::XH | Grade::X | Grade::SH | Grade::S => 0xF1C40F,
+        Grade::A => 0x2ECC71,
+        Grade::B => 0x3498DB,
+        Grade::C => 0x9B59B6,
+        Grade::D => 0xE67E22,
+        Grade::F => 0x95A5A6,
+    }
+}
+
 // TODO: make struct that implements Display
 pub fn grade_completion_mods<S: ScoreExt>(
     score: &S,
@@ -92,6 +106,50 @@ fn completion(score_hits: u32, n_objects: u32) -> u32 {
     }
 }
 
+/// Describes how far into the map a failed score got by mapping its completion
+/// onto the map's drain time.
+///
+/// Returns `None` if the score is not a fail.
+pub fn fail_position<S: ScoreExt>(
+    score: &S,
+    mode: GameMode,
+    n_objects: u32,
+    seconds_drain: u32,
+) -> Option<String> {
+    if score.grade() != Grade::F || mode == GameMode::Catch {
+        return None;
+    }
+
+    let clock_rate = score.mods().clock_rate().unwrap_or(1.0);
+    let seconds_drain = (seconds_drain as f32 / clock_rate) as u32;
+    let completion = completion(score.total_hits(mode as u8), n_objects);
+
+    let position = format!(
+        "Failed at {completion}% (around `{timestamp}` of `{len}`)",
+        timestamp = SecToMinSec::new(completion_timestamp(completion, seconds_drain)),
+        len = SecToMinSec::new(seconds_drain),
+    );
+
+    Some(position)
+}
+
+fn completion_timestamp(completion: u32, seconds: u32) -> u32 {
+    seconds * completion.min(100) / 100
+}
+
+/// Estimates the bonus pp based on the user's weighted top scores.
+pub fn bonus_pp(scores: &[Score], stats: impl UserStats) -> f32 {
+    let mut bonus_pp = BonusPP::new();
+
+    for (i, score) in scores.iter().enumerate() {
+        if let Some(weight) = score.weight {
+            bonus_pp.update(weight.pp, i);
+        }
+    }
+
+    bonus_pp.calculate(stats)
+}
+
 pub struct TopCounts {
     pub top1s: Cow<'static, str>,
     pub top1s_rank: Option<String>,
@@ -109,105 +167,111 @@ pub struct TopCounts {
 }
 
 impl TopCounts {
+    const UNAVAILABLE: Cow<'static, str> = Cow::Borrowed("-");
+
     pub fn count_len(&self) -> usize {
-        self.top100s.len()
+        self.into_iter()
+            .map(|top_count| top_count.count.len())
+            .max()
+            .unwrap_or(0)
     }
 
-    pub async fn request(user: &RedisData<User>, mode: GameMode) -> Result<Self> {
-        Self::request_osustats(user, mode).await
+    /// Whether the user has no scores within the top100 of any leaderboard.
+    pub fn is_empty(&self) -> bool {
+        self.top100s == "0"
     }
 
-    async fn request_osustats(user: &RedisData<User>, mode: GameMode) -> Result<Self> {
-        let mut counts = [
-            MaybeUninit::uninit(),
-            MaybeUninit::uninit(),
-            MaybeUninit::uninit(),
-            MaybeUninit::uninit(),
-            MaybeUninit::uninit(),
-        ];
-
-        let mut params = OsuStatsParams::new(user.username());
-        params.mode(mode);
-        let mut params_clone = params.clone();
-        let mut get_amount = true;
-
-        let mut iter = [100, 50, 25, 15, 8].into_iter().zip(counts.iter_mut());
-
-        // Try to request 2 ranks concurrently
-        while let Some((next_rank, next_count)) = iter.next() {
-            if !get_amount {
-                next_count.write("0".into());
-
-                continue;
-            }
-
-            params.max_rank(next_rank);
-            let next_fut = Context::client().get_global_scores(&params);
-
-            let count = match iter.next() {
-                Some((next_next_rank, next_next_count)) => {
-                    params_clone.max_rank(next_next_rank);
-
-                    let next_next_fut = Context::client().get_global_scores(&params_clone);
+    /// Requests the counts from osustats.
+    ///
+    /// If osustats is unavailable, the error is only logged and all counts
+    /// except for the top1s will be shown as unavailable.
+    pub async fn request(user: &RedisData<User>, mode: GameMode) -> Self {
+        let top1s = match user {
+            RedisData::Original(user) => user.scores_first_count,
+            RedisData::Archive(user) => user.scores_first_count,
+        };
 
-                    let (next_raw, next_next_raw) = tokio::try_join!(next_fut, next_next_fut)
-                        .wrap_err("Failed to get global scores count")?;
+        let counts = collect_top_counts(Self::request_osustats(user.username(), mode)).await;
 
-                    let next_count_ = next_raw.count()?;
-                    let next_next_count_ = next_next_raw.count()?;
+        Self::new(top1s, counts)
+    }
 
-                    next_count.write(WithComma::new(next_count_).to_string().into());
-                    next_next_count.write(WithComma::new(next_next_count_).to_string().into());
+    fn request_osustats(
+        username: &str,
+        mode: GameMode,
+    ) -> FuturesOrdered<impl Future<Output = Result<usize>>> {
+        let mut params = OsuStatsParams::new(username);
+        params.mode(mode);
 
-                    next_next_count_
-                }
-                None => {
-                    let next_raw = next_fut
+        // Request all brackets concurrently; dropping the stream cancels
+        // whatever requests are still pending
+        [100, 50, 25, 15, 8]
+            .into_iter()
+            .map(|max_rank| {
+                let mut params = params.clone();
+                params.max_rank(max_rank);
+
+                async move {
+                    Context::client()
+                        .get_global_scores(&params)
                         .await
-                        .wrap_err("Failed to get global scores count")?;
+                        .wrap_err("Failed to get global scores count")?
+                        .count()
+                }
+            })
+            .collect()
+    }
 
-                    let next_count_ = next_raw.count()?;
-                    next_count.write(WithComma::new(next_count_).to_string().into());
+    fn new(top1s: u32, counts: Result<[Cow<'static, str>; 5]>) -> Self {
+        let [top100s, top50s, top25s, top15s, top8s] = counts.unwrap_or_else(|err| {
+            warn!(?err, "Failed to get top counts");
 
-                    next_count_
-                }
-            };
+            [Self::UNAVAILABLE; 5]
+        });
 
-            if count == 0 {
-                get_amount = false;
-            }
+        Self {
+            top1s: WithComma::new(top1s).to_string().into(),
+            top1s_rank: None,
+            top8s,
+            top8s_rank: None,
+            top15s,
+            top15s_rank: None,
+            top25s,
+            top25s_rank: None,
+            top50s,
+            top50s_rank: None,
+            top100s,
+            top100s_rank: None,
+            last_update: None,
         }
+    }
+}
 
-        let top1s = match user {
-            RedisData::Original(user) => user.scores_first_count,
-            RedisData::Archive(user) => user.scores_first_count,
+/// Collects the counts of the rank brackets, ordered from largest to smallest
+/// bracket.
+///
+/// As soon as a bracket's count is zero, all smaller brackets must be zero too
+/// so their results won't be awaited.
+async fn collect_top_counts<S>(mut counts: S) -> Result<[Cow<'static, str>; 5]>
+where
+    S: Stream<Item = Result<usize>> + Unpin,
+{
+    let mut buf = [0; 5];
+
+    for slot in buf.iter_mut() {
+        let count = match counts.next().await {
+            Some(res) => res?,
+            None => 0,
         };
 
-        let top1s = WithComma::new(top1s).to_string().into();
-
-        let [top100s, top50s, top25s, top15s, top8s] = counts;
-
-        // SAFETY: All counts were initialized in the loop
-        let this = unsafe {
-            Self {
-                top1s,
-                top1s_rank: None,
-                top8s: top8s.assume_init(),
-                top8s_rank: None,
-                top15s: top15s.assume_init(),
-                top15s_rank: None,
-                top25s: top25s.assume_init(),
-                top25s_rank: None,
-                top50s: top50s.assume_init(),
-                top50s_rank: None,
-                top100s: top100s.assume_init(),
-                top100s_rank: None,
-                last_update: None,
-            }
-        };
+        *slot = count;
 
-        Ok(this)
+        if count == 0 {
+            break;
+        }
     }
+
+    Ok(buf.map(|count| WithComma::new(count).to_string().into()))
 }
 
 pub struct TopCount<'a> {
@@ -489,7 +553,11 @@ impl IfFc {
 
                 (attrs.pp as f32, statistics, GameMode::Catch)
             }
-            DifficultyAttributes::Mania(_) => return None,
+            DifficultyAttributes::Mania(attrs) => {
+                let (pp, statistics) = Self::mania(attrs, mods, stats);
+
+                (pp, statistics, GameMode::Mania)
+            }
         };
 
         Some(Self {
@@ -499,6 +567,56 @@ impl IfFc {
         })
     }
 
+    /// Turns misses and unplayed objects into 320s and 300s according to the
+    /// score's current ratio between the two.
+    fn mania(
+        attrs: ManiaDifficultyAttributes,
+        mods: u32,
+        stats: &LegacyScoreStatistics,
+    ) -> (f32, LegacyScoreStatistics) {
+        let total_objects = attrs.n_objects;
+        let passed_objects = stats.count_geki
+            + stats.count_300
+            + stats.count_katu
+            + stats.count_100
+            + stats.count_50
+            + stats.count_miss;
+
+        let missing = total_objects.saturating_sub(passed_objects) + stats.count_miss;
+        let count_hits = passed_objects - stats.count_miss;
+
+        let ratio = if count_hits == 0 {
+            1.0
+        } else {
+            stats.count_geki as f32 / count_hits as f32
+        };
+
+        let new320s = (ratio * missing as f32).round() as u32;
+        let n320 = stats.count_geki + new320s;
+        let n300 = stats.count_300 + missing - new320s;
+
+        let attrs = ManiaPerformance::from(attrs)
+            .mods(mods)
+            .n320(n320)
+            .n300(n300)
+            .n200(stats.count_katu)
+            .n100(stats.count_100)
+            .n50(stats.count_50)
+            .misses(0)
+            .calculate();
+
+        let statistics = LegacyScoreStatistics {
+            count_geki: n320,
+            count_300: n300,
+            count_katu: stats.count_katu,
+            count_100: stats.count_100,
+            count_50: stats.count_50,
+            count_miss: 0,
+        };
+
+        (attrs.pp as f32, statistics)
+    }
+
     pub fn accuracy(&self) -> f32 {
         self.statistics.accuracy(self.mode)
     }
@@ -508,13 +626,51 @@ impl IfFc {
     }
 }
 
+/// How [`get_combined_thumbnail`] arranges the avatars.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ThumbnailLayout {
+    /// Stitch all avatars horizontally into a single 128px tall stripe.
+    #[default]
+    Strip,
+    /// Same as [`ThumbnailLayout::Strip`] for few avatars but once there are
+    /// more than [`ThumbnailLayout::GRID_THRESHOLD`], they're arranged in two
+    /// rows of square tiles instead.
+    Grid,
+}
+
+impl ThumbnailLayout {
+    const GRID_THRESHOLD: u32 = 4;
+    const MIN_TILE_SIZE: u32 = 64;
+}
+
 pub async fn get_combined_thumbnail<'s>(
     avatar_urls: impl IntoIterator<Item = &'s str>,
     amount: u32,
     width: Option<u32>,
+    layout: ThumbnailLayout,
 ) -> Result<Vec<u8>> {
     let width = width.map_or(128, |w| w.max(128));
-    let mut combined = DynamicImage::new_rgba8(width, 128);
+
+    let grid = layout == ThumbnailLayout::Grid && amount > ThumbnailLayout::GRID_THRESHOLD;
+
+    // For the grid layout, the first row holds the additional avatar if the
+    // amount is odd
+    let per_row = if grid { (amount + 1) / 2 } else { amount };
+
+    let tile = if grid {
+        (width / per_row).clamp(ThumbnailLayout::MIN_TILE_SIZE, 128)
+    } else {
+        128
+    };
+
+    let width = if grid {
+        width.max(per_row * tile)
+    } else {
+        width
+    };
+    let height = if grid { 2 * tile } else { 128 };
+
+    let mut combined = DynamicImage::new_rgba8(width, height);
     let w = (width / amount).min(128);
     let total_offset = (width - amount * w) / 2;
 
@@ -529,6 +685,24 @@ pub async fn get_combined_thumbnail<'s>(
 
     // Closure that stitches the stripe onto the combined image
     let mut img_combining = |img: DynamicImage, i: u32| {
+        if grid {
+            let img = img.resize_exact(tile, tile, FilterType::Lanczos3);
+
+            let row = i / per_row;
+            let in_row = if row == 0 { per_row } else { amount - per_row };
+            let dst_x = (width - in_row * tile) / 2 + (i % per_row) * tile;
+            let dst_y = row * tile;
+
+            for x in 0..tile {
+                for y in 0..tile {
+                    let pixel = img.get_pixel(x, y);
+                    combined.put_pixel(dst_x + x, dst_y + y, pixel);
+                }
+            }
+
+            return;
+        }
+
         let img = img.resize_exact(128, 128, FilterType::Lanczos3);
 
         let dst_offset = total_offset + i * w;
@@ -556,7 +730,7 @@ pub async fn get_combined_thumbnail<'s>(
         i += 1;
     }
 
-    let capacity = width as usize * 128;
+    let capacity = width as usize * height as usize;
     let png_bytes: Vec<u8> = Vec::with_capacity(capacity);
     let mut cursor = Cursor::new(png_bytes);
     combined.write_to(&mut cursor, ImageOutputFormat::Png)?;
@@ -593,6 +767,89 @@ impl<'a> MapInfo<'a> {
         self
     }
 
+    /// The full [`Display`] output without the `Length/BPM/Objects` line.
+    ///
+    /// That is the `CS/AR/OD/HP/Stars` line, followed by a note if any of
+    /// the attributes were set through `DifficultyAdjust`.
+    #[allow(dead_code)]
+    pub fn attributes_line(&self) -> String {
+        let mut line = String::with_capacity(64);
+        let _ = self.write_attributes(&mut line, &self.adjusted_attributes());
+
+        line
+    }
+
+    fn adjusted_attributes(&self) -> BeatmapAttributes {
+        let mods = self.mods.map_or(0, GameMods::bits);
+
+        let mut builder = self.map.attributes();
+
+        let clock_rate = self
+            .clock_rate
+            .or_else(|| self.mods.and_then(GameMods::clock_rate));
+
+        if let Some(clock_rate) = clock_rate {
+            builder = builder.clock_rate(f64::from(clock_rate));
+        }
+
+        let settings = self
+            .mods
+            .into_iter()
+            .flat_map(GameMods::iter)
+            .filter_map(DifficultyAdjustSettings::new);
+
+        for settings in settings {
+            if let Some(cs) = settings.cs {
+                builder = builder.cs(cs, false);
+            }
+
+            if let Some(ar) = settings.ar {
+                builder = builder.ar(ar, false);
+            }
+
+            if let Some(hp) = settings.hp {
+                builder = builder.hp(hp, false);
+            }
+
+            if let Some(od) = settings.od {
+                builder = builder.od(od, false);
+            }
+        }
+
+        builder.mods(mods).build()
+    }
+
+    fn write_attributes(&self, w: &mut impl FmtWrite, attrs: &BeatmapAttributes) -> FmtResult {
+        let mods = self.mods.map_or(0, GameMods::bits);
+        let overridden = OverriddenAttributes::new(self.mods);
+
+        let (cs_key, cs_value) = if self.map.mode() == GameMode::Mania {
+            ("Keys", Self::keys(mods, attrs.cs as f32))
+        } else {
+            ("CS", round(attrs.cs as f32))
+        };
+
+        write!(
+            w,
+            "{cs_key}: `{cs_value}`{cs_mark} AR: `{ar}`{ar_mark} OD: `{od}`{od_mark} \
+            HP: `{hp}`{hp_mark} Stars: `{stars}`",
+            ar = round(attrs.ar as f32),
+            od = round(attrs.od as f32),
+            hp = round(attrs.hp as f32),
+            stars = round(self.stars),
+            cs_mark = OverriddenAttributes::marker(overridden.cs),
+            ar_mark = OverriddenAttributes::marker(overridden.ar),
+            od_mark = OverriddenAttributes::marker(overridden.od),
+            hp_mark = OverriddenAttributes::marker(overridden.hp),
+        )?;
+
+        if overridden.any() {
+            w.write_str("\n\\* Set through Difficulty Adjust")?;
+        }
+
+        Ok(())
+    }
+
     pub fn keys(mods: u32, cs: f32) -> f32 {
         if (mods & GameModIntermode::OneKey.bits().unwrap()) > 0 {
             1.0
@@ -620,107 +877,7 @@ impl<'a> MapInfo<'a> {
 
 impl Display for MapInfo<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let mods = self.mods.map_or(0, GameMods::bits);
-
-        let mut builder = self.map.attributes();
-
-        let clock_rate = self
-            .clock_rate
-            .or_else(|| self.mods.and_then(GameMods::clock_rate));
-
-        if let Some(clock_rate) = clock_rate {
-            builder = builder.clock_rate(f64::from(clock_rate));
-        }
-
-        if let Some(mods) = self.mods {
-            for gamemod in mods.iter() {
-                match gamemod {
-                    GameMod::DifficultyAdjustOsu(m) => {
-                        let DifficultyAdjustOsu {
-                            circle_size,
-                            approach_rate,
-                            drain_rate,
-                            overall_difficulty,
-                            ..
-                        } = m;
-
-                        if let Some(cs) = circle_size {
-                            builder = builder.cs(*cs, false);
-                        }
-
-                        if let Some(ar) = approach_rate {
-                            builder = builder.ar(*ar, false);
-                        }
-
-                        if let Some(hp) = drain_rate {
-                            builder = builder.hp(*hp, false);
-                        }
-
-                        if let Some(od) = overall_difficulty {
-                            builder = builder.od(*od, false);
-                        }
-                    }
-                    GameMod::DifficultyAdjustTaiko(m) => {
-                        let DifficultyAdjustTaiko {
-                            drain_rate,
-                            overall_difficulty,
-                            ..
-                        } = m;
-
-                        if let Some(hp) = drain_rate {
-                            builder = builder.hp(*hp, false);
-                        }
-
-                        if let Some(od) = overall_difficulty {
-                            builder = builder.od(*od, false);
-                        }
-                    }
-                    GameMod::DifficultyAdjustCatch(m) => {
-                        let DifficultyAdjustCatch {
-                            circle_size,
-                            approach_rate,
-                            drain_rate,
-                            overall_difficulty,
-                            ..
-                        } = m;
-
-                        if let Some(cs) = circle_size {
-                            builder = builder.cs(*cs, false);
-                        }
-
-                        if let Some(ar) = approach_rate {
-                            builder = builder.ar(*ar, false);
-                        }
-
-                        if let Some(hp) = drain_rate {
-                            builder = builder.hp(*hp, false);
-                        }
-
-                        if let Some(od) = overall_difficulty {
-                            builder = builder.od(*od, false);
-                        }
-                    }
-                    GameMod::DifficultyAdjustMania(m) => {
-                        let DifficultyAdjustMania {
-                            drain_rate,
-                            overall_difficulty,
-                            ..
-                        } = m;
-
-                        if let Some(hp) = drain_rate {
-                            builder = builder.hp(*hp, false);
-                        }
-
-                        if let Some(od) = overall_difficulty {
-                            builder = builder.od(*od, false);
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        let attrs = builder.mods(mods).build();
+        let attrs = self.adjusted_attributes();
 
         let clock_rate = attrs.clock_rate;
         let mut sec_drain = self.map.seconds_drain();
@@ -733,24 +890,57 @@ impl Display for MapInfo<'_> {
             sec_drain = (sec_drain as f32 / clock_rate) as u32;
         }
 
-        let (cs_key, cs_value) = if self.map.mode() == GameMode::Mania {
-            ("Keys", Self::keys(mods, attrs.cs as f32))
-        } else {
-            ("CS", round(attrs.cs as f32))
-        };
-
-        write!(
+        writeln!(
             f,
-            "Length: `{len}` BPM: `{bpm}` Objects: `{objs}`\n\
-            {cs_key}: `{cs_value}` AR: `{ar}` OD: `{od}` HP: `{hp}` Stars: `{stars}`",
+            "Length: `{len}` BPM: `{bpm}` Objects: `{objs}`",
             len = SecToMinSec::new(sec_drain),
             bpm = round(bpm),
             objs = self.map.n_objects(),
-            ar = round(attrs.ar as f32),
-            od = round(attrs.od as f32),
-            hp = round(attrs.hp as f32),
-            stars = round(self.stars),
-        )
+        )?;
+
+        self.write_attributes(f, &attrs)
+    }
+}
+
+/// Attributes that were set manually through a `DifficultyAdjust` mod instead
+/// of being scaled by mods.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct OverriddenAttributes {
+    cs: bool,
+    ar: bool,
+    od: bool,
+    hp: bool,
+}
+
+impl OverriddenAttributes {
+    fn new(mods: Option<&GameMods>) -> Self {
+        let mut overridden = Self::default();
+
+        let settings = mods
+            .into_iter()
+            .flat_map(GameMods::iter)
+            .filter_map(DifficultyAdjustSettings::new);
+
+        for settings in settings {
+            overridden.cs |= settings.cs.is_some();
+            overridden.ar |= settings.ar.is_some();
+            overridden.hp |= settings.hp.is_some();
+            overridden.od |= settings.od.is_some();
+        }
+
+        overridden
+    }
+
+    fn any(self) -> bool {
+        self.cs || self.ar || self.od || self.hp
+    }
+
+    fn marker(overridden: bool) -> &'static str {
+        if overridden {
+            "\\*"
+        } else {
+            ""
+        }
     }
 }
 
@@ -814,6 +1004,17 @@ impl PersonalBestIndex {
         }
     }
 
+    /// The index the score has or would have in the top100, if any.
+    pub fn idx(&self) -> Option<usize> {
+        match self {
+            PersonalBestIndex::FoundScore { idx }
+            | PersonalBestIndex::Presumably { idx }
+            | PersonalBestIndex::IfRanked { idx } => Some(*idx),
+            PersonalBestIndex::ScoreV1d { would_be_idx, .. } => Some(*would_be_idx),
+            PersonalBestIndex::FoundBetter { .. } | PersonalBestIndex::NotTop100 => None,
+        }
+    }
+
     pub fn into_embed_description(self, origin: &MessageOrigin) -> Option<String> {
         match self {
             PersonalBestIndex::FoundScore { idx } => Some(format!("Personal Best #{}", idx + 1)),
@@ -841,3 +1042,209 @@ impl PersonalBestIndex {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::model::mods::{
+        DifficultyAdjustMania, DifficultyAdjustOsu, GameMod, GameModsIntermode,
+    };
+
+    use super::*;
+
+    #[test]
+    fn mania_if_fc_exceeds_score_pp() {
+        let attrs = ManiaDifficultyAttributes {
+            stars: 5.0,
+            hit_window: 40.0,
+            n_objects: 1500,
+            max_combo: 2000,
+            ..Default::default()
+        };
+
+        let stats = LegacyScoreStatistics {
+            count_geki: 1300,
+            count_300: 150,
+            count_katu: 30,
+            count_100: 12,
+            count_50: 3,
+            count_miss: 5,
+        };
+
+        let score_pp = ManiaPerformance::from(attrs.clone())
+            .n320(stats.count_geki)
+            .n300(stats.count_300)
+            .n200(stats.count_katu)
+            .n100(stats.count_100)
+            .n50(stats.count_50)
+            .misses(stats.count_miss)
+            .calculate()
+            .pp as f32;
+
+        let (fc_pp, statistics) = IfFc::mania(attrs, 0, &stats);
+
+        assert_eq!(statistics.count_miss, 0);
+        assert_eq!(
+            statistics.count_geki + statistics.count_300,
+            stats.count_geki + stats.count_300 + stats.count_miss
+        );
+        assert!(fc_pp > score_pp);
+    }
+
+    #[test]
+    fn grade_colors() {
+        assert_eq!(grade_color(Grade::XH), grade_color(Grade::S));
+        assert_eq!(grade_color(Grade::X), 0xF1C40F);
+        assert_eq!(grade_color(Grade::A), 0x2ECC71);
+        assert_eq!(grade_color(Grade::F), 0x95A5A6);
+        assert_ne!(grade_color(Grade::A), grade_color(Grade::B));
+    }
+
+    #[test]
+    fn empty_top_counts() {
+        let mut counts = TopCounts {
+            top1s: "0".into(),
+            top1s_rank: None,
+            top8s: "0".into(),
+            top8s_rank: None,
+            top15s: "0".into(),
+            top15s_rank: None,
+            top25s: "0".into(),
+            top25s_rank: None,
+            top50s: "0".into(),
+            top50s_rank: None,
+            top100s: "0".into(),
+            top100s_rank: None,
+            last_update: None,
+        };
+
+        assert!(counts.is_empty());
+
+        counts.top100s = "1,234".into();
+        assert!(!counts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn top_counts_stop_at_zero() {
+        let counts = futures::stream::iter([Ok(1234), Ok(56), Ok(0), Ok(7), Ok(8)]);
+        let counts = collect_top_counts(counts).await.unwrap();
+
+        assert_eq!(counts, ["1,234", "56", "0", "0", "0"]);
+
+        let counts = futures::stream::iter([Ok(0), Err(eyre::eyre!("unreachable"))]);
+        let counts = collect_top_counts(counts).await.unwrap();
+
+        assert_eq!(counts, ["0", "0", "0", "0", "0"]);
+    }
+
+    #[tokio::test]
+    async fn unavailable_osustats_keeps_top1s() {
+        let counts = futures::stream::iter([Err(eyre::eyre!("osustats is down"))]);
+        let counts = TopCounts::new(1234, collect_top_counts(counts).await);
+
+        let counts: Vec<_> = counts
+            .into_iter()
+            .map(|top_count| top_count.count.into_owned())
+            .collect();
+
+        assert_eq!(counts, ["1,234", "-", "-", "-", "-", "-"]);
+    }
+
+    #[test]
+    fn completion_maps_onto_drain_time() {
+        assert_eq!(completion(150, 600), 25);
+        assert_eq!(completion_timestamp(25, 200), 50);
+        assert_eq!(completion_timestamp(63, 200), 126);
+        assert_eq!(completion_timestamp(0, 200), 0);
+        assert_eq!(completion_timestamp(100, 200), 200);
+        assert_eq!(
+            SecToMinSec::new(completion_timestamp(50, 245)).to_string(),
+            "2:02"
+        );
+    }
+
+    #[test]
+    fn only_difficulty_adjusted_attributes_are_marked() {
+        let da = DifficultyAdjustOsu {
+            approach_rate: Some(10.0),
+            overall_difficulty: Some(9.0),
+            ..Default::default()
+        };
+
+        let mods: GameMods = [
+            GameMod::HardRockOsu(Default::default()),
+            GameMod::DifficultyAdjustOsu(da),
+        ]
+        .into_iter()
+        .collect();
+
+        let overridden = OverriddenAttributes::new(Some(&mods));
+
+        let expected = OverriddenAttributes {
+            cs: false,
+            ar: true,
+            od: true,
+            hp: false,
+        };
+
+        assert_eq!(overridden, expected);
+        assert_eq!(OverriddenAttributes::marker(overridden.ar), "\\*");
+        assert_eq!(OverriddenAttributes::marker(overridden.cs), "");
+    }
+
+    #[test]
+    fn no_difficulty_adjust_no_markers() {
+        let mods: GameMods = [GameMod::HardRockOsu(Default::default())]
+            .into_iter()
+            .collect();
+
+        assert!(!OverriddenAttributes::new(Some(&mods)).any());
+        assert!(!OverriddenAttributes::new(None).any());
+    }
+
+    #[test]
+    fn attributes_line_is_second_display_line() {
+        let map = OsuMap::test_map(GameMode::Mania, [8.0, 7.0, 8.0, 5.0], 120);
+
+        let mods = GameModsIntermode::try_from_acronyms("4KDT")
+            .unwrap()
+            .with_mode(GameMode::Mania);
+
+        let mut map_info = MapInfo::new(&map, 4.2);
+        map_info.mods(&mods);
+
+        let line = map_info.attributes_line();
+        let display = map_info.to_string();
+        assert_eq!(
+            display.split_once('\n').map(|(_, rest)| rest),
+            Some(line.as_str())
+        );
+        assert!(line.starts_with("Keys: `4`"));
+        assert!(!line.contains("Difficulty Adjust"));
+    }
+
+    #[test]
+    fn attributes_line_notes_difficulty_adjust() {
+        let map = OsuMap::test_map(GameMode::Mania, [8.0, 7.0, 8.0, 5.0], 120);
+
+        let da = DifficultyAdjustMania {
+            overall_difficulty: Some(9.0),
+            ..Default::default()
+        };
+
+        let mods: GameMods = [GameMod::DifficultyAdjustMania(da)].into_iter().collect();
+
+        let mut map_info = MapInfo::new(&map, 4.2);
+        map_info.mods(&mods);
+
+        let line = map_info.attributes_line();
+        let display = map_info.to_string();
+        assert_eq!(
+            display.split_once('\n').map(|(_, rest)| rest),
+            Some(line.as_str())
+        );
+
+        let (attributes, note) = line.split_once('\n').unwrap();
+        assert!(attributes.contains("OD: `9`\\*"));
+        assert_eq!(note, "\\* Set through Difficulty Adjust");
+    }
+}
@@ -121,3 +121,39 @@ impl<F> Deref for FilterCriteria<F> {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::query::TopCriteria;
+
+    #[test]
+    fn parse_all_operators() {
+        let criteria =
+            TopCriteria::create("pp!=500 stars>=7 ar<9.5 combo=1000 artist!=camellia hi");
+
+        assert!(!criteria.pp.contains(500.0));
+        assert!(criteria.pp.contains(501.0));
+        assert!(criteria.stars.contains(7.0));
+        assert!(!criteria.stars.contains(6.9));
+        assert!(criteria.ar.contains(9.4));
+        assert!(!criteria.ar.contains(9.5));
+        assert!(criteria.combo.contains(1000));
+        assert!(!criteria.combo.contains(999));
+        assert!(!criteria.artist.matches("Camellia"));
+        assert!(criteria.artist.matches("xi"));
+
+        assert_eq!(criteria.search_terms().collect::<Vec<_>>(), ["hi"]);
+    }
+
+    #[test]
+    fn not_equal_does_not_break_other_operators() {
+        let criteria = TopCriteria::create("miss!:0 miss<=3 pp>=!");
+
+        assert!(!criteria.miss.contains(0));
+        assert!(criteria.miss.contains(2));
+        assert!(criteria.miss.contains(3));
+        assert!(!criteria.miss.contains(4));
+        assert!(criteria.pp.is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+
+use super::display_range;
+use crate::util::query::{operator::Operator, optional::OptionalRange, IFilterCriteria};
+
+#[derive(Default)]
+pub struct LeaderboardCriteria {
+    pub stars: OptionalRange<f32>,
+    pub pp: OptionalRange<f32>,
+    pub ar: OptionalRange<f32>,
+    pub cs: OptionalRange<f32>,
+    pub hp: OptionalRange<f32>,
+    pub od: OptionalRange<f32>,
+    pub length: OptionalRange<f32>,
+    pub bpm: OptionalRange<f32>,
+    pub acc: OptionalRange<f32>,
+    pub combo: OptionalRange<u32>,
+    pub miss: OptionalRange<u32>,
+    pub score: OptionalRange<u32>,
+}
+
+impl<'q> IFilterCriteria<'q> for LeaderboardCriteria {
+    fn try_parse_key_value(
+        &mut self,
+        key: Cow<'q, str>,
+        value: Cow<'q, str>,
+        op: Operator,
+    ) -> bool {
+        match key.as_ref() {
+            "star" | "stars" => self.stars.try_update(op, &value, 0.005),
+            "pp" => self.pp.try_update(op, &value, 0.005),
+            "ar" => self.ar.try_update(op, &value, 0.005),
+            "dr" | "hp" => self.hp.try_update(op, &value, 0.005),
+            "cs" => self.cs.try_update(op, &value, 0.005),
+            "od" => self.od.try_update(op, &value, 0.005),
+            "bpm" => self.bpm.try_update(op, &value, 0.05),
+            "length" | "len" => super::try_update_len(&mut self.length, op, &value),
+            "acc" | "accuracy" => self.acc.try_update(op, &value, 0.005),
+            "combo" | "maxcombo" => self.combo.try_update(op, &value, 0),
+            "miss" | "nmiss" | "countmiss" | "misses" | "nmisses" => {
+                self.miss.try_update(op, &value, 0)
+            }
+            "score" => self.score.try_update(op, &value, 0),
+            _ => false,
+        }
+    }
+
+    fn any_field(&self) -> bool {
+        let Self {
+            stars,
+            pp,
+            ar,
+            cs,
+            hp,
+            od,
+            length,
+            bpm,
+            acc,
+            combo,
+            miss,
+            score,
+        } = self;
+
+        !(stars.is_empty()
+            && pp.is_empty()
+            && ar.is_empty()
+            && cs.is_empty()
+            && hp.is_empty()
+            && od.is_empty()
+            && length.is_empty()
+            && bpm.is_empty()
+            && acc.is_empty()
+            && combo.is_empty()
+            && miss.is_empty()
+            && score.is_empty())
+    }
+
+    fn display(&self, content: &mut String) {
+        let Self {
+            stars,
+            pp,
+            ar,
+            cs,
+            hp,
+            od,
+            length,
+            bpm,
+            acc,
+            combo,
+            miss,
+            score,
+        } = self;
+
+        display_range(content, "AR", ar);
+        display_range(content, "CS", cs);
+        display_range(content, "HP", hp);
+        display_range(content, "OD", od);
+        display_range(content, "Length", length);
+        display_range(content, "Stars", stars);
+        display_range(content, "BPM", bpm);
+        display_range(content, "Accuracy", acc);
+        display_range(content, "Combo", combo);
+        display_range(content, "Misses", miss);
+        display_range(content, "Score", score);
+        display_range(content, "PP", pp);
+    }
+}
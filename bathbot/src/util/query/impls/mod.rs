@@ -1,7 +1,8 @@
 use std::fmt::{Debug, Write};
 
 pub use self::{
-    bookmark::BookmarkCriteria, regular::RegularCriteria, scores::ScoresCriteria, top::TopCriteria,
+    bookmark::BookmarkCriteria, leaderboard::LeaderboardCriteria, regular::RegularCriteria,
+    scores::ScoresCriteria, top::TopCriteria,
 };
 use super::{
     operator::Operator,
@@ -10,28 +11,58 @@ use super::{
 };
 
 mod bookmark;
+mod leaderboard;
 mod regular;
 mod scores;
 mod top;
 
 fn try_update_len(length: &mut OptionalRange<f32>, op: Operator, value: &str) -> bool {
-    let Ok(len) = value.trim_end_matches(&['m', 's', 'h']).parse::<f32>() else {
+    let Some((len, scale)) = parse_len(value) else {
         return false;
     };
 
-    let scale = if value.ends_with("ms") {
-        1.0 / 1000.0
-    } else if value.ends_with('s') {
-        1.0
-    } else if value.ends_with('m') {
-        60.0
-    } else if value.ends_with('h') {
-        3_600.0
-    } else {
-        1.0
-    };
+    length.try_update_value(op, len, scale / 2.0)
+}
+
+/// Parses a length into seconds, also returning the scale of its smallest
+/// unit.
+///
+/// Accepts plain numbers, single units like `500ms`, `90s`, `2.5m`, or `1h`,
+/// and compound lengths with descending units like `1h30m` or `3m20s`.
+fn parse_len(value: &str) -> Option<(f32, f32)> {
+    if let Some(ms) = value.strip_suffix("ms") {
+        return ms.parse::<f32>().ok().map(|ms| (ms / 1000.0, 1.0 / 1000.0));
+    }
+
+    if let Ok(secs) = value.parse::<f32>() {
+        return Some((secs, 1.0));
+    }
+
+    let mut total = 0.0;
+    let mut rest = value;
+    let mut prev_scale = f32::INFINITY;
+
+    while !rest.is_empty() {
+        let unit_idx = rest.find(['h', 'm', 's'])?;
+
+        let scale = match rest.as_bytes()[unit_idx] {
+            b'h' => 3_600.0,
+            b'm' => 60.0,
+            _ => 1.0,
+        };
+
+        // Units must be descending and can only appear once
+        if scale >= prev_scale {
+            return None;
+        }
+
+        let amount = rest[..unit_idx].parse::<f32>().ok()?;
+        total += amount * scale;
+        prev_scale = scale;
+        rest = &rest[unit_idx + 1..];
+    }
 
-    length.try_update_value(op, len * scale, scale / 2.0)
+    prev_scale.is_finite().then_some((total, prev_scale))
 }
 
 fn display_range<T>(content: &mut String, name: &str, range: &OptionalRange<T>)
@@ -50,3 +81,44 @@ fn display_text(content: &mut String, name: &str, text: &OptionalText<'_>) {
         let _ = write!(content, "`{name}: {text:?}`");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn len_range(value: &str) -> Option<OptionalRange<f32>> {
+        let mut length = OptionalRange::default();
+
+        try_update_len(&mut length, Operator::Equal, value).then_some(length)
+    }
+
+    #[test]
+    fn single_unit_lengths() {
+        assert_eq!(parse_len("90"), Some((90.0, 1.0)));
+        assert_eq!(parse_len("90s"), Some((90.0, 1.0)));
+        assert_eq!(parse_len("2.5m"), Some((150.0, 60.0)));
+        assert_eq!(parse_len("1h"), Some((3_600.0, 3_600.0)));
+        assert_eq!(parse_len("500ms"), Some((0.5, 0.001)));
+    }
+
+    #[test]
+    fn compound_lengths() {
+        assert_eq!(parse_len("1m30s"), Some((90.0, 1.0)));
+        assert_eq!(parse_len("3m20s"), Some((200.0, 1.0)));
+        assert_eq!(parse_len("1h30m"), Some((5_400.0, 60.0)));
+        assert_eq!(parse_len("1h2m3s"), Some((3_723.0, 1.0)));
+
+        let length = len_range("1m30s").unwrap();
+        assert!(length.contains(90.0));
+        assert!(!length.contains(91.0));
+    }
+
+    #[test]
+    fn invalid_lengths() {
+        for value in [
+            "", "m", "abc", "1m30", "30s1m", "1m1m", "1x", "1h-s", "1.2.3m",
+        ] {
+            assert!(len_range(value).is_none(), "{value} should be invalid");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub enum Operator {
     Equal,
+    NotEqual,
     Less,
     LessOrEqual,
     Greater,
@@ -10,6 +11,7 @@ impl From<&str> for Operator {
     fn from(s: &str) -> Self {
         match s {
             "=" | ":" => Self::Equal,
+            "!=" | "!:" => Self::NotEqual,
             "<" => Self::Less,
             "<=" | "<:" => Self::LessOrEqual,
             ">" => Self::Greater,
@@ -18,3 +20,20 @@ impl From<&str> for Operator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_operators() {
+        assert!(matches!(Operator::from("="), Operator::Equal));
+        assert!(matches!(Operator::from(":"), Operator::Equal));
+        assert!(matches!(Operator::from("!="), Operator::NotEqual));
+        assert!(matches!(Operator::from("!:"), Operator::NotEqual));
+        assert!(matches!(Operator::from("<"), Operator::Less));
+        assert!(matches!(Operator::from("<="), Operator::LessOrEqual));
+        assert!(matches!(Operator::from(">"), Operator::Greater));
+        assert!(matches!(Operator::from(">="), Operator::GreaterOrEqual));
+    }
+}
@@ -8,19 +8,22 @@ use std::{
 };
 
 use bathbot_util::{datetime::DATE_FORMAT, CowUtils};
-use time::Date;
+use time::{Date, Month};
 
 use super::operator::Operator;
 
 #[derive(Default)]
 pub struct OptionalText<'q> {
     search_term: Cow<'q, str>,
+    is_negated: bool,
 }
 
 impl Debug for OptionalText<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         if self.search_term.is_empty() {
             f.write_str("<none>")
+        } else if self.is_negated {
+            write!(f, "≠{}", self.search_term)
         } else {
             f.write_str(self.search_term.as_ref())
         }
@@ -33,12 +36,14 @@ impl<'q> OptionalText<'q> {
     }
 
     pub fn matches(&self, value: &str) -> bool {
-        self.is_empty() || self.search_term == value.cow_to_ascii_lowercase()
+        self.is_empty() || (self.search_term == value.cow_to_ascii_lowercase()) != self.is_negated
     }
 
     pub fn try_update(&mut self, op: Operator, value: Cow<'q, str>) -> bool {
         match op {
-            Operator::Equal => {
+            Operator::Equal | Operator::NotEqual => {
+                self.is_negated = matches!(op, Operator::NotEqual);
+
                 self.search_term = match value {
                     Cow::Borrowed(b) => b.trim_matches('"').into(),
                     Cow::Owned(o) => {
@@ -65,6 +70,9 @@ pub struct OptionalRange<T> {
 
     is_lower_inclusive: bool,
     is_upper_inclusive: bool,
+
+    /// Inclusive bounds of values that were excluded through `!=`
+    excluded: Vec<(T, T)>,
 }
 
 impl<T> Default for OptionalRange<T> {
@@ -75,13 +83,14 @@ impl<T> Default for OptionalRange<T> {
             max: None,
             is_lower_inclusive: false,
             is_upper_inclusive: false,
+            excluded: Vec::new(),
         }
     }
 }
 
 impl<N> OptionalRange<N> {
     pub fn is_empty(&self) -> bool {
-        self.min.is_none() && self.max.is_none()
+        self.min.is_none() && self.max.is_none() && self.excluded.is_empty()
     }
 
     pub fn try_update<T>(&mut self, op: Operator, value: &str, tolerance: T) -> bool
@@ -106,6 +115,7 @@ impl<N> OptionalRange<N> {
                 self.is_lower_inclusive = true;
                 self.is_upper_inclusive = true;
             }
+            Operator::NotEqual => self.excluded.push((value - tolerance, value + tolerance)),
             Operator::Less => self.max = Some(value - tolerance),
             Operator::LessOrEqual => {
                 self.max = Some(value + tolerance);
@@ -124,14 +134,67 @@ impl<N> OptionalRange<N> {
 
 impl OptionalRange<Date> {
     pub fn try_update_date(&mut self, op: Operator, value: &str) -> bool {
-        Date::parse(value, &DATE_FORMAT).map_or(false, |date| {
-            self.try_update_value(op, date, Duration::ZERO)
-        })
+        if let Ok(date) = Date::parse(value, &DATE_FORMAT) {
+            return self.try_update_value(op, date, Duration::ZERO);
+        }
+
+        value
+            .parse()
+            .ok()
+            .filter(|year| (1000..10_000).contains(year))
+            .map_or(false, |year| self.try_update_year(op, year))
+    }
+
+    /// Updates the range so that it considers whole years e.g. `>2020` means
+    /// 2021-01-01 or later and `<=2020` means 2020-12-31 or earlier.
+    fn try_update_year(&mut self, op: Operator, year: i32) -> bool {
+        let first = Date::from_calendar_date(year, Month::January, 1);
+        let last = Date::from_calendar_date(year, Month::December, 31);
+
+        let (Ok(first), Ok(last)) = (first, last) else {
+            return false;
+        };
+
+        match op {
+            Operator::Equal => {
+                self.min = Some(first);
+                self.max = Some(last);
+                self.is_lower_inclusive = true;
+                self.is_upper_inclusive = true;
+            }
+            Operator::NotEqual => self.excluded.push((first, last)),
+            Operator::Less => self.max = Some(first),
+            Operator::LessOrEqual => {
+                self.max = Some(last);
+                self.is_upper_inclusive = true;
+            }
+            Operator::Greater => self.min = Some(last),
+            Operator::GreaterOrEqual => {
+                self.min = Some(first);
+                self.is_lower_inclusive = true;
+            }
+        }
+
+        true
     }
 }
 
 impl<T: PartialOrd> OptionalRange<T> {
     pub fn contains(&self, value: T) -> bool {
+        let is_excluded = self.excluded.iter().any(|(lower, upper)| {
+            matches!(
+                value.partial_cmp(lower),
+                Some(Ordering::Greater | Ordering::Equal)
+            ) && matches!(
+                value.partial_cmp(upper),
+                Some(Ordering::Less | Ordering::Equal)
+            )
+        });
+
+        if is_excluded {
+            return false;
+        }
+
         if let Some(ref min) = self.min {
             match value.partial_cmp(min) {
                 Some(Ordering::Less) | None => return false,
@@ -166,17 +229,45 @@ impl Debug for OptionalRange<u32> {
 
 impl Debug for OptionalRange<Date> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        fmt_eq(self, f)
+        fmt_eq_range(self, f)?;
+
+        // Dates are excluded without tolerance so differing bounds can only
+        // stem from a whole year such as `!=2020`
+        let excluded = self.excluded.iter().map(|(lower, upper)| {
+            if lower == upper {
+                lower.to_string()
+            } else {
+                lower.year().to_string()
+            }
+        });
+
+        fmt_excluded(self, excluded, f)
     }
 }
 
 fn fmt_float(optional: &OptionalRange<f32>, f: &mut Formatter<'_>) -> FmtResult {
+    fmt_float_range(optional, f)?;
+
+    // Excluded values are stored with their tolerance so show their center
+    let excluded = optional
+        .excluded
+        .iter()
+        .map(|(lower, upper)| (lower + upper) / 2.0);
+
+    fmt_excluded(optional, excluded, f)
+}
+
+fn fmt_float_range(optional: &OptionalRange<f32>, f: &mut Formatter<'_>) -> FmtResult {
     if optional.min.is_none()
         && optional.max.is_none()
         && !optional.is_lower_inclusive
         && !optional.is_upper_inclusive
     {
-        return f.write_str("..");
+        if optional.excluded.is_empty() {
+            f.write_str("..")?;
+        }
+
+        return Ok(());
     }
 
     if optional.is_lower_inclusive {
@@ -209,12 +300,28 @@ fn fmt_float(optional: &OptionalRange<f32>, f: &mut Formatter<'_>) -> FmtResult
 }
 
 fn fmt_eq<T: Copy + Display + Eq>(optional: &OptionalRange<T>, f: &mut Formatter<'_>) -> FmtResult {
+    fmt_eq_range(optional, f)?;
+
+    // No tolerance for these types so lower and upper bound coincide
+    let excluded = optional.excluded.iter().map(|(lower, _)| *lower);
+
+    fmt_excluded(optional, excluded, f)
+}
+
+fn fmt_eq_range<T: Copy + Display + Eq>(
+    optional: &OptionalRange<T>,
+    f: &mut Formatter<'_>,
+) -> FmtResult {
     if optional.min.is_none()
         && optional.max.is_none()
         && !optional.is_lower_inclusive
         && !optional.is_upper_inclusive
     {
-        return f.write_str("..");
+        if optional.excluded.is_empty() {
+            f.write_str("..")?;
+        }
+
+        return Ok(());
     } else if let Some(value) = optional
         .min
         .zip(optional.max)
@@ -252,3 +359,66 @@ fn fmt_eq<T: Copy + Display + Eq>(optional: &OptionalRange<T>, f: &mut Formatter
 
     Ok(())
 }
+
+fn fmt_excluded<T, V: Display>(
+    optional: &OptionalRange<T>,
+    excluded: impl Iterator<Item = V>,
+    f: &mut Formatter<'_>,
+) -> FmtResult {
+    let has_range = optional.min.is_some() || optional.max.is_some();
+
+    for (value, i) in excluded.zip(0..) {
+        if has_range || i > 0 {
+            f.write_str(" ")?;
+        }
+
+        write!(f, "≠{value}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_excludes_values() {
+        let mut range = OptionalRange::<f32>::default();
+        assert!(range.try_update(Operator::NotEqual, "4", 0.5));
+        assert!(range.try_update(Operator::NotEqual, "5", 0.5));
+        assert!(!range.is_empty());
+
+        assert!(!range.contains(4.0));
+        assert!(!range.contains(5.2));
+        assert!(range.contains(6.0));
+        assert_eq!(format!("{range:?}"), "≠4 ≠5");
+
+        assert!(range.try_update(Operator::GreaterOrEqual, "3", 0.0));
+        assert!(!range.contains(2.0));
+        assert!(range.contains(3.0));
+        assert_eq!(format!("{range:?}"), "[3..) ≠4 ≠5");
+    }
+
+    #[test]
+    fn text_not_equal() {
+        let mut text = OptionalText::default();
+        assert!(text.matches("anything"));
+
+        assert!(text.try_update(Operator::NotEqual, Cow::Borrowed("\"insane\"")));
+        assert!(!text.matches("Insane"));
+        assert!(text.matches("Extra"));
+        assert_eq!(format!("{text:?}"), "≠insane");
+
+        assert!(!text.try_update(Operator::Less, Cow::Borrowed("insane")));
+    }
+
+    #[test]
+    fn date_not_equal_year() {
+        let mut range = OptionalRange::<Date>::default();
+        assert!(range.try_update_date(Operator::NotEqual, "2020"));
+        assert!(range.try_update_date(Operator::NotEqual, "2022-05-17"));
+
+        assert_eq!(format!("{range:?}"), "≠2020 ≠2022-05-17");
+    }
+}
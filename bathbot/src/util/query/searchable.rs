@@ -7,6 +7,7 @@ use rosu_pp::{model::beatmap::BeatmapAttributesBuilder, Beatmap as Map};
 use rosu_v2::prelude::{
     BeatmapExtended, BeatmapsetExtended, GameModIntermode, GameMode, GameMods, Score,
 };
+use time::OffsetDateTime;
 
 use super::{FilterCriteria, RegularCriteria as RC};
 use crate::{commands::osu::TopIfEntry, manager::OsuMap};
@@ -28,8 +29,9 @@ impl Searchable<RC<'_>> for BeatmapExtended {
         matches &= criteria.bpm.contains(self.bpm);
         matches &= self.mode != GameMode::Mania || criteria.keys.contains(self.cs);
 
-        if let Some(ref mapset) = self.mapset {
-            matches &= mapset.matches(criteria);
+        match self.mapset {
+            Some(ref mapset) => matches &= mapset.matches(criteria),
+            None => matches &= criteria.ranked_date.is_empty(),
         }
 
         if matches && criteria.has_search_terms() {
@@ -71,6 +73,7 @@ impl Searchable<RC<'_>> for BeatmapsetExtended {
         matches &= criteria.artist.matches(artist.as_ref());
         matches &= criteria.creator.matches(creator.as_ref());
         matches &= criteria.title.matches(title.as_ref());
+        matches &= ranked_date_matches(criteria, self.ranked_date);
 
         if let Some(ref maps) = self.maps {
             matches &= maps.iter().any(|map| map.matches(criteria));
@@ -108,6 +111,7 @@ impl Searchable<RC<'_>> for DbBeatmapset {
         matches &= criteria.artist.matches(artist.as_ref());
         matches &= criteria.creator.matches(creator.as_ref());
         matches &= criteria.title.matches(title.as_ref());
+        matches &= ranked_date_matches(criteria, self.ranked_date);
 
         if matches && criteria.has_search_terms() {
             let terms = [artist, creator, title];
@@ -179,6 +183,9 @@ impl Searchable<RC<'_>> for Score {
             matches &= criteria.artist.matches(artist.as_ref());
             matches &= criteria.creator.matches(creator.as_ref());
             matches &= criteria.title.matches(title.as_ref());
+            matches &= ranked_date_matches(criteria, mapset.ranked_date);
+        } else if self.mapset.is_none() {
+            matches &= criteria.ranked_date.is_empty();
         }
 
         if matches && criteria.has_search_terms() {
@@ -229,6 +236,7 @@ impl Searchable<RC<'_>> for (&'_ ScoreSlim, &'_ OsuMap) {
 
         matches &= score.mode != GameMode::Mania
             || criteria.keys.contains(keys(&score.mods, attrs.cs as f32));
+        matches &= ranked_date_matches(criteria, map.ranked_date());
 
         if matches && criteria.has_search_terms() {
             let artist = map.artist().cow_to_ascii_lowercase();
@@ -251,6 +259,15 @@ impl Searchable<RC<'_>> for (&'_ ScoreSlim, &'_ OsuMap) {
     }
 }
 
+/// Maps without ranked date only match if there is no ranked date filter
+fn ranked_date_matches(
+    criteria: &FilterCriteria<RC<'_>>,
+    ranked_date: Option<OffsetDateTime>,
+) -> bool {
+    criteria.ranked_date.is_empty()
+        || ranked_date.is_some_and(|datetime| criteria.ranked_date.contains(datetime.date()))
+}
+
 fn keys(mods: &GameMods, cs: f32) -> f32 {
     [
         (GameModIntermode::OneKey, 1.0),
@@ -268,3 +285,77 @@ fn keys(mods: &GameMods, cs: f32) -> f32 {
     .find_map(|(gamemod, keys)| mods.contains_intermode(gamemod).then_some(keys))
     .unwrap_or(cs)
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, Time};
+
+    use super::*;
+    use crate::util::query::IFilterCriteria;
+
+    fn mapset(ranked_year: Option<i32>) -> DbBeatmapset {
+        let ranked_date = ranked_year.map(|year| {
+            Date::from_calendar_date(year, Month::June, 15)
+                .unwrap()
+                .with_time(Time::MIDNIGHT)
+                .assume_utc()
+        });
+
+        DbBeatmapset {
+            mapset_id: 1,
+            user_id: 2,
+            artist: "Artist".to_owned(),
+            title: "Title".to_owned(),
+            creator: "Creator".to_owned(),
+            rank_status: 1,
+            ranked_date,
+            thumbnail: String::new(),
+            cover: String::new(),
+        }
+    }
+
+    fn matching_years(query: &str) -> Vec<Option<i32>> {
+        let criteria = RC::create(query);
+
+        [
+            Some(2012),
+            Some(2015),
+            Some(2019),
+            Some(2020),
+            Some(2023),
+            None,
+        ]
+        .into_iter()
+        .filter(|&year| mapset(year).matches(&criteria))
+        .collect()
+    }
+
+    #[test]
+    fn ranked_after_year() {
+        assert_eq!(matching_years("ranked>2019"), [Some(2020), Some(2023)]);
+        assert_eq!(
+            matching_years("ranked>=2019"),
+            [Some(2019), Some(2020), Some(2023)]
+        );
+    }
+
+    #[test]
+    fn ranked_before_year() {
+        assert_eq!(matching_years("ranked<2015"), [Some(2012)]);
+        assert_eq!(matching_years("ranked<=2015"), [Some(2012), Some(2015)]);
+    }
+
+    #[test]
+    fn ranked_in_year_or_date() {
+        assert_eq!(matching_years("ranked=2020"), [Some(2020)]);
+        assert_eq!(
+            matching_years("ranked>2019-07-01 ranked<2023"),
+            [Some(2020)]
+        );
+    }
+
+    #[test]
+    fn no_ranked_filter_keeps_unranked() {
+        assert_eq!(matching_years("artist=artist").len(), 6);
+    }
+}